@@ -1,6 +1,43 @@
 export type WenNewStandard = {
-  "version": "0.0.1-alpha",
+  "version": "0.1.0-alpha",
   "name": "wen_new_standard",
+  "constants": [
+    {
+      "name": "MAX_NAME_LEN",
+      "type": {
+        "defined": "usize"
+      },
+      "value": "32"
+    },
+    {
+      "name": "MAX_SYMBOL_LEN",
+      "type": {
+        "defined": "usize"
+      },
+      "value": "10"
+    },
+    {
+      "name": "MAX_URI_LEN",
+      "type": {
+        "defined": "usize"
+      },
+      "value": "200"
+    },
+    {
+      "name": "MAX_METADATA_KEY_LEN",
+      "type": {
+        "defined": "usize"
+      },
+      "value": "32"
+    },
+    {
+      "name": "MAX_METADATA_VALUE_LEN",
+      "type": {
+        "defined": "usize"
+      },
+      "value": "256"
+    }
+  ],
   "instructions": [
     {
       "name": "createGroupAccount",
@@ -28,6 +65,16 @@ export type WenNewStandard = {
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "soulbound",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "royaltyEnforcement",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "mint",
           "isMut": true,
@@ -39,17 +86,18 @@ export type WenNewStandard = {
           "isSigner": false
         },
         {
-          "name": "manager",
+          "name": "managerAuthority",
           "isMut": false,
-          "isSigner": false
+          "isSigner": false,
+          "isOptional": true
         },
         {
-          "name": "systemProgram",
+          "name": "manager",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         },
@@ -87,13 +135,21 @@ export type WenNewStandard = {
         {
           "name": "authority",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true,
+          "docs": [
+            "group update authority and metadata update authority of the group mint"
+          ]
         },
         {
           "name": "group",
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "finalization",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "mint",
           "isMut": false,
@@ -108,6 +164,16 @@ export type WenNewStandard = {
           "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": [
@@ -120,40 +186,47 @@ export type WenNewStandard = {
       ]
     },
     {
-      "name": "createMintAccount",
+      "name": "updateGroupMetadata",
       "docs": [
-        "create mint"
+        "update the name, symbol and uri of the group mint"
       ],
       "accounts": [
         {
           "name": "payer",
           "isMut": true,
-          "isSigner": true
+          "isSigner": true,
+          "docs": [
+            "pays for metadata growth and is refunded when it shrinks"
+          ]
         },
         {
           "name": "authority",
-          "isMut": true,
-          "isSigner": true
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "group update authority, or a delegate granted `UPDATE_METADATA`"
+          ]
         },
         {
-          "name": "receiver",
+          "name": "group",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "mint",
-          "isMut": true,
-          "isSigner": true
+          "name": "groupPermission",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "mintTokenAccount",
+          "name": "mint",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "extraMetasAccount",
-          "isMut": true,
-          "isSigner": false
+          "name": "managerAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
         },
         {
           "name": "manager",
@@ -166,17 +239,17 @@ export type WenNewStandard = {
           "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "associatedTokenProgram",
+          "name": "eventAuthority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "program",
           "isMut": false,
           "isSigner": false
         }
@@ -185,15 +258,64 @@ export type WenNewStandard = {
         {
           "name": "args",
           "type": {
-            "defined": "CreateMintAccountArgs"
+            "defined": "UpdateGroupMetadataArgs"
           }
         }
       ]
     },
     {
-      "name": "addGroupToMint",
+      "name": "getMembersPage",
       "docs": [
-        "add mint to group"
+        "return a page of the group member index"
+      ],
+      "accounts": [
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "memberIndex",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "page",
+          "type": "u32"
+        }
+      ],
+      "returns": {
+        "vec": "publicKey"
+      }
+    },
+    {
+      "name": "getGroupSupply",
+      "docs": [
+        "return the size, max size and finalization of a group"
+      ],
+      "accounts": [
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "finalization",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [],
+      "returns": {
+        "defined": "GroupSupply"
+      }
+    },
+    {
+      "name": "indexGroupMember",
+      "docs": [
+        "index a member added before the group member index existed"
       ],
       "accounts": [
         {
@@ -201,10 +323,42 @@ export type WenNewStandard = {
           "isMut": true,
           "isSigner": true
         },
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "memberIndex",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeGroup",
+      "docs": [
+        "close an empty group, and its mint once the supply is burned"
+      ],
+      "accounts": [
         {
           "name": "authority",
           "isMut": true,
-          "isSigner": true
+          "isSigner": true,
+          "docs": [
+            "group update authority, refunded the freed rent by default"
+          ]
         },
         {
           "name": "group",
@@ -212,22 +366,41 @@ export type WenNewStandard = {
           "isSigner": false
         },
         {
-          "name": "member",
+          "name": "mint",
           "isMut": true,
-          "isSigner": false
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "group mint, only needed to close it along the group"
+          ]
         },
         {
-          "name": "mint",
+          "name": "closeAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "close authority of the group mint, only needed along `mint`"
+          ]
+        },
+        {
+          "name": "rentDestination",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "eventAuthority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "program",
           "isMut": false,
           "isSigner": false
         }
@@ -235,9 +408,9 @@ export type WenNewStandard = {
       "args": []
     },
     {
-      "name": "addRoyaltiesToMint",
+      "name": "finalizeGroup",
       "docs": [
-        "add royalties to mint"
+        "permanently lock the membership of a group"
       ],
       "accounts": [
         {
@@ -247,92 +420,106 @@ export type WenNewStandard = {
         },
         {
           "name": "authority",
-          "isMut": true,
+          "isMut": false,
           "isSigner": true
         },
         {
-          "name": "mint",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "extraMetasAccount",
+          "name": "group",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
+          "name": "finalization",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "associatedTokenProgram",
+          "name": "eventAuthority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "program",
           "isMut": false,
           "isSigner": false
         }
       ],
-      "args": [
-        {
-          "name": "args",
-          "type": {
-            "defined": "AddRoyaltiesArgs"
-          }
-        }
-      ]
+      "args": []
     },
     {
-      "name": "execute",
+      "name": "addGroupToGroup",
       "docs": [
-        "validate transfer"
+        "add a group as a sub-group of a parent group"
       ],
       "accounts": [
         {
-          "name": "sourceAccount",
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "update authority of the sub-group"
+          ]
+        },
+        {
+          "name": "parentAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "update authority of the parent group"
+          ]
+        },
+        {
+          "name": "group",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "mint",
+          "name": "parentGroup",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "destinationAccount",
+          "name": "hierarchy",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "parentHierarchy",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "ownerDelegate",
+          "name": "eventAuthority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "extraMetasAccount",
+          "name": "program",
           "isMut": false,
           "isSigner": false
         }
       ],
-      "args": [
-        {
-          "name": "amount",
-          "type": "u64"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "approve",
+      "name": "approveGroupDelegate",
       "docs": [
-        "approve transfer"
+        "let a delegate add members to the group on behalf of the group authority"
       ],
       "accounts": [
         {
@@ -342,308 +529,85 @@ export type WenNewStandard = {
         },
         {
           "name": "authority",
-          "isMut": true,
+          "isMut": false,
           "isSigner": true
         },
         {
-          "name": "mint",
+          "name": "group",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "approveAccount",
+          "name": "groupDelegates",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "paymentMint",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "distributionAddress",
-          "isMut": true,
+          "name": "eventAuthority",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "payerAddress",
-          "isMut": true,
+          "name": "program",
+          "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "distribution",
-          "isMut": true,
-          "isSigner": false
+          "name": "delegate",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "revokeGroupDelegate",
+      "docs": [
+        "revoke a group delegate"
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
         },
         {
-          "name": "systemProgram",
+          "name": "group",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "distributionProgram",
-          "isMut": false,
+          "name": "groupDelegates",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "eventAuthority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "associatedTokenProgram",
+          "name": "program",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "amount",
-          "type": "u64"
+          "name": "delegate",
+          "type": "publicKey"
         }
       ]
-    }
-  ],
-  "accounts": [
+    },
     {
-      "name": "approveAccount",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "slot",
-            "type": "u64"
-          }
-        ]
-      }
-    },
-    {
-      "name": "tokenGroup",
-      "docs": [
-        "Data struct for a `TokenGroup`"
-      ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "updateAuthority",
-            "docs": [
-              "The authority that can sign to update the group"
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "mint",
-            "docs": [
-              "The associated mint, used to counter spoofing to be sure that group",
-              "belongs to a particular mint"
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "size",
-            "docs": [
-              "The current number of group members"
-            ],
-            "type": "u32"
-          },
-          {
-            "name": "maxSize",
-            "docs": [
-              "The maximum number of group members"
-            ],
-            "type": "u32"
-          }
-        ]
-      }
-    },
-    {
-      "name": "manager",
-      "docs": [
-        "Data struct for a `Manager`"
-      ],
-      "type": {
-        "kind": "struct",
-        "fields": []
-      }
-    },
-    {
-      "name": "tokenGroupMember",
-      "docs": [
-        "Data struct for a `TokenGroupMember`"
-      ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "mint",
-            "docs": [
-              "The associated mint, used to counter spoofing to be sure that member",
-              "belongs to a particular mint"
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "group",
-            "docs": [
-              "The pubkey of the `TokenGroup`"
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "memberNumber",
-            "docs": [
-              "The member number"
-            ],
-            "type": "u32"
-          }
-        ]
-      }
-    }
-  ],
-  "types": [
-    {
-      "name": "CreateGroupAccountArgs",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "name",
-            "type": "string"
-          },
-          {
-            "name": "symbol",
-            "type": "string"
-          },
-          {
-            "name": "uri",
-            "type": "string"
-          },
-          {
-            "name": "maxSize",
-            "type": "u32"
-          }
-        ]
-      }
-    },
-    {
-      "name": "UpdateGroupAccountArgs",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "name",
-            "type": "string"
-          },
-          {
-            "name": "symbol",
-            "type": "string"
-          },
-          {
-            "name": "uri",
-            "type": "string"
-          },
-          {
-            "name": "maxSize",
-            "type": "u32"
-          }
-        ]
-      }
-    },
-    {
-      "name": "CreateMintAccountArgs",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "name",
-            "type": "string"
-          },
-          {
-            "name": "symbol",
-            "type": "string"
-          },
-          {
-            "name": "uri",
-            "type": "string"
-          }
-        ]
-      }
-    },
-    {
-      "name": "CreatorWithShare",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "address",
-            "type": "string"
-          },
-          {
-            "name": "share",
-            "type": "u8"
-          }
-        ]
-      }
-    },
-    {
-      "name": "AddRoyaltiesArgs",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "royaltyBasisPoints",
-            "type": "u16"
-          },
-          {
-            "name": "creators",
-            "type": {
-              "vec": {
-                "defined": "CreatorWithShare"
-              }
-            }
-          }
-        ]
-      }
-    }
-  ],
-  "errors": [
-    {
-      "code": 6000,
-      "name": "SizeExceedsMaxSize",
-      "msg": "Collection size exceeds max size."
-    },
-    {
-      "code": 6001,
-      "name": "MaxSizeBelowCurrentSize",
-      "msg": "Max size cannot be reduced below current size."
-    },
-    {
-      "code": 6002,
-      "name": "CreatorShareInvalid",
-      "msg": "Creators shares must add up to 100."
-    },
-    {
-      "code": 6003,
-      "name": "MissingApproveAccount",
-      "msg": "Missing approve account."
-    },
-    {
-      "code": 6004,
-      "name": "ExpiredApproveAccount",
-      "msg": "Approve account has expired."
-    }
-  ]
-};
-
-export const IDL: WenNewStandard = {
-  "version": "0.0.1-alpha",
-  "name": "wen_new_standard",
-  "instructions": [
-    {
-      "name": "createGroupAccount",
+      "name": "grantGroupPermission",
       "docs": [
-        "create group"
+        "let a delegate perform some group operations on behalf of the group authority, until the grant expires"
       ],
       "accounts": [
         {
@@ -654,113 +618,93 @@ export const IDL: WenNewStandard = {
         {
           "name": "authority",
           "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "receiver",
-          "isMut": false,
-          "isSigner": false
+          "isSigner": true
         },
         {
           "name": "group",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "mint",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "mintTokenAccount",
+          "name": "groupPermission",
           "isMut": true,
           "isSigner": false
         },
-        {
-          "name": "manager",
-          "isMut": false,
-          "isSigner": false
-        },
         {
           "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "eventAuthority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "associatedTokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenProgram",
+          "name": "program",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
+        {
+          "name": "delegate",
+          "type": "publicKey"
+        },
         {
           "name": "args",
           "type": {
-            "defined": "CreateGroupAccountArgs"
+            "defined": "GroupPermissionArgs"
           }
         }
       ]
     },
     {
-      "name": "updateGroupAccount",
+      "name": "revokeGroupPermission",
       "docs": [
-        "update group"
+        "revoke the permissions of a delegate"
       ],
       "accounts": [
         {
-          "name": "payer",
+          "name": "authority",
           "isMut": true,
-          "isSigner": true
+          "isSigner": true,
+          "docs": [
+            "refunded the rent of the grant"
+          ]
         },
         {
-          "name": "authority",
+          "name": "group",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "group",
+          "name": "groupPermission",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "mint",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
+          "name": "eventAuthority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "program",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "args",
-          "type": {
-            "defined": "UpdateGroupAccountArgs"
-          }
+          "name": "delegate",
+          "type": "publicKey"
         }
       ]
     },
     {
-      "name": "createMintAccount",
+      "name": "migrateGroupToNative",
       "docs": [
-        "create mint"
+        "initialize the native token group extension in the group mint"
       ],
       "accounts": [
         {
@@ -770,46 +714,74 @@ export const IDL: WenNewStandard = {
         },
         {
           "name": "authority",
-          "isMut": true,
+          "isMut": false,
           "isSigner": true
         },
         {
-          "name": "receiver",
+          "name": "group",
           "isMut": false,
           "isSigner": false
         },
         {
           "name": "mint",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "mintTokenAccount",
-          "isMut": true,
+          "name": "managerAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "manager",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "extraMetasAccount",
-          "isMut": true,
+          "name": "systemProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "manager",
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "nominateGroupAuthority",
+      "docs": [
+        "nominate a new group update authority"
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "group update authority"
+          ]
         },
         {
-          "name": "systemProgram",
+          "name": "group",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "mint",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "associatedTokenProgram",
+          "name": "nomination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         },
@@ -821,28 +793,26 @@ export const IDL: WenNewStandard = {
       ],
       "args": [
         {
-          "name": "args",
-          "type": {
-            "defined": "CreateMintAccountArgs"
-          }
+          "name": "nominee",
+          "type": "publicKey"
         }
       ]
     },
     {
-      "name": "addGroupToMint",
+      "name": "acceptGroupAuthority",
       "docs": [
-        "add mint to group"
+        "accept a group update authority nomination"
       ],
       "accounts": [
         {
-          "name": "payer",
-          "isMut": true,
+          "name": "nominee",
+          "isMut": false,
           "isSigner": true
         },
         {
           "name": "authority",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
           "name": "group",
@@ -850,20 +820,52 @@ export const IDL: WenNewStandard = {
           "isSigner": false
         },
         {
-          "name": "member",
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nomination",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "mint",
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelGroupAuthorityNomination",
+      "docs": [
+        "cancel a group update authority nomination"
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "group update authority at nomination"
+          ]
         },
         {
-          "name": "systemProgram",
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "nomination",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "tokenProgram",
           "isMut": false,
@@ -873,9 +875,9 @@ export const IDL: WenNewStandard = {
       "args": []
     },
     {
-      "name": "addRoyaltiesToMint",
+      "name": "setGroupExtensionPolicy",
       "docs": [
-        "add royalties to mint"
+        "set the extension policy of group members"
       ],
       "accounts": [
         {
@@ -885,36 +887,70 @@ export const IDL: WenNewStandard = {
         },
         {
           "name": "authority",
-          "isMut": true,
-          "isSigner": true
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "group authority or a delegate granted `SET_POLICY`"
+          ]
         },
         {
-          "name": "mint",
-          "isMut": true,
+          "name": "group",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "extraMetasAccount",
+          "name": "groupPermission",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "policy",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "systemProgram",
           "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "GroupExtensionPolicyArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setMintConfig",
+      "docs": [
+        "set the price, treasury and window of the public mint of a group"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "rent",
+          "name": "authority",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "associatedTokenProgram",
+          "name": "group",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "mintConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         }
@@ -923,54 +959,80 @@ export const IDL: WenNewStandard = {
         {
           "name": "args",
           "type": {
-            "defined": "AddRoyaltiesArgs"
+            "defined": "MintConfigArgs"
           }
         }
       ]
     },
     {
-      "name": "execute",
+      "name": "closeMintConfig",
       "docs": [
-        "validate transfer"
+        "end the public mint of a group"
       ],
       "accounts": [
         {
-          "name": "sourceAccount",
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "group",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "mint",
-          "isMut": false,
+          "name": "mintConfig",
+          "isMut": true,
           "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setMintAllowlist",
+      "docs": [
+        "set the allowlist of member mint receivers"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "destinationAccount",
+          "name": "authority",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "ownerDelegate",
+          "name": "group",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "extraMetasAccount",
+          "name": "allowlist",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "amount",
-          "type": "u64"
+          "name": "args",
+          "type": {
+            "defined": "MintAllowlistArgs"
+          }
         }
       ]
     },
     {
-      "name": "approve",
+      "name": "setGroupRoyaltyDefaults",
       "docs": [
-        "approve transfer"
+        "set the royalties inherited by group member mints"
       ],
       "accounts": [
         {
@@ -980,269 +1042,13560 @@ export const IDL: WenNewStandard = {
         },
         {
           "name": "authority",
-          "isMut": true,
-          "isSigner": true
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "group authority or a delegate granted `UPDATE_ROYALTY_DEFAULTS`"
+          ]
         },
         {
-          "name": "mint",
+          "name": "group",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "approveAccount",
+          "name": "groupPermission",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "royaltyDefaults",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "paymentMint",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "distributionAddress",
+          "name": "args",
+          "type": {
+            "defined": "GroupRoyaltyDefaultsArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setEnforcementMode",
+      "docs": [
+        "set how strictly royalties are enforced on transfers of group members, or freeze them"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "payerAddress",
-          "isMut": true,
-          "isSigner": false
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
         },
         {
-          "name": "distribution",
-          "isMut": true,
+          "name": "group",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "soulbound",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "distributionProgram",
+          "name": "royaltyEnforcement",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "eventAuthority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "associatedTokenProgram",
+          "name": "program",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "amount",
-          "type": "u64"
+          "name": "mode",
+          "type": {
+            "defined": "EnforcementMode"
+          }
         }
       ]
-    }
-  ],
-  "accounts": [
+    },
     {
-      "name": "approveAccount",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "slot",
-            "type": "u64"
-          }
-        ]
-      }
-    },
-    {
-      "name": "tokenGroup",
+      "name": "registerSecondaryHook",
       "docs": [
-        "Data struct for a `TokenGroup`"
+        "register a transfer hook program invoked after royalties on transfers of group members"
       ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "updateAuthority",
-            "docs": [
-              "The authority that can sign to update the group"
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "mint",
-            "docs": [
-              "The associated mint, used to counter spoofing to be sure that group",
-              "belongs to a particular mint"
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "size",
-            "docs": [
-              "The current number of group members"
-            ],
-            "type": "u32"
-          },
-          {
-            "name": "maxSize",
-            "docs": [
-              "The maximum number of group members"
-            ],
-            "type": "u32"
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "secondaryHook",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "programId",
+          "type": "publicKey"
+        },
+        {
+          "name": "accounts",
+          "type": {
+            "vec": {
+              "defined": "SecondaryHookAccount"
+            }
           }
-        ]
-      }
+        }
+      ]
     },
     {
-      "name": "manager",
+      "name": "unregisterSecondaryHook",
       "docs": [
-        "Data struct for a `Manager`"
+        "unregister the secondary transfer hook of a group"
       ],
-      "type": {
-        "kind": "struct",
-        "fields": []
-      }
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "refunded the rent of the secondary hook"
+          ]
+        },
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "secondaryHook",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
     },
     {
-      "name": "tokenGroupMember",
+      "name": "setMemoRequired",
       "docs": [
-        "Data struct for a `TokenGroupMember`"
+        "require transfers of group members to be annotated with a memo"
       ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "mint",
-            "docs": [
-              "The associated mint, used to counter spoofing to be sure that member",
-              "belongs to a particular mint"
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "group",
-            "docs": [
-              "The pubkey of the `TokenGroup`"
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "memberNumber",
-            "docs": [
-              "The member number"
-            ],
-            "type": "u32"
-          }
-        ]
-      }
-    }
-  ],
-  "types": [
-    {
-      "name": "CreateGroupAccountArgs",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "name",
-            "type": "string"
-          },
-          {
-            "name": "symbol",
-            "type": "string"
-          },
-          {
-            "name": "uri",
-            "type": "string"
-          },
-          {
-            "name": "maxSize",
-            "type": "u32"
-          }
-        ]
-      }
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "royaltyEnforcement",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "required",
+          "type": "bool"
+        }
+      ]
     },
     {
-      "name": "UpdateGroupAccountArgs",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "name",
-            "type": "string"
-          },
-          {
-            "name": "symbol",
-            "type": "string"
-          },
-          {
-            "name": "uri",
-            "type": "string"
-          },
-          {
-            "name": "maxSize",
-            "type": "u32"
-          }
-        ]
-      }
+      "name": "setCollectionDistribution",
+      "docs": [
+        "route royalties of group members to a single distribution of the group mint"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionDistribution",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "enabled",
+          "type": "bool"
+        }
+      ]
     },
     {
-      "name": "CreateMintAccountArgs",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "name",
-            "type": "string"
-          },
-          {
+      "name": "setRoyaltyTimelock",
+      "docs": [
+        "set the delay before royalty updates of group members take effect"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "royaltyTimelock",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "delay",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "cancelRoyaltyUpdate",
+      "docs": [
+        "cancel a pending royalty update of a group member"
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pendingRoyaltyUpdate",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "addExemption",
+      "docs": [
+        "exempt an owner or program from royalties on transfers of group members"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "royaltyExemptions",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "address",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "removeExemption",
+      "docs": [
+        "remove a royalty exemption of the group"
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "royaltyExemptions",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "address",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "createMintAccount",
+      "docs": [
+        "create mint"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "mint authority, can be a PDA signing through CPI"
+          ]
+        },
+        {
+          "name": "receiver",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "mintTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "extraMetasAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "mintRecord",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "manager",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "global manager, or the manager namespaced to `args.manager_authority`"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "CreateMintAccountArgs"
+          }
+        }
+      ],
+      "returns": {
+        "defined": "CreatedMintAccount"
+      }
+    },
+    {
+      "name": "createMintAccountPda",
+      "docs": [
+        "create mint at a PDA derived from the authority and a user seed"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "`user_seed` is checked here, before the mint is derived from it"
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "mint authority, can be a PDA signing through CPI"
+          ]
+        },
+        {
+          "name": "receiver",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mintTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "extraMetasAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "mintRecord",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "manager",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "global manager, or the manager namespaced to `args.manager_authority`"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "CreateMintAccountArgs"
+          }
+        },
+        {
+          "name": "userSeed",
+          "type": "string"
+        }
+      ],
+      "returns": {
+        "defined": "CreatedMintAccount"
+      }
+    },
+    {
+      "name": "createMintAccountsBatch",
+      "docs": [
+        "create multiple mints"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "receiver",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "managerAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "manager",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "vec": {
+              "defined": "CreateMintAccountArgs"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "clawback",
+      "docs": [
+        "claw back a mint from its holder using the manager permanent delegate"
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sourceTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destinationTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "managerAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "manager",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initManagerForAuthority",
+      "docs": [
+        "create a manager namespaced to the authority"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "manager",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "thawMintAccount",
+      "docs": [
+        "thaw a mint token account"
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mintTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "managerAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "manager",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "burnMintAccount",
+      "docs": [
+        "burn a mint token account of the holder, no transfer approval needed"
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "holder of the token account, refunded its rent"
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mintTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "group",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "group of the member, only needed for group members"
+          ]
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "memberCounter",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "for group members"
+          ]
+        },
+        {
+          "name": "memberIndex",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "mintRecord",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "mintRecordPayer",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeMintRecord",
+      "docs": [
+        "close the record of a burned mint, signed by the record payer until the mint authority is revoked"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "authority is set"
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mintRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createMintAccountUnminted",
+      "docs": [
+        "create mint without minting it"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "extraMetasAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "mintRecord",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "manager",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "global manager, or the manager namespaced to `args.manager_authority`"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "CreateMintAccountArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "mintToClaimer",
+      "docs": [
+        "mint an unminted mint to its claimer"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "metadata update authority of the mint, or for group members the group authority, one of",
+            "its delegates or a delegate granted `ADD_MEMBER`"
+          ]
+        },
+        {
+          "name": "claimer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "claimerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "managerAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "manager",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "group of the member, only needed when the authority claims on behalf of the group"
+          ]
+        },
+        {
+          "name": "groupDelegates",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "groupPermission",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "needed with the group"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "finalizeMint",
+      "docs": [
+        "revoke the manager mint authority"
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "managerAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "manager",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "addGroupToMint",
+      "docs": [
+        "add mint to group"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "group authority, one of its delegates or a delegate granted `ADD_MEMBER`, also the",
+            "transfer hook authority of mints whose transfer hook doesn't invoke WNS yet"
+          ]
+        },
+        {
+          "name": "group",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "groupDelegates",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "groupPermission",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "memberCounter",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "last member number assigned in the group"
+          ]
+        },
+        {
+          "name": "memberIndex",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "finalization",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "soulbound",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "policy",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "extraMetasAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [],
+      "returns": "u32"
+    },
+    {
+      "name": "addGroupMembersBatch",
+      "docs": [
+        "add up to `MAX_MEMBER_BATCH_SIZE` mints to group"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "group authority, one of its delegates or a delegate granted `ADD_MEMBER`"
+          ]
+        },
+        {
+          "name": "group",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "groupDelegates",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "groupPermission",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "memberCounter",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "last member number assigned in the group"
+          ]
+        },
+        {
+          "name": "finalization",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "soulbound",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "policy",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [],
+      "returns": {
+        "vec": "u32"
+      }
+    },
+    {
+      "name": "moveMember",
+      "docs": [
+        "move a member mint to another group, signed by both group authorities"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "sourceAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "source group authority, one of its delegates or a delegate granted `ADD_MEMBER`"
+          ]
+        },
+        {
+          "name": "sourceGroup",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sourceGroupDelegates",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sourceGroupPermission",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sourceMemberCounter",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "last member number assigned in the source group, created before the size shrinks"
+          ]
+        },
+        {
+          "name": "sourceMemberIndex",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destinationAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "destination group authority, one of its delegates or a delegate granted `ADD_MEMBER`"
+          ]
+        },
+        {
+          "name": "destinationGroup",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destinationGroupDelegates",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "destinationGroupPermission",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "destinationMemberCounter",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "last member number assigned in the destination group"
+          ]
+        },
+        {
+          "name": "destinationMemberIndex",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destinationFinalization",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "destinationSoulbound",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "destinationPolicy",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "extraMetasAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [],
+      "returns": "u32"
+    },
+    {
+      "name": "publicMint",
+      "docs": [
+        "create a member mint of a group for the price of its public mint"
+      ],
+      "accounts": [
+        {
+          "name": "minter",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "pays the price and receives the member mint"
+          ]
+        },
+        {
+          "name": "group",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mintConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "publicMintClaim",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "member mints received by the minter through the public mint"
+          ]
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "paymentMint",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "only needed for token prices"
+          ]
+        },
+        {
+          "name": "minterTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "token account of the minter, only needed for token prices"
+          ]
+        },
+        {
+          "name": "treasuryTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "associated token account of the treasury, only needed for token prices"
+          ]
+        },
+        {
+          "name": "paymentTokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "token program of the payment mint, unused for SOL prices"
+          ]
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "memberCounter",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "last member number assigned in the group"
+          ]
+        },
+        {
+          "name": "memberIndex",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "finalization",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "soulbound",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "policy",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "royaltyDefaults",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "hierarchy",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "parentRoyaltyDefaults",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "mintTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "extraMetasAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "managerAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "manager",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateMemberToNative",
+      "docs": [
+        "initialize the native token group member extension in a member mint"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "memberPointerAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "group member pointer authority of the mint, usually its creator"
+          ]
+        },
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "groupMint",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "group mint, migrated with `migrate_group_to_native`"
+          ]
+        },
+        {
+          "name": "managerAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "manager",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "removeGroupFromMint",
+      "docs": [
+        "remove mint from its group, closing the member account"
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "group update authority and group member pointer authority of the mint,",
+            "refunded the freed rent by default"
+          ]
+        },
+        {
+          "name": "group",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "memberCounter",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "last member number assigned in the group, created before the size shrinks"
+          ]
+        },
+        {
+          "name": "memberIndex",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "extraMetasAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentDestination",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createGroupMemberMint",
+      "docs": [
+        "create mint and add it to group"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "mint authority, must be the group authority, one of its delegates or a delegate granted",
+            "`ADD_MEMBER` since the mint joins the group"
+          ]
+        },
+        {
+          "name": "receiver",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "group",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "groupDelegates",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "groupPermission",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "memberCounter",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "last member number assigned in the group"
+          ]
+        },
+        {
+          "name": "memberIndex",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "finalization",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "soulbound",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "policy",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "allowlist",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "allowlistClaim",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "receiver mint count, only needed when the group has an allowlist"
+          ]
+        },
+        {
+          "name": "royaltyDefaults",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "hierarchy",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "parentRoyaltyDefaults",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "groupMint",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "migrated to the native token group extension, behind the `native-group` feature"
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "mintTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "extraMetasAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "mintRecord",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "manager",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "global manager, or the manager namespaced to `args.manager_authority`"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "CreateMintAccountArgs"
+          }
+        },
+        {
+          "name": "proof",
+          "type": {
+            "option": {
+              "vec": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "enableEditions",
+      "docs": [
+        "enable numbered editions of a master mint"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "metadata update authority of the master mint"
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "masterEdition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "EnableEditionsArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "printEdition",
+      "docs": [
+        "print the next edition of a master mint"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "metadata update authority of the master mint, mint authority of the edition"
+          ]
+        },
+        {
+          "name": "receiver",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "masterMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "masterMetadataAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "masterEdition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "group",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "masterMember",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "memberCounter",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "last member number assigned in the group"
+          ]
+        },
+        {
+          "name": "memberIndex",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "finalization",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "policy",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "mintTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "extraMetasAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "managerAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "manager",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "addMetadata",
+      "docs": [
+        "add metadata to mint"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "metadata update authority of the mint"
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "policy",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "AddMetadataArgs"
+          }
+        },
+        {
+          "name": "mode",
+          "type": {
+            "option": {
+              "defined": "MetadataWriteMode"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "addMetadataBatch",
+      "docs": [
+        "add multiple metadata fields to mint"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "metadata update authority of the mint"
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "policy",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "vec": {
+              "defined": "AddMetadataArgs"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "removeMetadata",
+      "docs": [
+        "remove metadata from mint"
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "metadata update authority of the mint, refunded the freed rent by default"
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "rentDestination",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "managerAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "manager",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "RemoveMetadataArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateMintMetadata",
+      "docs": [
+        "update the name, symbol and uri of mint"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "pays for metadata growth and is refunded when it shrinks"
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "metadata update authority of the mint"
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "managerAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "manager",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "UpdateMintMetadataArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "nominateMetadataAuthority",
+      "docs": [
+        "nominate a new metadata update authority"
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "metadata update authority of the mint"
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "nomination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "nominee",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "acceptMetadataAuthority",
+      "docs": [
+        "accept a metadata update authority nomination"
+      ],
+      "accounts": [
+        {
+          "name": "nominee",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "nomination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelNomination",
+      "docs": [
+        "cancel a metadata update authority nomination"
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "metadata update authority at nomination"
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "nomination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setContentHash",
+      "docs": [
+        "set or clear the content hash of mint"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "metadata update authority of the mint"
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "contentHash",
+          "type": {
+            "option": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "verifyContentHash",
+      "docs": [
+        "check a hash against the content hash of mint"
+      ],
+      "accounts": [
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "contentHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ],
+      "returns": "bool"
+    },
+    {
+      "name": "makeImmutable",
+      "docs": [
+        "make mint metadata immutable"
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "metadata update authority and metadata pointer authority of the mint"
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "freezeMetadata",
+      "docs": [
+        "remove the metadata update authority once the collection is revealed, same as `make_immutable`"
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "metadata update authority and metadata pointer authority of the mint"
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "addRoyaltiesToMint",
+      "docs": [
+        "add royalties to mint"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "extraMetasAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "policy",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "AddRoyaltiesArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "modifyRoyalties",
+      "docs": [
+        "modify royalties of mint"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "metadata update authority of the mint"
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "extraMetasAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "verifiedCreators",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "group of the member, only needed for group members"
+          ]
+        },
+        {
+          "name": "royaltyDefaults",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "policy",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "royaltyTimelock",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "pendingRoyaltyUpdate",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "royalties queued for timelocked group members"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "AddRoyaltiesArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "applyRoyaltyUpdate",
+      "docs": [
+        "apply pending royalties of a timelocked group member"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "metadata update authority of the mint"
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "extraMetasAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "verifiedCreators",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "group of the member, only needed for group members"
+          ]
+        },
+        {
+          "name": "royaltyDefaults",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "policy",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "royaltyTimelock",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "pendingRoyaltyUpdate",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "royalties queued for timelocked group members"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "removeRoyalties",
+      "docs": [
+        "remove royalties of mint"
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "metadata update authority of the mint, refunded the freed rent by default"
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "extraMetasAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "verifiedCreators",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "group of the member, only needed for group members"
+          ]
+        },
+        {
+          "name": "royaltyDefaults",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "policy",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "rentDestination",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "managerAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "manager",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "verifyCreator",
+      "docs": [
+        "verify the signing creator of mint royalties"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "creator",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "creator listed in the mint royalties"
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "verifiedCreators",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "unverifyCreator",
+      "docs": [
+        "unverify the signing creator of mint royalties"
+      ],
+      "accounts": [
+        {
+          "name": "creator",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "verified creator of the mint"
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "verifiedCreators",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeExpiredApproval",
+      "docs": [
+        "close a consumed or expired approval"
+      ],
+      "accounts": [
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "approveAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initProvenance",
+      "docs": [
+        "opt a mint in to the provenance log of its transfers"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "provenance",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "extraMetasAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "updateExtraMetasList",
+      "docs": [
+        "rewrite the extra metas of a mint to its canonical list"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "funds the growth of the extra metas account and is refunded when it shrinks"
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "extraMetasAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "secondaryHook",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "execute",
+      "docs": [
+        "validate transfer"
+      ],
+      "accounts": [
+        {
+          "name": "sourceAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "destinationAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ownerDelegate",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "extraMetasAccount",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "approve",
+      "docs": [
+        "approve transfer"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "approveAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "paymentMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "distributionAddress",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payerAddress",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "distribution",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "distributionEventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "distributionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "group of the member, only needed for group members"
+          ]
+        },
+        {
+          "name": "collectionDistribution",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "royaltyDefaults",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "sourceTokenAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "token account the approved transfer moves from, records its owner as seller when",
+            "approved by its delegate"
+          ]
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "expirySlot",
+          "type": {
+            "option": "u64"
+          }
+        },
+        {
+          "name": "transferAmount",
+          "type": "u64"
+        },
+        {
+          "name": "destination",
+          "type": "publicKey"
+        },
+        {
+          "name": "seller",
+          "type": "publicKey"
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "mintAllowlist",
+      "docs": [
+        "Merkle allowlist of the receivers of member mints, set by the group authority"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "group",
+            "docs": [
+              "The pubkey of the `TokenGroup`"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "root",
+            "docs": [
+              "Root of the Merkle tree of allowed receivers, see `verify_allowlist_proof`"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "limitPerWallet",
+            "docs": [
+              "Maximum number of member mints per receiver"
+            ],
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "allowlistClaim",
+      "docs": [
+        "Number of member mints received by a wallet through the group allowlist"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "count",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "approveAccount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "slot",
+            "docs": [
+              "Slot of the approval, 0 once consumed by a transfer"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "expirySlot",
+            "docs": [
+              "Last slot in which the approval can be consumed"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "payer",
+            "docs": [
+              "Funded the account rent, refunded when the expired approval is closed"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "Token amount of the approved transfer"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "destination",
+            "docs": [
+              "Destination owner of the approved transfer"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "seller",
+            "docs": [
+              "Source owner of the approved transfer, as declared by the marketplace"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "buyer",
+            "docs": [
+              "Authority that paid the sale and its royalties"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "salePrice",
+            "docs": [
+              "Sale amount the royalties were taken from"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "paymentMint",
+            "docs": [
+              "Mint the royalties were paid in, `Pubkey::default()` for SOL"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "royaltyAmount",
+            "docs": [
+              "Royalty amount paid for the approved transfer"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "collectionDistribution",
+      "docs": [
+        "Routes the royalties of group members to a single distribution of the group mint, set by",
+        "the group authority"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "group",
+            "docs": [
+              "The pubkey of the `TokenGroup`"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "enabled",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "masterEdition",
+      "docs": [
+        "Numbered copies authorized for a master mint, printed through `print_edition`"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "docs": [
+              "The master mint"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "maxSupply",
+            "docs": [
+              "Maximum number of editions"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "currentSupply",
+            "docs": [
+              "Number of editions printed, also the number of the last edition"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "tokenGroup",
+      "docs": [
+        "Data struct for a `TokenGroup`"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "updateAuthority",
+            "docs": [
+              "The authority that can sign to update the group"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "The associated mint, used to counter spoofing to be sure that group",
+              "belongs to a particular mint"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "size",
+            "docs": [
+              "The current number of group members. Counts member mints, not the",
+              "supply of each mint"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "maxSize",
+            "docs": [
+              "The maximum number of group members, 0 for unlimited"
+            ],
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "groupFinalization",
+      "docs": [
+        "Marks a group as finalized, no member can ever be added once it exists"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "group",
+            "docs": [
+              "The pubkey of the `TokenGroup`"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "size",
+            "docs": [
+              "Size of the group at finalization"
+            ],
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "groupSoulbound",
+      "docs": [
+        "Marks a group as soulbound, set at group creation and never removed. Members of soulbound",
+        "groups must be non-transferable and the transfer hook treats the group as `Frozen`"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "group",
+            "docs": [
+              "The pubkey of the `TokenGroup`"
+            ],
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "groupDelegates",
+      "docs": [
+        "Signers allowed to add members to a group on behalf of the group authority, e.g. launchpads"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "group",
+            "docs": [
+              "The pubkey of the `TokenGroup`"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "delegates",
+            "docs": [
+              "Wallets or program PDAs signing through CPI"
+            ],
+            "type": {
+              "vec": "publicKey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "groupPermission",
+      "docs": [
+        "Operations a delegate may perform on a group on behalf of the group authority, until",
+        "the grant expires or is revoked"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "group",
+            "docs": [
+              "The pubkey of the `TokenGroup`"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "delegate",
+            "type": "publicKey"
+          },
+          {
+            "name": "permissions",
+            "docs": [
+              "Bitmask of the `GroupPermission` operation flags"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "expiresAt",
+            "docs": [
+              "Unix timestamp the grant expires at, never expires when `None`"
+            ],
+            "type": {
+              "option": "i64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "groupHierarchy",
+      "docs": [
+        "Position of a group in a hierarchy of groups, e.g. seasons of a parent collection"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "group",
+            "docs": [
+              "The pubkey of the `TokenGroup`, unset until the group joins a hierarchy"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "parent",
+            "docs": [
+              "The parent group, `None` for top level groups"
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "ancestors",
+            "docs": [
+              "All the ancestors of the group, parent first"
+            ],
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "children",
+            "docs": [
+              "Number of sub-groups of the group"
+            ],
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "manager",
+      "docs": [
+        "Data struct for a `Manager`"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": []
+      }
+    },
+    {
+      "name": "tokenGroupMember",
+      "docs": [
+        "Data struct for a `TokenGroupMember`"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "docs": [
+              "The associated mint, used to counter spoofing to be sure that member",
+              "belongs to a particular mint"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "group",
+            "docs": [
+              "The pubkey of the `TokenGroup`"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "memberNumber",
+            "docs": [
+              "The member number"
+            ],
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "groupMemberCounter",
+      "docs": [
+        "Last member number assigned in a group. Numbers are never reused, so members",
+        "keep a stable number when others are removed."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "group",
+            "docs": [
+              "The pubkey of the `TokenGroup`, unset until the first member number is assigned"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "lastMemberNumber",
+            "docs": [
+              "The last assigned member number"
+            ],
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "groupMemberIndex",
+      "docs": [
+        "Page of the members of a group ordered by member number, removed members are left as",
+        "`Pubkey::default()` so members keep their position"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "group",
+            "docs": [
+              "The pubkey of the `TokenGroup`"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "page",
+            "docs": [
+              "The page number, page `n` holds members `n * MEMBER_INDEX_PAGE_SIZE + 1` onwards"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "members",
+            "docs": [
+              "Member mints"
+            ],
+            "type": {
+              "vec": "publicKey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "mintConfig",
+      "docs": [
+        "Public mint of member mints of a group, set by the group authority"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "group",
+            "docs": [
+              "The pubkey of the `TokenGroup`"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "paymentMint",
+            "docs": [
+              "Mint of the price, `Pubkey::default()` for SOL"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "price",
+            "docs": [
+              "Price of a member mint in lamports or base units of `payment_mint`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "treasury",
+            "docs": [
+              "Receives the price, or owns the associated token account receiving it for tokens"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "startTime",
+            "docs": [
+              "Unix timestamp the public mint opens at, open right away when `None`"
+            ],
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "endTime",
+            "docs": [
+              "Unix timestamp the public mint closes at, never closes when `None`"
+            ],
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "limitPerWallet",
+            "docs": [
+              "Maximum number of member mints per wallet, 0 for unlimited"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "name",
+            "docs": [
+              "Name of the member mints, followed by ` #<member number>`"
+            ],
+            "type": "string"
+          },
+          {
+            "name": "symbol",
+            "type": "string"
+          },
+          {
+            "name": "baseUri",
+            "docs": [
+              "Uri of the member mints, followed by the member number"
+            ],
+            "type": "string"
+          }
+        ]
+      }
+    },
+    {
+      "name": "publicMintClaim",
+      "docs": [
+        "Number of member mints received by a wallet through the public mint of the group"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "count",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "metadataAuthorityNomination",
+      "docs": [
+        "Pending transfer of the metadata update authority of a mint.",
+        "While pending, the metadata update authority of mints storing metadata in the mint",
+        "is this PDA, so the nomination can be accepted without the nominator signing."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "docs": [
+              "The mint"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "authority",
+            "docs": [
+              "The metadata update authority at nomination, refunded the rent on close"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "nominee",
+            "docs": [
+              "The nominated metadata update authority"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "groupAuthorityNomination",
+      "docs": [
+        "Pending transfer of the update authority of a group. The group keeps its update",
+        "authority until the nominee accepts, so every group instruction still requires it."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "group",
+            "docs": [
+              "The group"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "authority",
+            "docs": [
+              "The group update authority at nomination, refunded the rent on close"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "nominee",
+            "docs": [
+              "The nominated group update authority"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "groupExtensionPolicy",
+      "docs": [
+        "Constraints on the mints joining a group, set by the group authority"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "group",
+            "docs": [
+              "The pubkey of the `TokenGroup`"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "allowedExtensions",
+            "docs": [
+              "Extension types member mints may have, any extension is allowed when empty"
+            ],
+            "type": {
+              "vec": "u16"
+            }
+          },
+          {
+            "name": "requiredExtensions",
+            "docs": [
+              "Extension types member mints must have"
+            ],
+            "type": {
+              "vec": "u16"
+            }
+          },
+          {
+            "name": "minRoyaltyBasisPoints",
+            "docs": [
+              "Bounds of the member royalty basis points, mints without royalties have 0"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "maxRoyaltyBasisPoints",
+            "type": "u16"
+          },
+          {
+            "name": "maxAdditionalMetadataBytes",
+            "docs": [
+              "Maximum size of the additional metadata of members, uncapped when 0"
+            ],
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "provenanceRecord",
+      "docs": [
+        "Last transfers of a mint, appended by the transfer hook once the mint opted in"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "next",
+            "docs": [
+              "Index of the entry overwritten by the next transfer once the log is full"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "entries",
+            "type": {
+              "vec": {
+                "defined": "ProvenanceEntry"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "mintRecord",
+      "docs": [
+        "Provenance of a mint, written once at creation"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "docs": [
+              "The mint authority at creation"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "payer",
+            "docs": [
+              "Paid the record rent, refunded when the record is closed"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "slot",
+            "docs": [
+              "Slot the mint was created at"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "group",
+            "docs": [
+              "The `TokenGroup` the mint was created in, if any"
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "manager",
+            "docs": [
+              "The `Manager` holding the mint authorities, global or namespaced to an authority"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "groupRoyaltyDefaults",
+      "docs": [
+        "Royalties applied to member mints created without royalties, set by the group authority"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "group",
+            "docs": [
+              "The pubkey of the `TokenGroup`"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "royaltyBasisPoints",
+            "type": "u16"
+          },
+          {
+            "name": "creators",
+            "type": {
+              "vec": {
+                "defined": "DefaultCreator"
+              }
+            }
+          },
+          {
+            "name": "locked",
+            "docs": [
+              "Only the group authority can modify the royalties of members when set"
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "royaltyEnforcement",
+      "docs": [
+        "Royalty enforcement mode of a group, set by the group authority"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "group",
+            "docs": [
+              "The pubkey of the `TokenGroup`"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "mode",
+            "type": {
+              "defined": "EnforcementMode"
+            }
+          },
+          {
+            "name": "memoRequired",
+            "docs": [
+              "Transfers must follow a memo instruction in their transaction"
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "royaltyExemptions",
+      "docs": [
+        "Owners and programs whose transfers of group members don't pay royalties, set by the group authority"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "group",
+            "docs": [
+              "The pubkey of the `TokenGroup`"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "exemptions",
+            "docs": [
+              "Token account owners or invoking programs"
+            ],
+            "type": {
+              "vec": "publicKey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "royaltyTimelock",
+      "docs": [
+        "Delay before royalty updates of group members take effect, set by the group authority"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "group",
+            "docs": [
+              "The pubkey of the `TokenGroup`"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "delay",
+            "docs": [
+              "Seconds between `modify_royalties` and `apply_royalty_update`, 0 when disabled"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "pendingRoyaltyUpdate",
+      "docs": [
+        "Royalties queued by `modify_royalties` for a timelocked group member"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "docs": [
+              "The pubkey of the mint"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "payer",
+            "docs": [
+              "Refunded the rent when the update is applied or cancelled"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "effectiveAfter",
+            "docs": [
+              "Unix timestamp after which the update can be applied"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "royalties",
+            "type": {
+              "defined": "AddRoyaltiesArgs"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "secondaryHook",
+      "docs": [
+        "Transfer hook program invoked by the transfer hook after royalties are enforced on",
+        "transfers of group members, set by the group authority"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "group",
+            "docs": [
+              "The pubkey of the `TokenGroup`"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "programId",
+            "type": "publicKey"
+          },
+          {
+            "name": "accounts",
+            "docs": [
+              "Static extra accounts of the secondary hook program, in the order it resolves them"
+            ],
+            "type": {
+              "vec": {
+                "defined": "SecondaryHookAccount"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "verifiedCreators",
+      "docs": [
+        "Royalty creators of a mint that signed to endorse it"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "docs": [
+              "The pubkey of the mint"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "creators",
+            "type": {
+              "vec": "publicKey"
+            }
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "MintAllowlistArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "limitPerWallet",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CreateGroupAccountArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "name",
+            "type": "string"
+          },
+          {
+            "name": "symbol",
+            "type": "string"
+          },
+          {
+            "name": "uri",
+            "type": "string"
+          },
+          {
+            "name": "maxSize",
+            "type": "u32"
+          },
+          {
+            "name": "soulbound",
+            "docs": [
+              "Require every member to be non-transferable and freeze transfers of the group,",
+              "can't be changed once the group is created"
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "UpdateGroupMetadataArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "name",
+            "type": {
+              "option": "string"
+            }
+          },
+          {
+            "name": "symbol",
+            "type": {
+              "option": "string"
+            }
+          },
+          {
+            "name": "uri",
+            "type": {
+              "option": "string"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "MintConfigArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "paymentMint",
+            "docs": [
+              "Mint of the price, `Pubkey::default()` for SOL"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "treasury",
+            "type": "publicKey"
+          },
+          {
+            "name": "startTime",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "endTime",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "limitPerWallet",
+            "docs": [
+              "0 for unlimited"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "name",
+            "type": "string"
+          },
+          {
+            "name": "symbol",
+            "type": "string"
+          },
+          {
+            "name": "baseUri",
+            "type": "string"
+          }
+        ]
+      }
+    },
+    {
+      "name": "GroupPermissionArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "permissions",
+            "docs": [
+              "Bitmask of the `GroupPermission` operation flags"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "expiresAt",
+            "docs": [
+              "Unix timestamp the grant expires at, never expires when `None`"
+            ],
+            "type": {
+              "option": "i64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "GroupExtensionPolicyArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "allowedExtensions",
+            "docs": [
+              "Extension types member mints may have, any extension is allowed when empty"
+            ],
+            "type": {
+              "vec": "u16"
+            }
+          },
+          {
+            "name": "requiredExtensions",
+            "docs": [
+              "Extension types member mints must have"
+            ],
+            "type": {
+              "vec": "u16"
+            }
+          },
+          {
+            "name": "minRoyaltyBasisPoints",
+            "type": "u16"
+          },
+          {
+            "name": "maxRoyaltyBasisPoints",
+            "type": "u16"
+          },
+          {
+            "name": "maxAdditionalMetadataBytes",
+            "docs": [
+              "Maximum size of the additional metadata of members, uncapped when 0"
+            ],
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "GroupRoyaltyDefaultsArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "royaltyBasisPoints",
+            "type": "u16"
+          },
+          {
+            "name": "creators",
+            "type": {
+              "vec": {
+                "defined": "DefaultCreator"
+              }
+            }
+          },
+          {
+            "name": "locked",
+            "docs": [
+              "Restrict `modify_royalties` on members to the group authority"
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "GroupSupply",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "size",
+            "type": "u32"
+          },
+          {
+            "name": "maxSize",
+            "docs": [
+              "0 for unlimited"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "finalized",
+            "docs": [
+              "No member can be added to a finalized group"
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "UpdateGroupAccountArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "name",
+            "type": "string"
+          },
+          {
+            "name": "symbol",
+            "type": "string"
+          },
+          {
+            "name": "uri",
+            "type": "string"
+          },
+          {
+            "name": "maxSize",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AddMetadataArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "field",
+            "type": "string"
+          },
+          {
+            "name": "value",
+            "type": "string"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CreateMintAccountArgs",
+      "docs": [
+        "Clients predating the creation options only send the name, symbol and uri, the options",
+        "then take their `Default` values"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "name",
+            "type": "string"
+          },
+          {
+            "name": "symbol",
+            "type": "string"
+          },
+          {
+            "name": "uri",
+            "type": "string"
+          },
+          {
+            "name": "soulbound",
+            "docs": [
+              "Initialize the mint with the NonTransferable extension. Soulbound mints",
+              "have no transfer hook since the token can never move."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "permanentDelegate",
+            "docs": [
+              "Initialize the mint with the PermanentDelegate extension"
+            ],
+            "type": {
+              "option": {
+                "defined": "PermanentDelegateConfig"
+              }
+            }
+          },
+          {
+            "name": "frozen",
+            "docs": [
+              "Initialize the DefaultAccountState extension as frozen. Token accounts,",
+              "including the receiver's, stay frozen until thawed through `thaw_mint_account`"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "freezeAuthority",
+            "docs": [
+              "Mint freeze authority, defaults to the `Manager` PDA which frozen mints require"
+            ],
+            "type": {
+              "option": {
+                "defined": "FreezeAuthorityConfig"
+              }
+            }
+          },
+          {
+            "name": "decimals",
+            "docs": [
+              "Mint decimals, defaults to 0"
+            ],
+            "type": {
+              "option": "u8"
+            }
+          },
+          {
+            "name": "initialSupply",
+            "docs": [
+              "Amount minted to the receiver, defaults to 1"
+            ],
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "additionalMetadata",
+            "docs": [
+              "Additional metadata fields written right after the metadata is initialized"
+            ],
+            "type": {
+              "vec": {
+                "defined": "AddMetadataArgs"
+              }
+            }
+          },
+          {
+            "name": "royalties",
+            "docs": [
+              "Royalties enforced from creation, equivalent to a following `add_royalties_to_mint`"
+            ],
+            "type": {
+              "option": {
+                "defined": "AddRoyaltiesArgs"
+              }
+            }
+          },
+          {
+            "name": "updateAuthority",
+            "docs": [
+              "Metadata and metadata pointer update authority, defaults to the mint authority"
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "immutable",
+            "docs": [
+              "Remove the metadata update authority once all creation time fields are written"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "allowPdaReceiver",
+            "docs": [
+              "Allow receivers that aren't wallets, e.g. escrow or launchpad PDAs"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "externalMetadata",
+            "docs": [
+              "Store the metadata in the `[METADATA_SEED, mint]` PDA instead of the mint,",
+              "the metadata pointer references that account"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "contentHash",
+            "docs": [
+              "Hash of the off-chain JSON committed in the `CONTENT_HASH_FIELD` metadata field"
+            ],
+            "type": {
+              "option": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
+          },
+          {
+            "name": "mintRecord",
+            "docs": [
+              "Record the creator and creation slot in the `[MINT_RECORD_SEED, mint]` PDA"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "managerAuthority",
+            "docs": [
+              "Authority of the `Manager` created by `init_manager_for_authority` holding the mint",
+              "authorities, the global manager when `None`"
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "provenance",
+            "docs": [
+              "Set the WNS transfer hook program even without royalties or group, so transfers are",
+              "logged once `init_provenance` opts the mint in. Soulbound mints never transfer"
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CreatedMintAccount",
+      "docs": [
+        "Returned by `create_mint_account` for programs creating mints through CPI"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "extraMetasAccount",
+            "type": "publicKey"
+          },
+          {
+            "name": "manager",
+            "type": "publicKey"
+          },
+          {
+            "name": "metadataAddress",
+            "docs": [
+              "metadata pointer address, the mint itself unless `external_metadata` stores the",
+              "metadata in the `[METADATA_SEED, mint]` PDA"
+            ],
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "EnableEditionsArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maxSupply",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RemoveMetadataArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "field",
+            "type": "string"
+          }
+        ]
+      }
+    },
+    {
+      "name": "UpdateMintMetadataArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "name",
+            "type": {
+              "option": "string"
+            }
+          },
+          {
+            "name": "symbol",
+            "type": {
+              "option": "string"
+            }
+          },
+          {
+            "name": "uri",
+            "type": {
+              "option": "string"
+            }
+          },
+          {
+            "name": "contentHash",
+            "docs": [
+              "New content hash, required along a new uri when the mint has a content hash"
+            ],
+            "type": {
+              "option": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
+          },
+          {
+            "name": "clearContentHash",
+            "docs": [
+              "Clear the content hash instead of setting a new one"
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CreatorWithShare",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "address",
+            "type": "string"
+          },
+          {
+            "name": "share",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AddRoyaltiesArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "royaltyBasisPoints",
+            "type": "u16"
+          },
+          {
+            "name": "creators",
+            "type": {
+              "vec": {
+                "defined": "CreatorWithShare"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "ProvenanceEntry",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "slot",
+            "type": "u64"
+          },
+          {
+            "name": "fromOwner",
+            "type": "publicKey"
+          },
+          {
+            "name": "toOwner",
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "salePrice",
+            "docs": [
+              "Sale price of the approval consumed by the transfer, 0 without one"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "DefaultCreator",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "address",
+            "type": "publicKey"
+          },
+          {
+            "name": "share",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SecondaryHookAccount",
+      "docs": [
+        "Account passed to the secondary hook program after its validation account"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "address",
+            "type": "publicKey"
+          },
+          {
+            "name": "isWritable",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PermanentDelegateConfig",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Manager"
+          },
+          {
+            "name": "Address",
+            "fields": [
+              "publicKey"
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "FreezeAuthorityConfig",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Address",
+            "fields": [
+              "publicKey"
+            ]
+          },
+          {
+            "name": "Disabled"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MetadataWriteMode",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Insert"
+          },
+          {
+            "name": "Update"
+          },
+          {
+            "name": "Upsert"
+          }
+        ]
+      }
+    },
+    {
+      "name": "EnforcementMode",
+      "docs": [
+        "How the transfer hook enforces royalties on transfers of group members. Every group member",
+        "resolves the mode, with or without royalties, but only `Frozen` applies to members without",
+        "royalties as they have no approval to require. Existing mints resolve the mode once their extra metas are rebuilt, e.g. by",
+        "`update_extra_metas_list`, and are `Standard` until then."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Strict"
+          },
+          {
+            "name": "Standard"
+          },
+          {
+            "name": "Permissive"
+          },
+          {
+            "name": "Frozen"
+          }
+        ]
+      }
+    }
+  ],
+  "events": [
+    {
+      "name": "MintCreated",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "authority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "receiver",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "name",
+          "type": "string",
+          "index": false
+        },
+        {
+          "name": "symbol",
+          "type": "string",
+          "index": false
+        },
+        {
+          "name": "uri",
+          "type": "string",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RoyaltiesUpdated",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "royaltyBasisPoints",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "creators",
+          "type": {
+            "vec": "string"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MetadataFrozen",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RoyaltiesRemoved",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RoyaltyExemptionAdded",
+      "fields": [
+        {
+          "name": "group",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "address",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RoyaltyExemptionRemoved",
+      "fields": [
+        {
+          "name": "group",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "address",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MetadataUpdated",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "key",
+          "type": "string",
+          "index": false
+        },
+        {
+          "name": "oldValue",
+          "type": {
+            "option": "string"
+          },
+          "index": false
+        },
+        {
+          "name": "newValue",
+          "type": {
+            "option": "string"
+          },
+          "index": false
+        },
+        {
+          "name": "authority",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "CreatorVerified",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "CreatorUnverified",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RoyaltyUpdateQueued",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "royaltyBasisPoints",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "creators",
+          "type": {
+            "vec": "string"
+          },
+          "index": false
+        },
+        {
+          "name": "effectiveAfter",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TransferApproved",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "seller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "buyer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "destination",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "salePrice",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "paymentMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "royaltyAmount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ApprovedTransferExecuted",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "source",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "destination",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "seller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "buyer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "salePrice",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "paymentMint",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ExtraMetasUpdated",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "group",
+          "type": {
+            "option": "publicKey"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EnforcementModeUpdated",
+      "fields": [
+        {
+          "name": "group",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "oldMode",
+          "type": {
+            "defined": "EnforcementMode"
+          },
+          "index": false
+        },
+        {
+          "name": "newMode",
+          "type": {
+            "defined": "EnforcementMode"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SecondaryHookRegistered",
+      "fields": [
+        {
+          "name": "group",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "programId",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SecondaryHookUnregistered",
+      "fields": [
+        {
+          "name": "group",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RoyaltyUpdateCancelled",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "GroupUpdated",
+      "fields": [
+        {
+          "name": "group",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "oldMaxSize",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "newMaxSize",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "size",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MemberRemoved",
+      "fields": [
+        {
+          "name": "group",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "memberNumber",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "GroupClosed",
+      "fields": [
+        {
+          "name": "group",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mintClosed",
+          "type": "bool",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MemberAdded",
+      "fields": [
+        {
+          "name": "group",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "memberNumber",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "GroupFinalized",
+      "fields": [
+        {
+          "name": "group",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "size",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "GroupParentSet",
+      "fields": [
+        {
+          "name": "group",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "parent",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "GroupDelegateApproved",
+      "fields": [
+        {
+          "name": "group",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "delegate",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "GroupDelegateRevoked",
+      "fields": [
+        {
+          "name": "group",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "delegate",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "GroupSizeChanged",
+      "fields": [
+        {
+          "name": "group",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "size",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "maxSize",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "GroupPermissionGranted",
+      "fields": [
+        {
+          "name": "group",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "delegate",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "permissions",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "expiresAt",
+          "type": {
+            "option": "i64"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "GroupPermissionRevoked",
+      "fields": [
+        {
+          "name": "group",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "delegate",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ManagerInitialized",
+      "fields": [
+        {
+          "name": "manager",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "authority",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "SizeExceedsMaxSize",
+      "msg": "Collection size exceeds max size."
+    },
+    {
+      "code": 6001,
+      "name": "MaxSizeBelowCurrentSize",
+      "msg": "Max size cannot be reduced below current size."
+    },
+    {
+      "code": 6002,
+      "name": "InvalidCreatorShareTotal",
+      "msg": "Creators shares must add up to 100."
+    },
+    {
+      "code": 6003,
+      "name": "MissingApproveAccount",
+      "msg": "Missing approve account."
+    },
+    {
+      "code": 6004,
+      "name": "ExpiredApproveAccount",
+      "msg": "Approve account has expired."
+    },
+    {
+      "code": 6005,
+      "name": "InvalidBatchAccounts",
+      "msg": "Remaining accounts must contain a mint, token account and extra metas account per entry."
+    },
+    {
+      "code": 6006,
+      "name": "InvalidMintTokenAccount",
+      "msg": "Mint token account is not the receiver's associated token account."
+    },
+    {
+      "code": 6007,
+      "name": "InvalidExtraMetasAccount",
+      "msg": "Extra metas account does not match the mint."
+    },
+    {
+      "code": 6008,
+      "name": "SoulboundMint",
+      "msg": "Soulbound mints cannot be transferred."
+    },
+    {
+      "code": 6009,
+      "name": "InvalidPermanentDelegate",
+      "msg": "Manager is not the permanent delegate of the mint."
+    },
+    {
+      "code": 6010,
+      "name": "InvalidInitialSupply",
+      "msg": "Initial supply must be greater than 0."
+    },
+    {
+      "code": 6011,
+      "name": "ReservedMetadataKey",
+      "msg": "Metadata key is reserved."
+    },
+    {
+      "code": 6012,
+      "name": "TooManyCreators",
+      "msg": "Too many creators."
+    },
+    {
+      "code": 6013,
+      "name": "MintAlreadyClaimed",
+      "msg": "Mint has already been claimed."
+    },
+    {
+      "code": 6014,
+      "name": "InvalidMetadataAuthority",
+      "msg": "Invalid metadata update authority."
+    },
+    {
+      "code": 6015,
+      "name": "NameTooLong",
+      "msg": "Name is too long."
+    },
+    {
+      "code": 6016,
+      "name": "SymbolTooLong",
+      "msg": "Symbol is too long."
+    },
+    {
+      "code": 6017,
+      "name": "UriTooLong",
+      "msg": "Uri is too long."
+    },
+    {
+      "code": 6018,
+      "name": "EmptyField",
+      "msg": "Name and uri cannot be empty."
+    },
+    {
+      "code": 6019,
+      "name": "ExtraMetasAlreadyInitialized",
+      "msg": "Extra metas account is already initialized."
+    },
+    {
+      "code": 6020,
+      "name": "MetadataImmutable",
+      "msg": "Metadata is immutable."
+    },
+    {
+      "code": 6021,
+      "name": "MissingRoyalties",
+      "msg": "Mint has no royalties."
+    },
+    {
+      "code": 6022,
+      "name": "MintAuthorityAlreadyRevoked",
+      "msg": "Mint authority has already been revoked."
+    },
+    {
+      "code": 6023,
+      "name": "InvalidFinalizeAuthority",
+      "msg": "Authority cannot finalize this mint."
+    },
+    {
+      "code": 6024,
+      "name": "ReceiverNotAllowed",
+      "msg": "Receiver must be a wallet unless PDA receivers are allowed."
+    },
+    {
+      "code": 6025,
+      "name": "InvalidMetadataAccount",
+      "msg": "Metadata account does not match the mint metadata pointer."
+    },
+    {
+      "code": 6026,
+      "name": "MissingMetadataField",
+      "msg": "Metadata field not found."
+    },
+    {
+      "code": 6027,
+      "name": "InvalidExtensionPolicy",
+      "msg": "Invalid group extension policy."
+    },
+    {
+      "code": 6028,
+      "name": "ExtensionNotAllowed",
+      "msg": "Mint extension is not allowed by the group policy."
+    },
+    {
+      "code": 6029,
+      "name": "MissingRequiredExtension",
+      "msg": "Mint is missing an extension required by the group policy."
+    },
+    {
+      "code": 6030,
+      "name": "RoyaltiesOutOfPolicyBounds",
+      "msg": "Royalty basis points are out of the group policy bounds."
+    },
+    {
+      "code": 6031,
+      "name": "NoFreezeAuthority",
+      "msg": "Mint freeze authority is not the manager."
+    },
+    {
+      "code": 6032,
+      "name": "InvalidMintRecordAccount",
+      "msg": "Invalid mint record account."
+    },
+    {
+      "code": 6033,
+      "name": "MintNotBurned",
+      "msg": "Mint supply must be burned to close its record."
+    },
+    {
+      "code": 6034,
+      "name": "InvalidMaxEditions",
+      "msg": "Max editions must be greater than 0."
+    },
+    {
+      "code": 6035,
+      "name": "MaxEditionsReached",
+      "msg": "All editions have been printed."
+    },
+    {
+      "code": 6036,
+      "name": "GroupAuthoritySignatureRequired",
+      "msg": "Group authority must sign to create member mints."
+    },
+    {
+      "code": 6037,
+      "name": "InvalidAllowlistProof",
+      "msg": "Receiver is not in the group allowlist."
+    },
+    {
+      "code": 6038,
+      "name": "AllowlistLimitReached",
+      "msg": "Receiver reached the group allowlist limit."
+    },
+    {
+      "code": 6039,
+      "name": "InvalidAllowlistClaimAccount",
+      "msg": "Invalid allowlist claim account."
+    },
+    {
+      "code": 6040,
+      "name": "TooManyMetadataFields",
+      "msg": "Too many metadata fields."
+    },
+    {
+      "code": 6041,
+      "name": "StaleNomination",
+      "msg": "Metadata update authority changed since the nomination."
+    },
+    {
+      "code": 6042,
+      "name": "MetadataNotFrozen",
+      "msg": "Metadata update authority was not removed."
+    },
+    {
+      "code": 6043,
+      "name": "MetadataSizeLimitExceeded",
+      "msg": "Additional metadata exceeds the group size limit."
+    },
+    {
+      "code": 6044,
+      "name": "InvalidGroupPolicyAccount",
+      "msg": "Group member and policy accounts are required for group members."
+    },
+    {
+      "code": 6045,
+      "name": "MetadataKeyExists",
+      "msg": "Metadata key already exists."
+    },
+    {
+      "code": 6046,
+      "name": "MetadataKeyNotFound",
+      "msg": "Metadata key not found."
+    },
+    {
+      "code": 6047,
+      "name": "ContentHashRequired",
+      "msg": "Content hash must be updated or cleared along the uri."
+    },
+    {
+      "code": 6048,
+      "name": "MissingMetadata",
+      "msg": "Mint has no token metadata."
+    },
+    {
+      "code": 6049,
+      "name": "MetadataKeyTooLong",
+      "msg": "Metadata key too long."
+    },
+    {
+      "code": 6050,
+      "name": "MetadataValueTooLong",
+      "msg": "Metadata value too long."
+    },
+    {
+      "code": 6051,
+      "name": "InvalidMetadataCharacters",
+      "msg": "Metadata contains control characters."
+    },
+    {
+      "code": 6052,
+      "name": "ZeroCreatorShare",
+      "msg": "Creator shares must be greater than 0."
+    },
+    {
+      "code": 6053,
+      "name": "DuplicateCreator",
+      "msg": "Creators must be unique."
+    },
+    {
+      "code": 6054,
+      "name": "InvalidGroupRoyaltyDefaultsAccount",
+      "msg": "Group member, group and royalty defaults accounts are required for group members."
+    },
+    {
+      "code": 6055,
+      "name": "RoyaltiesLocked",
+      "msg": "Royalties of group members are locked to the group authority."
+    },
+    {
+      "code": 6056,
+      "name": "InvalidGroupMemberAccount",
+      "msg": "Group member account is required for group members."
+    },
+    {
+      "code": 6057,
+      "name": "TooManyRoyaltyExemptions",
+      "msg": "Too many royalty exemptions."
+    },
+    {
+      "code": 6058,
+      "name": "RoyaltyExemptionExists",
+      "msg": "Royalty exemption already exists."
+    },
+    {
+      "code": 6059,
+      "name": "RoyaltyExemptionNotFound",
+      "msg": "Royalty exemption not found."
+    },
+    {
+      "code": 6060,
+      "name": "CreatorNotFound",
+      "msg": "Creator is not part of the mint royalties."
+    },
+    {
+      "code": 6061,
+      "name": "CreatorAlreadyVerified",
+      "msg": "Creator is already verified."
+    },
+    {
+      "code": 6062,
+      "name": "CreatorNotVerified",
+      "msg": "Creator is not verified."
+    },
+    {
+      "code": 6063,
+      "name": "VerifiedCreatorRemoval",
+      "msg": "Verified creators must sign or unverify before being removed."
+    },
+    {
+      "code": 6064,
+      "name": "InvalidRoyaltyTimelockAccount",
+      "msg": "Royalty timelock account is required for group members."
+    },
+    {
+      "code": 6065,
+      "name": "InvalidRoyaltyTimelockDelay",
+      "msg": "Royalty timelock delay can't be negative."
+    },
+    {
+      "code": 6066,
+      "name": "InvalidPendingRoyaltyUpdateAccount",
+      "msg": "Pending royalty update account is required for timelocked group members."
+    },
+    {
+      "code": 6067,
+      "name": "RoyaltyUpdateTimelocked",
+      "msg": "Pending royalty update is still timelocked."
+    },
+    {
+      "code": 6068,
+      "name": "ArithmeticOverflow",
+      "msg": "Arithmetic overflow."
+    },
+    {
+      "code": 6069,
+      "name": "InvalidCollectionDistributionAccount",
+      "msg": "Group member, group, collection distribution and royalty defaults accounts are required for group members."
+    },
+    {
+      "code": 6070,
+      "name": "InvalidDistributionAccount",
+      "msg": "Distribution account does not match the distribution of the mint royalties."
+    },
+    {
+      "code": 6071,
+      "name": "InvalidApprovalExpiry",
+      "msg": "Approval expiry slot must be between the current slot and the maximum approval horizon."
+    },
+    {
+      "code": 6072,
+      "name": "ApprovalExpired",
+      "msg": "Approval has expired."
+    },
+    {
+      "code": 6073,
+      "name": "ApprovalNotExpired",
+      "msg": "Approval has not expired yet."
+    },
+    {
+      "code": 6074,
+      "name": "ApprovalMismatch",
+      "msg": "Transfer doesn't match the approved amount and destination."
+    },
+    {
+      "code": 6075,
+      "name": "NotInTransferContext",
+      "msg": "Transfer hook can only be executed during a transfer."
+    },
+    {
+      "code": 6076,
+      "name": "TransfersFrozen",
+      "msg": "Transfers of the group are frozen."
+    },
+    {
+      "code": 6077,
+      "name": "InvalidSecondaryHookProgram",
+      "msg": "Secondary hook program can't be the metadata program."
+    },
+    {
+      "code": 6078,
+      "name": "TooManySecondaryHookAccounts",
+      "msg": "Too many secondary hook accounts."
+    },
+    {
+      "code": 6079,
+      "name": "InvalidSecondaryHookAccount",
+      "msg": "Invalid secondary hook account."
+    },
+    {
+      "code": 6080,
+      "name": "InvalidRoyaltyEnforcementAccount",
+      "msg": "Invalid royalty enforcement account."
+    },
+    {
+      "code": 6081,
+      "name": "MemoRequired",
+      "msg": "Transfers of the group require a memo."
+    },
+    {
+      "code": 6082,
+      "name": "InvalidProvenanceAccount",
+      "msg": "Invalid provenance account."
+    },
+    {
+      "code": 6083,
+      "name": "GroupNotEmpty",
+      "msg": "Group still has members."
+    },
+    {
+      "code": 6084,
+      "name": "GroupFull",
+      "msg": "Group is full."
+    },
+    {
+      "code": 6085,
+      "name": "GroupFinalized",
+      "msg": "Group is finalized."
+    },
+    {
+      "code": 6086,
+      "name": "InvalidParentGroup",
+      "msg": "Group can't be added to this parent group."
+    },
+    {
+      "code": 6087,
+      "name": "GroupHierarchyTooDeep",
+      "msg": "Group hierarchy is too deep."
+    },
+    {
+      "code": 6088,
+      "name": "InvalidParentRoyaltyDefaultsAccount",
+      "msg": "Parent group royalty defaults account is required for sub-groups."
+    },
+    {
+      "code": 6089,
+      "name": "TooManyGroupDelegates",
+      "msg": "Too many group delegates."
+    },
+    {
+      "code": 6090,
+      "name": "GroupDelegateExists",
+      "msg": "Group delegate already exists."
+    },
+    {
+      "code": 6091,
+      "name": "GroupDelegateNotFound",
+      "msg": "Group delegate not found."
+    },
+    {
+      "code": 6092,
+      "name": "MemberPointerMismatch",
+      "msg": "Mint group member pointer doesn't point at the member account."
+    },
+    {
+      "code": 6093,
+      "name": "MissingMintExtension",
+      "msg": "Mint is missing an extension of WNS mints."
+    },
+    {
+      "code": 6094,
+      "name": "InvalidMemberIndexAccount",
+      "msg": "Invalid group member index account."
+    },
+    {
+      "code": 6095,
+      "name": "InvalidMemberBatchAccounts",
+      "msg": "Remaining accounts must contain a mint, member, extra metas and member index account per member."
+    },
+    {
+      "code": 6096,
+      "name": "TooManyBatchMembers",
+      "msg": "Too many members in batch."
+    },
+    {
+      "code": 6097,
+      "name": "SoulboundRequired",
+      "msg": "Members of soulbound groups must be non-transferable."
+    },
+    {
+      "code": 6098,
+      "name": "SoulboundGroupFrozen",
+      "msg": "Soulbound groups can't change their enforcement mode."
+    },
+    {
+      "code": 6099,
+      "name": "InvalidMintWindow",
+      "msg": "Public mint end time must be after its start time."
+    },
+    {
+      "code": 6100,
+      "name": "MintNotStarted",
+      "msg": "Public mint has not started."
+    },
+    {
+      "code": 6101,
+      "name": "MintEnded",
+      "msg": "Public mint has ended."
+    },
+    {
+      "code": 6102,
+      "name": "PublicMintLimitReached",
+      "msg": "Public mint limit per wallet reached."
+    },
+    {
+      "code": 6103,
+      "name": "InvalidPaymentAccounts",
+      "msg": "Payment mint and token accounts are required for token prices."
+    },
+    {
+      "code": 6104,
+      "name": "GroupPermissionDenied",
+      "msg": "Group permission doesn't cover this operation."
+    },
+    {
+      "code": 6105,
+      "name": "GroupPermissionExpired",
+      "msg": "Group permission has expired."
+    },
+    {
+      "code": 6106,
+      "name": "InvalidGroupPermissions",
+      "msg": "Invalid group permissions."
+    },
+    {
+      "code": 6107,
+      "name": "InvalidDestinationGroup",
+      "msg": "Member is already in the destination group."
+    },
+    {
+      "code": 6108,
+      "name": "InvalidManagerAccount",
+      "msg": "Manager account does not match the manager authority."
+    },
+    {
+      "code": 6109,
+      "name": "MissingTransferHook",
+      "msg": "Transfer hook of the mint does not invoke WNS."
+    },
+    {
+      "code": 6110,
+      "name": "InvalidMemberCounterAccount",
+      "msg": "Invalid group member counter account."
+    },
+    {
+      "code": 6111,
+      "name": "InvalidGroupDelegatesAccount",
+      "msg": "Invalid group delegates account."
+    },
+    {
+      "code": 6112,
+      "name": "InvalidGroupPermissionAccount",
+      "msg": "Invalid group permission account."
+    },
+    {
+      "code": 6113,
+      "name": "UserSeedTooLong",
+      "msg": "User seed must not exceed 32 bytes."
+    }
+  ]
+};
+
+export const IDL: WenNewStandard = {
+  "version": "0.1.0-alpha",
+  "name": "wen_new_standard",
+  "constants": [
+    {
+      "name": "MAX_NAME_LEN",
+      "type": {
+        "defined": "usize"
+      },
+      "value": "32"
+    },
+    {
+      "name": "MAX_SYMBOL_LEN",
+      "type": {
+        "defined": "usize"
+      },
+      "value": "10"
+    },
+    {
+      "name": "MAX_URI_LEN",
+      "type": {
+        "defined": "usize"
+      },
+      "value": "200"
+    },
+    {
+      "name": "MAX_METADATA_KEY_LEN",
+      "type": {
+        "defined": "usize"
+      },
+      "value": "32"
+    },
+    {
+      "name": "MAX_METADATA_VALUE_LEN",
+      "type": {
+        "defined": "usize"
+      },
+      "value": "256"
+    }
+  ],
+  "instructions": [
+    {
+      "name": "createGroupAccount",
+      "docs": [
+        "create group"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "receiver",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "group",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "soulbound",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "royaltyEnforcement",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "mintTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "managerAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "manager",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "CreateGroupAccountArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateGroupAccount",
+      "docs": [
+        "update group"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "group update authority and metadata update authority of the group mint"
+          ]
+        },
+        {
+          "name": "group",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "finalization",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "UpdateGroupAccountArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateGroupMetadata",
+      "docs": [
+        "update the name, symbol and uri of the group mint"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "pays for metadata growth and is refunded when it shrinks"
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "group update authority, or a delegate granted `UPDATE_METADATA`"
+          ]
+        },
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "groupPermission",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "managerAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "manager",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "UpdateGroupMetadataArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "getMembersPage",
+      "docs": [
+        "return a page of the group member index"
+      ],
+      "accounts": [
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "memberIndex",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "page",
+          "type": "u32"
+        }
+      ],
+      "returns": {
+        "vec": "publicKey"
+      }
+    },
+    {
+      "name": "getGroupSupply",
+      "docs": [
+        "return the size, max size and finalization of a group"
+      ],
+      "accounts": [
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "finalization",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [],
+      "returns": {
+        "defined": "GroupSupply"
+      }
+    },
+    {
+      "name": "indexGroupMember",
+      "docs": [
+        "index a member added before the group member index existed"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "memberIndex",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeGroup",
+      "docs": [
+        "close an empty group, and its mint once the supply is burned"
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "group update authority, refunded the freed rent by default"
+          ]
+        },
+        {
+          "name": "group",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "group mint, only needed to close it along the group"
+          ]
+        },
+        {
+          "name": "closeAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "close authority of the group mint, only needed along `mint`"
+          ]
+        },
+        {
+          "name": "rentDestination",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "finalizeGroup",
+      "docs": [
+        "permanently lock the membership of a group"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "finalization",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "addGroupToGroup",
+      "docs": [
+        "add a group as a sub-group of a parent group"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "update authority of the sub-group"
+          ]
+        },
+        {
+          "name": "parentAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "update authority of the parent group"
+          ]
+        },
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "parentGroup",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "hierarchy",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "parentHierarchy",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "approveGroupDelegate",
+      "docs": [
+        "let a delegate add members to the group on behalf of the group authority"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "groupDelegates",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "delegate",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "revokeGroupDelegate",
+      "docs": [
+        "revoke a group delegate"
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "groupDelegates",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "delegate",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "grantGroupPermission",
+      "docs": [
+        "let a delegate perform some group operations on behalf of the group authority, until the grant expires"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "groupPermission",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "delegate",
+          "type": "publicKey"
+        },
+        {
+          "name": "args",
+          "type": {
+            "defined": "GroupPermissionArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "revokeGroupPermission",
+      "docs": [
+        "revoke the permissions of a delegate"
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "refunded the rent of the grant"
+          ]
+        },
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "groupPermission",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "delegate",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "migrateGroupToNative",
+      "docs": [
+        "initialize the native token group extension in the group mint"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "managerAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "manager",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "nominateGroupAuthority",
+      "docs": [
+        "nominate a new group update authority"
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "group update authority"
+          ]
+        },
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nomination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "nominee",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "acceptGroupAuthority",
+      "docs": [
+        "accept a group update authority nomination"
+      ],
+      "accounts": [
+        {
+          "name": "nominee",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "group",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nomination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelGroupAuthorityNomination",
+      "docs": [
+        "cancel a group update authority nomination"
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "group update authority at nomination"
+          ]
+        },
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nomination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setGroupExtensionPolicy",
+      "docs": [
+        "set the extension policy of group members"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "group authority or a delegate granted `SET_POLICY`"
+          ]
+        },
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "groupPermission",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "policy",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "GroupExtensionPolicyArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setMintConfig",
+      "docs": [
+        "set the price, treasury and window of the public mint of a group"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mintConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "MintConfigArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "closeMintConfig",
+      "docs": [
+        "end the public mint of a group"
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mintConfig",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setMintAllowlist",
+      "docs": [
+        "set the allowlist of member mint receivers"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "allowlist",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "MintAllowlistArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setGroupRoyaltyDefaults",
+      "docs": [
+        "set the royalties inherited by group member mints"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "group authority or a delegate granted `UPDATE_ROYALTY_DEFAULTS`"
+          ]
+        },
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "groupPermission",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "royaltyDefaults",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "GroupRoyaltyDefaultsArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setEnforcementMode",
+      "docs": [
+        "set how strictly royalties are enforced on transfers of group members, or freeze them"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "soulbound",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "royaltyEnforcement",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "mode",
+          "type": {
+            "defined": "EnforcementMode"
+          }
+        }
+      ]
+    },
+    {
+      "name": "registerSecondaryHook",
+      "docs": [
+        "register a transfer hook program invoked after royalties on transfers of group members"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "secondaryHook",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "programId",
+          "type": "publicKey"
+        },
+        {
+          "name": "accounts",
+          "type": {
+            "vec": {
+              "defined": "SecondaryHookAccount"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "unregisterSecondaryHook",
+      "docs": [
+        "unregister the secondary transfer hook of a group"
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "refunded the rent of the secondary hook"
+          ]
+        },
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "secondaryHook",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setMemoRequired",
+      "docs": [
+        "require transfers of group members to be annotated with a memo"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "royaltyEnforcement",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "required",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setCollectionDistribution",
+      "docs": [
+        "route royalties of group members to a single distribution of the group mint"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionDistribution",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "enabled",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setRoyaltyTimelock",
+      "docs": [
+        "set the delay before royalty updates of group members take effect"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "royaltyTimelock",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "delay",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "cancelRoyaltyUpdate",
+      "docs": [
+        "cancel a pending royalty update of a group member"
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pendingRoyaltyUpdate",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "addExemption",
+      "docs": [
+        "exempt an owner or program from royalties on transfers of group members"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "royaltyExemptions",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "address",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "removeExemption",
+      "docs": [
+        "remove a royalty exemption of the group"
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "royaltyExemptions",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "address",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "createMintAccount",
+      "docs": [
+        "create mint"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "mint authority, can be a PDA signing through CPI"
+          ]
+        },
+        {
+          "name": "receiver",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "mintTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "extraMetasAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "mintRecord",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "manager",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "global manager, or the manager namespaced to `args.manager_authority`"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "CreateMintAccountArgs"
+          }
+        }
+      ],
+      "returns": {
+        "defined": "CreatedMintAccount"
+      }
+    },
+    {
+      "name": "createMintAccountPda",
+      "docs": [
+        "create mint at a PDA derived from the authority and a user seed"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "`user_seed` is checked here, before the mint is derived from it"
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "mint authority, can be a PDA signing through CPI"
+          ]
+        },
+        {
+          "name": "receiver",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mintTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "extraMetasAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "mintRecord",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "manager",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "global manager, or the manager namespaced to `args.manager_authority`"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "CreateMintAccountArgs"
+          }
+        },
+        {
+          "name": "userSeed",
+          "type": "string"
+        }
+      ],
+      "returns": {
+        "defined": "CreatedMintAccount"
+      }
+    },
+    {
+      "name": "createMintAccountsBatch",
+      "docs": [
+        "create multiple mints"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "receiver",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "managerAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "manager",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "vec": {
+              "defined": "CreateMintAccountArgs"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "clawback",
+      "docs": [
+        "claw back a mint from its holder using the manager permanent delegate"
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sourceTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destinationTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "managerAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "manager",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initManagerForAuthority",
+      "docs": [
+        "create a manager namespaced to the authority"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "manager",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "thawMintAccount",
+      "docs": [
+        "thaw a mint token account"
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mintTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "managerAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "manager",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "burnMintAccount",
+      "docs": [
+        "burn a mint token account of the holder, no transfer approval needed"
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "holder of the token account, refunded its rent"
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mintTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "group",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "group of the member, only needed for group members"
+          ]
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "memberCounter",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "for group members"
+          ]
+        },
+        {
+          "name": "memberIndex",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "mintRecord",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "mintRecordPayer",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeMintRecord",
+      "docs": [
+        "close the record of a burned mint, signed by the record payer until the mint authority is revoked"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "authority is set"
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mintRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createMintAccountUnminted",
+      "docs": [
+        "create mint without minting it"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "extraMetasAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "mintRecord",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "manager",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "global manager, or the manager namespaced to `args.manager_authority`"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "CreateMintAccountArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "mintToClaimer",
+      "docs": [
+        "mint an unminted mint to its claimer"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "metadata update authority of the mint, or for group members the group authority, one of",
+            "its delegates or a delegate granted `ADD_MEMBER`"
+          ]
+        },
+        {
+          "name": "claimer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "claimerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "managerAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "manager",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "group of the member, only needed when the authority claims on behalf of the group"
+          ]
+        },
+        {
+          "name": "groupDelegates",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "groupPermission",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "needed with the group"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "finalizeMint",
+      "docs": [
+        "revoke the manager mint authority"
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "managerAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "manager",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "addGroupToMint",
+      "docs": [
+        "add mint to group"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "group authority, one of its delegates or a delegate granted `ADD_MEMBER`, also the",
+            "transfer hook authority of mints whose transfer hook doesn't invoke WNS yet"
+          ]
+        },
+        {
+          "name": "group",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "groupDelegates",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "groupPermission",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "memberCounter",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "last member number assigned in the group"
+          ]
+        },
+        {
+          "name": "memberIndex",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "finalization",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "soulbound",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "policy",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "extraMetasAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [],
+      "returns": "u32"
+    },
+    {
+      "name": "addGroupMembersBatch",
+      "docs": [
+        "add up to `MAX_MEMBER_BATCH_SIZE` mints to group"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "group authority, one of its delegates or a delegate granted `ADD_MEMBER`"
+          ]
+        },
+        {
+          "name": "group",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "groupDelegates",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "groupPermission",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "memberCounter",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "last member number assigned in the group"
+          ]
+        },
+        {
+          "name": "finalization",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "soulbound",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "policy",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [],
+      "returns": {
+        "vec": "u32"
+      }
+    },
+    {
+      "name": "moveMember",
+      "docs": [
+        "move a member mint to another group, signed by both group authorities"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "sourceAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "source group authority, one of its delegates or a delegate granted `ADD_MEMBER`"
+          ]
+        },
+        {
+          "name": "sourceGroup",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sourceGroupDelegates",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sourceGroupPermission",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sourceMemberCounter",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "last member number assigned in the source group, created before the size shrinks"
+          ]
+        },
+        {
+          "name": "sourceMemberIndex",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destinationAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "destination group authority, one of its delegates or a delegate granted `ADD_MEMBER`"
+          ]
+        },
+        {
+          "name": "destinationGroup",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destinationGroupDelegates",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "destinationGroupPermission",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "destinationMemberCounter",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "last member number assigned in the destination group"
+          ]
+        },
+        {
+          "name": "destinationMemberIndex",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destinationFinalization",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "destinationSoulbound",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "destinationPolicy",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "extraMetasAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [],
+      "returns": "u32"
+    },
+    {
+      "name": "publicMint",
+      "docs": [
+        "create a member mint of a group for the price of its public mint"
+      ],
+      "accounts": [
+        {
+          "name": "minter",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "pays the price and receives the member mint"
+          ]
+        },
+        {
+          "name": "group",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mintConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "publicMintClaim",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "member mints received by the minter through the public mint"
+          ]
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "paymentMint",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "only needed for token prices"
+          ]
+        },
+        {
+          "name": "minterTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "token account of the minter, only needed for token prices"
+          ]
+        },
+        {
+          "name": "treasuryTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "associated token account of the treasury, only needed for token prices"
+          ]
+        },
+        {
+          "name": "paymentTokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "token program of the payment mint, unused for SOL prices"
+          ]
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "memberCounter",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "last member number assigned in the group"
+          ]
+        },
+        {
+          "name": "memberIndex",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "finalization",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "soulbound",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "policy",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "royaltyDefaults",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "hierarchy",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "parentRoyaltyDefaults",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "mintTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "extraMetasAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "managerAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "manager",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateMemberToNative",
+      "docs": [
+        "initialize the native token group member extension in a member mint"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "memberPointerAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "group member pointer authority of the mint, usually its creator"
+          ]
+        },
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "groupMint",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "group mint, migrated with `migrate_group_to_native`"
+          ]
+        },
+        {
+          "name": "managerAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "manager",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "removeGroupFromMint",
+      "docs": [
+        "remove mint from its group, closing the member account"
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "group update authority and group member pointer authority of the mint,",
+            "refunded the freed rent by default"
+          ]
+        },
+        {
+          "name": "group",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "memberCounter",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "last member number assigned in the group, created before the size shrinks"
+          ]
+        },
+        {
+          "name": "memberIndex",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "extraMetasAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentDestination",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createGroupMemberMint",
+      "docs": [
+        "create mint and add it to group"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "mint authority, must be the group authority, one of its delegates or a delegate granted",
+            "`ADD_MEMBER` since the mint joins the group"
+          ]
+        },
+        {
+          "name": "receiver",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "group",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "groupDelegates",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "groupPermission",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "memberCounter",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "last member number assigned in the group"
+          ]
+        },
+        {
+          "name": "memberIndex",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "finalization",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "soulbound",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "policy",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "allowlist",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "allowlistClaim",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "receiver mint count, only needed when the group has an allowlist"
+          ]
+        },
+        {
+          "name": "royaltyDefaults",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "hierarchy",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "parentRoyaltyDefaults",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "groupMint",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "migrated to the native token group extension, behind the `native-group` feature"
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "mintTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "extraMetasAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "mintRecord",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "manager",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "global manager, or the manager namespaced to `args.manager_authority`"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "CreateMintAccountArgs"
+          }
+        },
+        {
+          "name": "proof",
+          "type": {
+            "option": {
+              "vec": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "enableEditions",
+      "docs": [
+        "enable numbered editions of a master mint"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "metadata update authority of the master mint"
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "masterEdition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "EnableEditionsArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "printEdition",
+      "docs": [
+        "print the next edition of a master mint"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "metadata update authority of the master mint, mint authority of the edition"
+          ]
+        },
+        {
+          "name": "receiver",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "masterMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "masterMetadataAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "masterEdition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "group",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "masterMember",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "memberCounter",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "last member number assigned in the group"
+          ]
+        },
+        {
+          "name": "memberIndex",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "finalization",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "policy",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "mintTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "extraMetasAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "managerAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "manager",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "addMetadata",
+      "docs": [
+        "add metadata to mint"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "metadata update authority of the mint"
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "policy",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "AddMetadataArgs"
+          }
+        },
+        {
+          "name": "mode",
+          "type": {
+            "option": {
+              "defined": "MetadataWriteMode"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "addMetadataBatch",
+      "docs": [
+        "add multiple metadata fields to mint"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "metadata update authority of the mint"
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "policy",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "vec": {
+              "defined": "AddMetadataArgs"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "removeMetadata",
+      "docs": [
+        "remove metadata from mint"
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "metadata update authority of the mint, refunded the freed rent by default"
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "rentDestination",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "managerAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "manager",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "RemoveMetadataArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateMintMetadata",
+      "docs": [
+        "update the name, symbol and uri of mint"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "pays for metadata growth and is refunded when it shrinks"
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "metadata update authority of the mint"
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "managerAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "manager",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "UpdateMintMetadataArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "nominateMetadataAuthority",
+      "docs": [
+        "nominate a new metadata update authority"
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "metadata update authority of the mint"
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "nomination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "nominee",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "acceptMetadataAuthority",
+      "docs": [
+        "accept a metadata update authority nomination"
+      ],
+      "accounts": [
+        {
+          "name": "nominee",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "nomination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelNomination",
+      "docs": [
+        "cancel a metadata update authority nomination"
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "metadata update authority at nomination"
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "nomination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setContentHash",
+      "docs": [
+        "set or clear the content hash of mint"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "metadata update authority of the mint"
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "contentHash",
+          "type": {
+            "option": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "verifyContentHash",
+      "docs": [
+        "check a hash against the content hash of mint"
+      ],
+      "accounts": [
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "contentHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ],
+      "returns": "bool"
+    },
+    {
+      "name": "makeImmutable",
+      "docs": [
+        "make mint metadata immutable"
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "metadata update authority and metadata pointer authority of the mint"
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "freezeMetadata",
+      "docs": [
+        "remove the metadata update authority once the collection is revealed, same as `make_immutable`"
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "metadata update authority and metadata pointer authority of the mint"
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "addRoyaltiesToMint",
+      "docs": [
+        "add royalties to mint"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "extraMetasAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "policy",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "AddRoyaltiesArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "modifyRoyalties",
+      "docs": [
+        "modify royalties of mint"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "metadata update authority of the mint"
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "extraMetasAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "verifiedCreators",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "group of the member, only needed for group members"
+          ]
+        },
+        {
+          "name": "royaltyDefaults",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "policy",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "royaltyTimelock",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "pendingRoyaltyUpdate",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "royalties queued for timelocked group members"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "AddRoyaltiesArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "applyRoyaltyUpdate",
+      "docs": [
+        "apply pending royalties of a timelocked group member"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "metadata update authority of the mint"
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "extraMetasAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "verifiedCreators",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "group of the member, only needed for group members"
+          ]
+        },
+        {
+          "name": "royaltyDefaults",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "policy",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "royaltyTimelock",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "pendingRoyaltyUpdate",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "royalties queued for timelocked group members"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "removeRoyalties",
+      "docs": [
+        "remove royalties of mint"
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "metadata update authority of the mint, refunded the freed rent by default"
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "extraMetasAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "verifiedCreators",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "group of the member, only needed for group members"
+          ]
+        },
+        {
+          "name": "royaltyDefaults",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "policy",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "rentDestination",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "managerAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "manager",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "verifyCreator",
+      "docs": [
+        "verify the signing creator of mint royalties"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "creator",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "creator listed in the mint royalties"
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "verifiedCreators",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "unverifyCreator",
+      "docs": [
+        "unverify the signing creator of mint royalties"
+      ],
+      "accounts": [
+        {
+          "name": "creator",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "verified creator of the mint"
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "verifiedCreators",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeExpiredApproval",
+      "docs": [
+        "close a consumed or expired approval"
+      ],
+      "accounts": [
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "approveAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initProvenance",
+      "docs": [
+        "opt a mint in to the provenance log of its transfers"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "provenance",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "extraMetasAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "updateExtraMetasList",
+      "docs": [
+        "rewrite the extra metas of a mint to its canonical list"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "funds the growth of the extra metas account and is refunded when it shrinks"
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "extraMetasAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "secondaryHook",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "execute",
+      "docs": [
+        "validate transfer"
+      ],
+      "accounts": [
+        {
+          "name": "sourceAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "destinationAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ownerDelegate",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "extraMetasAccount",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "approve",
+      "docs": [
+        "approve transfer"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "approveAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "paymentMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "distributionAddress",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payerAddress",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "distribution",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "distributionEventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "distributionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "group of the member, only needed for group members"
+          ]
+        },
+        {
+          "name": "collectionDistribution",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "royaltyDefaults",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "sourceTokenAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "token account the approved transfer moves from, records its owner as seller when",
+            "approved by its delegate"
+          ]
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "expirySlot",
+          "type": {
+            "option": "u64"
+          }
+        },
+        {
+          "name": "transferAmount",
+          "type": "u64"
+        },
+        {
+          "name": "destination",
+          "type": "publicKey"
+        },
+        {
+          "name": "seller",
+          "type": "publicKey"
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "mintAllowlist",
+      "docs": [
+        "Merkle allowlist of the receivers of member mints, set by the group authority"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "group",
+            "docs": [
+              "The pubkey of the `TokenGroup`"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "root",
+            "docs": [
+              "Root of the Merkle tree of allowed receivers, see `verify_allowlist_proof`"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "limitPerWallet",
+            "docs": [
+              "Maximum number of member mints per receiver"
+            ],
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "allowlistClaim",
+      "docs": [
+        "Number of member mints received by a wallet through the group allowlist"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "count",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "approveAccount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "slot",
+            "docs": [
+              "Slot of the approval, 0 once consumed by a transfer"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "expirySlot",
+            "docs": [
+              "Last slot in which the approval can be consumed"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "payer",
+            "docs": [
+              "Funded the account rent, refunded when the expired approval is closed"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "Token amount of the approved transfer"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "destination",
+            "docs": [
+              "Destination owner of the approved transfer"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "seller",
+            "docs": [
+              "Source owner of the approved transfer, as declared by the marketplace"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "buyer",
+            "docs": [
+              "Authority that paid the sale and its royalties"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "salePrice",
+            "docs": [
+              "Sale amount the royalties were taken from"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "paymentMint",
+            "docs": [
+              "Mint the royalties were paid in, `Pubkey::default()` for SOL"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "royaltyAmount",
+            "docs": [
+              "Royalty amount paid for the approved transfer"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "collectionDistribution",
+      "docs": [
+        "Routes the royalties of group members to a single distribution of the group mint, set by",
+        "the group authority"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "group",
+            "docs": [
+              "The pubkey of the `TokenGroup`"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "enabled",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "masterEdition",
+      "docs": [
+        "Numbered copies authorized for a master mint, printed through `print_edition`"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "docs": [
+              "The master mint"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "maxSupply",
+            "docs": [
+              "Maximum number of editions"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "currentSupply",
+            "docs": [
+              "Number of editions printed, also the number of the last edition"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "tokenGroup",
+      "docs": [
+        "Data struct for a `TokenGroup`"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "updateAuthority",
+            "docs": [
+              "The authority that can sign to update the group"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "The associated mint, used to counter spoofing to be sure that group",
+              "belongs to a particular mint"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "size",
+            "docs": [
+              "The current number of group members. Counts member mints, not the",
+              "supply of each mint"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "maxSize",
+            "docs": [
+              "The maximum number of group members, 0 for unlimited"
+            ],
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "groupFinalization",
+      "docs": [
+        "Marks a group as finalized, no member can ever be added once it exists"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "group",
+            "docs": [
+              "The pubkey of the `TokenGroup`"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "size",
+            "docs": [
+              "Size of the group at finalization"
+            ],
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "groupSoulbound",
+      "docs": [
+        "Marks a group as soulbound, set at group creation and never removed. Members of soulbound",
+        "groups must be non-transferable and the transfer hook treats the group as `Frozen`"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "group",
+            "docs": [
+              "The pubkey of the `TokenGroup`"
+            ],
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "groupDelegates",
+      "docs": [
+        "Signers allowed to add members to a group on behalf of the group authority, e.g. launchpads"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "group",
+            "docs": [
+              "The pubkey of the `TokenGroup`"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "delegates",
+            "docs": [
+              "Wallets or program PDAs signing through CPI"
+            ],
+            "type": {
+              "vec": "publicKey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "groupPermission",
+      "docs": [
+        "Operations a delegate may perform on a group on behalf of the group authority, until",
+        "the grant expires or is revoked"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "group",
+            "docs": [
+              "The pubkey of the `TokenGroup`"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "delegate",
+            "type": "publicKey"
+          },
+          {
+            "name": "permissions",
+            "docs": [
+              "Bitmask of the `GroupPermission` operation flags"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "expiresAt",
+            "docs": [
+              "Unix timestamp the grant expires at, never expires when `None`"
+            ],
+            "type": {
+              "option": "i64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "groupHierarchy",
+      "docs": [
+        "Position of a group in a hierarchy of groups, e.g. seasons of a parent collection"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "group",
+            "docs": [
+              "The pubkey of the `TokenGroup`, unset until the group joins a hierarchy"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "parent",
+            "docs": [
+              "The parent group, `None` for top level groups"
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "ancestors",
+            "docs": [
+              "All the ancestors of the group, parent first"
+            ],
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "children",
+            "docs": [
+              "Number of sub-groups of the group"
+            ],
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "manager",
+      "docs": [
+        "Data struct for a `Manager`"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": []
+      }
+    },
+    {
+      "name": "tokenGroupMember",
+      "docs": [
+        "Data struct for a `TokenGroupMember`"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "docs": [
+              "The associated mint, used to counter spoofing to be sure that member",
+              "belongs to a particular mint"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "group",
+            "docs": [
+              "The pubkey of the `TokenGroup`"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "memberNumber",
+            "docs": [
+              "The member number"
+            ],
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "groupMemberCounter",
+      "docs": [
+        "Last member number assigned in a group. Numbers are never reused, so members",
+        "keep a stable number when others are removed."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "group",
+            "docs": [
+              "The pubkey of the `TokenGroup`, unset until the first member number is assigned"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "lastMemberNumber",
+            "docs": [
+              "The last assigned member number"
+            ],
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "groupMemberIndex",
+      "docs": [
+        "Page of the members of a group ordered by member number, removed members are left as",
+        "`Pubkey::default()` so members keep their position"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "group",
+            "docs": [
+              "The pubkey of the `TokenGroup`"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "page",
+            "docs": [
+              "The page number, page `n` holds members `n * MEMBER_INDEX_PAGE_SIZE + 1` onwards"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "members",
+            "docs": [
+              "Member mints"
+            ],
+            "type": {
+              "vec": "publicKey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "mintConfig",
+      "docs": [
+        "Public mint of member mints of a group, set by the group authority"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "group",
+            "docs": [
+              "The pubkey of the `TokenGroup`"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "paymentMint",
+            "docs": [
+              "Mint of the price, `Pubkey::default()` for SOL"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "price",
+            "docs": [
+              "Price of a member mint in lamports or base units of `payment_mint`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "treasury",
+            "docs": [
+              "Receives the price, or owns the associated token account receiving it for tokens"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "startTime",
+            "docs": [
+              "Unix timestamp the public mint opens at, open right away when `None`"
+            ],
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "endTime",
+            "docs": [
+              "Unix timestamp the public mint closes at, never closes when `None`"
+            ],
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "limitPerWallet",
+            "docs": [
+              "Maximum number of member mints per wallet, 0 for unlimited"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "name",
+            "docs": [
+              "Name of the member mints, followed by ` #<member number>`"
+            ],
+            "type": "string"
+          },
+          {
+            "name": "symbol",
+            "type": "string"
+          },
+          {
+            "name": "baseUri",
+            "docs": [
+              "Uri of the member mints, followed by the member number"
+            ],
+            "type": "string"
+          }
+        ]
+      }
+    },
+    {
+      "name": "publicMintClaim",
+      "docs": [
+        "Number of member mints received by a wallet through the public mint of the group"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "count",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "metadataAuthorityNomination",
+      "docs": [
+        "Pending transfer of the metadata update authority of a mint.",
+        "While pending, the metadata update authority of mints storing metadata in the mint",
+        "is this PDA, so the nomination can be accepted without the nominator signing."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "docs": [
+              "The mint"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "authority",
+            "docs": [
+              "The metadata update authority at nomination, refunded the rent on close"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "nominee",
+            "docs": [
+              "The nominated metadata update authority"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "groupAuthorityNomination",
+      "docs": [
+        "Pending transfer of the update authority of a group. The group keeps its update",
+        "authority until the nominee accepts, so every group instruction still requires it."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "group",
+            "docs": [
+              "The group"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "authority",
+            "docs": [
+              "The group update authority at nomination, refunded the rent on close"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "nominee",
+            "docs": [
+              "The nominated group update authority"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "groupExtensionPolicy",
+      "docs": [
+        "Constraints on the mints joining a group, set by the group authority"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "group",
+            "docs": [
+              "The pubkey of the `TokenGroup`"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "allowedExtensions",
+            "docs": [
+              "Extension types member mints may have, any extension is allowed when empty"
+            ],
+            "type": {
+              "vec": "u16"
+            }
+          },
+          {
+            "name": "requiredExtensions",
+            "docs": [
+              "Extension types member mints must have"
+            ],
+            "type": {
+              "vec": "u16"
+            }
+          },
+          {
+            "name": "minRoyaltyBasisPoints",
+            "docs": [
+              "Bounds of the member royalty basis points, mints without royalties have 0"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "maxRoyaltyBasisPoints",
+            "type": "u16"
+          },
+          {
+            "name": "maxAdditionalMetadataBytes",
+            "docs": [
+              "Maximum size of the additional metadata of members, uncapped when 0"
+            ],
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "provenanceRecord",
+      "docs": [
+        "Last transfers of a mint, appended by the transfer hook once the mint opted in"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "next",
+            "docs": [
+              "Index of the entry overwritten by the next transfer once the log is full"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "entries",
+            "type": {
+              "vec": {
+                "defined": "ProvenanceEntry"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "mintRecord",
+      "docs": [
+        "Provenance of a mint, written once at creation"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "docs": [
+              "The mint authority at creation"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "payer",
+            "docs": [
+              "Paid the record rent, refunded when the record is closed"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "slot",
+            "docs": [
+              "Slot the mint was created at"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "group",
+            "docs": [
+              "The `TokenGroup` the mint was created in, if any"
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "manager",
+            "docs": [
+              "The `Manager` holding the mint authorities, global or namespaced to an authority"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "groupRoyaltyDefaults",
+      "docs": [
+        "Royalties applied to member mints created without royalties, set by the group authority"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "group",
+            "docs": [
+              "The pubkey of the `TokenGroup`"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "royaltyBasisPoints",
+            "type": "u16"
+          },
+          {
+            "name": "creators",
+            "type": {
+              "vec": {
+                "defined": "DefaultCreator"
+              }
+            }
+          },
+          {
+            "name": "locked",
+            "docs": [
+              "Only the group authority can modify the royalties of members when set"
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "royaltyEnforcement",
+      "docs": [
+        "Royalty enforcement mode of a group, set by the group authority"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "group",
+            "docs": [
+              "The pubkey of the `TokenGroup`"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "mode",
+            "type": {
+              "defined": "EnforcementMode"
+            }
+          },
+          {
+            "name": "memoRequired",
+            "docs": [
+              "Transfers must follow a memo instruction in their transaction"
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "royaltyExemptions",
+      "docs": [
+        "Owners and programs whose transfers of group members don't pay royalties, set by the group authority"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "group",
+            "docs": [
+              "The pubkey of the `TokenGroup`"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "exemptions",
+            "docs": [
+              "Token account owners or invoking programs"
+            ],
+            "type": {
+              "vec": "publicKey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "royaltyTimelock",
+      "docs": [
+        "Delay before royalty updates of group members take effect, set by the group authority"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "group",
+            "docs": [
+              "The pubkey of the `TokenGroup`"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "delay",
+            "docs": [
+              "Seconds between `modify_royalties` and `apply_royalty_update`, 0 when disabled"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "pendingRoyaltyUpdate",
+      "docs": [
+        "Royalties queued by `modify_royalties` for a timelocked group member"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "docs": [
+              "The pubkey of the mint"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "payer",
+            "docs": [
+              "Refunded the rent when the update is applied or cancelled"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "effectiveAfter",
+            "docs": [
+              "Unix timestamp after which the update can be applied"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "royalties",
+            "type": {
+              "defined": "AddRoyaltiesArgs"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "secondaryHook",
+      "docs": [
+        "Transfer hook program invoked by the transfer hook after royalties are enforced on",
+        "transfers of group members, set by the group authority"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "group",
+            "docs": [
+              "The pubkey of the `TokenGroup`"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "programId",
+            "type": "publicKey"
+          },
+          {
+            "name": "accounts",
+            "docs": [
+              "Static extra accounts of the secondary hook program, in the order it resolves them"
+            ],
+            "type": {
+              "vec": {
+                "defined": "SecondaryHookAccount"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "verifiedCreators",
+      "docs": [
+        "Royalty creators of a mint that signed to endorse it"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "docs": [
+              "The pubkey of the mint"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "creators",
+            "type": {
+              "vec": "publicKey"
+            }
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "MintAllowlistArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "limitPerWallet",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CreateGroupAccountArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "name",
+            "type": "string"
+          },
+          {
+            "name": "symbol",
+            "type": "string"
+          },
+          {
+            "name": "uri",
+            "type": "string"
+          },
+          {
+            "name": "maxSize",
+            "type": "u32"
+          },
+          {
+            "name": "soulbound",
+            "docs": [
+              "Require every member to be non-transferable and freeze transfers of the group,",
+              "can't be changed once the group is created"
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "UpdateGroupMetadataArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "name",
+            "type": {
+              "option": "string"
+            }
+          },
+          {
+            "name": "symbol",
+            "type": {
+              "option": "string"
+            }
+          },
+          {
+            "name": "uri",
+            "type": {
+              "option": "string"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "MintConfigArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "paymentMint",
+            "docs": [
+              "Mint of the price, `Pubkey::default()` for SOL"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "treasury",
+            "type": "publicKey"
+          },
+          {
+            "name": "startTime",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "endTime",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "limitPerWallet",
+            "docs": [
+              "0 for unlimited"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "name",
+            "type": "string"
+          },
+          {
+            "name": "symbol",
+            "type": "string"
+          },
+          {
+            "name": "baseUri",
+            "type": "string"
+          }
+        ]
+      }
+    },
+    {
+      "name": "GroupPermissionArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "permissions",
+            "docs": [
+              "Bitmask of the `GroupPermission` operation flags"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "expiresAt",
+            "docs": [
+              "Unix timestamp the grant expires at, never expires when `None`"
+            ],
+            "type": {
+              "option": "i64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "GroupExtensionPolicyArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "allowedExtensions",
+            "docs": [
+              "Extension types member mints may have, any extension is allowed when empty"
+            ],
+            "type": {
+              "vec": "u16"
+            }
+          },
+          {
+            "name": "requiredExtensions",
+            "docs": [
+              "Extension types member mints must have"
+            ],
+            "type": {
+              "vec": "u16"
+            }
+          },
+          {
+            "name": "minRoyaltyBasisPoints",
+            "type": "u16"
+          },
+          {
+            "name": "maxRoyaltyBasisPoints",
+            "type": "u16"
+          },
+          {
+            "name": "maxAdditionalMetadataBytes",
+            "docs": [
+              "Maximum size of the additional metadata of members, uncapped when 0"
+            ],
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "GroupRoyaltyDefaultsArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "royaltyBasisPoints",
+            "type": "u16"
+          },
+          {
+            "name": "creators",
+            "type": {
+              "vec": {
+                "defined": "DefaultCreator"
+              }
+            }
+          },
+          {
+            "name": "locked",
+            "docs": [
+              "Restrict `modify_royalties` on members to the group authority"
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "GroupSupply",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "size",
+            "type": "u32"
+          },
+          {
+            "name": "maxSize",
+            "docs": [
+              "0 for unlimited"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "finalized",
+            "docs": [
+              "No member can be added to a finalized group"
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "UpdateGroupAccountArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "name",
+            "type": "string"
+          },
+          {
+            "name": "symbol",
+            "type": "string"
+          },
+          {
+            "name": "uri",
+            "type": "string"
+          },
+          {
+            "name": "maxSize",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AddMetadataArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "field",
+            "type": "string"
+          },
+          {
+            "name": "value",
+            "type": "string"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CreateMintAccountArgs",
+      "docs": [
+        "Clients predating the creation options only send the name, symbol and uri, the options",
+        "then take their `Default` values"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "name",
+            "type": "string"
+          },
+          {
+            "name": "symbol",
+            "type": "string"
+          },
+          {
+            "name": "uri",
+            "type": "string"
+          },
+          {
+            "name": "soulbound",
+            "docs": [
+              "Initialize the mint with the NonTransferable extension. Soulbound mints",
+              "have no transfer hook since the token can never move."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "permanentDelegate",
+            "docs": [
+              "Initialize the mint with the PermanentDelegate extension"
+            ],
+            "type": {
+              "option": {
+                "defined": "PermanentDelegateConfig"
+              }
+            }
+          },
+          {
+            "name": "frozen",
+            "docs": [
+              "Initialize the DefaultAccountState extension as frozen. Token accounts,",
+              "including the receiver's, stay frozen until thawed through `thaw_mint_account`"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "freezeAuthority",
+            "docs": [
+              "Mint freeze authority, defaults to the `Manager` PDA which frozen mints require"
+            ],
+            "type": {
+              "option": {
+                "defined": "FreezeAuthorityConfig"
+              }
+            }
+          },
+          {
+            "name": "decimals",
+            "docs": [
+              "Mint decimals, defaults to 0"
+            ],
+            "type": {
+              "option": "u8"
+            }
+          },
+          {
+            "name": "initialSupply",
+            "docs": [
+              "Amount minted to the receiver, defaults to 1"
+            ],
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "additionalMetadata",
+            "docs": [
+              "Additional metadata fields written right after the metadata is initialized"
+            ],
+            "type": {
+              "vec": {
+                "defined": "AddMetadataArgs"
+              }
+            }
+          },
+          {
+            "name": "royalties",
+            "docs": [
+              "Royalties enforced from creation, equivalent to a following `add_royalties_to_mint`"
+            ],
+            "type": {
+              "option": {
+                "defined": "AddRoyaltiesArgs"
+              }
+            }
+          },
+          {
+            "name": "updateAuthority",
+            "docs": [
+              "Metadata and metadata pointer update authority, defaults to the mint authority"
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "immutable",
+            "docs": [
+              "Remove the metadata update authority once all creation time fields are written"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "allowPdaReceiver",
+            "docs": [
+              "Allow receivers that aren't wallets, e.g. escrow or launchpad PDAs"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "externalMetadata",
+            "docs": [
+              "Store the metadata in the `[METADATA_SEED, mint]` PDA instead of the mint,",
+              "the metadata pointer references that account"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "contentHash",
+            "docs": [
+              "Hash of the off-chain JSON committed in the `CONTENT_HASH_FIELD` metadata field"
+            ],
+            "type": {
+              "option": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
+          },
+          {
+            "name": "mintRecord",
+            "docs": [
+              "Record the creator and creation slot in the `[MINT_RECORD_SEED, mint]` PDA"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "managerAuthority",
+            "docs": [
+              "Authority of the `Manager` created by `init_manager_for_authority` holding the mint",
+              "authorities, the global manager when `None`"
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "provenance",
+            "docs": [
+              "Set the WNS transfer hook program even without royalties or group, so transfers are",
+              "logged once `init_provenance` opts the mint in. Soulbound mints never transfer"
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CreatedMintAccount",
+      "docs": [
+        "Returned by `create_mint_account` for programs creating mints through CPI"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "extraMetasAccount",
+            "type": "publicKey"
+          },
+          {
+            "name": "manager",
+            "type": "publicKey"
+          },
+          {
+            "name": "metadataAddress",
+            "docs": [
+              "metadata pointer address, the mint itself unless `external_metadata` stores the",
+              "metadata in the `[METADATA_SEED, mint]` PDA"
+            ],
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "EnableEditionsArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maxSupply",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RemoveMetadataArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "field",
+            "type": "string"
+          }
+        ]
+      }
+    },
+    {
+      "name": "UpdateMintMetadataArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "name",
+            "type": {
+              "option": "string"
+            }
+          },
+          {
             "name": "symbol",
+            "type": {
+              "option": "string"
+            }
+          },
+          {
+            "name": "uri",
+            "type": {
+              "option": "string"
+            }
+          },
+          {
+            "name": "contentHash",
+            "docs": [
+              "New content hash, required along a new uri when the mint has a content hash"
+            ],
+            "type": {
+              "option": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
+          },
+          {
+            "name": "clearContentHash",
+            "docs": [
+              "Clear the content hash instead of setting a new one"
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CreatorWithShare",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "address",
             "type": "string"
           },
-          {
-            "name": "uri",
-            "type": "string"
-          }
-        ]
-      }
+          {
+            "name": "share",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AddRoyaltiesArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "royaltyBasisPoints",
+            "type": "u16"
+          },
+          {
+            "name": "creators",
+            "type": {
+              "vec": {
+                "defined": "CreatorWithShare"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "ProvenanceEntry",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "slot",
+            "type": "u64"
+          },
+          {
+            "name": "fromOwner",
+            "type": "publicKey"
+          },
+          {
+            "name": "toOwner",
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "salePrice",
+            "docs": [
+              "Sale price of the approval consumed by the transfer, 0 without one"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "DefaultCreator",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "address",
+            "type": "publicKey"
+          },
+          {
+            "name": "share",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SecondaryHookAccount",
+      "docs": [
+        "Account passed to the secondary hook program after its validation account"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "address",
+            "type": "publicKey"
+          },
+          {
+            "name": "isWritable",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PermanentDelegateConfig",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Manager"
+          },
+          {
+            "name": "Address",
+            "fields": [
+              "publicKey"
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "FreezeAuthorityConfig",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Address",
+            "fields": [
+              "publicKey"
+            ]
+          },
+          {
+            "name": "Disabled"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MetadataWriteMode",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Insert"
+          },
+          {
+            "name": "Update"
+          },
+          {
+            "name": "Upsert"
+          }
+        ]
+      }
+    },
+    {
+      "name": "EnforcementMode",
+      "docs": [
+        "How the transfer hook enforces royalties on transfers of group members. Every group member",
+        "resolves the mode, with or without royalties, but only `Frozen` applies to members without",
+        "royalties as they have no approval to require. Existing mints resolve the mode once their extra metas are rebuilt, e.g. by",
+        "`update_extra_metas_list`, and are `Standard` until then."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Strict"
+          },
+          {
+            "name": "Standard"
+          },
+          {
+            "name": "Permissive"
+          },
+          {
+            "name": "Frozen"
+          }
+        ]
+      }
+    }
+  ],
+  "events": [
+    {
+      "name": "MintCreated",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "authority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "receiver",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "name",
+          "type": "string",
+          "index": false
+        },
+        {
+          "name": "symbol",
+          "type": "string",
+          "index": false
+        },
+        {
+          "name": "uri",
+          "type": "string",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RoyaltiesUpdated",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "royaltyBasisPoints",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "creators",
+          "type": {
+            "vec": "string"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MetadataFrozen",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RoyaltiesRemoved",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RoyaltyExemptionAdded",
+      "fields": [
+        {
+          "name": "group",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "address",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RoyaltyExemptionRemoved",
+      "fields": [
+        {
+          "name": "group",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "address",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MetadataUpdated",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "key",
+          "type": "string",
+          "index": false
+        },
+        {
+          "name": "oldValue",
+          "type": {
+            "option": "string"
+          },
+          "index": false
+        },
+        {
+          "name": "newValue",
+          "type": {
+            "option": "string"
+          },
+          "index": false
+        },
+        {
+          "name": "authority",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "CreatorWithShare",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "address",
-            "type": "string"
+      "name": "CreatorVerified",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "CreatorUnverified",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RoyaltyUpdateQueued",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "royaltyBasisPoints",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "creators",
+          "type": {
+            "vec": "string"
           },
-          {
-            "name": "share",
-            "type": "u8"
-          }
-        ]
-      }
+          "index": false
+        },
+        {
+          "name": "effectiveAfter",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TransferApproved",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "seller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "buyer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "destination",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "salePrice",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "paymentMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "royaltyAmount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ApprovedTransferExecuted",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "source",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "destination",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "seller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "buyer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "salePrice",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "paymentMint",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ExtraMetasUpdated",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "group",
+          "type": {
+            "option": "publicKey"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EnforcementModeUpdated",
+      "fields": [
+        {
+          "name": "group",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "oldMode",
+          "type": {
+            "defined": "EnforcementMode"
+          },
+          "index": false
+        },
+        {
+          "name": "newMode",
+          "type": {
+            "defined": "EnforcementMode"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SecondaryHookRegistered",
+      "fields": [
+        {
+          "name": "group",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "programId",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SecondaryHookUnregistered",
+      "fields": [
+        {
+          "name": "group",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RoyaltyUpdateCancelled",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "GroupUpdated",
+      "fields": [
+        {
+          "name": "group",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "oldMaxSize",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "newMaxSize",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "size",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MemberRemoved",
+      "fields": [
+        {
+          "name": "group",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "memberNumber",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "GroupClosed",
+      "fields": [
+        {
+          "name": "group",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mintClosed",
+          "type": "bool",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MemberAdded",
+      "fields": [
+        {
+          "name": "group",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "memberNumber",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "GroupFinalized",
+      "fields": [
+        {
+          "name": "group",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "size",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "GroupParentSet",
+      "fields": [
+        {
+          "name": "group",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "parent",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "GroupDelegateApproved",
+      "fields": [
+        {
+          "name": "group",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "delegate",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "GroupDelegateRevoked",
+      "fields": [
+        {
+          "name": "group",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "delegate",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "GroupSizeChanged",
+      "fields": [
+        {
+          "name": "group",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "size",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "maxSize",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "GroupPermissionGranted",
+      "fields": [
+        {
+          "name": "group",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "delegate",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "permissions",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "expiresAt",
+          "type": {
+            "option": "i64"
+          },
+          "index": false
+        }
+      ]
     },
     {
-      "name": "AddRoyaltiesArgs",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "royaltyBasisPoints",
-            "type": "u16"
-          },
-          {
-            "name": "creators",
-            "type": {
-              "vec": {
-                "defined": "CreatorWithShare"
-              }
-            }
-          }
-        ]
-      }
+      "name": "GroupPermissionRevoked",
+      "fields": [
+        {
+          "name": "group",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "delegate",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ManagerInitialized",
+      "fields": [
+        {
+          "name": "manager",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "authority",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
     }
   ],
   "errors": [
@@ -1258,7 +14611,7 @@ export const IDL: WenNewStandard = {
     },
     {
       "code": 6002,
-      "name": "CreatorShareInvalid",
+      "name": "InvalidCreatorShareTotal",
       "msg": "Creators shares must add up to 100."
     },
     {
@@ -1270,6 +14623,551 @@ export const IDL: WenNewStandard = {
       "code": 6004,
       "name": "ExpiredApproveAccount",
       "msg": "Approve account has expired."
+    },
+    {
+      "code": 6005,
+      "name": "InvalidBatchAccounts",
+      "msg": "Remaining accounts must contain a mint, token account and extra metas account per entry."
+    },
+    {
+      "code": 6006,
+      "name": "InvalidMintTokenAccount",
+      "msg": "Mint token account is not the receiver's associated token account."
+    },
+    {
+      "code": 6007,
+      "name": "InvalidExtraMetasAccount",
+      "msg": "Extra metas account does not match the mint."
+    },
+    {
+      "code": 6008,
+      "name": "SoulboundMint",
+      "msg": "Soulbound mints cannot be transferred."
+    },
+    {
+      "code": 6009,
+      "name": "InvalidPermanentDelegate",
+      "msg": "Manager is not the permanent delegate of the mint."
+    },
+    {
+      "code": 6010,
+      "name": "InvalidInitialSupply",
+      "msg": "Initial supply must be greater than 0."
+    },
+    {
+      "code": 6011,
+      "name": "ReservedMetadataKey",
+      "msg": "Metadata key is reserved."
+    },
+    {
+      "code": 6012,
+      "name": "TooManyCreators",
+      "msg": "Too many creators."
+    },
+    {
+      "code": 6013,
+      "name": "MintAlreadyClaimed",
+      "msg": "Mint has already been claimed."
+    },
+    {
+      "code": 6014,
+      "name": "InvalidMetadataAuthority",
+      "msg": "Invalid metadata update authority."
+    },
+    {
+      "code": 6015,
+      "name": "NameTooLong",
+      "msg": "Name is too long."
+    },
+    {
+      "code": 6016,
+      "name": "SymbolTooLong",
+      "msg": "Symbol is too long."
+    },
+    {
+      "code": 6017,
+      "name": "UriTooLong",
+      "msg": "Uri is too long."
+    },
+    {
+      "code": 6018,
+      "name": "EmptyField",
+      "msg": "Name and uri cannot be empty."
+    },
+    {
+      "code": 6019,
+      "name": "ExtraMetasAlreadyInitialized",
+      "msg": "Extra metas account is already initialized."
+    },
+    {
+      "code": 6020,
+      "name": "MetadataImmutable",
+      "msg": "Metadata is immutable."
+    },
+    {
+      "code": 6021,
+      "name": "MissingRoyalties",
+      "msg": "Mint has no royalties."
+    },
+    {
+      "code": 6022,
+      "name": "MintAuthorityAlreadyRevoked",
+      "msg": "Mint authority has already been revoked."
+    },
+    {
+      "code": 6023,
+      "name": "InvalidFinalizeAuthority",
+      "msg": "Authority cannot finalize this mint."
+    },
+    {
+      "code": 6024,
+      "name": "ReceiverNotAllowed",
+      "msg": "Receiver must be a wallet unless PDA receivers are allowed."
+    },
+    {
+      "code": 6025,
+      "name": "InvalidMetadataAccount",
+      "msg": "Metadata account does not match the mint metadata pointer."
+    },
+    {
+      "code": 6026,
+      "name": "MissingMetadataField",
+      "msg": "Metadata field not found."
+    },
+    {
+      "code": 6027,
+      "name": "InvalidExtensionPolicy",
+      "msg": "Invalid group extension policy."
+    },
+    {
+      "code": 6028,
+      "name": "ExtensionNotAllowed",
+      "msg": "Mint extension is not allowed by the group policy."
+    },
+    {
+      "code": 6029,
+      "name": "MissingRequiredExtension",
+      "msg": "Mint is missing an extension required by the group policy."
+    },
+    {
+      "code": 6030,
+      "name": "RoyaltiesOutOfPolicyBounds",
+      "msg": "Royalty basis points are out of the group policy bounds."
+    },
+    {
+      "code": 6031,
+      "name": "NoFreezeAuthority",
+      "msg": "Mint freeze authority is not the manager."
+    },
+    {
+      "code": 6032,
+      "name": "InvalidMintRecordAccount",
+      "msg": "Invalid mint record account."
+    },
+    {
+      "code": 6033,
+      "name": "MintNotBurned",
+      "msg": "Mint supply must be burned to close its record."
+    },
+    {
+      "code": 6034,
+      "name": "InvalidMaxEditions",
+      "msg": "Max editions must be greater than 0."
+    },
+    {
+      "code": 6035,
+      "name": "MaxEditionsReached",
+      "msg": "All editions have been printed."
+    },
+    {
+      "code": 6036,
+      "name": "GroupAuthoritySignatureRequired",
+      "msg": "Group authority must sign to create member mints."
+    },
+    {
+      "code": 6037,
+      "name": "InvalidAllowlistProof",
+      "msg": "Receiver is not in the group allowlist."
+    },
+    {
+      "code": 6038,
+      "name": "AllowlistLimitReached",
+      "msg": "Receiver reached the group allowlist limit."
+    },
+    {
+      "code": 6039,
+      "name": "InvalidAllowlistClaimAccount",
+      "msg": "Invalid allowlist claim account."
+    },
+    {
+      "code": 6040,
+      "name": "TooManyMetadataFields",
+      "msg": "Too many metadata fields."
+    },
+    {
+      "code": 6041,
+      "name": "StaleNomination",
+      "msg": "Metadata update authority changed since the nomination."
+    },
+    {
+      "code": 6042,
+      "name": "MetadataNotFrozen",
+      "msg": "Metadata update authority was not removed."
+    },
+    {
+      "code": 6043,
+      "name": "MetadataSizeLimitExceeded",
+      "msg": "Additional metadata exceeds the group size limit."
+    },
+    {
+      "code": 6044,
+      "name": "InvalidGroupPolicyAccount",
+      "msg": "Group member and policy accounts are required for group members."
+    },
+    {
+      "code": 6045,
+      "name": "MetadataKeyExists",
+      "msg": "Metadata key already exists."
+    },
+    {
+      "code": 6046,
+      "name": "MetadataKeyNotFound",
+      "msg": "Metadata key not found."
+    },
+    {
+      "code": 6047,
+      "name": "ContentHashRequired",
+      "msg": "Content hash must be updated or cleared along the uri."
+    },
+    {
+      "code": 6048,
+      "name": "MissingMetadata",
+      "msg": "Mint has no token metadata."
+    },
+    {
+      "code": 6049,
+      "name": "MetadataKeyTooLong",
+      "msg": "Metadata key too long."
+    },
+    {
+      "code": 6050,
+      "name": "MetadataValueTooLong",
+      "msg": "Metadata value too long."
+    },
+    {
+      "code": 6051,
+      "name": "InvalidMetadataCharacters",
+      "msg": "Metadata contains control characters."
+    },
+    {
+      "code": 6052,
+      "name": "ZeroCreatorShare",
+      "msg": "Creator shares must be greater than 0."
+    },
+    {
+      "code": 6053,
+      "name": "DuplicateCreator",
+      "msg": "Creators must be unique."
+    },
+    {
+      "code": 6054,
+      "name": "InvalidGroupRoyaltyDefaultsAccount",
+      "msg": "Group member, group and royalty defaults accounts are required for group members."
+    },
+    {
+      "code": 6055,
+      "name": "RoyaltiesLocked",
+      "msg": "Royalties of group members are locked to the group authority."
+    },
+    {
+      "code": 6056,
+      "name": "InvalidGroupMemberAccount",
+      "msg": "Group member account is required for group members."
+    },
+    {
+      "code": 6057,
+      "name": "TooManyRoyaltyExemptions",
+      "msg": "Too many royalty exemptions."
+    },
+    {
+      "code": 6058,
+      "name": "RoyaltyExemptionExists",
+      "msg": "Royalty exemption already exists."
+    },
+    {
+      "code": 6059,
+      "name": "RoyaltyExemptionNotFound",
+      "msg": "Royalty exemption not found."
+    },
+    {
+      "code": 6060,
+      "name": "CreatorNotFound",
+      "msg": "Creator is not part of the mint royalties."
+    },
+    {
+      "code": 6061,
+      "name": "CreatorAlreadyVerified",
+      "msg": "Creator is already verified."
+    },
+    {
+      "code": 6062,
+      "name": "CreatorNotVerified",
+      "msg": "Creator is not verified."
+    },
+    {
+      "code": 6063,
+      "name": "VerifiedCreatorRemoval",
+      "msg": "Verified creators must sign or unverify before being removed."
+    },
+    {
+      "code": 6064,
+      "name": "InvalidRoyaltyTimelockAccount",
+      "msg": "Royalty timelock account is required for group members."
+    },
+    {
+      "code": 6065,
+      "name": "InvalidRoyaltyTimelockDelay",
+      "msg": "Royalty timelock delay can't be negative."
+    },
+    {
+      "code": 6066,
+      "name": "InvalidPendingRoyaltyUpdateAccount",
+      "msg": "Pending royalty update account is required for timelocked group members."
+    },
+    {
+      "code": 6067,
+      "name": "RoyaltyUpdateTimelocked",
+      "msg": "Pending royalty update is still timelocked."
+    },
+    {
+      "code": 6068,
+      "name": "ArithmeticOverflow",
+      "msg": "Arithmetic overflow."
+    },
+    {
+      "code": 6069,
+      "name": "InvalidCollectionDistributionAccount",
+      "msg": "Group member, group, collection distribution and royalty defaults accounts are required for group members."
+    },
+    {
+      "code": 6070,
+      "name": "InvalidDistributionAccount",
+      "msg": "Distribution account does not match the distribution of the mint royalties."
+    },
+    {
+      "code": 6071,
+      "name": "InvalidApprovalExpiry",
+      "msg": "Approval expiry slot must be between the current slot and the maximum approval horizon."
+    },
+    {
+      "code": 6072,
+      "name": "ApprovalExpired",
+      "msg": "Approval has expired."
+    },
+    {
+      "code": 6073,
+      "name": "ApprovalNotExpired",
+      "msg": "Approval has not expired yet."
+    },
+    {
+      "code": 6074,
+      "name": "ApprovalMismatch",
+      "msg": "Transfer doesn't match the approved amount and destination."
+    },
+    {
+      "code": 6075,
+      "name": "NotInTransferContext",
+      "msg": "Transfer hook can only be executed during a transfer."
+    },
+    {
+      "code": 6076,
+      "name": "TransfersFrozen",
+      "msg": "Transfers of the group are frozen."
+    },
+    {
+      "code": 6077,
+      "name": "InvalidSecondaryHookProgram",
+      "msg": "Secondary hook program can't be the metadata program."
+    },
+    {
+      "code": 6078,
+      "name": "TooManySecondaryHookAccounts",
+      "msg": "Too many secondary hook accounts."
+    },
+    {
+      "code": 6079,
+      "name": "InvalidSecondaryHookAccount",
+      "msg": "Invalid secondary hook account."
+    },
+    {
+      "code": 6080,
+      "name": "InvalidRoyaltyEnforcementAccount",
+      "msg": "Invalid royalty enforcement account."
+    },
+    {
+      "code": 6081,
+      "name": "MemoRequired",
+      "msg": "Transfers of the group require a memo."
+    },
+    {
+      "code": 6082,
+      "name": "InvalidProvenanceAccount",
+      "msg": "Invalid provenance account."
+    },
+    {
+      "code": 6083,
+      "name": "GroupNotEmpty",
+      "msg": "Group still has members."
+    },
+    {
+      "code": 6084,
+      "name": "GroupFull",
+      "msg": "Group is full."
+    },
+    {
+      "code": 6085,
+      "name": "GroupFinalized",
+      "msg": "Group is finalized."
+    },
+    {
+      "code": 6086,
+      "name": "InvalidParentGroup",
+      "msg": "Group can't be added to this parent group."
+    },
+    {
+      "code": 6087,
+      "name": "GroupHierarchyTooDeep",
+      "msg": "Group hierarchy is too deep."
+    },
+    {
+      "code": 6088,
+      "name": "InvalidParentRoyaltyDefaultsAccount",
+      "msg": "Parent group royalty defaults account is required for sub-groups."
+    },
+    {
+      "code": 6089,
+      "name": "TooManyGroupDelegates",
+      "msg": "Too many group delegates."
+    },
+    {
+      "code": 6090,
+      "name": "GroupDelegateExists",
+      "msg": "Group delegate already exists."
+    },
+    {
+      "code": 6091,
+      "name": "GroupDelegateNotFound",
+      "msg": "Group delegate not found."
+    },
+    {
+      "code": 6092,
+      "name": "MemberPointerMismatch",
+      "msg": "Mint group member pointer doesn't point at the member account."
+    },
+    {
+      "code": 6093,
+      "name": "MissingMintExtension",
+      "msg": "Mint is missing an extension of WNS mints."
+    },
+    {
+      "code": 6094,
+      "name": "InvalidMemberIndexAccount",
+      "msg": "Invalid group member index account."
+    },
+    {
+      "code": 6095,
+      "name": "InvalidMemberBatchAccounts",
+      "msg": "Remaining accounts must contain a mint, member, extra metas and member index account per member."
+    },
+    {
+      "code": 6096,
+      "name": "TooManyBatchMembers",
+      "msg": "Too many members in batch."
+    },
+    {
+      "code": 6097,
+      "name": "SoulboundRequired",
+      "msg": "Members of soulbound groups must be non-transferable."
+    },
+    {
+      "code": 6098,
+      "name": "SoulboundGroupFrozen",
+      "msg": "Soulbound groups can't change their enforcement mode."
+    },
+    {
+      "code": 6099,
+      "name": "InvalidMintWindow",
+      "msg": "Public mint end time must be after its start time."
+    },
+    {
+      "code": 6100,
+      "name": "MintNotStarted",
+      "msg": "Public mint has not started."
+    },
+    {
+      "code": 6101,
+      "name": "MintEnded",
+      "msg": "Public mint has ended."
+    },
+    {
+      "code": 6102,
+      "name": "PublicMintLimitReached",
+      "msg": "Public mint limit per wallet reached."
+    },
+    {
+      "code": 6103,
+      "name": "InvalidPaymentAccounts",
+      "msg": "Payment mint and token accounts are required for token prices."
+    },
+    {
+      "code": 6104,
+      "name": "GroupPermissionDenied",
+      "msg": "Group permission doesn't cover this operation."
+    },
+    {
+      "code": 6105,
+      "name": "GroupPermissionExpired",
+      "msg": "Group permission has expired."
+    },
+    {
+      "code": 6106,
+      "name": "InvalidGroupPermissions",
+      "msg": "Invalid group permissions."
+    },
+    {
+      "code": 6107,
+      "name": "InvalidDestinationGroup",
+      "msg": "Member is already in the destination group."
+    },
+    {
+      "code": 6108,
+      "name": "InvalidManagerAccount",
+      "msg": "Manager account does not match the manager authority."
+    },
+    {
+      "code": 6109,
+      "name": "MissingTransferHook",
+      "msg": "Transfer hook of the mint does not invoke WNS."
+    },
+    {
+      "code": 6110,
+      "name": "InvalidMemberCounterAccount",
+      "msg": "Invalid group member counter account."
+    },
+    {
+      "code": 6111,
+      "name": "InvalidGroupDelegatesAccount",
+      "msg": "Invalid group delegates account."
+    },
+    {
+      "code": 6112,
+      "name": "InvalidGroupPermissionAccount",
+      "msg": "Invalid group permission account."
+    },
+    {
+      "code": 6113,
+      "name": "UserSeedTooLong",
+      "msg": "User seed must not exceed 32 bytes."
     }
   ]
 };
@@ -5,7 +5,7 @@ export type WenRoyaltyDistribution = {
     {
       "name": "initializeDistribution",
       "docs": [
-        "Initializes a new distribution account."
+        "Initializes a new distribution account for a payment mint of the collection."
       ],
       "accounts": [
         {
@@ -34,12 +34,19 @@ export type WenRoyaltyDistribution = {
           "isSigner": false
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "paymentMint",
+          "type": "publicKey"
+        }
+      ]
     },
     {
       "name": "updateDistribution",
       "docs": [
-        "Update royalty amount for creators a distribution account."
+        "Update royalty amount for creators a distribution account.",
+        "Each creator is credited the floor of its share of the amount, the remainder is",
+        "credited one lamport each to creators in the order they're declared in the metadata."
       ],
       "accounts": [
         {
@@ -86,6 +93,37 @@ export type WenRoyaltyDistribution = {
           "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "paymentMint",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "only needed to pay token royalties"
+          ]
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": [
@@ -100,7 +138,7 @@ export type WenRoyaltyDistribution = {
     {
       "name": "claimDistribution",
       "docs": [
-        "Claim royalties from a distribution account."
+        "Claim royalties from a distribution account, all of them when `amount` is `None`."
       ],
       "accounts": [
         {
@@ -111,7 +149,7 @@ export type WenRoyaltyDistribution = {
         {
           "name": "creator",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
           "name": "distribution",
@@ -119,14 +157,46 @@ export type WenRoyaltyDistribution = {
           "isSigner": false
         },
         {
-          "name": "distributionAddress",
+          "name": "claimDestination",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "destination registered by the creator, claims to it are permissionless"
+          ]
+        },
+        {
+          "name": "destination",
           "isMut": true,
-          "isSigner": false
+          "isSigner": false,
+          "isOptional": true
         },
         {
-          "name": "payerAddress",
+          "name": "paymentMintAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "only needed to claim token royalties"
+          ]
+        },
+        {
+          "name": "distributionTokenAccount",
           "isMut": true,
-          "isSigner": false
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "token account of the distribution, only needed to claim token royalties"
+          ]
+        },
+        {
+          "name": "creatorTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "token account of the creator or its registered destination, only needed to claim token royalties"
+          ]
         },
         {
           "name": "tokenProgram",
@@ -138,108 +208,45 @@ export type WenRoyaltyDistribution = {
         {
           "name": "paymentMint",
           "type": "publicKey"
+        },
+        {
+          "name": "amount",
+          "type": {
+            "option": "u64"
+          }
         }
       ]
-    }
-  ],
-  "accounts": [
-    {
-      "name": "distributionAccount",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "collection",
-            "type": "publicKey"
-          },
-          {
-            "name": "authority",
-            "type": "publicKey"
-          },
-          {
-            "name": "data",
-            "type": {
-              "vec": {
-                "defined": "Creator"
-              }
-            }
-          }
-        ]
-      }
-    }
-  ],
-  "types": [
-    {
-      "name": "CreatorShare",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "address",
-            "type": "publicKey"
-          },
-          {
-            "name": "pct",
-            "type": "u8"
-          }
-        ]
-      }
-    },
-    {
-      "name": "UpdateDistributionArgs",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "amount",
-            "type": "u64"
-          },
-          {
-            "name": "paymentMint",
-            "type": "publicKey"
-          }
-        ]
-      }
     },
     {
-      "name": "Creator",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "address",
-            "type": "publicKey"
-          },
-          {
-            "name": "amount",
-            "type": "u64"
+      "name": "getClaimable",
+      "docs": [
+        "Get the royalties a creator can claim from a distribution account as return data."
+      ],
+      "accounts": [
+        {
+          "name": "distribution",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "creator",
+          "type": "publicKey"
+        },
+        {
+          "name": "paymentMint",
+          "type": {
+            "option": "publicKey"
           }
-        ]
-      }
-    }
-  ],
-  "errors": [
-    {
-      "code": 6000,
-      "name": "InvalidGroupAuthority",
-      "msg": "Invalid Group Authority for collection account"
+        }
+      ],
+      "returns": "u64"
     },
     {
-      "code": 6001,
-      "name": "InvalidCreatorPctAmount",
-      "msg": "Invalid creator pct amount. Must add up to 100"
-    }
-  ]
-};
-
-export const IDL: WenRoyaltyDistribution = {
-  "version": "0.0.1-alpha",
-  "name": "wen_royalty_distribution",
-  "instructions": [
-    {
-      "name": "initializeDistribution",
+      "name": "setClaimDestination",
       "docs": [
-        "Initializes a new distribution account."
+        "Register where the royalties of the signing creator in a payment mint are claimed to."
       ],
       "accounts": [
         {
@@ -248,17 +255,12 @@ export const IDL: WenRoyaltyDistribution = {
           "isSigner": true
         },
         {
-          "name": "authority",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "mint",
+          "name": "creator",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "distribution",
+          "name": "claimDestination",
           "isMut": true,
           "isSigner": false
         },
@@ -268,12 +270,21 @@ export const IDL: WenRoyaltyDistribution = {
           "isSigner": false
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "paymentMint",
+          "type": "publicKey"
+        },
+        {
+          "name": "destination",
+          "type": "publicKey"
+        }
+      ]
     },
     {
-      "name": "updateDistribution",
+      "name": "claimDistributionBatch",
       "docs": [
-        "Update royalty amount for creators a distribution account."
+        "Claim all royalties from several distribution accounts at once."
       ],
       "accounts": [
         {
@@ -282,39 +293,100 @@ export const IDL: WenRoyaltyDistribution = {
           "isSigner": true
         },
         {
-          "name": "authority",
+          "name": "creator",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "mint",
+          "name": "paymentMintAccount",
           "isMut": false,
-          "isSigner": false
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "only needed to claim token royalties"
+          ]
         },
         {
-          "name": "distribution",
+          "name": "creatorTokenAccount",
           "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "token account of the creator, only needed to claim token royalties"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "paymentMint",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "pushDistribution",
+      "docs": [
+        "Pay all royalties of a creator that can't sign, such as a PDA, to the creator."
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "distributionAddress",
+          "name": "creator",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "payerAddress",
+          "name": "distribution",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "claimDestination",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "associatedTokenProgram",
+          "name": "destination",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "paymentMintAccount",
           "isMut": false,
-          "isSigner": false
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "only needed to push token royalties"
+          ]
+        },
+        {
+          "name": "distributionTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "token account of the distribution, only needed to push token royalties"
+          ]
+        },
+        {
+          "name": "creatorTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "associated token account of the creator or its registered destination, only needed to",
+            "push token royalties"
+          ]
         },
         {
           "name": "tokenProgram",
@@ -324,17 +396,15 @@ export const IDL: WenRoyaltyDistribution = {
       ],
       "args": [
         {
-          "name": "args",
-          "type": {
-            "defined": "UpdateDistributionArgs"
-          }
+          "name": "paymentMint",
+          "type": "publicKey"
         }
       ]
     },
     {
-      "name": "claimDistribution",
+      "name": "migrateDistribution",
       "docs": [
-        "Claim royalties from a distribution account."
+        "Move the claims of a distribution account of the previous layout to a payment mint distribution."
       ],
       "accounts": [
         {
@@ -343,23 +413,50 @@ export const IDL: WenRoyaltyDistribution = {
           "isSigner": true
         },
         {
-          "name": "creator",
+          "name": "legacyDistribution",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "distribution",
+          "name": "legacyAuthority",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "distributionAddress",
+          "name": "distribution",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "payerAddress",
+          "name": "paymentMintAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "only needed to migrate token royalties"
+          ]
+        },
+        {
+          "name": "legacyDistributionTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "token account of the previous distribution, only needed to migrate token royalties"
+          ]
+        },
+        {
+          "name": "distributionTokenAccount",
           "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "token account of the distribution, only needed to migrate token royalties"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
@@ -374,35 +471,950 @@ export const IDL: WenRoyaltyDistribution = {
           "type": "publicKey"
         }
       ]
-    }
-  ],
-  "accounts": [
+    },
     {
-      "name": "distributionAccount",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "collection",
-            "type": "publicKey"
-          },
-          {
-            "name": "authority",
-            "type": "publicKey"
+      "name": "closeDistribution",
+      "docs": [
+        "Close a fully claimed distribution account."
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "payer recorded at initialization or authority of the distribution"
+          ]
+        },
+        {
+          "name": "distribution",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentDestination",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    }
+  ],
+  "accounts": [
+    {
+      "name": "paymentDistribution",
+      "docs": [
+        "Royalties of a collection in a single payment mint, seeded by the collection and the",
+        "payment mint. SOL royalties are held as lamports, token royalties by the associated",
+        "token account of the distribution."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "collection",
+            "type": "publicKey"
+          },
+          {
+            "name": "paymentMint",
+            "docs": [
+              "mint of the royalty payments, `Pubkey::default()` for SOL"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "authority",
+            "type": "publicKey"
+          },
+          {
+            "name": "payer",
+            "docs": [
+              "funded the account rent, can close it along the authority"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "data",
+            "type": {
+              "vec": {
+                "defined": "CreatorClaim"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "claimDestination",
+      "docs": [
+        "Destination registered by a creator for its royalties in a payment mint, claims to it",
+        "don't need the creator signature"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "type": "publicKey"
+          },
+          {
+            "name": "paymentMint",
+            "docs": [
+              "mint of the royalty payments, `Pubkey::default()` for SOL"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "destination",
+            "docs": [
+              "system account for SOL, token account of the payment mint for tokens"
+            ],
+            "type": "publicKey"
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "CreatorPayment",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "address",
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CreatorShare",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "address",
+            "type": "publicKey"
+          },
+          {
+            "name": "pct",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "UpdateDistributionArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "paymentMint",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Creator",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "address",
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LegacyDistributionAccount",
+      "docs": [
+        "Distribution of the previous layout mixing every payment mint of a collection, seeded by",
+        "the collection alone. Only read by `migrate_distribution`, its layout is frozen to the",
+        "bytes of deployed accounts."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "collection",
+            "type": "publicKey"
+          },
+          {
+            "name": "authority",
+            "type": "publicKey"
+          },
+          {
+            "name": "data",
+            "type": {
+              "vec": {
+                "defined": "Creator"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "CreatorClaim",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "address",
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    }
+  ],
+  "events": [
+    {
+      "name": "RoyaltyPaid",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "paymentMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "creators",
+          "type": {
+            "vec": {
+              "defined": "CreatorPayment"
+            }
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "DistributionClaimed",
+      "fields": [
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "paymentMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "distributions",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "InvalidGroupAuthority",
+      "msg": "Invalid Group Authority for collection account"
+    },
+    {
+      "code": 6001,
+      "name": "InvalidCreatorPctAmount",
+      "msg": "Invalid creator pct amount. Must add up to 100"
+    },
+    {
+      "code": 6002,
+      "name": "InvalidMetadataAccount",
+      "msg": "Metadata account does not match the mint metadata pointer"
+    },
+    {
+      "code": 6003,
+      "name": "InvalidPaymentTokenAccounts",
+      "msg": "Payment mint and token accounts are required to claim token royalties"
+    },
+    {
+      "code": 6004,
+      "name": "InsufficientClaimableBalance",
+      "msg": "Claim amount exceeds the claimable balance"
+    },
+    {
+      "code": 6005,
+      "name": "InvalidBatchAccounts",
+      "msg": "Invalid number of remaining accounts for the batch"
+    },
+    {
+      "code": 6006,
+      "name": "TooManyDistributions",
+      "msg": "Too many distribution accounts in the batch"
+    },
+    {
+      "code": 6007,
+      "name": "DistributionNotEmpty",
+      "msg": "Distribution account still holds claims or funds"
+    },
+    {
+      "code": 6008,
+      "name": "InvalidCloseAuthority",
+      "msg": "Only the distribution payer or authority can close it"
+    },
+    {
+      "code": 6009,
+      "name": "InvalidPaymentMint",
+      "msg": "Payment mint account does not match the payment mint"
+    },
+    {
+      "code": 6010,
+      "name": "InvalidDistributionTokenAccount",
+      "msg": "Distribution token account is not the associated token account of the distribution"
+    },
+    {
+      "code": 6011,
+      "name": "InvalidClaimDestination",
+      "msg": "Destination does not match the claim destination registered by the creator"
+    },
+    {
+      "code": 6012,
+      "name": "InvalidDistributionMint",
+      "msg": "Mint is neither the distribution collection nor a member of its group"
+    },
+    {
+      "code": 6013,
+      "name": "InvalidDistributionAddress",
+      "msg": "Distribution address must be the distribution account for SOL royalties"
+    },
+    {
+      "code": 6014,
+      "name": "ArithmeticOverflow",
+      "msg": "Arithmetic overflow"
+    }
+  ]
+};
+
+export const IDL: WenRoyaltyDistribution = {
+  "version": "0.0.1-alpha",
+  "name": "wen_royalty_distribution",
+  "instructions": [
+    {
+      "name": "initializeDistribution",
+      "docs": [
+        "Initializes a new distribution account for a payment mint of the collection."
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "distribution",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "paymentMint",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "updateDistribution",
+      "docs": [
+        "Update royalty amount for creators a distribution account.",
+        "Each creator is credited the floor of its share of the amount, the remainder is",
+        "credited one lamport each to creators in the order they're declared in the metadata."
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "distribution",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "distributionAddress",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payerAddress",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "paymentMint",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "only needed to pay token royalties"
+          ]
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "UpdateDistributionArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "claimDistribution",
+      "docs": [
+        "Claim royalties from a distribution account, all of them when `amount` is `None`."
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "distribution",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "claimDestination",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "destination registered by the creator, claims to it are permissionless"
+          ]
+        },
+        {
+          "name": "destination",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "paymentMintAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "only needed to claim token royalties"
+          ]
+        },
+        {
+          "name": "distributionTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "token account of the distribution, only needed to claim token royalties"
+          ]
+        },
+        {
+          "name": "creatorTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "token account of the creator or its registered destination, only needed to claim token royalties"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "paymentMint",
+          "type": "publicKey"
+        },
+        {
+          "name": "amount",
+          "type": {
+            "option": "u64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "getClaimable",
+      "docs": [
+        "Get the royalties a creator can claim from a distribution account as return data."
+      ],
+      "accounts": [
+        {
+          "name": "distribution",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "creator",
+          "type": "publicKey"
+        },
+        {
+          "name": "paymentMint",
+          "type": {
+            "option": "publicKey"
+          }
+        }
+      ],
+      "returns": "u64"
+    },
+    {
+      "name": "setClaimDestination",
+      "docs": [
+        "Register where the royalties of the signing creator in a payment mint are claimed to."
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "creator",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "claimDestination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "paymentMint",
+          "type": "publicKey"
+        },
+        {
+          "name": "destination",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "claimDistributionBatch",
+      "docs": [
+        "Claim all royalties from several distribution accounts at once."
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "paymentMintAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "only needed to claim token royalties"
+          ]
+        },
+        {
+          "name": "creatorTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "token account of the creator, only needed to claim token royalties"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "paymentMint",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "pushDistribution",
+      "docs": [
+        "Pay all royalties of a creator that can't sign, such as a PDA, to the creator."
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "distribution",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "claimDestination",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "destination",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "paymentMintAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "only needed to push token royalties"
+          ]
+        },
+        {
+          "name": "distributionTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "token account of the distribution, only needed to push token royalties"
+          ]
+        },
+        {
+          "name": "creatorTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "associated token account of the creator or its registered destination, only needed to",
+            "push token royalties"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "paymentMint",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "migrateDistribution",
+      "docs": [
+        "Move the claims of a distribution account of the previous layout to a payment mint distribution."
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "legacyDistribution",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "legacyAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "distribution",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "paymentMintAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "only needed to migrate token royalties"
+          ]
+        },
+        {
+          "name": "legacyDistributionTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "token account of the previous distribution, only needed to migrate token royalties"
+          ]
+        },
+        {
+          "name": "distributionTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "token account of the distribution, only needed to migrate token royalties"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "paymentMint",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "closeDistribution",
+      "docs": [
+        "Close a fully claimed distribution account."
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "payer recorded at initialization or authority of the distribution"
+          ]
+        },
+        {
+          "name": "distribution",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentDestination",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    }
+  ],
+  "accounts": [
+    {
+      "name": "paymentDistribution",
+      "docs": [
+        "Royalties of a collection in a single payment mint, seeded by the collection and the",
+        "payment mint. SOL royalties are held as lamports, token royalties by the associated",
+        "token account of the distribution."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "collection",
+            "type": "publicKey"
+          },
+          {
+            "name": "paymentMint",
+            "docs": [
+              "mint of the royalty payments, `Pubkey::default()` for SOL"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "authority",
+            "type": "publicKey"
+          },
+          {
+            "name": "payer",
+            "docs": [
+              "funded the account rent, can close it along the authority"
+            ],
+            "type": "publicKey"
           },
           {
             "name": "data",
             "type": {
               "vec": {
-                "defined": "Creator"
+                "defined": "CreatorClaim"
               }
             }
           }
         ]
       }
+    },
+    {
+      "name": "claimDestination",
+      "docs": [
+        "Destination registered by a creator for its royalties in a payment mint, claims to it",
+        "don't need the creator signature"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "type": "publicKey"
+          },
+          {
+            "name": "paymentMint",
+            "docs": [
+              "mint of the royalty payments, `Pubkey::default()` for SOL"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "destination",
+            "docs": [
+              "system account for SOL, token account of the payment mint for tokens"
+            ],
+            "type": "publicKey"
+          }
+        ]
+      }
     }
   ],
   "types": [
+    {
+      "name": "CreatorPayment",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "address",
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
     {
       "name": "CreatorShare",
       "type": {
@@ -450,6 +1462,107 @@ export const IDL: WenRoyaltyDistribution = {
           }
         ]
       }
+    },
+    {
+      "name": "LegacyDistributionAccount",
+      "docs": [
+        "Distribution of the previous layout mixing every payment mint of a collection, seeded by",
+        "the collection alone. Only read by `migrate_distribution`, its layout is frozen to the",
+        "bytes of deployed accounts."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "collection",
+            "type": "publicKey"
+          },
+          {
+            "name": "authority",
+            "type": "publicKey"
+          },
+          {
+            "name": "data",
+            "type": {
+              "vec": {
+                "defined": "Creator"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "CreatorClaim",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "address",
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    }
+  ],
+  "events": [
+    {
+      "name": "RoyaltyPaid",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "paymentMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "creators",
+          "type": {
+            "vec": {
+              "defined": "CreatorPayment"
+            }
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "DistributionClaimed",
+      "fields": [
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "paymentMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "distributions",
+          "type": "u32",
+          "index": false
+        }
+      ]
     }
   ],
   "errors": [
@@ -462,6 +1575,71 @@ export const IDL: WenRoyaltyDistribution = {
       "code": 6001,
       "name": "InvalidCreatorPctAmount",
       "msg": "Invalid creator pct amount. Must add up to 100"
+    },
+    {
+      "code": 6002,
+      "name": "InvalidMetadataAccount",
+      "msg": "Metadata account does not match the mint metadata pointer"
+    },
+    {
+      "code": 6003,
+      "name": "InvalidPaymentTokenAccounts",
+      "msg": "Payment mint and token accounts are required to claim token royalties"
+    },
+    {
+      "code": 6004,
+      "name": "InsufficientClaimableBalance",
+      "msg": "Claim amount exceeds the claimable balance"
+    },
+    {
+      "code": 6005,
+      "name": "InvalidBatchAccounts",
+      "msg": "Invalid number of remaining accounts for the batch"
+    },
+    {
+      "code": 6006,
+      "name": "TooManyDistributions",
+      "msg": "Too many distribution accounts in the batch"
+    },
+    {
+      "code": 6007,
+      "name": "DistributionNotEmpty",
+      "msg": "Distribution account still holds claims or funds"
+    },
+    {
+      "code": 6008,
+      "name": "InvalidCloseAuthority",
+      "msg": "Only the distribution payer or authority can close it"
+    },
+    {
+      "code": 6009,
+      "name": "InvalidPaymentMint",
+      "msg": "Payment mint account does not match the payment mint"
+    },
+    {
+      "code": 6010,
+      "name": "InvalidDistributionTokenAccount",
+      "msg": "Distribution token account is not the associated token account of the distribution"
+    },
+    {
+      "code": 6011,
+      "name": "InvalidClaimDestination",
+      "msg": "Destination does not match the claim destination registered by the creator"
+    },
+    {
+      "code": 6012,
+      "name": "InvalidDistributionMint",
+      "msg": "Mint is neither the distribution collection nor a member of its group"
+    },
+    {
+      "code": 6013,
+      "name": "InvalidDistributionAddress",
+      "msg": "Distribution address must be the distribution account for SOL royalties"
+    },
+    {
+      "code": 6014,
+      "name": "ArithmeticOverflow",
+      "msg": "Arithmetic overflow"
     }
   ]
 };
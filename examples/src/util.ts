@@ -19,7 +19,8 @@ export const createCollectionWithRoyalties = async (args: { name: string; symbol
     }
 
     const { ix: createCollectionIx, group } = await buildCreateCollectionIx(provider, collectionArgs, authorityPubkey.toString());
-    const addDistributionIx = await buildAddDistributionIx(provider, collectionPubkey.toString(), authorityPubkey.toString());
+    // Only supporting SOL to start
+    const addDistributionIx = await buildAddDistributionIx(provider, collectionPubkey.toString(), authorityPubkey.toString(), PublicKey.default.toString());
 
     let blockhash = await provider.connection
         .getLatestBlockhash()
@@ -67,7 +68,7 @@ export const mintNft = async (args: { name: string; symbol: string; uri: string;
 
     const mintIx = await buildMintNftIx(provider, mintDetails, minterPubkey.toString(), nftAuthPubkey.toString());
     const addToGroupIx = await buildAddGroupIx(provider, groupAuthPubkey.toString(), mintPubkey.toString(), collectionPubkey.toString());
-    const addRoyaltiesToMintIx = await buildAddRoyaltiesIx(provider, nftAuthPubkey.toString(), mintPubkey.toString(), args.royaltyBasisPoints, args.creators);
+    const addRoyaltiesToMintIx = await buildAddRoyaltiesIx(provider, nftAuthPubkey.toString(), mintPubkey.toString(), args.royaltyBasisPoints, args.creators, collectionPubkey.toString());
 
     let blockhash = await provider.connection
         .getLatestBlockhash()
@@ -103,7 +104,7 @@ export const purchaseNft = async (args: PurchaseNftArgs) => {
 
     const destination = args.buyer;
 
-    const approveIx = await buildApproveIx(provider, sender, nftMint, collection, paymentAmount, paymentMint);
+    const approveIx = await buildApproveIx(provider, sender, nftMint, collection, paymentAmount, paymentMint, destination);
     const createAtaIx = await buildAtaCreateIx(sender, nftMint, destination);
     const transferIx = await buildCpiTransferIx(provider, nftMint, sender, destination);
 
@@ -1,8 +1,8 @@
 import { ASSOCIATED_TOKEN_PROGRAM_ID } from "@solana/spl-token";
 import { TOKEN_PROGRAM_ID } from "./constants";
-import { getATAAddressSync, getGroupAccount, getManagerAccount, getMetadataProgram } from "./core";
+import { getATAAddressSync, getGroupAccount, getGroupPda, getManagerAccount, getMetadataProgram } from "./core";
 import { CreateCollectionArgs } from "./interfaces";
-import { PublicKey, SYSVAR_RENT_PUBKEY, SystemProgram } from "@solana/web3.js";
+import { PublicKey, SystemProgram } from "@solana/web3.js";
 import { Provider } from "@coral-xyz/anchor";
 
 export const buildCreateCollectionIx = async (provider: Provider, args: CreateCollectionArgs, authority: string) => {
@@ -18,7 +18,8 @@ export const buildCreateCollectionIx = async (provider: Provider, args: CreateCo
             name: args.name,
             symbol: args.symbol,
             uri: args.uri,
-            maxSize: args.maxSize
+            maxSize: args.maxSize,
+            soulbound: false
         })
         .accountsStrict({
             payer: authorityPubkey,
@@ -27,10 +28,12 @@ export const buildCreateCollectionIx = async (provider: Provider, args: CreateCo
             mint: mintPubkey,
             mintTokenAccount: getATAAddressSync({ mint: mintPubkey, owner: authorityPubkey }),
             systemProgram: SystemProgram.programId,
-            rent: SYSVAR_RENT_PUBKEY,
             associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
             tokenProgram: TOKEN_PROGRAM_ID,
             group: groupAccount,
+            soulbound: getGroupPda("group_soulbound", groupAccount),
+            royaltyEnforcement: getGroupPda("royalty_enforcement", groupAccount),
+            managerAuthority: null,
             manager: managerAccount
         })
         .instruction();
@@ -1,4 +1,4 @@
-import { AnchorProvider, Idl, Program, Provider, utils } from "@coral-xyz/anchor";
+import { AnchorProvider, BN, Idl, Program, Provider, utils } from "@coral-xyz/anchor";
 import { Connection, PublicKey } from "@solana/web3.js";
 import { DISTRIBUTION_PROGRAM_ID, WNS_PROGRAM_ID, TOKEN_PROGRAM_ID } from "./constants";
 import { ASSOCIATED_TOKEN_PROGRAM_ID } from "@solana/spl-token";
@@ -69,8 +69,32 @@ export const getExtraMetasAccount = (mint: string) => {
     return extraMetasAccount;
 }
 
-export const getDistributionAccount = (collection: string) => {
-    const [distributionAccount] = PublicKey.findProgramAddressSync([new PublicKey(collection).toBuffer()], DISTRIBUTION_PROGRAM_ID);
+export const getGroupPda = (seed: string, group: PublicKey) => {
+    const [groupPda] = PublicKey.findProgramAddressSync([utils.bytes.utf8.encode(seed), group.toBuffer()], WNS_PROGRAM_ID);
+
+    return groupPda;
+}
+
+export const getGroupPermissionAccount = (group: PublicKey, authority: PublicKey) => {
+    const [groupPermissionAccount] = PublicKey.findProgramAddressSync([utils.bytes.utf8.encode("group_permission"), group.toBuffer(), authority.toBuffer()], WNS_PROGRAM_ID);
+
+    return groupPermissionAccount;
+}
+
+export const getMemberIndexAccount = (group: PublicKey, page: number) => {
+    const [memberIndexAccount] = PublicKey.findProgramAddressSync([utils.bytes.utf8.encode("member_index"), group.toBuffer(), new BN(page).toArrayLike(Buffer, "le", 4)], WNS_PROGRAM_ID);
+
+    return memberIndexAccount;
+}
+
+export const getEventAuthority = (programId: PublicKey) => {
+    const [eventAuthority] = PublicKey.findProgramAddressSync([utils.bytes.utf8.encode("__event_authority")], programId);
+
+    return eventAuthority;
+}
+
+export const getDistributionAccount = (collection: string, paymentMint: string) => {
+    const [distributionAccount] = PublicKey.findProgramAddressSync([utils.bytes.utf8.encode("distribution"), new PublicKey(collection).toBuffer(), new PublicKey(paymentMint).toBuffer()], DISTRIBUTION_PROGRAM_ID);
 
     return distributionAccount;
 }
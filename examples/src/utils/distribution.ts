@@ -3,14 +3,14 @@ import { getATAAddressSync, getDistributionAccount, getDistributionProgram } fro
 import { Provider } from "@coral-xyz/anchor";
 import { TOKEN_PROGRAM_ID } from "./constants";
 
-export const buildAddDistributionIx = async (provider: Provider, collection: string, authority: string) => {
+export const buildAddDistributionIx = async (provider: Provider, collection: string, authority: string, paymentMint: string) => {
     const distributionProgram = getDistributionProgram(provider);
-    const distributionAccount = getDistributionAccount(collection);
+    const distributionAccount = getDistributionAccount(collection, paymentMint);
     
     const authorityPubkey = new PublicKey(authority);
 
     const ix = await distributionProgram.methods
-        .initializeDistribution()
+        .initializeDistribution(new PublicKey(paymentMint))
         .accountsStrict({
             payer: authorityPubkey,
             authority: authorityPubkey,
@@ -25,27 +25,34 @@ export const buildAddDistributionIx = async (provider: Provider, collection: str
 
 export const buildClaimDistributionIx = async (provider: Provider, collection: string, creator: string, mintToClaim: string) => {
     const distributionProgram = getDistributionProgram(provider);
-    const distributionAccount = getDistributionAccount(collection);
+    const distributionAccount = getDistributionAccount(collection, mintToClaim);
 
     const creatorPubkey = new PublicKey(creator);
     const mintPubkey = new PublicKey(mintToClaim);
 
-    let creatorTokenAccount = creatorPubkey;
-    let programTokenAccount = distributionAccount;
+    // token accounts are only needed to claim token royalties
+    let paymentMintAccount: PublicKey | null = null;
+    let creatorTokenAccount: PublicKey | null = null;
+    let programTokenAccount: PublicKey | null = null;
 
     if (mintToClaim !== PublicKey.default.toString()) {
+        paymentMintAccount = mintPubkey;
         creatorTokenAccount = getATAAddressSync({ mint: mintPubkey, owner: creatorPubkey });
         programTokenAccount = getATAAddressSync({ mint: mintPubkey, owner: distributionAccount });
     }
 
+    // claims the whole claimable amount, to the creator as it has no registered destination
     const ix = await distributionProgram.methods
-        .claimDistribution(mintPubkey)
+        .claimDistribution(mintPubkey, null)
         .accountsStrict({
             payer: creatorPubkey,
             creator: creatorPubkey,
             distribution: distributionAccount,
-            payerAddress: creatorTokenAccount,
-            distributionAddress: programTokenAccount,
+            claimDestination: null,
+            destination: null,
+            paymentMintAccount,
+            distributionTokenAccount: programTokenAccount,
+            creatorTokenAccount,
             tokenProgram: TOKEN_PROGRAM_ID,
         })
         .instruction();
@@ -1,8 +1,8 @@
 import { Provider } from "@coral-xyz/anchor";
-import { getATAAddressSync, getExtraMetasAccount, getGroupAccount, getManagerAccount, getMemberAccount, getMetadataProgram } from "./core";
+import { getATAAddressSync, getEventAuthority, getExtraMetasAccount, getGroupAccount, getGroupPda, getGroupPermissionAccount, getManagerAccount, getMemberAccount, getMemberIndexAccount, getMetadataProgram } from "./core";
 import { CreateNftArgs, Creator } from "./interfaces";
-import { PublicKey, SYSVAR_RENT_PUBKEY, SystemProgram } from "@solana/web3.js";
-import { TOKEN_PROGRAM_ID } from "./constants";
+import { PublicKey, SystemProgram } from "@solana/web3.js";
+import { MEMBER_INDEX_PAGE_SIZE, TOKEN_PROGRAM_ID, WNS_PROGRAM_ID } from "./constants";
 import { ASSOCIATED_TOKEN_PROGRAM_ID } from "@solana/spl-token";
 
 export const buildMintNftIx = async (provider: Provider, args: CreateNftArgs, minter: string, authority: string) => {
@@ -15,7 +15,27 @@ export const buildMintNftIx = async (provider: Provider, args: CreateNftArgs, mi
     const extraMetasAccount = getExtraMetasAccount(args.mint);
 
     const ix = await metadataProgram.methods
-        .createMintAccount(args)
+        .createMintAccount({
+            name: args.name,
+            symbol: args.symbol,
+            uri: args.uri,
+            soulbound: false,
+            permanentDelegate: null,
+            frozen: false,
+            freezeAuthority: null,
+            decimals: null,
+            initialSupply: null,
+            additionalMetadata: [],
+            royalties: null,
+            updateAuthority: null,
+            immutable: false,
+            allowPdaReceiver: false,
+            externalMetadata: false,
+            contentHash: null,
+            mintRecord: false,
+            managerAuthority: null,
+            provenance: false
+        })
         .accountsStrict({
             payer: minterPubkey,
             authority: authorityPubkey,
@@ -23,11 +43,14 @@ export const buildMintNftIx = async (provider: Provider, args: CreateNftArgs, mi
             mint: mintPubkey,
             mintTokenAccount: getATAAddressSync({ mint: mintPubkey, owner: minterPubkey }),
             systemProgram: SystemProgram.programId,
-            rent: SYSVAR_RENT_PUBKEY,
             associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
             tokenProgram: TOKEN_PROGRAM_ID,
             manager: managerAccount,
             extraMetasAccount,
+            metadataAccount: null,
+            mintRecord: null,
+            eventAuthority: getEventAuthority(WNS_PROGRAM_ID),
+            program: WNS_PROGRAM_ID
         })
         .instruction();
     return ix;
@@ -38,9 +61,16 @@ export const buildAddGroupIx = async (provider: Provider, collectionAuthority: s
 
     const groupAccount = getGroupAccount(collectionMint);
     const memberAccount = getMemberAccount(mint);
+    const memberCounterAccount = getGroupPda("group_member_counter", groupAccount);
     const collectionAuthPubkey = new PublicKey(collectionAuthority);
     const mintPubkey = new PublicKey(mint);
 
+    // member numbers continue from the group size until the member counter is created
+    const group = await metadataProgram.account.tokenGroup.fetch(groupAccount);
+    const memberCounter = await metadataProgram.account.groupMemberCounter.fetchNullable(memberCounterAccount);
+    const lastMemberNumber = memberCounter && !memberCounter.group.equals(PublicKey.default) ? memberCounter.lastMemberNumber : group.size;
+    const memberIndexPage = Math.floor(lastMemberNumber / MEMBER_INDEX_PAGE_SIZE);
+
     const ix = await metadataProgram.methods
         .addGroupToMint()
         .accountsStrict({
@@ -50,20 +80,34 @@ export const buildAddGroupIx = async (provider: Provider, collectionAuthority: s
             systemProgram: SystemProgram.programId,
             tokenProgram: TOKEN_PROGRAM_ID,
             group: groupAccount,
-            member: memberAccount
+            groupDelegates: getGroupPda("group_delegates", groupAccount),
+            groupPermission: getGroupPermissionAccount(groupAccount, collectionAuthPubkey),
+            member: memberAccount,
+            memberCounter: memberCounterAccount,
+            memberIndex: getMemberIndexAccount(groupAccount, memberIndexPage),
+            finalization: getGroupPda("group_finalization", groupAccount),
+            soulbound: getGroupPda("group_soulbound", groupAccount),
+            policy: getGroupPda("policy", groupAccount),
+            metadataAccount: null,
+            extraMetasAccount: getExtraMetasAccount(mint),
+            eventAuthority: getEventAuthority(WNS_PROGRAM_ID),
+            program: WNS_PROGRAM_ID
         })
         .instruction();
 
     return ix;
 }
 
-export const buildAddRoyaltiesIx = async (provider: Provider, metadataAuthority: string, mint: string, royaltyBasisPoints: number, creators: Creator[]) => {
+export const buildAddRoyaltiesIx = async (provider: Provider, metadataAuthority: string, mint: string, royaltyBasisPoints: number, creators: Creator[], collectionMint?: string) => {
     const metadataProgram = getMetadataProgram(provider);
 
     const extraMetasAccount = getExtraMetasAccount(mint);
     const metadataAuthPubkey = new PublicKey(metadataAuthority);
     const mintPubkey = new PublicKey(mint);
 
+    // group members are checked against the extension policy of their group
+    const groupAccount = collectionMint ? getGroupAccount(collectionMint) : null;
+
     const ix = await metadataProgram.methods
         .addRoyaltiesToMint({
             royaltyBasisPoints,
@@ -73,11 +117,14 @@ export const buildAddRoyaltiesIx = async (provider: Provider, metadataAuthority:
             payer: metadataAuthPubkey,
             authority: metadataAuthPubkey,
             systemProgram: SystemProgram.programId,
-            rent: SYSVAR_RENT_PUBKEY,
             associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
             tokenProgram: TOKEN_PROGRAM_ID,
             extraMetasAccount,
             mint: mintPubkey,
+            member: groupAccount ? getMemberAccount(mint) : null,
+            policy: groupAccount ? getGroupPda("policy", groupAccount) : null,
+            eventAuthority: getEventAuthority(WNS_PROGRAM_ID),
+            program: WNS_PROGRAM_ID
         })
         .instruction();
 
@@ -1,13 +1,14 @@
 import { PublicKey, SystemProgram } from "@solana/web3.js";
 import { BN, Provider } from "@coral-xyz/anchor";
 import { ASSOCIATED_TOKEN_PROGRAM_ID, createAssociatedTokenAccountInstruction, createTransferCheckedInstruction, createTransferCheckedWithTransferHookInstruction } from "@solana/spl-token";
-import { getATAAddressSync, getApprovalAccount, getDistributionAccount, getMetadataProgram } from "./core";
-import { TOKEN_PROGRAM_ID, DISTRIBUTION_PROGRAM_ID } from "./constants";
+import { getATAAddressSync, getApprovalAccount, getDistributionAccount, getEventAuthority, getGroupAccount, getGroupPda, getMemberAccount, getMetadataProgram } from "./core";
+import { TOKEN_PROGRAM_ID, DISTRIBUTION_PROGRAM_ID, WNS_PROGRAM_ID } from "./constants";
 
-export const buildApproveIx = async (provider: Provider, sender: string, mint: string, collection: string, paymentAmount: number, paymentMint: string) => {
+export const buildApproveIx = async (provider: Provider, sender: string, mint: string, collection: string, paymentAmount: number, paymentMint: string, receiver: string) => {
     const metadataProgram = getMetadataProgram(provider);
     const approveAccount = getApprovalAccount(mint);
-    const distributionAccount = getDistributionAccount(collection);
+    const distributionAccount = getDistributionAccount(collection, paymentMint);
+    const groupAccount = getGroupAccount(collection);
     
     const senderPubkey = new PublicKey(sender);
     const mintPubkey = new PublicKey(mint);
@@ -21,9 +22,9 @@ export const buildApproveIx = async (provider: Provider, sender: string, mint: s
         programTokenAccount = getATAAddressSync({ mint: paymentMintPubkey, owner: distributionAccount });
     }
 
-    // get approve ix
+    // get approve ix, only a transfer of the token to the receiver can consume the approval
     const approveIx = await metadataProgram.methods
-        .approve(new BN(paymentAmount))
+        .approve(new BN(paymentAmount), null, new BN(1), new PublicKey(receiver), senderPubkey)
         .accountsStrict({
             payer: senderPubkey,
             authority: senderPubkey,
@@ -36,7 +37,16 @@ export const buildApproveIx = async (provider: Provider, sender: string, mint: s
             payerAddress: senderTokenAccount,
             distribution: distributionAccount,
             distributionAddress: programTokenAccount,
-            distributionProgram: DISTRIBUTION_PROGRAM_ID
+            distributionEventAuthority: getEventAuthority(DISTRIBUTION_PROGRAM_ID),
+            distributionProgram: DISTRIBUTION_PROGRAM_ID,
+            metadataAccount: null,
+            member: getMemberAccount(mint),
+            group: groupAccount,
+            collectionDistribution: getGroupPda("collection_distribution", groupAccount),
+            royaltyDefaults: getGroupPda("group_royalty_defaults", groupAccount),
+            sourceTokenAccount: null,
+            eventAuthority: getEventAuthority(WNS_PROGRAM_ID),
+            program: WNS_PROGRAM_ID
         })
         .instruction();
 
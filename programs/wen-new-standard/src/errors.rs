@@ -7,9 +7,231 @@ pub enum MetadataErrors {
     #[msg("Max size cannot be reduced below current size.")]
     MaxSizeBelowCurrentSize,
     #[msg("Creators shares must add up to 100.")]
-    CreatorShareInvalid,
+    InvalidCreatorShareTotal,
     #[msg("Missing approve account.")]
     MissingApproveAccount,
     #[msg("Approve account has expired.")]
     ExpiredApproveAccount,
+    #[msg(
+        "Remaining accounts must contain a mint, token account and extra metas account per entry."
+    )]
+    InvalidBatchAccounts,
+    #[msg("Mint token account is not the receiver's associated token account.")]
+    InvalidMintTokenAccount,
+    #[msg("Extra metas account does not match the mint.")]
+    InvalidExtraMetasAccount,
+    #[msg("Soulbound mints cannot be transferred.")]
+    SoulboundMint,
+    #[msg("Manager is not the permanent delegate of the mint.")]
+    InvalidPermanentDelegate,
+    #[msg("Initial supply must be greater than 0.")]
+    InvalidInitialSupply,
+    #[msg("Metadata key is reserved.")]
+    ReservedMetadataKey,
+    #[msg("Too many creators.")]
+    TooManyCreators,
+    #[msg("Mint has already been claimed.")]
+    MintAlreadyClaimed,
+    #[msg("Invalid metadata update authority.")]
+    InvalidMetadataAuthority,
+    #[msg("Name is too long.")]
+    NameTooLong,
+    #[msg("Symbol is too long.")]
+    SymbolTooLong,
+    #[msg("Uri is too long.")]
+    UriTooLong,
+    #[msg("Name and uri cannot be empty.")]
+    EmptyField,
+    #[msg("Extra metas account is already initialized.")]
+    ExtraMetasAlreadyInitialized,
+    #[msg("Metadata is immutable.")]
+    MetadataImmutable,
+    #[msg("Mint has no royalties.")]
+    MissingRoyalties,
+    #[msg("Mint authority has already been revoked.")]
+    MintAuthorityAlreadyRevoked,
+    #[msg("Authority cannot finalize this mint.")]
+    InvalidFinalizeAuthority,
+    #[msg("Receiver must be a wallet unless PDA receivers are allowed.")]
+    ReceiverNotAllowed,
+    #[msg("Metadata account does not match the mint metadata pointer.")]
+    InvalidMetadataAccount,
+    #[msg("Metadata field not found.")]
+    MissingMetadataField,
+    #[msg("Invalid group extension policy.")]
+    InvalidExtensionPolicy,
+    #[msg("Mint extension is not allowed by the group policy.")]
+    ExtensionNotAllowed,
+    #[msg("Mint is missing an extension required by the group policy.")]
+    MissingRequiredExtension,
+    #[msg("Royalty basis points are out of the group policy bounds.")]
+    RoyaltiesOutOfPolicyBounds,
+    #[msg("Mint freeze authority is not the manager.")]
+    NoFreezeAuthority,
+    #[msg("Invalid mint record account.")]
+    InvalidMintRecordAccount,
+    #[msg("Mint supply must be burned to close its record.")]
+    MintNotBurned,
+    #[msg("Max editions must be greater than 0.")]
+    InvalidMaxEditions,
+    #[msg("All editions have been printed.")]
+    MaxEditionsReached,
+    #[msg("Group authority must sign to create member mints.")]
+    GroupAuthoritySignatureRequired,
+    #[msg("Receiver is not in the group allowlist.")]
+    InvalidAllowlistProof,
+    #[msg("Receiver reached the group allowlist limit.")]
+    AllowlistLimitReached,
+    #[msg("Invalid allowlist claim account.")]
+    InvalidAllowlistClaimAccount,
+    #[msg("Too many metadata fields.")]
+    TooManyMetadataFields,
+    #[msg("Metadata update authority changed since the nomination.")]
+    StaleNomination,
+    #[msg("Metadata update authority was not removed.")]
+    MetadataNotFrozen,
+    #[msg("Additional metadata exceeds the group size limit.")]
+    MetadataSizeLimitExceeded,
+    #[msg("Group member and policy accounts are required for group members.")]
+    InvalidGroupPolicyAccount,
+    #[msg("Metadata key already exists.")]
+    MetadataKeyExists,
+    #[msg("Metadata key not found.")]
+    MetadataKeyNotFound,
+    #[msg("Content hash must be updated or cleared along the uri.")]
+    ContentHashRequired,
+    #[msg("Mint has no token metadata.")]
+    MissingMetadata,
+    #[msg("Metadata key too long.")]
+    MetadataKeyTooLong,
+    #[msg("Metadata value too long.")]
+    MetadataValueTooLong,
+    #[msg("Metadata contains control characters.")]
+    InvalidMetadataCharacters,
+    #[msg("Creator shares must be greater than 0.")]
+    ZeroCreatorShare,
+    #[msg("Creators must be unique.")]
+    DuplicateCreator,
+    #[msg("Group member, group and royalty defaults accounts are required for group members.")]
+    InvalidGroupRoyaltyDefaultsAccount,
+    #[msg("Royalties of group members are locked to the group authority.")]
+    RoyaltiesLocked,
+    #[msg("Group member account is required for group members.")]
+    InvalidGroupMemberAccount,
+    #[msg("Too many royalty exemptions.")]
+    TooManyRoyaltyExemptions,
+    #[msg("Royalty exemption already exists.")]
+    RoyaltyExemptionExists,
+    #[msg("Royalty exemption not found.")]
+    RoyaltyExemptionNotFound,
+    #[msg("Creator is not part of the mint royalties.")]
+    CreatorNotFound,
+    #[msg("Creator is already verified.")]
+    CreatorAlreadyVerified,
+    #[msg("Creator is not verified.")]
+    CreatorNotVerified,
+    #[msg("Verified creators must sign or unverify before being removed.")]
+    VerifiedCreatorRemoval,
+    #[msg("Royalty timelock account is required for group members.")]
+    InvalidRoyaltyTimelockAccount,
+    #[msg("Royalty timelock delay can't be negative.")]
+    InvalidRoyaltyTimelockDelay,
+    #[msg("Pending royalty update account is required for timelocked group members.")]
+    InvalidPendingRoyaltyUpdateAccount,
+    #[msg("Pending royalty update is still timelocked.")]
+    RoyaltyUpdateTimelocked,
+    #[msg("Arithmetic overflow.")]
+    ArithmeticOverflow,
+    #[msg("Group member, group, collection distribution and royalty defaults accounts are required for group members.")]
+    InvalidCollectionDistributionAccount,
+    #[msg("Distribution account does not match the distribution of the mint royalties.")]
+    InvalidDistributionAccount,
+    #[msg(
+        "Approval expiry slot must be between the current slot and the maximum approval horizon."
+    )]
+    InvalidApprovalExpiry,
+    #[msg("Approval has expired.")]
+    ApprovalExpired,
+    #[msg("Approval has not expired yet.")]
+    ApprovalNotExpired,
+    #[msg("Transfer doesn't match the approved amount and destination.")]
+    ApprovalMismatch,
+    #[msg("Transfer hook can only be executed during a transfer.")]
+    NotInTransferContext,
+    #[msg("Transfers of the group are frozen.")]
+    TransfersFrozen,
+    #[msg("Secondary hook program can't be the metadata program.")]
+    InvalidSecondaryHookProgram,
+    #[msg("Too many secondary hook accounts.")]
+    TooManySecondaryHookAccounts,
+    #[msg("Invalid secondary hook account.")]
+    InvalidSecondaryHookAccount,
+    #[msg("Invalid royalty enforcement account.")]
+    InvalidRoyaltyEnforcementAccount,
+    #[msg("Transfers of the group require a memo.")]
+    MemoRequired,
+    #[msg("Invalid provenance account.")]
+    InvalidProvenanceAccount,
+    #[msg("Group still has members.")]
+    GroupNotEmpty,
+    #[msg("Group is full.")]
+    GroupFull,
+    #[msg("Group is finalized.")]
+    GroupFinalized,
+    #[msg("Group can't be added to this parent group.")]
+    InvalidParentGroup,
+    #[msg("Group hierarchy is too deep.")]
+    GroupHierarchyTooDeep,
+    #[msg("Parent group royalty defaults account is required for sub-groups.")]
+    InvalidParentRoyaltyDefaultsAccount,
+    #[msg("Too many group delegates.")]
+    TooManyGroupDelegates,
+    #[msg("Group delegate already exists.")]
+    GroupDelegateExists,
+    #[msg("Group delegate not found.")]
+    GroupDelegateNotFound,
+    #[msg("Mint group member pointer doesn't point at the member account.")]
+    MemberPointerMismatch,
+    #[msg("Mint is missing an extension of WNS mints.")]
+    MissingMintExtension,
+    #[msg("Invalid group member index account.")]
+    InvalidMemberIndexAccount,
+    #[msg("Remaining accounts must contain a mint, member, extra metas and member index account per member.")]
+    InvalidMemberBatchAccounts,
+    #[msg("Too many members in batch.")]
+    TooManyBatchMembers,
+    #[msg("Members of soulbound groups must be non-transferable.")]
+    SoulboundRequired,
+    #[msg("Soulbound groups can't change their enforcement mode.")]
+    SoulboundGroupFrozen,
+    #[msg("Public mint end time must be after its start time.")]
+    InvalidMintWindow,
+    #[msg("Public mint has not started.")]
+    MintNotStarted,
+    #[msg("Public mint has ended.")]
+    MintEnded,
+    #[msg("Public mint limit per wallet reached.")]
+    PublicMintLimitReached,
+    #[msg("Payment mint and token accounts are required for token prices.")]
+    InvalidPaymentAccounts,
+    #[msg("Group permission doesn't cover this operation.")]
+    GroupPermissionDenied,
+    #[msg("Group permission has expired.")]
+    GroupPermissionExpired,
+    #[msg("Invalid group permissions.")]
+    InvalidGroupPermissions,
+    #[msg("Member is already in the destination group.")]
+    InvalidDestinationGroup,
+    #[msg("Manager account does not match the manager authority.")]
+    InvalidManagerAccount,
+    #[msg("Transfer hook of the mint does not invoke WNS.")]
+    MissingTransferHook,
+    #[msg("Invalid group member counter account.")]
+    InvalidMemberCounterAccount,
+    #[msg("Invalid group delegates account.")]
+    InvalidGroupDelegatesAccount,
+    #[msg("Invalid group permission account.")]
+    InvalidGroupPermissionAccount,
+    #[msg("User seed must not exceed 32 bytes.")]
+    UserSeedTooLong,
 }
@@ -0,0 +1,235 @@
+use anchor_lang::prelude::*;
+
+use crate::EnforcementMode;
+
+/// Emitted when a WNS mint is created
+#[event]
+pub struct MintCreated {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub receiver: Pubkey,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+}
+
+/// Emitted when royalties are added to or modified on a mint
+#[event]
+pub struct RoyaltiesUpdated {
+    pub mint: Pubkey,
+    pub royalty_basis_points: u16,
+    pub creators: Vec<String>,
+}
+
+/// Emitted when the metadata update authority of a mint is removed
+#[event]
+pub struct MetadataFrozen {
+    pub mint: Pubkey,
+}
+
+/// Emitted when the royalties of a mint are removed
+#[event]
+pub struct RoyaltiesRemoved {
+    pub mint: Pubkey,
+}
+
+/// Emitted when an address is added to the royalty exemptions of a group
+#[event]
+pub struct RoyaltyExemptionAdded {
+    pub group: Pubkey,
+    pub address: Pubkey,
+}
+
+/// Emitted when an address is removed from the royalty exemptions of a group
+#[event]
+pub struct RoyaltyExemptionRemoved {
+    pub group: Pubkey,
+    pub address: Pubkey,
+}
+
+/// Emitted when a metadata field is added, updated or removed
+#[event]
+pub struct MetadataUpdated {
+    pub mint: Pubkey,
+    pub key: String,
+    /// `None` when the field was added
+    pub old_value: Option<String>,
+    /// `None` when the field was removed
+    pub new_value: Option<String>,
+    pub authority: Pubkey,
+}
+
+/// Emitted when a creator endorses the royalties of a mint
+#[event]
+pub struct CreatorVerified {
+    pub mint: Pubkey,
+    pub creator: Pubkey,
+}
+
+/// Emitted when a creator withdraws the endorsement of a mint
+#[event]
+pub struct CreatorUnverified {
+    pub mint: Pubkey,
+    pub creator: Pubkey,
+}
+
+/// Emitted when royalties of a timelocked group member are queued
+#[event]
+pub struct RoyaltyUpdateQueued {
+    pub mint: Pubkey,
+    pub royalty_basis_points: u16,
+    pub creators: Vec<String>,
+    pub effective_after: i64,
+}
+
+/// Emitted when royalties are paid for a transfer
+#[event]
+pub struct TransferApproved {
+    pub mint: Pubkey,
+    pub seller: Pubkey,
+    pub buyer: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub sale_price: u64,
+    pub payment_mint: Pubkey,
+    pub royalty_amount: u64,
+}
+
+/// Emitted when a transfer consumes its approval, joins the `TransferApproved` of the sale
+#[event]
+pub struct ApprovedTransferExecuted {
+    pub mint: Pubkey,
+    pub source: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub seller: Pubkey,
+    pub buyer: Pubkey,
+    pub sale_price: u64,
+    pub payment_mint: Pubkey,
+}
+
+/// Emitted when the extra metas of a mint are rewritten to the canonical list
+#[event]
+pub struct ExtraMetasUpdated {
+    pub mint: Pubkey,
+    pub group: Option<Pubkey>,
+}
+
+/// Emitted when the group authority sets the royalty enforcement mode of its members
+#[event]
+pub struct EnforcementModeUpdated {
+    pub group: Pubkey,
+    pub old_mode: EnforcementMode,
+    pub new_mode: EnforcementMode,
+}
+
+/// Emitted when the group authority registers a secondary transfer hook
+#[event]
+pub struct SecondaryHookRegistered {
+    pub group: Pubkey,
+    pub program_id: Pubkey,
+}
+
+/// Emitted when the group authority unregisters its secondary transfer hook
+#[event]
+pub struct SecondaryHookUnregistered {
+    pub group: Pubkey,
+}
+
+/// Emitted when the group authority cancels a pending royalty update
+#[event]
+pub struct RoyaltyUpdateCancelled {
+    pub mint: Pubkey,
+}
+
+/// Emitted when the group authority updates the max size of a group
+#[event]
+pub struct GroupUpdated {
+    pub group: Pubkey,
+    pub old_max_size: u32,
+    pub new_max_size: u32,
+    pub size: u32,
+}
+
+/// Emitted when the group authority removes a mint from its group, or its supply is burned
+#[event]
+pub struct MemberRemoved {
+    pub group: Pubkey,
+    pub mint: Pubkey,
+    pub member_number: u32,
+}
+
+/// Emitted when the group authority closes an empty group
+#[event]
+pub struct GroupClosed {
+    pub group: Pubkey,
+    pub mint: Pubkey,
+    pub mint_closed: bool,
+}
+
+/// Emitted when a mint joins a group
+#[event]
+pub struct MemberAdded {
+    pub group: Pubkey,
+    pub mint: Pubkey,
+    pub member_number: u32,
+}
+
+/// Emitted when the group authority finalizes a group
+#[event]
+pub struct GroupFinalized {
+    pub group: Pubkey,
+    pub size: u32,
+}
+
+/// Emitted when a group is added as a sub-group of a parent group
+#[event]
+pub struct GroupParentSet {
+    pub group: Pubkey,
+    pub parent: Pubkey,
+}
+
+/// Emitted when the group authority approves a delegate adding members to the group
+#[event]
+pub struct GroupDelegateApproved {
+    pub group: Pubkey,
+    pub delegate: Pubkey,
+}
+
+/// Emitted when the group authority revokes a group delegate
+#[event]
+pub struct GroupDelegateRevoked {
+    pub group: Pubkey,
+    pub delegate: Pubkey,
+}
+
+/// Emitted when members join or leave a group, with the resulting size
+#[event]
+pub struct GroupSizeChanged {
+    pub group: Pubkey,
+    pub size: u32,
+    pub max_size: u32,
+}
+
+/// Emitted when the group authority grants permissions to a delegate
+#[event]
+pub struct GroupPermissionGranted {
+    pub group: Pubkey,
+    pub delegate: Pubkey,
+    pub permissions: u8,
+    pub expires_at: Option<i64>,
+}
+
+/// Emitted when the group authority revokes the permissions of a delegate
+#[event]
+pub struct GroupPermissionRevoked {
+    pub group: Pubkey,
+    pub delegate: Pubkey,
+}
+
+/// Emitted when a manager namespaced to an authority is created
+#[event]
+pub struct ManagerInitialized {
+    pub manager: Pubkey,
+    pub authority: Pubkey,
+}
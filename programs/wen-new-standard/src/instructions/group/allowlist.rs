@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::{MintAllowlist, TokenGroup, ALLOWLIST_SEED};
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct MintAllowlistArgs {
+    pub root: [u8; 32],
+    pub limit_per_wallet: u32,
+}
+
+#[derive(Accounts)]
+#[instruction(args: MintAllowlistArgs)]
+pub struct SetMintAllowlist<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub authority: Signer<'info>,
+    #[account(
+        constraint = group.update_authority == authority.key(),
+    )]
+    pub group: Account<'info, TokenGroup>,
+    #[account(
+        init_if_needed,
+        seeds = [ALLOWLIST_SEED, group.key().as_ref()],
+        bump,
+        payer = payer,
+        space = MintAllowlist::LEN
+    )]
+    pub allowlist: Account<'info, MintAllowlist>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Sets the allowlist checked when member mints are created, per-wallet counts are kept
+pub fn handler(ctx: Context<SetMintAllowlist>, args: MintAllowlistArgs) -> Result<()> {
+    let allowlist = &mut ctx.accounts.allowlist;
+    allowlist.group = ctx.accounts.group.key();
+    allowlist.root = args.root;
+    allowlist.limit_per_wallet = args.limit_per_wallet;
+
+    Ok(())
+}
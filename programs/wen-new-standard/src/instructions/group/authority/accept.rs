@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+
+use anchor_spl::token_interface::{
+    token_metadata_update_authority, Mint, Token2022, TokenMetadataUpdateAuthority,
+};
+use spl_pod::optional_keys::OptionalNonZeroPubkey;
+
+use crate::{
+    get_metadata, GroupAuthorityNomination, MetadataErrors, TokenGroup, GROUP_NOMINATION_SEED,
+};
+
+#[derive(Accounts)]
+#[instruction()]
+pub struct AcceptGroupAuthority<'info> {
+    pub nominee: Signer<'info>,
+    /// CHECK: nominator refunded the nomination rent, checked against the nomination
+    #[account(mut)]
+    pub authority: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub group: Account<'info, TokenGroup>,
+    #[account(
+        address = group.mint,
+        mint::token_program = token_program,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        seeds = [GROUP_NOMINATION_SEED, group.key().as_ref()],
+        bump = nomination.bump,
+        has_one = nominee,
+        has_one = authority,
+        close = authority,
+    )]
+    pub nomination: Account<'info, GroupAuthorityNomination>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+impl AcceptGroupAuthority<'_> {
+    fn update_token_metadata_update_authority(&self) -> Result<()> {
+        let group_key = self.group.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            GROUP_NOMINATION_SEED,
+            group_key.as_ref(),
+            &[self.nomination.bump],
+        ]];
+        let cpi_accounts = TokenMetadataUpdateAuthority {
+            token_program_id: self.token_program.to_account_info(),
+            metadata: self.mint.to_account_info(),
+            current_authority: self.nomination.to_account_info(),
+            new_authority: self.nominee.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        token_metadata_update_authority(
+            cpi_ctx,
+            OptionalNonZeroPubkey::try_from(Some(self.nominee.key()))?,
+        )?;
+        Ok(())
+    }
+}
+
+/// Makes the nominee the group update authority, and the metadata update authority of the
+/// group mint when the nomination holds it
+pub fn handler(ctx: Context<AcceptGroupAuthority>) -> Result<()> {
+    // the group update authority must not have changed since the nomination
+    if ctx.accounts.group.update_authority != ctx.accounts.nomination.authority {
+        return Err(MetadataErrors::StaleNomination.into());
+    }
+    ctx.accounts.group.update_authority = ctx.accounts.nominee.key();
+
+    let metadata = get_metadata(&ctx.accounts.mint.to_account_info(), None)?;
+    if Option::<Pubkey>::from(metadata.update_authority) == Some(ctx.accounts.nomination.key()) {
+        ctx.accounts.update_token_metadata_update_authority()?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+
+use anchor_spl::token_interface::{
+    token_metadata_update_authority, Mint, Token2022, TokenMetadataUpdateAuthority,
+};
+use spl_pod::optional_keys::OptionalNonZeroPubkey;
+
+use crate::{get_metadata, GroupAuthorityNomination, TokenGroup, GROUP_NOMINATION_SEED};
+
+#[derive(Accounts)]
+#[instruction()]
+pub struct CancelGroupAuthorityNomination<'info> {
+    /// group update authority at nomination
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub group: Account<'info, TokenGroup>,
+    #[account(
+        address = group.mint,
+        mint::token_program = token_program,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        seeds = [GROUP_NOMINATION_SEED, group.key().as_ref()],
+        bump = nomination.bump,
+        has_one = authority,
+        close = authority,
+    )]
+    pub nomination: Account<'info, GroupAuthorityNomination>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+impl CancelGroupAuthorityNomination<'_> {
+    fn restore_token_metadata_update_authority(&self) -> Result<()> {
+        let group_key = self.group.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            GROUP_NOMINATION_SEED,
+            group_key.as_ref(),
+            &[self.nomination.bump],
+        ]];
+        let cpi_accounts = TokenMetadataUpdateAuthority {
+            token_program_id: self.token_program.to_account_info(),
+            metadata: self.mint.to_account_info(),
+            current_authority: self.nomination.to_account_info(),
+            new_authority: self.authority.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        token_metadata_update_authority(
+            cpi_ctx,
+            OptionalNonZeroPubkey::try_from(Some(self.authority.key()))?,
+        )?;
+        Ok(())
+    }
+}
+
+/// Cancels a pending group authority nomination, the group update authority is unchanged and
+/// the metadata update authority of the group mint goes back to the nominator
+pub fn handler(ctx: Context<CancelGroupAuthorityNomination>) -> Result<()> {
+    let metadata = get_metadata(&ctx.accounts.mint.to_account_info(), None)?;
+    if Option::<Pubkey>::from(metadata.update_authority) == Some(ctx.accounts.nomination.key()) {
+        ctx.accounts.restore_token_metadata_update_authority()?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,7 @@
+pub mod accept;
+pub mod cancel;
+pub mod nominate;
+
+pub use accept::*;
+pub use cancel::*;
+pub use nominate::*;
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+
+use anchor_spl::token_interface::{
+    token_metadata_update_authority, Mint, Token2022, TokenMetadataUpdateAuthority,
+};
+use spl_pod::optional_keys::OptionalNonZeroPubkey;
+
+use crate::{get_metadata, GroupAuthorityNomination, TokenGroup, GROUP_NOMINATION_SEED};
+
+#[derive(Accounts)]
+#[instruction(nominee: Pubkey)]
+pub struct NominateGroupAuthority<'info> {
+    /// group update authority
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        constraint = group.update_authority == authority.key(),
+    )]
+    pub group: Account<'info, TokenGroup>,
+    #[account(
+        address = group.mint,
+        mint::token_program = token_program,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        init,
+        seeds = [GROUP_NOMINATION_SEED, group.key().as_ref()],
+        bump,
+        payer = authority,
+        space = GroupAuthorityNomination::LEN
+    )]
+    pub nomination: Account<'info, GroupAuthorityNomination>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+impl NominateGroupAuthority<'_> {
+    fn escrow_token_metadata_update_authority(&self) -> Result<()> {
+        let cpi_accounts = TokenMetadataUpdateAuthority {
+            token_program_id: self.token_program.to_account_info(),
+            metadata: self.mint.to_account_info(),
+            current_authority: self.authority.to_account_info(),
+            new_authority: self.nomination.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
+        token_metadata_update_authority(
+            cpi_ctx,
+            OptionalNonZeroPubkey::try_from(Some(self.nomination.key()))?,
+        )?;
+        Ok(())
+    }
+}
+
+/// Nominates `nominee` as group update authority, effective once accepted. The metadata update
+/// authority of the group mint is held by the nomination until then.
+pub fn handler(ctx: Context<NominateGroupAuthority>, nominee: Pubkey) -> Result<()> {
+    let nomination = &mut ctx.accounts.nomination;
+    nomination.group = ctx.accounts.group.key();
+    nomination.authority = ctx.accounts.authority.key();
+    nomination.nominee = nominee;
+    nomination.bump = ctx.bumps.nomination;
+
+    // the group mint metadata moves along the group, when the group authority holds it
+    let metadata = get_metadata(&ctx.accounts.mint.to_account_info(), None)?;
+    if Option::<Pubkey>::from(metadata.update_authority) == Some(ctx.accounts.authority.key()) {
+        ctx.accounts.escrow_token_metadata_update_authority()?;
+    }
+
+    Ok(())
+}
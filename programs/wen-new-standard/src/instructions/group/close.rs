@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{close_account, CloseAccount, Mint, Token2022};
+
+use crate::{GroupClosed, MetadataErrors, TokenGroup};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction()]
+pub struct CloseGroup<'info> {
+    /// group update authority, refunded the freed rent by default
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        constraint = group.update_authority == authority.key(),
+        constraint = group.size == 0 @ MetadataErrors::GroupNotEmpty,
+    )]
+    pub group: Account<'info, TokenGroup>,
+    /// group mint, only needed to close it along the group
+    #[account(
+        mut,
+        address = group.mint,
+        mint::token_program = token_program,
+        constraint = mint.supply == 0 @ MetadataErrors::MintNotBurned,
+    )]
+    pub mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+    /// close authority of the group mint, only needed along `mint`
+    pub close_authority: Option<Signer<'info>>,
+    /// CHECK: receives the freed rent, defaults to the authority
+    #[account(mut)]
+    pub rent_destination: Option<UncheckedAccount<'info>>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+impl<'info> CloseGroup<'info> {
+    fn close_mint(&self, destination: AccountInfo<'info>) -> Result<()> {
+        let (Some(mint), Some(close_authority)) =
+            (self.mint.as_ref(), self.close_authority.as_ref())
+        else {
+            return Err(ErrorCode::AccountNotSigner.into());
+        };
+        let cpi_accounts = CloseAccount {
+            account: mint.to_account_info(),
+            destination,
+            authority: close_authority.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
+        close_account(cpi_ctx)?;
+        Ok(())
+    }
+}
+
+/// Closes a group without members. The group mint is also closed when passed,
+/// its supply must be burned and its close authority must sign.
+pub fn handler(ctx: Context<CloseGroup>) -> Result<()> {
+    let rent_destination = ctx
+        .accounts
+        .rent_destination
+        .as_ref()
+        .map(|rent_destination| rent_destination.to_account_info())
+        .unwrap_or(ctx.accounts.authority.to_account_info());
+
+    let mint_closed = ctx.accounts.mint.is_some();
+    if mint_closed {
+        ctx.accounts.close_mint(rent_destination.clone())?;
+    }
+
+    let group = ctx.accounts.group.key();
+    let mint = ctx.accounts.group.mint;
+    ctx.accounts.group.close(rent_destination)?;
+
+    emit_cpi!(GroupClosed {
+        group,
+        mint,
+        mint_closed,
+    });
+
+    Ok(())
+}
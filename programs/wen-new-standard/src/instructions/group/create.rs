@@ -10,8 +10,10 @@ use anchor_spl::{
 };
 
 use crate::{
-    update_account_lamports_to_minimum_balance, Manager, TokenGroup, GROUP_ACCOUNT_SEED,
-    MANAGER_SEED,
+    create_account_with_minimum_balance, init_royalty_enforcement,
+    update_account_lamports_to_minimum_balance, validate_metadata, EnforcementMode, GroupSoulbound,
+    Manager, TokenGroup, GROUP_ACCOUNT_SEED, GROUP_SOULBOUND_SEED, MANAGER_SEED,
+    ROYALTY_ENFORCEMENT_SEED,
 };
 
 #[derive(AnchorDeserialize, AnchorSerialize)]
@@ -20,6 +22,9 @@ pub struct CreateGroupAccountArgs {
     pub symbol: String,
     pub uri: String,
     pub max_size: u32,
+    /// Require every member to be non-transferable and freeze transfers of the group,
+    /// can't be changed once the group is created
+    pub soulbound: bool,
 }
 
 pub const GROUP_EXTENSIONS: [ExtensionType; 3] = [
@@ -47,6 +52,20 @@ pub struct CreateGroupAccount<'info> {
         space = TokenGroup::LEN
     )]
     pub group: Account<'info, TokenGroup>,
+    /// CHECK: soulbound PDA of the group, only created for soulbound groups
+    #[account(
+        mut,
+        seeds = [GROUP_SOULBOUND_SEED, group.key().as_ref()],
+        bump,
+    )]
+    pub soulbound: UncheckedAccount<'info>,
+    /// CHECK: royalty enforcement PDA of the group, created `Frozen` for soulbound groups
+    #[account(
+        mut,
+        seeds = [ROYALTY_ENFORCEMENT_SEED, group.key().as_ref()],
+        bump,
+    )]
+    pub royalty_enforcement: UncheckedAccount<'info>,
     #[account(
         init,
         signer,
@@ -71,13 +90,17 @@ pub struct CreateGroupAccount<'info> {
         associated_token::authority = receiver,
     )]
     pub mint_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: authority the manager is namespaced to, omitted for the global manager
+    pub manager_authority: Option<UncheckedAccount<'info>>,
     #[account(
-        seeds = [MANAGER_SEED],
+        seeds = [
+            MANAGER_SEED,
+            Manager::namespace(manager_authority.as_ref().map(|authority| authority.key)),
+        ],
         bump
     )]
     pub manager: Account<'info, Manager>,
     pub system_program: Program<'info, System>,
-    pub rent: Sysvar<'info, Rent>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub token_program: Program<'info, Token2022>,
 }
@@ -119,6 +142,8 @@ impl<'info> CreateGroupAccount<'info> {
 }
 
 pub fn handler(ctx: Context<CreateGroupAccount>, args: CreateGroupAccountArgs) -> Result<()> {
+    validate_metadata(&args.name, &args.symbol, &args.uri)?;
+
     // initialize token metadata
     ctx.accounts
         .initialize_metadata(TokenMetadataInitializeArgs {
@@ -127,13 +152,42 @@ pub fn handler(ctx: Context<CreateGroupAccount>, args: CreateGroupAccountArgs) -
             uri: args.uri,
         })?;
 
-    // using a custom group account until token22 implements group account
+    // the WNS group account stays authoritative, the native group extension is only
+    // written along it behind the `native-group` feature
     let group = &mut ctx.accounts.group;
     group.max_size = args.max_size;
     group.update_authority = ctx.accounts.authority.key();
     group.mint = ctx.accounts.mint.key();
     group.size = 0;
 
+    // soulbound groups are frozen from the start, so even a transferable member couldn't move:
+    // every member resolves the enforcement mode of its group, with or without royalties
+    if args.soulbound {
+        let group = ctx.accounts.group.key();
+        create_account_with_minimum_balance(
+            ctx.accounts.soulbound.to_account_info(),
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            GroupSoulbound::LEN,
+            0,
+            &crate::id(),
+            &[&[GROUP_SOULBOUND_SEED, group.as_ref(), &[ctx.bumps.soulbound]]],
+        )?;
+        GroupSoulbound { group }
+            .try_serialize(&mut &mut ctx.accounts.soulbound.try_borrow_mut_data()?[..])?;
+
+        let mut royalty_enforcement = init_royalty_enforcement(
+            ctx.accounts.royalty_enforcement.to_account_info(),
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            group,
+            ctx.bumps.royalty_enforcement,
+        )?;
+        royalty_enforcement.mode = EnforcementMode::Frozen;
+        royalty_enforcement
+            .try_serialize(&mut &mut ctx.accounts.royalty_enforcement.try_borrow_mut_data()?[..])?;
+    }
+
     // mint to receiver
     ctx.accounts.mint_to_receiver()?;
 
@@ -141,6 +195,21 @@ pub fn handler(ctx: Context<CreateGroupAccount>, args: CreateGroupAccountArgs) -
     // move mint authority to Manager
     ctx.accounts.update_mint_authority(manager_pubkey)?;
 
+    #[cfg(feature = "native-group")]
+    crate::initialize_native_group(
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.manager.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        Manager::namespace(
+            ctx.accounts
+                .manager_authority
+                .as_ref()
+                .map(|authority| authority.key),
+        ),
+        ctx.bumps.manager,
+        args.max_size,
+    )?;
+
     // update mint lamports to minimum rent balance
     update_account_lamports_to_minimum_balance(
         ctx.accounts.mint.to_account_info(),
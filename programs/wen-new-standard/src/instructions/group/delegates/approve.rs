@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    GroupDelegateApproved, GroupDelegates, MetadataErrors, TokenGroup, GROUP_DELEGATES_SEED,
+    MAX_GROUP_DELEGATES,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(delegate: Pubkey)]
+pub struct ApproveGroupDelegate<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub authority: Signer<'info>,
+    #[account(
+        constraint = group.update_authority == authority.key(),
+    )]
+    pub group: Account<'info, TokenGroup>,
+    #[account(
+        init_if_needed,
+        seeds = [GROUP_DELEGATES_SEED, group.key().as_ref()],
+        bump,
+        payer = payer,
+        space = GroupDelegates::LEN
+    )]
+    pub group_delegates: Account<'info, GroupDelegates>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Lets `delegate` add members to the group like the group authority. Delegates can't
+/// update, finalize or transfer the group.
+pub fn handler(ctx: Context<ApproveGroupDelegate>, delegate: Pubkey) -> Result<()> {
+    let group_delegates = &mut ctx.accounts.group_delegates;
+    if group_delegates.delegates.contains(&delegate) {
+        return Err(MetadataErrors::GroupDelegateExists.into());
+    }
+    if group_delegates.delegates.len() >= MAX_GROUP_DELEGATES {
+        return Err(MetadataErrors::TooManyGroupDelegates.into());
+    }
+    group_delegates.group = ctx.accounts.group.key();
+    group_delegates.delegates.push(delegate);
+
+    emit_cpi!(GroupDelegateApproved {
+        group: ctx.accounts.group.key(),
+        delegate,
+    });
+
+    Ok(())
+}
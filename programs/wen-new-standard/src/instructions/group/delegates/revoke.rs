@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    GroupDelegateRevoked, GroupDelegates, MetadataErrors, TokenGroup, GROUP_DELEGATES_SEED,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(delegate: Pubkey)]
+pub struct RevokeGroupDelegate<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        constraint = group.update_authority == authority.key(),
+    )]
+    pub group: Account<'info, TokenGroup>,
+    #[account(
+        mut,
+        seeds = [GROUP_DELEGATES_SEED, group.key().as_ref()],
+        bump,
+    )]
+    pub group_delegates: Account<'info, GroupDelegates>,
+}
+
+pub fn handler(ctx: Context<RevokeGroupDelegate>, delegate: Pubkey) -> Result<()> {
+    let group_delegates = &mut ctx.accounts.group_delegates;
+    let index = group_delegates
+        .delegates
+        .iter()
+        .position(|approved| approved == &delegate)
+        .ok_or(MetadataErrors::GroupDelegateNotFound)?;
+    group_delegates.delegates.swap_remove(index);
+
+    emit_cpi!(GroupDelegateRevoked {
+        group: ctx.accounts.group.key(),
+        delegate,
+    });
+
+    Ok(())
+}
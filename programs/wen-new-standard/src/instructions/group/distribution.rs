@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::{CollectionDistribution, TokenGroup, COLLECTION_DISTRIBUTION_SEED};
+
+#[derive(Accounts)]
+#[instruction(enabled: bool)]
+pub struct SetCollectionDistribution<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub authority: Signer<'info>,
+    #[account(
+        constraint = group.update_authority == authority.key(),
+    )]
+    pub group: Account<'info, TokenGroup>,
+    #[account(
+        init_if_needed,
+        seeds = [COLLECTION_DISTRIBUTION_SEED, group.key().as_ref()],
+        bump,
+        payer = payer,
+        space = CollectionDistribution::LEN
+    )]
+    pub collection_distribution: Account<'info, CollectionDistribution>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Routes the royalties of group members to the distribution of the group mint when `enabled`,
+/// members with creators other than the group royalty defaults keep their own distribution
+pub fn handler(ctx: Context<SetCollectionDistribution>, enabled: bool) -> Result<()> {
+    let collection_distribution = &mut ctx.accounts.collection_distribution;
+    collection_distribution.group = ctx.accounts.group.key();
+    collection_distribution.enabled = enabled;
+
+    Ok(())
+}
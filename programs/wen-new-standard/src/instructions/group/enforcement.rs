@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    init_royalty_enforcement, EnforcementMode, EnforcementModeUpdated, GroupSoulbound,
+    MetadataErrors, TokenGroup, GROUP_SOULBOUND_SEED, ROYALTY_ENFORCEMENT_SEED,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(mode: EnforcementMode)]
+pub struct SetEnforcementMode<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub authority: Signer<'info>,
+    #[account(
+        constraint = group.update_authority == authority.key(),
+    )]
+    pub group: Account<'info, TokenGroup>,
+    /// CHECK: soulbound PDA of the group, uninitialized for transferable groups
+    #[account(
+        seeds = [GROUP_SOULBOUND_SEED, group.key().as_ref()],
+        bump,
+    )]
+    pub soulbound: UncheckedAccount<'info>,
+    /// CHECK: royalty enforcement PDA of the group, created or grown from the previous layout in the handler
+    #[account(
+        mut,
+        seeds = [ROYALTY_ENFORCEMENT_SEED, group.key().as_ref()],
+        bump,
+    )]
+    pub royalty_enforcement: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Sets the royalty enforcement mode of the transfers of group members
+pub fn handler(ctx: Context<SetEnforcementMode>, mode: EnforcementMode) -> Result<()> {
+    // soulbound groups stay frozen
+    if mode != EnforcementMode::Frozen
+        && GroupSoulbound::load(&ctx.accounts.soulbound.to_account_info())?.is_some()
+    {
+        return Err(MetadataErrors::SoulboundGroupFrozen.into());
+    }
+
+    // groups that never set a mode were enforced with the default one
+    let mut royalty_enforcement = init_royalty_enforcement(
+        ctx.accounts.royalty_enforcement.to_account_info(),
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.group.key(),
+        ctx.bumps.royalty_enforcement,
+    )?;
+    let old_mode = royalty_enforcement.mode;
+    royalty_enforcement.mode = mode;
+    royalty_enforcement
+        .try_serialize(&mut &mut ctx.accounts.royalty_enforcement.try_borrow_mut_data()?[..])?;
+
+    emit_cpi!(EnforcementModeUpdated {
+        group: ctx.accounts.group.key(),
+        old_mode,
+        new_mode: mode,
+    });
+
+    Ok(())
+}
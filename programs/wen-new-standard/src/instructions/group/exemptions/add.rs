@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    MetadataErrors, RoyaltyExemptionAdded, RoyaltyExemptions, TokenGroup, MAX_ROYALTY_EXEMPTIONS,
+    ROYALTY_EXEMPTIONS_SEED,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(address: Pubkey)]
+pub struct AddExemption<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub authority: Signer<'info>,
+    #[account(
+        constraint = group.update_authority == authority.key(),
+    )]
+    pub group: Account<'info, TokenGroup>,
+    #[account(
+        init_if_needed,
+        seeds = [ROYALTY_EXEMPTIONS_SEED, group.key().as_ref()],
+        bump,
+        payer = payer,
+        space = RoyaltyExemptions::LEN
+    )]
+    pub royalty_exemptions: Account<'info, RoyaltyExemptions>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Exempts transfers of group members from royalties when `address` is the source or
+/// destination owner, or the program invoked by the transaction
+pub fn handler(ctx: Context<AddExemption>, address: Pubkey) -> Result<()> {
+    let royalty_exemptions = &mut ctx.accounts.royalty_exemptions;
+    if royalty_exemptions.exemptions.contains(&address) {
+        return Err(MetadataErrors::RoyaltyExemptionExists.into());
+    }
+    if royalty_exemptions.exemptions.len() >= MAX_ROYALTY_EXEMPTIONS {
+        return Err(MetadataErrors::TooManyRoyaltyExemptions.into());
+    }
+    royalty_exemptions.group = ctx.accounts.group.key();
+    royalty_exemptions.exemptions.push(address);
+
+    emit_cpi!(RoyaltyExemptionAdded {
+        group: ctx.accounts.group.key(),
+        address,
+    });
+
+    Ok(())
+}
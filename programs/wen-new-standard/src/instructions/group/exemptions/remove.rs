@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    MetadataErrors, RoyaltyExemptionRemoved, RoyaltyExemptions, TokenGroup, ROYALTY_EXEMPTIONS_SEED,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(address: Pubkey)]
+pub struct RemoveExemption<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        constraint = group.update_authority == authority.key(),
+    )]
+    pub group: Account<'info, TokenGroup>,
+    #[account(
+        mut,
+        seeds = [ROYALTY_EXEMPTIONS_SEED, group.key().as_ref()],
+        bump,
+    )]
+    pub royalty_exemptions: Account<'info, RoyaltyExemptions>,
+}
+
+pub fn handler(ctx: Context<RemoveExemption>, address: Pubkey) -> Result<()> {
+    let royalty_exemptions = &mut ctx.accounts.royalty_exemptions;
+    let index = royalty_exemptions
+        .exemptions
+        .iter()
+        .position(|exemption| exemption == &address)
+        .ok_or(MetadataErrors::RoyaltyExemptionNotFound)?;
+    royalty_exemptions.exemptions.swap_remove(index);
+
+    emit_cpi!(RoyaltyExemptionRemoved {
+        group: ctx.accounts.group.key(),
+        address,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    GroupFinalization, GroupFinalized, MetadataErrors, TokenGroup, GROUP_FINALIZATION_SEED,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction()]
+pub struct FinalizeGroup<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub authority: Signer<'info>,
+    #[account(
+        constraint = group.update_authority == authority.key(),
+    )]
+    pub group: Account<'info, TokenGroup>,
+    #[account(
+        init_if_needed,
+        seeds = [GROUP_FINALIZATION_SEED, group.key().as_ref()],
+        bump,
+        payer = payer,
+        space = GroupFinalization::LEN
+    )]
+    pub finalization: Account<'info, GroupFinalization>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Permanently locks the membership of a group, members can still be removed or burned.
+/// Finalizing twice fails with `GroupFinalized`.
+pub fn handler(ctx: Context<FinalizeGroup>) -> Result<()> {
+    if ctx.accounts.finalization.group != Pubkey::default() {
+        return Err(MetadataErrors::GroupFinalized.into());
+    }
+
+    let finalization = &mut ctx.accounts.finalization;
+    finalization.group = ctx.accounts.group.key();
+    finalization.size = ctx.accounts.group.size;
+
+    emit_cpi!(GroupFinalized {
+        group: ctx.accounts.group.key(),
+        size: ctx.accounts.group.size,
+    });
+
+    Ok(())
+}
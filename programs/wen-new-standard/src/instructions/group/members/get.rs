@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::{GroupMemberIndex, TokenGroup, MEMBER_INDEX_SEED};
+
+#[derive(Accounts)]
+#[instruction(page: u32)]
+pub struct GetMembersPage<'info> {
+    pub group: Account<'info, TokenGroup>,
+    /// CHECK: member index page PDA, uninitialized when no member of the page was indexed
+    #[account(
+        seeds = [MEMBER_INDEX_SEED, group.key().as_ref(), &page.to_le_bytes()],
+        bump,
+    )]
+    pub member_index: UncheckedAccount<'info>,
+}
+
+/// Returns the members of a page of the group member index, removed members excluded.
+/// Clients iterate pages from 0 until `page * MEMBER_INDEX_PAGE_SIZE` reaches the last
+/// assigned member number.
+pub fn handler(ctx: Context<GetMembersPage>, _page: u32) -> Result<Vec<Pubkey>> {
+    let members = GroupMemberIndex::load(&ctx.accounts.member_index.to_account_info())?
+        .map(|index| {
+            index
+                .members
+                .into_iter()
+                .filter(|member| *member != Pubkey::default())
+                .collect()
+        })
+        .unwrap_or_default();
+    Ok(members)
+}
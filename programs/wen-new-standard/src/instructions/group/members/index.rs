@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::{index_member, TokenGroup, TokenGroupMember, MEMBER_ACCOUNT_SEED};
+
+#[derive(Accounts)]
+#[instruction()]
+pub struct IndexGroupMember<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub group: Account<'info, TokenGroup>,
+    #[account(
+        seeds = [MEMBER_ACCOUNT_SEED, member.mint.as_ref()],
+        bump,
+        has_one = group,
+    )]
+    pub member: Account<'info, TokenGroupMember>,
+    /// CHECK: member index page PDA of the member number, created in the handler
+    #[account(mut)]
+    pub member_index: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Indexes a member added before the group member index existed, permissionless
+pub fn handler(ctx: Context<IndexGroupMember>) -> Result<()> {
+    index_member(
+        ctx.accounts.member_index.to_account_info(),
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.group.key(),
+        ctx.accounts.member.member_number,
+        ctx.accounts.member.mint,
+    )
+}
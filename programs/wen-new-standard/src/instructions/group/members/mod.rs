@@ -0,0 +1,5 @@
+pub mod get;
+pub mod index;
+
+pub use get::*;
+pub use index::*;
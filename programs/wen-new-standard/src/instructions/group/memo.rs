@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::{init_royalty_enforcement, TokenGroup, ROYALTY_ENFORCEMENT_SEED};
+
+#[derive(Accounts)]
+#[instruction(required: bool)]
+pub struct SetMemoRequired<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub authority: Signer<'info>,
+    #[account(
+        constraint = group.update_authority == authority.key(),
+    )]
+    pub group: Account<'info, TokenGroup>,
+    /// CHECK: royalty enforcement PDA of the group, created or grown from the previous layout in the handler
+    #[account(
+        mut,
+        seeds = [ROYALTY_ENFORCEMENT_SEED, group.key().as_ref()],
+        bump,
+    )]
+    pub royalty_enforcement: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Requires transfers of group members, with or without royalties, to follow a memo instruction
+/// in their transaction when `required`, transfers invoked by programs need it at the top level
+pub fn handler(ctx: Context<SetMemoRequired>, required: bool) -> Result<()> {
+    let mut royalty_enforcement = init_royalty_enforcement(
+        ctx.accounts.royalty_enforcement.to_account_info(),
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.group.key(),
+        ctx.bumps.royalty_enforcement,
+    )?;
+    royalty_enforcement.memo_required = required;
+    royalty_enforcement
+        .try_serialize(&mut &mut ctx.accounts.royalty_enforcement.try_borrow_mut_data()?[..])?;
+
+    Ok(())
+}
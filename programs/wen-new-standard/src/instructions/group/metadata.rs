@@ -0,0 +1,170 @@
+use anchor_lang::{prelude::*, solana_program::entrypoint::ProgramResult};
+use anchor_spl::token_interface::{
+    spl_token_metadata_interface::state::Field, token_metadata_update_field, Mint, Token2022,
+    TokenMetadataUpdateField, TokenMetadataUpdateFieldArgs,
+};
+
+use crate::{
+    get_metadata_value, get_mint_metadata, refund_mint_lamports_above_minimum_balance,
+    update_account_lamports_to_minimum_balance, validate_group_permission, validate_metadata,
+    GroupPermission, Manager, MetadataErrors, MetadataUpdated, TokenGroup, GROUP_ACCOUNT_SEED,
+    GROUP_PERMISSION_SEED, MANAGER_SEED,
+};
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct UpdateGroupMetadataArgs {
+    pub name: Option<String>,
+    pub symbol: Option<String>,
+    pub uri: Option<String>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(args: UpdateGroupMetadataArgs)]
+pub struct UpdateGroupMetadata<'info> {
+    /// pays for metadata growth and is refunded when it shrinks
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// group update authority, or a delegate granted `UPDATE_METADATA`
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [GROUP_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub group: Account<'info, TokenGroup>,
+    /// CHECK: permission PDA of the authority in the group, uninitialized without a grant
+    #[account(
+        seeds = [GROUP_PERMISSION_SEED, group.key().as_ref(), authority.key().as_ref()],
+        bump,
+    )]
+    pub group_permission: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        mint::token_program = token_program,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    /// CHECK: authority the manager is namespaced to, omitted for the global manager
+    pub manager_authority: Option<UncheckedAccount<'info>>,
+    #[account(
+        seeds = [
+            MANAGER_SEED,
+            Manager::namespace(manager_authority.as_ref().map(|authority| authority.key)),
+        ],
+        bump
+    )]
+    pub manager: Account<'info, Manager>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+impl<'info> UpdateGroupMetadata<'info> {
+    /// Updates a field signed by the metadata update authority of the group mint, either the
+    /// authority or the `Manager` PDA
+    fn update_token_metadata_field(
+        &self,
+        field: Field,
+        value: String,
+        update_authority: AccountInfo<'info>,
+        manager_namespace: &[u8],
+        manager_bump: u8,
+    ) -> ProgramResult {
+        let cpi_accounts = TokenMetadataUpdateField {
+            token_program_id: self.token_program.to_account_info(),
+            metadata: self.mint.to_account_info(), // metadata account is the mint, since data is stored in mint
+            update_authority,
+        };
+        let signer_seeds: &[&[&[u8]]] = &[&[MANAGER_SEED, manager_namespace, &[manager_bump]]];
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        token_metadata_update_field(cpi_ctx, TokenMetadataUpdateFieldArgs { field, value })?;
+        Ok(())
+    }
+}
+
+/// Updates the name, symbol and uri of the group mint, fields left to `None` are unchanged.
+/// The group mint rent is adjusted to its new size. Delegates can only update the metadata
+/// once the group authority handed the group mint metadata update authority to the `Manager` PDA,
+/// which then signs the updates.
+pub fn handler(ctx: Context<UpdateGroupMetadata>, args: UpdateGroupMetadataArgs) -> Result<()> {
+    let manager_namespace = Manager::namespace(
+        ctx.accounts
+            .manager_authority
+            .as_ref()
+            .map(|authority| authority.key),
+    );
+    validate_group_permission(
+        &ctx.accounts.group,
+        &ctx.accounts.group_permission.to_account_info(),
+        ctx.accounts.authority.key,
+        GroupPermission::UPDATE_METADATA,
+    )?;
+    let metadata = get_mint_metadata(&ctx.accounts.mint.to_account_info())?;
+    let update_authority = Option::<Pubkey>::from(metadata.update_authority);
+    let update_authority = if update_authority == Some(ctx.accounts.authority.key()) {
+        ctx.accounts.authority.to_account_info()
+    } else if update_authority == Some(ctx.accounts.manager.key()) {
+        ctx.accounts.manager.to_account_info()
+    } else {
+        return Err(MetadataErrors::InvalidMetadataAuthority.into());
+    };
+
+    // same validation as creation, on the updated values
+    validate_metadata(
+        args.name.as_ref().unwrap_or(&metadata.name),
+        args.symbol.as_ref().unwrap_or(&metadata.symbol),
+        args.uri.as_ref().unwrap_or(&metadata.uri),
+    )?;
+
+    let updates: Vec<(Field, &str, String)> = [
+        (Field::Name, "name", args.name),
+        (Field::Symbol, "symbol", args.symbol),
+        (Field::Uri, "uri", args.uri),
+    ]
+    .into_iter()
+    .filter_map(|(field, key, value)| value.map(|value| (field, key, value)))
+    .collect();
+
+    let events: Vec<MetadataUpdated> = updates
+        .iter()
+        .map(|(_, key, value)| MetadataUpdated {
+            mint: ctx.accounts.mint.key(),
+            key: (*key).to_owned(),
+            old_value: get_metadata_value(&metadata, key),
+            new_value: Some(value.clone()),
+            authority: ctx.accounts.authority.key(),
+        })
+        .collect();
+    for (field, _, value) in updates {
+        ctx.accounts.update_token_metadata_field(
+            field,
+            value,
+            update_authority.clone(),
+            manager_namespace,
+            ctx.bumps.manager,
+        )?;
+    }
+
+    // adjust rent to the new mint size
+    update_account_lamports_to_minimum_balance(
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+    )?;
+    refund_mint_lamports_above_minimum_balance(
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.manager.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        manager_namespace,
+        ctx.bumps.manager,
+    )?;
+
+    for event in events {
+        emit_cpi!(event);
+    }
+
+    Ok(())
+}
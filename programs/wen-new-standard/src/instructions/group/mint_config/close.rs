@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+use crate::{MintConfig, TokenGroup, MINT_CONFIG_SEED};
+
+#[derive(Accounts)]
+#[instruction()]
+pub struct CloseMintConfig<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        constraint = group.update_authority == authority.key(),
+    )]
+    pub group: Account<'info, TokenGroup>,
+    #[account(
+        mut,
+        seeds = [MINT_CONFIG_SEED, group.key().as_ref()],
+        bump,
+        close = authority,
+    )]
+    pub mint_config: Account<'info, MintConfig>,
+}
+
+/// Ends the public mint of the group, refunding the rent of its config to the authority
+pub fn handler(_ctx: Context<CloseMintConfig>) -> Result<()> {
+    Ok(())
+}
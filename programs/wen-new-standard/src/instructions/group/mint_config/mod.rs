@@ -0,0 +1,5 @@
+pub mod close;
+pub mod set;
+
+pub use close::*;
+pub use set::*;
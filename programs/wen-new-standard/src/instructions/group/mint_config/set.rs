@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::{validate_metadata, MetadataErrors, MintConfig, TokenGroup, MINT_CONFIG_SEED};
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct MintConfigArgs {
+    /// Mint of the price, `Pubkey::default()` for SOL
+    pub payment_mint: Pubkey,
+    pub price: u64,
+    pub treasury: Pubkey,
+    pub start_time: Option<i64>,
+    pub end_time: Option<i64>,
+    /// 0 for unlimited
+    pub limit_per_wallet: u32,
+    pub name: String,
+    pub symbol: String,
+    pub base_uri: String,
+}
+
+#[derive(Accounts)]
+#[instruction(args: MintConfigArgs)]
+pub struct SetMintConfig<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub authority: Signer<'info>,
+    #[account(
+        constraint = group.update_authority == authority.key(),
+    )]
+    pub group: Account<'info, TokenGroup>,
+    #[account(
+        init_if_needed,
+        seeds = [MINT_CONFIG_SEED, group.key().as_ref()],
+        bump,
+        payer = payer,
+        space = MintConfig::LEN
+    )]
+    pub mint_config: Account<'info, MintConfig>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Sets the public mint of the group, per-wallet counts are kept
+pub fn handler(ctx: Context<SetMintConfig>, args: MintConfigArgs) -> Result<()> {
+    validate_metadata(&args.name, &args.symbol, &args.base_uri)?;
+    if let (Some(start_time), Some(end_time)) = (args.start_time, args.end_time) {
+        if end_time <= start_time {
+            return Err(MetadataErrors::InvalidMintWindow.into());
+        }
+    }
+
+    let mint_config = &mut ctx.accounts.mint_config;
+    mint_config.group = ctx.accounts.group.key();
+    mint_config.payment_mint = args.payment_mint;
+    mint_config.price = args.price;
+    mint_config.treasury = args.treasury;
+    mint_config.start_time = args.start_time;
+    mint_config.end_time = args.end_time;
+    mint_config.limit_per_wallet = args.limit_per_wallet;
+    mint_config.name = args.name;
+    mint_config.symbol = args.symbol;
+    mint_config.base_uri = args.base_uri;
+
+    Ok(())
+}
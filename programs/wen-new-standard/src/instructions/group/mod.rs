@@ -1,5 +1,45 @@
+pub mod allowlist;
+pub mod authority;
+pub mod close;
 pub mod create;
+pub mod delegates;
+pub mod distribution;
+pub mod enforcement;
+pub mod exemptions;
+pub mod finalize;
+pub mod members;
+pub mod memo;
+pub mod metadata;
+pub mod mint_config;
+pub mod native;
+pub mod parent;
+pub mod permissions;
+pub mod policy;
+pub mod royalties;
+pub mod secondary_hook;
+pub mod supply;
+pub mod timelock;
 pub mod update;
 
+pub use allowlist::*;
+pub use authority::*;
+pub use close::*;
 pub use create::*;
+pub use delegates::*;
+pub use distribution::*;
+pub use enforcement::*;
+pub use exemptions::*;
+pub use finalize::*;
+pub use members::*;
+pub use memo::*;
+pub use metadata::*;
+pub use mint_config::*;
+pub use native::*;
+pub use parent::*;
+pub use permissions::*;
+pub use policy::*;
+pub use royalties::*;
+pub use secondary_hook::*;
+pub use supply::*;
+pub use timelock::*;
 pub use update::*;
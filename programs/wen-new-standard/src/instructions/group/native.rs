@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, Token2022};
+
+use crate::{
+    initialize_native_group, update_account_lamports_to_minimum_balance, Manager, TokenGroup,
+    MANAGER_SEED,
+};
+
+#[derive(Accounts)]
+#[instruction()]
+pub struct MigrateGroupToNative<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub authority: Signer<'info>,
+    #[account(
+        constraint = group.update_authority == authority.key(),
+    )]
+    pub group: Account<'info, TokenGroup>,
+    #[account(
+        mut,
+        address = group.mint,
+        mint::token_program = token_program,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    /// CHECK: authority the manager is namespaced to, omitted for the global manager
+    pub manager_authority: Option<UncheckedAccount<'info>>,
+    #[account(
+        seeds = [
+            MANAGER_SEED,
+            Manager::namespace(manager_authority.as_ref().map(|authority| authority.key)),
+        ],
+        bump
+    )]
+    pub manager: Account<'info, Manager>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+/// Initializes the native token group extension in the group mint with the max size of the
+/// WNS group. The WNS group account is kept and stays authoritative, members are migrated
+/// one by one with `migrate_member_to_native`.
+pub fn handler(ctx: Context<MigrateGroupToNative>) -> Result<()> {
+    let manager_namespace = Manager::namespace(
+        ctx.accounts
+            .manager_authority
+            .as_ref()
+            .map(|authority| authority.key),
+    );
+    initialize_native_group(
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.manager.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        manager_namespace,
+        ctx.bumps.manager,
+        ctx.accounts.group.max_size,
+    )?;
+
+    // update mint lamports to minimum rent balance
+    update_account_lamports_to_minimum_balance(
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    Ok(())
+}
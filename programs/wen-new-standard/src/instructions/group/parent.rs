@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    GroupHierarchy, GroupParentSet, MetadataErrors, TokenGroup, GROUP_HIERARCHY_SEED,
+    MAX_GROUP_DEPTH,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction()]
+pub struct AddGroupToGroup<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// update authority of the sub-group
+    pub authority: Signer<'info>,
+    /// update authority of the parent group
+    pub parent_authority: Signer<'info>,
+    #[account(
+        constraint = group.update_authority == authority.key(),
+    )]
+    pub group: Account<'info, TokenGroup>,
+    #[account(
+        constraint = parent_group.update_authority == parent_authority.key(),
+        constraint = parent_group.key() != group.key() @ MetadataErrors::InvalidParentGroup,
+    )]
+    pub parent_group: Account<'info, TokenGroup>,
+    #[account(
+        init_if_needed,
+        seeds = [GROUP_HIERARCHY_SEED, group.key().as_ref()],
+        bump,
+        payer = payer,
+        space = GroupHierarchy::LEN
+    )]
+    pub hierarchy: Account<'info, GroupHierarchy>,
+    #[account(
+        init_if_needed,
+        seeds = [GROUP_HIERARCHY_SEED, parent_group.key().as_ref()],
+        bump,
+        payer = payer,
+        space = GroupHierarchy::LEN
+    )]
+    pub parent_hierarchy: Account<'info, GroupHierarchy>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Makes `group` a sub-group of `parent_group`. Hierarchies are built from the top, only groups
+/// without parent nor sub-groups can be added, at most `MAX_GROUP_DEPTH` levels below the top.
+pub fn handler(ctx: Context<AddGroupToGroup>) -> Result<()> {
+    let group = ctx.accounts.group.key();
+    let parent = ctx.accounts.parent_group.key();
+    ctx.accounts.hierarchy.group = group;
+    ctx.accounts.parent_hierarchy.group = parent;
+
+    // sub-groups have a single parent, and no sub-group of their own so their descendants can't
+    // be stale or become ancestors
+    let hierarchy = &ctx.accounts.hierarchy;
+    let parent_hierarchy = &ctx.accounts.parent_hierarchy;
+    if hierarchy.parent.is_some()
+        || hierarchy.children > 0
+        || parent_hierarchy.ancestors.contains(&group)
+    {
+        return Err(MetadataErrors::InvalidParentGroup.into());
+    }
+    if parent_hierarchy.ancestors.len() >= MAX_GROUP_DEPTH {
+        return Err(MetadataErrors::GroupHierarchyTooDeep.into());
+    }
+
+    let mut ancestors = vec![parent];
+    ancestors.extend(parent_hierarchy.ancestors.iter().copied());
+    let hierarchy = &mut ctx.accounts.hierarchy;
+    hierarchy.parent = Some(parent);
+    hierarchy.ancestors = ancestors;
+
+    let parent_hierarchy = &mut ctx.accounts.parent_hierarchy;
+    parent_hierarchy.children = parent_hierarchy
+        .children
+        .checked_add(1)
+        .ok_or(MetadataErrors::ArithmeticOverflow)?;
+
+    emit_cpi!(GroupParentSet { group, parent });
+
+    Ok(())
+}
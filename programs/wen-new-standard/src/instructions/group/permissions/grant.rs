@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    GroupPermission, GroupPermissionGranted, MetadataErrors, TokenGroup, GROUP_PERMISSION_SEED,
+};
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct GroupPermissionArgs {
+    /// Bitmask of the `GroupPermission` operation flags
+    pub permissions: u8,
+    /// Unix timestamp the grant expires at, never expires when `None`
+    pub expires_at: Option<i64>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(delegate: Pubkey, args: GroupPermissionArgs)]
+pub struct GrantGroupPermission<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub authority: Signer<'info>,
+    #[account(
+        constraint = group.update_authority == authority.key(),
+    )]
+    pub group: Account<'info, TokenGroup>,
+    #[account(
+        init_if_needed,
+        seeds = [GROUP_PERMISSION_SEED, group.key().as_ref(), delegate.as_ref()],
+        bump,
+        payer = payer,
+        space = GroupPermission::LEN
+    )]
+    pub group_permission: Account<'info, GroupPermission>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Lets `delegate` perform the operations of `args.permissions` on the group until
+/// `args.expires_at`, replacing any previous grant of the delegate
+pub fn handler(
+    ctx: Context<GrantGroupPermission>,
+    delegate: Pubkey,
+    args: GroupPermissionArgs,
+) -> Result<()> {
+    if args.permissions == 0 || args.permissions & !GroupPermission::ALL != 0 {
+        return Err(MetadataErrors::InvalidGroupPermissions.into());
+    }
+    let now = Clock::get()?.unix_timestamp;
+    if args.expires_at.is_some_and(|expires_at| expires_at <= now) {
+        return Err(MetadataErrors::GroupPermissionExpired.into());
+    }
+
+    let group_permission = &mut ctx.accounts.group_permission;
+    group_permission.group = ctx.accounts.group.key();
+    group_permission.delegate = delegate;
+    group_permission.permissions = args.permissions;
+    group_permission.expires_at = args.expires_at;
+
+    emit_cpi!(GroupPermissionGranted {
+        group: ctx.accounts.group.key(),
+        delegate,
+        permissions: args.permissions,
+        expires_at: args.expires_at,
+    });
+
+    Ok(())
+}
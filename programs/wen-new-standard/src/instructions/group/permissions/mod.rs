@@ -0,0 +1,5 @@
+pub mod grant;
+pub mod revoke;
+
+pub use grant::*;
+pub use revoke::*;
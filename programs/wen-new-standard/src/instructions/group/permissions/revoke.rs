@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::{GroupPermission, GroupPermissionRevoked, TokenGroup, GROUP_PERMISSION_SEED};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(delegate: Pubkey)]
+pub struct RevokeGroupPermission<'info> {
+    /// refunded the rent of the grant
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        constraint = group.update_authority == authority.key(),
+    )]
+    pub group: Account<'info, TokenGroup>,
+    #[account(
+        mut,
+        seeds = [GROUP_PERMISSION_SEED, group.key().as_ref(), delegate.as_ref()],
+        bump,
+        close = authority,
+    )]
+    pub group_permission: Account<'info, GroupPermission>,
+}
+
+/// Revokes every permission granted to `delegate`, expired or not
+pub fn handler(ctx: Context<RevokeGroupPermission>, delegate: Pubkey) -> Result<()> {
+    emit_cpi!(GroupPermissionRevoked {
+        group: ctx.accounts.group.key(),
+        delegate,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::spl_token_2022::extension::ExtensionType;
+
+use crate::{
+    validate_group_permission, GroupExtensionPolicy, GroupPermission, MetadataErrors, TokenGroup,
+    GROUP_PERMISSION_SEED, MAX_POLICY_EXTENSIONS, POLICY_SEED,
+};
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct GroupExtensionPolicyArgs {
+    /// Extension types member mints may have, any extension is allowed when empty
+    pub allowed_extensions: Vec<u16>,
+    /// Extension types member mints must have
+    pub required_extensions: Vec<u16>,
+    pub min_royalty_basis_points: u16,
+    pub max_royalty_basis_points: u16,
+    /// Maximum size of the additional metadata of members, uncapped when 0
+    pub max_additional_metadata_bytes: u32,
+}
+
+impl GroupExtensionPolicyArgs {
+    fn validate(&self) -> Result<()> {
+        if self.allowed_extensions.len() > MAX_POLICY_EXTENSIONS
+            || self.required_extensions.len() > MAX_POLICY_EXTENSIONS
+        {
+            return Err(MetadataErrors::InvalidExtensionPolicy.into());
+        }
+        for extension in self
+            .allowed_extensions
+            .iter()
+            .chain(self.required_extensions.iter())
+        {
+            if ExtensionType::try_from(*extension).is_err() {
+                return Err(MetadataErrors::InvalidExtensionPolicy.into());
+            }
+        }
+        if self.min_royalty_basis_points > self.max_royalty_basis_points
+            || self.max_royalty_basis_points > 10000
+        {
+            return Err(MetadataErrors::InvalidExtensionPolicy.into());
+        }
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+#[instruction(args: GroupExtensionPolicyArgs)]
+pub struct SetGroupExtensionPolicy<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// group authority or a delegate granted `SET_POLICY`
+    pub authority: Signer<'info>,
+    pub group: Account<'info, TokenGroup>,
+    /// CHECK: permission PDA of the authority in the group, uninitialized without a grant
+    #[account(
+        seeds = [GROUP_PERMISSION_SEED, group.key().as_ref(), authority.key().as_ref()],
+        bump,
+    )]
+    pub group_permission: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        seeds = [POLICY_SEED, group.key().as_ref()],
+        bump,
+        payer = payer,
+        space = GroupExtensionPolicy::LEN
+    )]
+    pub policy: Account<'info, GroupExtensionPolicy>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Sets the policy validated when mints join the group
+pub fn handler(
+    ctx: Context<SetGroupExtensionPolicy>,
+    args: GroupExtensionPolicyArgs,
+) -> Result<()> {
+    validate_group_permission(
+        &ctx.accounts.group,
+        &ctx.accounts.group_permission.to_account_info(),
+        ctx.accounts.authority.key,
+        GroupPermission::SET_POLICY,
+    )?;
+    args.validate()?;
+
+    let policy = &mut ctx.accounts.policy;
+    policy.group = ctx.accounts.group.key();
+    policy.allowed_extensions = args.allowed_extensions;
+    policy.required_extensions = args.required_extensions;
+    policy.min_royalty_basis_points = args.min_royalty_basis_points;
+    policy.max_royalty_basis_points = args.max_royalty_basis_points;
+    policy.max_additional_metadata_bytes = args.max_additional_metadata_bytes;
+
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    validate_group_permission, AddRoyaltiesArgs, CreatorWithShare, DefaultCreator, GroupPermission,
+    GroupRoyaltyDefaults, TokenGroup, GROUP_PERMISSION_SEED, GROUP_ROYALTY_DEFAULTS_SEED,
+};
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct GroupRoyaltyDefaultsArgs {
+    pub royalty_basis_points: u16,
+    pub creators: Vec<DefaultCreator>,
+    /// Restrict `modify_royalties` on members to the group authority
+    pub locked: bool,
+}
+
+#[derive(Accounts)]
+#[instruction(args: GroupRoyaltyDefaultsArgs)]
+pub struct SetGroupRoyaltyDefaults<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// group authority or a delegate granted `UPDATE_ROYALTY_DEFAULTS`
+    pub authority: Signer<'info>,
+    pub group: Account<'info, TokenGroup>,
+    /// CHECK: permission PDA of the authority in the group, uninitialized without a grant
+    #[account(
+        seeds = [GROUP_PERMISSION_SEED, group.key().as_ref(), authority.key().as_ref()],
+        bump,
+    )]
+    pub group_permission: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        seeds = [GROUP_ROYALTY_DEFAULTS_SEED, group.key().as_ref()],
+        bump,
+        payer = payer,
+        space = GroupRoyaltyDefaults::LEN
+    )]
+    pub royalty_defaults: Account<'info, GroupRoyaltyDefaults>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Sets the royalties inherited by member mints created without royalties
+pub fn handler(
+    ctx: Context<SetGroupRoyaltyDefaults>,
+    args: GroupRoyaltyDefaultsArgs,
+) -> Result<()> {
+    validate_group_permission(
+        &ctx.accounts.group,
+        &ctx.accounts.group_permission.to_account_info(),
+        ctx.accounts.authority.key,
+        GroupPermission::UPDATE_ROYALTY_DEFAULTS,
+    )?;
+    // same validation as the royalties written to mints
+    AddRoyaltiesArgs {
+        royalty_basis_points: args.royalty_basis_points,
+        creators: args
+            .creators
+            .iter()
+            .map(|creator| CreatorWithShare {
+                address: creator.address.to_string(),
+                share: creator.share,
+            })
+            .collect(),
+    }
+    .validate()?;
+
+    let royalty_defaults = &mut ctx.accounts.royalty_defaults;
+    royalty_defaults.group = ctx.accounts.group.key();
+    royalty_defaults.royalty_basis_points = args.royalty_basis_points;
+    royalty_defaults.creators = args.creators;
+    royalty_defaults.locked = args.locked;
+
+    Ok(())
+}
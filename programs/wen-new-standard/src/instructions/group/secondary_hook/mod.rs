@@ -0,0 +1,5 @@
+pub mod register;
+pub mod unregister;
+
+pub use register::*;
+pub use unregister::*;
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    MetadataErrors, SecondaryHook, SecondaryHookAccount, SecondaryHookRegistered, TokenGroup,
+    MAX_SECONDARY_HOOK_ACCOUNTS, SECONDARY_HOOK_SEED,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(program_id: Pubkey, accounts: Vec<SecondaryHookAccount>)]
+pub struct RegisterSecondaryHook<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub authority: Signer<'info>,
+    #[account(
+        constraint = group.update_authority == authority.key(),
+    )]
+    pub group: Account<'info, TokenGroup>,
+    #[account(
+        init_if_needed,
+        seeds = [SECONDARY_HOOK_SEED, group.key().as_ref()],
+        bump,
+        payer = payer,
+        space = SecondaryHook::LEN
+    )]
+    pub secondary_hook: Account<'info, SecondaryHook>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Registers the transfer hook program invoked after royalties are enforced on transfers of
+/// group members. Members resolve it once their extra metas are rebuilt with
+/// `update_extra_metas_list`.
+pub fn handler(
+    ctx: Context<RegisterSecondaryHook>,
+    program_id: Pubkey,
+    accounts: Vec<SecondaryHookAccount>,
+) -> Result<()> {
+    if program_id == crate::id() {
+        return Err(MetadataErrors::InvalidSecondaryHookProgram.into());
+    }
+    if accounts.len() > MAX_SECONDARY_HOOK_ACCOUNTS {
+        return Err(MetadataErrors::TooManySecondaryHookAccounts.into());
+    }
+
+    let secondary_hook = &mut ctx.accounts.secondary_hook;
+    secondary_hook.group = ctx.accounts.group.key();
+    secondary_hook.program_id = program_id;
+    secondary_hook.accounts = accounts;
+
+    emit_cpi!(SecondaryHookRegistered {
+        group: ctx.accounts.group.key(),
+        program_id,
+    });
+
+    Ok(())
+}
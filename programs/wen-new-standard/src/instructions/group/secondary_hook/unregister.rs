@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::{SecondaryHook, SecondaryHookUnregistered, TokenGroup, SECONDARY_HOOK_SEED};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction()]
+pub struct UnregisterSecondaryHook<'info> {
+    /// refunded the rent of the secondary hook
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        constraint = group.update_authority == authority.key(),
+    )]
+    pub group: Account<'info, TokenGroup>,
+    #[account(
+        mut,
+        seeds = [SECONDARY_HOOK_SEED, group.key().as_ref()],
+        bump,
+        close = authority,
+    )]
+    pub secondary_hook: Account<'info, SecondaryHook>,
+}
+
+/// Unregisters the secondary hook of the group, the transfer hook stops invoking it right away
+pub fn handler(ctx: Context<UnregisterSecondaryHook>) -> Result<()> {
+    emit_cpi!(SecondaryHookUnregistered {
+        group: ctx.accounts.group.key(),
+    });
+
+    Ok(())
+}
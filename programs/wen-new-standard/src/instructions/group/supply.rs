@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::{GroupFinalization, TokenGroup, GROUP_FINALIZATION_SEED};
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct GroupSupply {
+    pub size: u32,
+    /// 0 for unlimited
+    pub max_size: u32,
+    /// No member can be added to a finalized group
+    pub finalized: bool,
+}
+
+#[derive(Accounts)]
+#[instruction()]
+pub struct GetGroupSupply<'info> {
+    pub group: Account<'info, TokenGroup>,
+    /// CHECK: finalization PDA of the group, uninitialized until the group is finalized
+    #[account(
+        seeds = [GROUP_FINALIZATION_SEED, group.key().as_ref()],
+        bump,
+    )]
+    pub finalization: UncheckedAccount<'info>,
+}
+
+/// Returns the supply of the group through return data, e.g. for programs enforcing supply caps
+pub fn handler(ctx: Context<GetGroupSupply>) -> Result<GroupSupply> {
+    let finalized =
+        GroupFinalization::load(&ctx.accounts.finalization.to_account_info())?.is_some();
+    Ok(GroupSupply {
+        size: ctx.accounts.group.size,
+        max_size: ctx.accounts.group.max_size,
+        finalized,
+    })
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    PendingRoyaltyUpdate, RoyaltyUpdateCancelled, TokenGroup, TokenGroupMember,
+    MEMBER_ACCOUNT_SEED, PENDING_ROYALTY_UPDATE_SEED,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction()]
+pub struct CancelRoyaltyUpdate<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        constraint = group.update_authority == authority.key(),
+    )]
+    pub group: Account<'info, TokenGroup>,
+    #[account(
+        seeds = [MEMBER_ACCOUNT_SEED, member.mint.as_ref()],
+        bump,
+        constraint = member.group == group.key(),
+    )]
+    pub member: Account<'info, TokenGroupMember>,
+    #[account(
+        mut,
+        seeds = [PENDING_ROYALTY_UPDATE_SEED, member.mint.as_ref()],
+        bump,
+        close = payer,
+    )]
+    pub pending_royalty_update: Account<'info, PendingRoyaltyUpdate>,
+    /// CHECK: refunded the rent of the pending royalty update
+    #[account(
+        mut,
+        address = pending_royalty_update.payer,
+    )]
+    pub payer: UncheckedAccount<'info>,
+}
+
+/// Cancels a pending royalty update of a group member before it's applied
+pub fn handler(ctx: Context<CancelRoyaltyUpdate>) -> Result<()> {
+    emit_cpi!(RoyaltyUpdateCancelled {
+        mint: ctx.accounts.member.mint,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,5 @@
+pub mod cancel;
+pub mod set;
+
+pub use cancel::*;
+pub use set::*;
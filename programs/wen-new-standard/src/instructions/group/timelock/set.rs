@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::{MetadataErrors, RoyaltyTimelock, TokenGroup, ROYALTY_TIMELOCK_SEED};
+
+#[derive(Accounts)]
+#[instruction(delay: i64)]
+pub struct SetRoyaltyTimelock<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub authority: Signer<'info>,
+    #[account(
+        constraint = group.update_authority == authority.key(),
+    )]
+    pub group: Account<'info, TokenGroup>,
+    #[account(
+        init_if_needed,
+        seeds = [ROYALTY_TIMELOCK_SEED, group.key().as_ref()],
+        bump,
+        payer = payer,
+        space = RoyaltyTimelock::LEN
+    )]
+    pub royalty_timelock: Account<'info, RoyaltyTimelock>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Sets the delay in seconds before royalty updates of group members take effect, 0 opts out
+pub fn handler(ctx: Context<SetRoyaltyTimelock>, delay: i64) -> Result<()> {
+    if delay < 0 {
+        return Err(MetadataErrors::InvalidRoyaltyTimelockDelay.into());
+    }
+    let royalty_timelock = &mut ctx.accounts.royalty_timelock;
+    royalty_timelock.group = ctx.accounts.group.key();
+    royalty_timelock.delay = delay;
+
+    Ok(())
+}
@@ -4,7 +4,10 @@ use anchor_spl::token_interface::{
     TokenMetadataUpdateField, TokenMetadataUpdateFieldArgs,
 };
 
-use crate::{MetadataErrors, TokenGroup, GROUP_ACCOUNT_SEED};
+use crate::{
+    validate_metadata, GroupFinalization, GroupUpdated, MetadataErrors, TokenGroup,
+    GROUP_ACCOUNT_SEED, GROUP_FINALIZATION_SEED,
+};
 
 #[derive(AnchorDeserialize, AnchorSerialize)]
 pub struct UpdateGroupAccountArgs {
@@ -14,20 +17,27 @@ pub struct UpdateGroupAccountArgs {
     pub max_size: u32,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(args: UpdateGroupAccountArgs)]
 pub struct UpdateGroupAccount<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
-    #[account()]
-    /// CHECK: can be any account
-    pub authority: UncheckedAccount<'info>,
+    /// group update authority and metadata update authority of the group mint
+    pub authority: Signer<'info>,
     #[account(
         mut,
         seeds = [GROUP_ACCOUNT_SEED, mint.key().as_ref()],
         bump,
+        constraint = group.update_authority == authority.key(),
     )]
     pub group: Account<'info, TokenGroup>,
+    /// CHECK: finalization PDA of the group, uninitialized until the group is finalized
+    #[account(
+        seeds = [GROUP_FINALIZATION_SEED, group.key().as_ref()],
+        bump,
+    )]
+    pub finalization: UncheckedAccount<'info>,
     #[account(
         mint::token_program = token_program,
     )]
@@ -50,12 +60,23 @@ impl<'info> UpdateGroupAccount<'info> {
 }
 
 pub fn handler(ctx: Context<UpdateGroupAccount>, args: UpdateGroupAccountArgs) -> Result<()> {
-    if args.max_size < ctx.accounts.group.size {
-        return Err(MetadataErrors::MaxSizeBelowCurrentSize.into());
+    validate_metadata(&args.name, &args.symbol, &args.uri)?;
+
+    let old_max_size = ctx.accounts.group.max_size;
+    let size = ctx.accounts.group.size;
+
+    // finalized groups can only shrink their max size, 0 being unlimited
+    let grows = match (old_max_size, args.max_size) {
+        (0, _) => false,
+        (_, 0) => true,
+        (old_max_size, max_size) => max_size > old_max_size,
+    };
+    if grows && GroupFinalization::load(&ctx.accounts.finalization.to_account_info())?.is_some() {
+        return Err(MetadataErrors::GroupFinalized.into());
     }
 
-    // update group max size
-    ctx.accounts.group.max_size = args.max_size;
+    // a max size equal to the current size closes minting into the group, 0 is unlimited
+    ctx.accounts.group.update_max_size(args.max_size)?;
 
     // update metadata name
     ctx.accounts.update_metadata(TokenMetadataUpdateFieldArgs {
@@ -75,5 +96,12 @@ pub fn handler(ctx: Context<UpdateGroupAccount>, args: UpdateGroupAccountArgs) -
         value: args.uri,
     })?;
 
+    emit_cpi!(GroupUpdated {
+        group: ctx.accounts.group.key(),
+        old_max_size,
+        new_max_size: args.max_size,
+        size,
+    });
+
     Ok(())
 }
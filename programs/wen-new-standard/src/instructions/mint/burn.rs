@@ -0,0 +1,168 @@
+use anchor_lang::prelude::*;
+
+use anchor_spl::token_interface::{
+    burn_checked, close_account, BurnChecked, CloseAccount, Mint, Token2022, TokenAccount,
+};
+
+use crate::{
+    init_member_counter, unindex_member, GroupSizeChanged, MemberRemoved, MetadataErrors,
+    MintRecord, TokenGroup, TokenGroupMember, MEMBER_ACCOUNT_SEED, MINT_RECORD_SEED,
+};
+
+/// Burns the full balance of a holder token account and closes it, refunding its rent.
+/// Once the supply is burned, a group member leaves its group: the group size shrinks and the
+/// member account is closed, refunding the holder. The mint record is closed to its payer.
+///
+/// Token-2022 burns never invoke the transfer hook, so no approval or royalty is needed.
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction()]
+pub struct BurnMintAccount<'info> {
+    /// holder of the token account, refunded its rent
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(
+        mut,
+        mint::token_program = token_program,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = user,
+        token::token_program = token_program,
+    )]
+    pub mint_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// group of the member, only needed for group members
+    #[account(mut)]
+    pub group: Option<Account<'info, TokenGroup>>,
+    #[account(
+        mut,
+        seeds = [MEMBER_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub member: Option<Account<'info, TokenGroupMember>>,
+    /// CHECK: member counter PDA of the group, created before the size shrinks, only needed
+    /// for group members
+    #[account(mut)]
+    pub member_counter: Option<UncheckedAccount<'info>>,
+    /// CHECK: member index page PDA of the member number, only needed for group members
+    #[account(mut)]
+    pub member_index: Option<UncheckedAccount<'info>>,
+    #[account(
+        mut,
+        seeds = [MINT_RECORD_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub mint_record: Option<Account<'info, MintRecord>>,
+    /// CHECK: refunded the record rent, checked against the record, only needed with a record
+    #[account(mut)]
+    pub mint_record_payer: Option<UncheckedAccount<'info>>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+impl BurnMintAccount<'_> {
+    fn burn(&self, amount: u64) -> Result<()> {
+        let cpi_accounts = BurnChecked {
+            mint: self.mint.to_account_info(),
+            from: self.mint_token_account.to_account_info(),
+            authority: self.user.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
+        burn_checked(cpi_ctx, amount, self.mint.decimals)?;
+        Ok(())
+    }
+
+    fn close_token_account(&self) -> Result<()> {
+        let cpi_accounts = CloseAccount {
+            account: self.mint_token_account.to_account_info(),
+            destination: self.user.to_account_info(),
+            authority: self.user.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
+        close_account(cpi_ctx)?;
+        Ok(())
+    }
+
+    /// Removes the burned mint from its group, member numbers keep counting from the current
+    /// size and are never reused. Returns the member number, `None` outside any group.
+    fn leave_group(&mut self) -> Result<Option<u32>> {
+        let Some(member) = self.member.as_ref() else {
+            return Ok(None);
+        };
+        let group = self
+            .group
+            .as_mut()
+            .filter(|group| group.key() == member.group)
+            .ok_or(MetadataErrors::InvalidGroupMemberAccount)?;
+        let member_counter = self
+            .member_counter
+            .as_ref()
+            .ok_or(MetadataErrors::InvalidMemberCounterAccount)?;
+        let member_index = self
+            .member_index
+            .as_ref()
+            .ok_or(MetadataErrors::InvalidMemberIndexAccount)?;
+
+        init_member_counter(
+            member_counter.to_account_info(),
+            self.user.to_account_info(),
+            self.system_program.to_account_info(),
+            group.key(),
+            group.size,
+        )?;
+        group.decrement_size()?;
+        unindex_member(
+            &member_index.to_account_info(),
+            group.key(),
+            member.member_number,
+            self.mint.key(),
+        )?;
+        member.close(self.user.to_account_info())?;
+        Ok(Some(member.member_number))
+    }
+}
+
+pub fn handler(ctx: Context<BurnMintAccount>) -> Result<()> {
+    let amount = ctx.accounts.mint_token_account.amount;
+    if amount > 0 {
+        ctx.accounts.burn(amount)?;
+    }
+    ctx.accounts.close_token_account()?;
+
+    // the member account stays as long as some supply is left
+    ctx.accounts.mint.reload()?;
+    if ctx.accounts.mint.supply != 0 {
+        return Ok(());
+    }
+    if let Some(member_number) = ctx.accounts.leave_group()? {
+        let group = ctx
+            .accounts
+            .group
+            .as_ref()
+            .ok_or(MetadataErrors::InvalidGroupMemberAccount)?;
+        emit_cpi!(MemberRemoved {
+            group: group.key(),
+            mint: ctx.accounts.mint.key(),
+            member_number,
+        });
+        emit_cpi!(GroupSizeChanged {
+            group: group.key(),
+            size: group.size,
+            max_size: group.max_size,
+        });
+    }
+
+    if let Some(mint_record) = ctx.accounts.mint_record.as_ref() {
+        let payer = ctx
+            .accounts
+            .mint_record_payer
+            .as_ref()
+            .filter(|payer| payer.key() == mint_record.payer)
+            .ok_or(MetadataErrors::InvalidMintRecordAccount)?;
+        mint_record.close(payer.to_account_info())?;
+    }
+
+    Ok(())
+}
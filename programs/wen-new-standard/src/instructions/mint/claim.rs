@@ -0,0 +1,212 @@
+use anchor_lang::prelude::*;
+
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{
+        freeze_account, mint_to, set_authority,
+        spl_token_2022::{instruction::AuthorityType, state::AccountState},
+        thaw_account, FreezeAccount, Mint, MintTo, SetAuthority, ThawAccount, Token2022,
+        TokenAccount,
+    },
+};
+
+use crate::{
+    get_external_metadata_account, get_metadata, validate_group_member_authority, Manager,
+    MetadataErrors, TokenGroup, TokenGroupMember, GROUP_DELEGATES_SEED, GROUP_PERMISSION_SEED,
+    MANAGER_SEED, MEMBER_ACCOUNT_SEED,
+};
+
+#[derive(Accounts)]
+#[instruction()]
+pub struct MintToClaimer<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// metadata update authority of the mint, or for group members the group authority, one of
+    /// its delegates or a delegate granted `ADD_MEMBER`
+    pub authority: Signer<'info>,
+    #[account()]
+    /// CHECK: can be any account
+    pub claimer: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        mint::token_program = token_program,
+        constraint = mint.supply == 0 @ MetadataErrors::MintAlreadyClaimed,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    /// CHECK: account referenced by the metadata pointer, only needed for external metadata
+    pub metadata_account: Option<UncheckedAccount<'info>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::token_program = token_program,
+        associated_token::mint = mint,
+        associated_token::authority = claimer,
+    )]
+    pub claimer_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: authority the manager is namespaced to, omitted for the global manager
+    pub manager_authority: Option<UncheckedAccount<'info>>,
+    #[account(
+        seeds = [
+            MANAGER_SEED,
+            Manager::namespace(manager_authority.as_ref().map(|authority| authority.key)),
+        ],
+        bump
+    )]
+    pub manager: Account<'info, Manager>,
+    #[account(
+        seeds = [MEMBER_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub member: Option<Account<'info, TokenGroupMember>>,
+    /// group of the member, only needed when the authority claims on behalf of the group
+    pub group: Option<Account<'info, TokenGroup>>,
+    /// CHECK: delegates PDA of the group, checked in the handler, only needed with the group
+    pub group_delegates: Option<UncheckedAccount<'info>>,
+    /// CHECK: permission PDA of the authority in the group, checked in the handler, only
+    /// needed with the group
+    pub group_permission: Option<UncheckedAccount<'info>>,
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+impl MintToClaimer<'_> {
+    /// Group members can also be delivered by the authorities adding members to their group
+    fn validate_group_authority(&self) -> Result<()> {
+        let member = self
+            .member
+            .as_ref()
+            .ok_or(MetadataErrors::InvalidMetadataAuthority)?;
+        let group = self
+            .group
+            .as_ref()
+            .filter(|group| group.key() == member.group)
+            .ok_or(MetadataErrors::InvalidGroupMemberAccount)?;
+        let group_key = group.key();
+        let expected_group_delegates =
+            Pubkey::find_program_address(&[GROUP_DELEGATES_SEED, group_key.as_ref()], &crate::id())
+                .0;
+        let group_delegates = self
+            .group_delegates
+            .as_ref()
+            .filter(|group_delegates| group_delegates.key() == expected_group_delegates)
+            .ok_or(MetadataErrors::InvalidGroupDelegatesAccount)?;
+        let expected_group_permission = Pubkey::find_program_address(
+            &[
+                GROUP_PERMISSION_SEED,
+                group_key.as_ref(),
+                self.authority.key().as_ref(),
+            ],
+            &crate::id(),
+        )
+        .0;
+        let group_permission = self
+            .group_permission
+            .as_ref()
+            .filter(|group_permission| group_permission.key() == expected_group_permission)
+            .ok_or(MetadataErrors::InvalidGroupPermissionAccount)?;
+        validate_group_member_authority(
+            group,
+            &group_delegates.to_account_info(),
+            &group_permission.to_account_info(),
+            self.authority.key,
+        )
+    }
+
+    fn mint_to_claimer(&self, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        let cpi_accounts = MintTo {
+            mint: self.mint.to_account_info(),
+            to: self.claimer_token_account.to_account_info(),
+            authority: self.manager.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        mint_to(cpi_ctx, 1)?;
+        Ok(())
+    }
+
+    fn thaw_claimer_token_account(&self, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        let cpi_accounts = ThawAccount {
+            account: self.claimer_token_account.to_account_info(),
+            mint: self.mint.to_account_info(),
+            authority: self.manager.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        thaw_account(cpi_ctx)?;
+        Ok(())
+    }
+
+    fn freeze_claimer_token_account(&self, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        let cpi_accounts = FreezeAccount {
+            account: self.claimer_token_account.to_account_info(),
+            mint: self.mint.to_account_info(),
+            authority: self.manager.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        freeze_account(cpi_ctx)?;
+        Ok(())
+    }
+
+    fn revoke_mint_authority(&self, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        let cpi_accounts = SetAuthority {
+            current_authority: self.manager.to_account_info(),
+            account_or_mint: self.mint.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        set_authority(cpi_ctx, AuthorityType::MintTokens, None)?;
+        Ok(())
+    }
+}
+
+pub fn handler(ctx: Context<MintToClaimer>) -> Result<()> {
+    // only the metadata update authority, or the group of a member, can deliver the mint
+    let mint = ctx.accounts.mint.to_account_info();
+    let metadata_account = ctx
+        .accounts
+        .metadata_account
+        .as_ref()
+        .map(|metadata_account| metadata_account.to_account_info());
+    let external_metadata_account =
+        get_external_metadata_account(&mint, metadata_account.as_ref())?;
+    let metadata = get_metadata(&mint, external_metadata_account)?;
+    if Option::<Pubkey>::from(metadata.update_authority) != Some(ctx.accounts.authority.key()) {
+        ctx.accounts.validate_group_authority()?;
+    }
+
+    let manager_namespace = Manager::namespace(
+        ctx.accounts
+            .manager_authority
+            .as_ref()
+            .map(|authority| authority.key),
+    );
+    let signer_seeds: &[&[&[u8]]] = &[&[MANAGER_SEED, manager_namespace, &[ctx.bumps.manager]]];
+
+    // token accounts of frozen by default mints are thawed for the mint and frozen back
+    if ctx.accounts.claimer_token_account.state == AccountState::Frozen {
+        ctx.accounts.thaw_claimer_token_account(signer_seeds)?;
+        ctx.accounts.mint_to_claimer(signer_seeds)?;
+        ctx.accounts.freeze_claimer_token_account(signer_seeds)?;
+    } else {
+        ctx.accounts.mint_to_claimer(signer_seeds)?;
+    }
+
+    // no more tokens can be minted once claimed
+    ctx.accounts.revoke_mint_authority(signer_seeds)?;
+
+    Ok(())
+}
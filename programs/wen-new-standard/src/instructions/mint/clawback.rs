@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+
+use anchor_spl::token_interface::{
+    spl_token_2022::{extension::permanent_delegate::PermanentDelegate, onchain},
+    Mint, Token2022, TokenAccount,
+};
+
+use crate::{
+    get_extension_data, Manager, MetadataErrors, TokenGroup, TokenGroupMember, MANAGER_SEED,
+    MEMBER_ACCOUNT_SEED,
+};
+
+/// Moves the full balance of `source_token_account` to `destination_token_account`
+/// using the `Manager` PDA as permanent delegate.
+///
+/// The transfer hook accounts (extra metas account, WNS program and any extra metas
+/// resolved accounts) must be passed as remaining accounts.
+#[derive(Accounts)]
+#[instruction()]
+pub struct Clawback<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        constraint = group.update_authority == authority.key(),
+    )]
+    pub group: Account<'info, TokenGroup>,
+    #[account(
+        seeds = [MEMBER_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+        constraint = member.group == group.key(),
+    )]
+    pub member: Account<'info, TokenGroupMember>,
+    #[account(
+        mint::token_program = token_program,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub source_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub destination_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: authority the manager of the mint is namespaced to, omitted for the global manager
+    pub manager_authority: Option<UncheckedAccount<'info>>,
+    #[account(
+        seeds = [
+            MANAGER_SEED,
+            Manager::namespace(manager_authority.as_ref().map(|authority| authority.key)),
+        ],
+        bump
+    )]
+    pub manager: Account<'info, Manager>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, Clawback<'info>>) -> Result<()> {
+    // only mints with the manager as permanent delegate can be clawed back
+    let permanent_delegate =
+        get_extension_data::<PermanentDelegate>(&mut ctx.accounts.mint.to_account_info())
+            .map_err(|_| MetadataErrors::InvalidPermanentDelegate)?;
+    if Option::<Pubkey>::from(permanent_delegate.delegate) != Some(ctx.accounts.manager.key()) {
+        return Err(MetadataErrors::InvalidPermanentDelegate.into());
+    }
+
+    let manager_namespace = Manager::namespace(
+        ctx.accounts
+            .manager_authority
+            .as_ref()
+            .map(|authority| authority.key),
+    );
+    let signer_seeds: &[&[&[u8]]] = &[&[MANAGER_SEED, manager_namespace, &[ctx.bumps.manager]]];
+    onchain::invoke_transfer_checked(
+        ctx.accounts.token_program.key,
+        ctx.accounts.source_token_account.to_account_info(),
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.destination_token_account.to_account_info(),
+        ctx.accounts.manager.to_account_info(),
+        ctx.remaining_accounts,
+        ctx.accounts.source_token_account.amount,
+        ctx.accounts.mint.decimals,
+        signer_seeds,
+    )?;
+
+    Ok(())
+}
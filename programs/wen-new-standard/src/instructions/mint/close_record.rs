@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use anchor_spl::token_interface::{Mint, Token2022};
+
+use crate::{MetadataErrors, MintRecord, MINT_RECORD_SEED};
+
+/// Closes the record of a mint whose supply was fully burned, refunding its payer.
+/// A mint without supply could still be minted, e.g. an unclaimed lazy mint, until its mint
+/// authority is revoked, so only the payer can close its record before then.
+#[derive(Accounts)]
+#[instruction()]
+pub struct CloseMintRecord<'info> {
+    #[account(mut)]
+    /// CHECK: refunded the record rent, checked against the record, signs while the mint
+    /// authority is set
+    pub payer: UncheckedAccount<'info>,
+    #[account(
+        mint::token_program = token_program,
+        constraint = mint.supply == 0 @ MetadataErrors::MintNotBurned,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        seeds = [MINT_RECORD_SEED, mint.key().as_ref()],
+        bump = mint_record.bump,
+        has_one = payer,
+        close = payer,
+    )]
+    pub mint_record: Account<'info, MintRecord>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+pub fn handler(ctx: Context<CloseMintRecord>) -> Result<()> {
+    if ctx.accounts.mint.mint_authority.is_some() && !ctx.accounts.payer.is_signer {
+        return Err(MetadataErrors::MintNotBurned.into());
+    }
+
+    Ok(())
+}
@@ -1,29 +1,219 @@
 use anchor_lang::{prelude::*, solana_program::entrypoint::ProgramResult};
 
 use anchor_spl::{
-    associated_token::AssociatedToken,
+    associated_token::{self, get_associated_token_address_with_program_id, AssociatedToken},
     token_interface::{
-        mint_to, set_authority,
-        spl_token_2022::{extension::ExtensionType, instruction::AuthorityType},
-        token_metadata_initialize, Mint, MintTo, SetAuthority, Token2022, TokenAccount,
-        TokenMetadataInitialize, TokenMetadataInitializeArgs,
+        default_account_state_initialize, freeze_account, group_member_pointer_initialize,
+        initialize_mint2, metadata_pointer_initialize, mint_close_authority_initialize, mint_to,
+        non_transferable_mint_initialize, permanent_delegate_initialize, set_authority,
+        spl_token_2022::{
+            extension::ExtensionType,
+            instruction::AuthorityType,
+            state::{AccountState, Mint},
+        },
+        spl_token_metadata_interface::state::{Field, TokenMetadata},
+        thaw_account, token_metadata_initialize, token_metadata_update_authority,
+        token_metadata_update_field, transfer_hook_initialize, DefaultAccountStateInitialize,
+        FreezeAccount, GroupMemberPointerInitialize, InitializeMint2, MetadataPointerInitialize,
+        MintCloseAuthorityInitialize, MintTo, NonTransferableMintInitialize,
+        PermanentDelegateInitialize, SetAuthority, ThawAccount, Token2022, TokenMetadataInitialize,
+        TokenMetadataInitializeArgs, TokenMetadataUpdateAuthority, TokenMetadataUpdateField,
+        TokenMetadataUpdateFieldArgs, TransferHookInitialize,
     },
 };
+use spl_pod::optional_keys::OptionalNonZeroPubkey;
 use spl_tlv_account_resolution::state::ExtraAccountMetaList;
 use spl_transfer_hook_interface::instruction::ExecuteInstruction;
+use spl_type_length_value::state::TlvStateMut;
 
 use crate::{
-    get_meta_list, get_meta_list_size, update_account_lamports_to_minimum_balance, Manager,
-    MANAGER_SEED, META_LIST_ACCOUNT_SEED,
+    create_account_with_minimum_balance, encode_content_hash, get_meta_list, get_meta_list_size,
+    get_metadata_address, is_wallet_account, validate_additional_metadata_field, validate_metadata,
+    AddRoyaltiesArgs, Manager, MetadataErrors, MintCreated, MintRecord, CONTENT_HASH_FIELD,
+    MANAGER_SEED, METADATA_SEED, META_LIST_ACCOUNT_SEED, MINT_RECORD_SEED,
+    ROYALTY_BASIS_POINTS_FIELD,
 };
 
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, PartialEq, Eq)]
+pub enum PermanentDelegateConfig {
+    /// The `Manager` PDA is the permanent delegate, enabling `clawback` by the group authority
+    Manager,
+    /// Any other address
+    Address(Pubkey),
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, PartialEq, Eq)]
+pub enum FreezeAuthorityConfig {
+    /// Any other address, `thaw_mint_account` is unavailable
+    Address(Pubkey),
+    /// No freeze authority, token accounts can never be frozen
+    Disabled,
+}
+
 #[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct AddMetadataArgs {
+    pub field: String,
+    pub value: String,
+}
+
+/// Clients predating the creation options only send the name, symbol and uri, the options
+/// then take their `Default` values
+#[derive(AnchorSerialize, Default)]
 pub struct CreateMintAccountArgs {
     pub name: String,
     pub symbol: String,
     pub uri: String,
+    /// Initialize the mint with the NonTransferable extension. Soulbound mints
+    /// have no transfer hook since the token can never move.
+    pub soulbound: bool,
+    /// Initialize the mint with the PermanentDelegate extension
+    pub permanent_delegate: Option<PermanentDelegateConfig>,
+    /// Initialize the DefaultAccountState extension as frozen. Token accounts,
+    /// including the receiver's, stay frozen until thawed through `thaw_mint_account`
+    pub frozen: bool,
+    /// Mint freeze authority, defaults to the `Manager` PDA which frozen mints require
+    pub freeze_authority: Option<FreezeAuthorityConfig>,
+    /// Mint decimals, defaults to 0
+    pub decimals: Option<u8>,
+    /// Amount minted to the receiver, defaults to 1
+    pub initial_supply: Option<u64>,
+    /// Additional metadata fields written right after the metadata is initialized
+    pub additional_metadata: Vec<AddMetadataArgs>,
+    /// Royalties enforced from creation, equivalent to a following `add_royalties_to_mint`
+    pub royalties: Option<AddRoyaltiesArgs>,
+    /// Metadata and metadata pointer update authority, defaults to the mint authority
+    pub update_authority: Option<Pubkey>,
+    /// Remove the metadata update authority once all creation time fields are written
+    pub immutable: bool,
+    /// Allow receivers that aren't wallets, e.g. escrow or launchpad PDAs
+    pub allow_pda_receiver: bool,
+    /// Store the metadata in the `[METADATA_SEED, mint]` PDA instead of the mint,
+    /// the metadata pointer references that account
+    pub external_metadata: bool,
+    /// Hash of the off-chain JSON committed in the `CONTENT_HASH_FIELD` metadata field
+    pub content_hash: Option<[u8; 32]>,
+    /// Record the creator and creation slot in the `[MINT_RECORD_SEED, mint]` PDA
+    pub mint_record: bool,
+    /// Authority of the `Manager` created by `init_manager_for_authority` holding the mint
+    /// authorities, the global manager when `None`
+    pub manager_authority: Option<Pubkey>,
+    /// Set the WNS transfer hook program even without royalties or group, so transfers are
+    /// logged once `init_provenance` opts the mint in. Soulbound mints never transfer
+    pub provenance: bool,
+}
+
+impl AnchorDeserialize for CreateMintAccountArgs {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let name = String::deserialize_reader(reader)?;
+        let symbol = String::deserialize_reader(reader)?;
+        let uri = String::deserialize_reader(reader)?;
+        // nothing follows the uri in the args of older clients
+        let mut soulbound = [0u8; 1];
+        if reader.read(&mut soulbound)? == 0 {
+            return Ok(Self {
+                name,
+                symbol,
+                uri,
+                ..Self::default()
+            });
+        }
+        Ok(Self {
+            name,
+            symbol,
+            uri,
+            soulbound: bool::deserialize(&mut &soulbound[..])?,
+            permanent_delegate: AnchorDeserialize::deserialize_reader(reader)?,
+            frozen: AnchorDeserialize::deserialize_reader(reader)?,
+            freeze_authority: AnchorDeserialize::deserialize_reader(reader)?,
+            decimals: AnchorDeserialize::deserialize_reader(reader)?,
+            initial_supply: AnchorDeserialize::deserialize_reader(reader)?,
+            additional_metadata: AnchorDeserialize::deserialize_reader(reader)?,
+            royalties: AnchorDeserialize::deserialize_reader(reader)?,
+            update_authority: AnchorDeserialize::deserialize_reader(reader)?,
+            immutable: AnchorDeserialize::deserialize_reader(reader)?,
+            allow_pda_receiver: AnchorDeserialize::deserialize_reader(reader)?,
+            external_metadata: AnchorDeserialize::deserialize_reader(reader)?,
+            content_hash: AnchorDeserialize::deserialize_reader(reader)?,
+            mint_record: AnchorDeserialize::deserialize_reader(reader)?,
+            manager_authority: AnchorDeserialize::deserialize_reader(reader)?,
+            provenance: AnchorDeserialize::deserialize_reader(reader)?,
+        })
+    }
+}
+
+impl CreateMintAccountArgs {
+    /// Extensions initialized on the mint for these args
+    pub fn mint_extensions(&self) -> Vec<ExtensionType> {
+        let mut extensions = vec![
+            ExtensionType::MetadataPointer,
+            ExtensionType::GroupMemberPointer,
+            ExtensionType::MintCloseAuthority,
+        ];
+        if self.soulbound {
+            extensions.push(ExtensionType::NonTransferable);
+        } else {
+            extensions.push(ExtensionType::TransferHook);
+        }
+        if self.permanent_delegate.is_some() {
+            extensions.push(ExtensionType::PermanentDelegate);
+        }
+        if self.frozen {
+            extensions.push(ExtensionType::DefaultAccountState);
+        }
+        extensions
+    }
+
+    /// Freeze authority of the mint for these args
+    pub fn freeze_authority(&self, manager: Pubkey) -> Option<Pubkey> {
+        match self.freeze_authority {
+            None => Some(manager),
+            Some(FreezeAuthorityConfig::Address(address)) => Some(address),
+            Some(FreezeAuthorityConfig::Disabled) => None,
+        }
+    }
+
+    /// Size of the token metadata TLV entry written at creation, additional fields and royalties included
+    pub fn metadata_len(&self) -> usize {
+        // borsh strings and vectors are prefixed with a u32 length
+        let string_len = |value: &str| 4 + value.len();
+        let mut additional_metadata_len = 4;
+        for metadata in self.additional_metadata.iter() {
+            additional_metadata_len += string_len(&metadata.field) + string_len(&metadata.value);
+        }
+        if self.content_hash.is_some() {
+            // hex encoded hash
+            additional_metadata_len += string_len(CONTENT_HASH_FIELD) + 4 + 64;
+        }
+        if let Some(royalties) = &self.royalties {
+            additional_metadata_len += string_len(ROYALTY_BASIS_POINTS_FIELD)
+                + string_len(&royalties.royalty_basis_points.to_string());
+            for creator in royalties.creators.iter() {
+                additional_metadata_len +=
+                    string_len(&creator.address) + string_len(&creator.share.to_string());
+            }
+        }
+        // tlv type and length, update authority, mint
+        let header_len = 2 + 2 + 32 + 32;
+        header_len
+            + string_len(&self.name)
+            + string_len(&self.symbol)
+            + string_len(&self.uri)
+            + additional_metadata_len
+    }
 }
 
+/// Returned by `create_mint_account` for programs creating mints through CPI
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct CreatedMintAccount {
+    pub mint: Pubkey,
+    pub extra_metas_account: Pubkey,
+    pub manager: Pubkey,
+    /// metadata pointer address, the mint itself unless `external_metadata` stores the
+    /// metadata in the `[METADATA_SEED, mint]` PDA
+    pub metadata_address: Pubkey,
+}
+
+/// Extensions of a default (transferable) mint
 pub const MINT_EXTENSIONS: [ExtensionType; 4] = [
     ExtensionType::MetadataPointer,
     ExtensionType::GroupMemberPointer,
@@ -31,126 +221,749 @@ pub const MINT_EXTENSIONS: [ExtensionType; 4] = [
     ExtensionType::MintCloseAuthority,
 ];
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(args: CreateMintAccountArgs)]
 pub struct CreateMintAccount<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
-    #[account(mut)]
-    /// CHECK: can be any account
+    /// mint authority, can be a PDA signing through CPI
     pub authority: Signer<'info>,
     #[account()]
     /// CHECK: can be any account
     pub receiver: UncheckedAccount<'info>,
+    #[account(mut, signer)]
+    /// CHECK: initialized in the handler, extensions depend on args
+    pub mint: UncheckedAccount<'info>,
     #[account(
-        init,
-        signer,
-        payer = payer,
-        mint::token_program = token_program,
-        mint::decimals = 0,
-        mint::authority = authority,
-        mint::freeze_authority = manager,
-        mint::extensions = MINT_EXTENSIONS.to_vec(),
-        extensions::metadata_pointer::authority = authority.key(),
-        extensions::metadata_pointer::metadata_address = mint.key(),
-        extensions::group_member_pointer::authority = authority.key(),
-        extensions::transfer_hook::authority = authority.key(),
-        extensions::close_authority::authority = manager.key(),
-    )]
-    pub mint: Box<InterfaceAccount<'info, Mint>>,
-    #[account(
-        init,
-        payer = payer,
-        associated_token::token_program = token_program,
-        associated_token::mint = mint,
-        associated_token::authority = receiver,
+        mut,
+        address = get_associated_token_address_with_program_id(
+            &receiver.key(),
+            &mint.key(),
+            &token_program.key(),
+        ),
     )]
-    pub mint_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
-    /// CHECK: This account's data is a buffer of TLV data
+    /// CHECK: initialized in the handler as the receiver's associated token account
+    pub mint_token_account: UncheckedAccount<'info>,
+    /// CHECK: This account's data is a buffer of TLV data, initialized in the handler
     #[account(
-        init_if_needed,
-        space = get_meta_list_size(None),
+        mut,
         seeds = [META_LIST_ACCOUNT_SEED, mint.key().as_ref()],
         bump,
-        payer = payer,
     )]
     pub extra_metas_account: UncheckedAccount<'info>,
+    /// CHECK: metadata PDA of mints with external metadata, validated in the handler
+    #[account(mut)]
+    pub metadata_account: Option<UncheckedAccount<'info>>,
+    /// CHECK: mint record PDA, only needed when `args.mint_record` is set
+    #[account(mut)]
+    pub mint_record: Option<UncheckedAccount<'info>>,
+    /// global manager, or the manager namespaced to `args.manager_authority`
     #[account(
-        seeds = [MANAGER_SEED],
+        seeds = [MANAGER_SEED, Manager::namespace(args.manager_authority.as_ref())],
         bump
     )]
     pub manager: Account<'info, Manager>,
     pub system_program: Program<'info, System>,
-    pub rent: Sysvar<'info, Rent>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub token_program: Program<'info, Token2022>,
 }
 
 impl<'info> CreateMintAccount<'info> {
+    fn mint_accounts(&self) -> MintAccounts<'info> {
+        MintAccounts {
+            payer: self.payer.to_account_info(),
+            authority: self.authority.to_account_info(),
+            receiver: Some(self.receiver.to_account_info()),
+            mint: self.mint.to_account_info(),
+            mint_token_account: Some(self.mint_token_account.to_account_info()),
+            extra_metas_account: self.extra_metas_account.to_account_info(),
+            metadata_account: self
+                .metadata_account
+                .as_ref()
+                .map(|metadata_account| metadata_account.to_account_info()),
+            mint_record: self
+                .mint_record
+                .as_ref()
+                .map(|mint_record| mint_record.to_account_info()),
+            manager: self.manager.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+            associated_token_program: self.associated_token_program.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+        }
+    }
+}
+
+/// Accounts needed to create a WNS mint, shared by every instruction creating mints
+pub struct MintAccounts<'info> {
+    pub payer: AccountInfo<'info>,
+    pub authority: AccountInfo<'info>,
+    /// Receiver of the initial supply, `None` for mints delivered later through `mint_to_claimer`
+    pub receiver: Option<AccountInfo<'info>>,
+    pub mint: AccountInfo<'info>,
+    pub mint_token_account: Option<AccountInfo<'info>>,
+    pub extra_metas_account: AccountInfo<'info>,
+    /// Metadata PDA, only needed for mints with external metadata
+    pub metadata_account: Option<AccountInfo<'info>>,
+    /// Mint record PDA, only needed for recorded mints
+    pub mint_record: Option<AccountInfo<'info>>,
+    pub manager: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub associated_token_program: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+}
+
+impl<'info> MintAccounts<'info> {
+    fn initialize_mint(
+        &self,
+        args: &CreateMintAccountArgs,
+        member_address: Option<Pubkey>,
+        metadata_address: Pubkey,
+        mint_signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        let extensions = args.mint_extensions();
+        let space = ExtensionType::try_calculate_account_len::<Mint>(&extensions)?;
+        // fund the mint for its final size once, metadata is appended by the token program
+        let metadata_len = if args.external_metadata {
+            0
+        } else {
+            args.metadata_len()
+        };
+        create_account_with_minimum_balance(
+            self.mint.clone(),
+            self.payer.clone(),
+            self.system_program.clone(),
+            space,
+            space + metadata_len,
+            self.token_program.key,
+            mint_signer_seeds,
+        )?;
+
+        let authority = self.authority.key();
+        let manager = self.manager.key();
+
+        metadata_pointer_initialize(
+            CpiContext::new(
+                self.token_program.clone(),
+                MetadataPointerInitialize {
+                    token_program_id: self.token_program.clone(),
+                    mint: self.mint.clone(),
+                },
+            ),
+            (!args.immutable).then(|| args.update_authority.unwrap_or(authority)),
+            Some(metadata_address),
+        )?;
+        group_member_pointer_initialize(
+            CpiContext::new(
+                self.token_program.clone(),
+                GroupMemberPointerInitialize {
+                    token_program_id: self.token_program.clone(),
+                    mint: self.mint.clone(),
+                },
+            ),
+            Some(authority),
+            member_address,
+        )?;
+        mint_close_authority_initialize(
+            CpiContext::new(
+                self.token_program.clone(),
+                MintCloseAuthorityInitialize {
+                    token_program_id: self.token_program.clone(),
+                    mint: self.mint.clone(),
+                },
+            ),
+            Some(&manager),
+        )?;
+        if args.soulbound {
+            non_transferable_mint_initialize(CpiContext::new(
+                self.token_program.clone(),
+                NonTransferableMintInitialize {
+                    token_program_id: self.token_program.clone(),
+                    mint: self.mint.clone(),
+                },
+            ))?;
+        } else {
+            transfer_hook_initialize(
+                CpiContext::new(
+                    self.token_program.clone(),
+                    TransferHookInitialize {
+                        token_program_id: self.token_program.clone(),
+                        mint: self.mint.clone(),
+                    },
+                ),
+                Some(authority),
+                // group members resolve the enforcement mode of their group on every transfer
+                (args.royalties.is_some() || member_address.is_some() || args.provenance)
+                    .then(crate::id),
+            )?;
+        }
+        if let Some(permanent_delegate) = args.permanent_delegate {
+            let delegate = match permanent_delegate {
+                PermanentDelegateConfig::Manager => manager,
+                PermanentDelegateConfig::Address(address) => address,
+            };
+            permanent_delegate_initialize(
+                CpiContext::new(
+                    self.token_program.clone(),
+                    PermanentDelegateInitialize {
+                        token_program_id: self.token_program.clone(),
+                        mint: self.mint.clone(),
+                    },
+                ),
+                &delegate,
+            )?;
+        }
+        if args.frozen {
+            default_account_state_initialize(
+                CpiContext::new(
+                    self.token_program.clone(),
+                    DefaultAccountStateInitialize {
+                        token_program_id: self.token_program.clone(),
+                        mint: self.mint.clone(),
+                    },
+                ),
+                &AccountState::Frozen,
+            )?;
+        }
+
+        initialize_mint2(
+            CpiContext::new(
+                self.token_program.clone(),
+                InitializeMint2 {
+                    mint: self.mint.clone(),
+                },
+            ),
+            args.decimals.unwrap_or(0),
+            &authority,
+            args.freeze_authority(manager).as_ref(),
+        )?;
+        Ok(())
+    }
+
     fn initialize_token_metadata(&self, args: TokenMetadataInitializeArgs) -> ProgramResult {
         let cpi_accounts = TokenMetadataInitialize {
-            token_program_id: self.token_program.to_account_info(),
-            mint: self.mint.to_account_info(),
-            metadata: self.mint.to_account_info(), // metadata account is the mint, since data is stored in mint
-            mint_authority: self.authority.to_account_info(),
-            update_authority: self.authority.to_account_info(),
+            token_program_id: self.token_program.clone(),
+            mint: self.mint.clone(),
+            metadata: self.mint.clone(), // metadata account is the mint, since data is stored in mint
+            mint_authority: self.authority.clone(),
+            update_authority: self.authority.clone(),
         };
-        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
+        let cpi_ctx = CpiContext::new(self.token_program.clone(), cpi_accounts);
         token_metadata_initialize(cpi_ctx, args)?;
         Ok(())
     }
 
-    fn mint_to_receiver(&self) -> Result<()> {
+    fn update_token_metadata_field(&self, field: Field, value: String) -> ProgramResult {
+        let cpi_accounts = TokenMetadataUpdateField {
+            token_program_id: self.token_program.clone(),
+            metadata: self.mint.clone(),
+            update_authority: self.authority.clone(),
+        };
+        let cpi_ctx = CpiContext::new(self.token_program.clone(), cpi_accounts);
+        token_metadata_update_field(cpi_ctx, TokenMetadataUpdateFieldArgs { field, value })?;
+        Ok(())
+    }
+
+    fn update_token_metadata_update_authority(&self, new_authority: Option<Pubkey>) -> Result<()> {
+        let cpi_accounts = TokenMetadataUpdateAuthority {
+            token_program_id: self.token_program.clone(),
+            metadata: self.mint.clone(),
+            current_authority: self.authority.clone(),
+            new_authority: self.authority.clone(),
+        };
+        let cpi_ctx = CpiContext::new(self.token_program.clone(), cpi_accounts);
+        token_metadata_update_authority(cpi_ctx, OptionalNonZeroPubkey::try_from(new_authority)?)?;
+        Ok(())
+    }
+
+    fn create_mint_token_account(
+        &self,
+        receiver: &AccountInfo<'info>,
+        mint_token_account: &AccountInfo<'info>,
+    ) -> Result<()> {
+        let cpi_accounts = associated_token::Create {
+            payer: self.payer.clone(),
+            associated_token: mint_token_account.clone(),
+            authority: receiver.clone(),
+            mint: self.mint.clone(),
+            system_program: self.system_program.clone(),
+            token_program: self.token_program.clone(),
+        };
+        let cpi_ctx = CpiContext::new(self.associated_token_program.clone(), cpi_accounts);
+        associated_token::create(cpi_ctx)?;
+        Ok(())
+    }
+
+    fn mint_to_receiver(&self, mint_token_account: &AccountInfo<'info>, amount: u64) -> Result<()> {
         let cpi_ctx = MintTo {
-            mint: self.mint.to_account_info(),
-            to: self.mint_token_account.to_account_info(),
-            authority: self.authority.to_account_info(),
+            mint: self.mint.clone(),
+            to: mint_token_account.clone(),
+            authority: self.authority.clone(),
         };
-        let cpi_accounts = CpiContext::new(self.token_program.to_account_info(), cpi_ctx);
-        mint_to(cpi_accounts, 1)?;
+        let cpi_accounts = CpiContext::new(self.token_program.clone(), cpi_ctx);
+        mint_to(cpi_accounts, amount)?;
+        Ok(())
+    }
+
+    fn thaw_mint_token_account(
+        &self,
+        mint_token_account: &AccountInfo<'info>,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        let cpi_accounts = ThawAccount {
+            account: mint_token_account.clone(),
+            mint: self.mint.clone(),
+            authority: self.manager.clone(),
+        };
+        let cpi_ctx =
+            CpiContext::new_with_signer(self.token_program.clone(), cpi_accounts, signer_seeds);
+        thaw_account(cpi_ctx)?;
+        Ok(())
+    }
+
+    fn freeze_mint_token_account(
+        &self,
+        mint_token_account: &AccountInfo<'info>,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        let cpi_accounts = FreezeAccount {
+            account: mint_token_account.clone(),
+            mint: self.mint.clone(),
+            authority: self.manager.clone(),
+        };
+        let cpi_ctx =
+            CpiContext::new_with_signer(self.token_program.clone(), cpi_accounts, signer_seeds);
+        freeze_account(cpi_ctx)?;
         Ok(())
     }
 
     fn update_mint_authority(&self, manager_auth: Pubkey) -> Result<()> {
         let cpi_accounts = SetAuthority {
-            current_authority: self.authority.to_account_info(),
-            account_or_mint: self.mint.to_account_info(),
+            current_authority: self.authority.clone(),
+            account_or_mint: self.mint.clone(),
         };
-        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
+        let cpi_ctx = CpiContext::new(self.token_program.clone(), cpi_accounts);
         set_authority(cpi_ctx, AuthorityType::MintTokens, Some(manager_auth))?;
         Ok(())
     }
+
+    fn initialize_external_metadata_account(
+        &self,
+        metadata_account: &AccountInfo<'info>,
+        bump: u8,
+        metadata: &TokenMetadata,
+    ) -> Result<()> {
+        let mint_key = self.mint.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[METADATA_SEED, mint_key.as_ref(), &[bump]]];
+        create_account_with_minimum_balance(
+            metadata_account.clone(),
+            self.payer.clone(),
+            self.system_program.clone(),
+            metadata.tlv_size_of()?,
+            0,
+            &crate::id(),
+            signer_seeds,
+        )?;
+
+        let mut data = metadata_account.try_borrow_mut_data()?;
+        let mut state = TlvStateMut::unpack(&mut data)?;
+        state.alloc_and_pack_variable_len_entry(metadata, false)?;
+        Ok(())
+    }
+
+    fn initialize_mint_record(
+        &self,
+        mint_record: &AccountInfo<'info>,
+        bump: u8,
+        group: Option<Pubkey>,
+    ) -> Result<()> {
+        let mint_key = self.mint.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[MINT_RECORD_SEED, mint_key.as_ref(), &[bump]]];
+        create_account_with_minimum_balance(
+            mint_record.clone(),
+            self.payer.clone(),
+            self.system_program.clone(),
+            MintRecord::LEN,
+            0,
+            &crate::id(),
+            signer_seeds,
+        )?;
+
+        let record = MintRecord {
+            creator: self.authority.key(),
+            payer: self.payer.key(),
+            slot: Clock::get()?.slot,
+            group,
+            manager: self.manager.key(),
+            bump,
+        };
+        let mut data = mint_record.try_borrow_mut_data()?;
+        record.try_serialize(&mut &mut data[..])?;
+        Ok(())
+    }
+
+    fn initialize_extra_metas_account(
+        &self,
+        bump: u8,
+        has_royalties: bool,
+        group: Option<Pubkey>,
+    ) -> Result<()> {
+        // royalty enforced mints include the approve account, group members the metas of their group
+        let mint_key = self.mint.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[META_LIST_ACCOUNT_SEED, mint_key.as_ref(), &[bump]]];
+        create_account_with_minimum_balance(
+            self.extra_metas_account.clone(),
+            self.payer.clone(),
+            self.system_program.clone(),
+            get_meta_list_size(has_royalties, group)?,
+            0,
+            &crate::id(),
+            signer_seeds,
+        )?;
+
+        let metas = get_meta_list(has_royalties, group)?;
+        let mut data = self.extra_metas_account.try_borrow_mut_data()?;
+        ExtraAccountMetaList::init::<ExecuteInstruction>(&mut data, &metas)?;
+        Ok(())
+    }
+
+    /// Creates and initializes the mint, mints to the receiver and sets up the
+    /// extra metas account used by the transfer hook.
+    /// `mint_signer_seeds` is empty for keypair mints and holds the mint seeds for PDA mints.
+    /// `group` is recorded in the mint record and selects the royalty exemptions of the
+    /// extra metas, membership is set through `member_address`.
+    /// `manager_bump` is the bump of the manager selected by `args.manager_authority`.
+    pub fn create(
+        &self,
+        args: CreateMintAccountArgs,
+        member_address: Option<Pubkey>,
+        group: Option<Pubkey>,
+        extra_metas_bump: u8,
+        manager_bump: u8,
+        mint_signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        validate_metadata(&args.name, &args.symbol, &args.uri)?;
+        // the manager is the global one or the one namespaced to `manager_authority`
+        let manager_authority = args.manager_authority;
+        let manager_namespace = Manager::namespace(manager_authority.as_ref());
+        let expected_manager = Pubkey::create_program_address(
+            &[MANAGER_SEED, manager_namespace, &[manager_bump]],
+            &crate::id(),
+        )
+        .map_err(|_| MetadataErrors::InvalidManagerAccount)?;
+        if self.manager.key() != expected_manager {
+            return Err(MetadataErrors::InvalidManagerAccount.into());
+        }
+        // the extra metas account may have been pre-funded, but never initialized
+        if !args.soulbound && !self.extra_metas_account.data_is_empty() {
+            return Err(MetadataErrors::ExtraMetasAlreadyInitialized.into());
+        }
+        let initial_supply = args.initial_supply.unwrap_or(1);
+        if initial_supply == 0 {
+            return Err(MetadataErrors::InvalidInitialSupply.into());
+        }
+        for metadata in args.additional_metadata.iter() {
+            validate_additional_metadata_field(&metadata.field, &metadata.value)?;
+        }
+        // tokens minted to a program or off curve address may not be recoverable
+        if let Some(receiver) = &self.receiver {
+            if !args.allow_pda_receiver && !is_wallet_account(receiver) {
+                return Err(MetadataErrors::ReceiverNotAllowed.into());
+            }
+        }
+        // the receiver's token account of frozen mints is thawed by the manager for the mint
+        if args.frozen && args.freeze_authority(self.manager.key()) != Some(self.manager.key()) {
+            return Err(MetadataErrors::NoFreezeAuthority.into());
+        }
+        if let Some(royalties) = &args.royalties {
+            // soulbound mints have no transfer hook to enforce royalties
+            if args.soulbound {
+                return Err(MetadataErrors::SoulboundMint.into());
+            }
+            royalties.validate()?;
+        }
+
+        // external metadata is stored in a PDA of this program
+        let external_metadata_account = if args.external_metadata {
+            let metadata_account = self
+                .metadata_account
+                .as_ref()
+                .ok_or(MetadataErrors::InvalidMetadataAccount)?;
+            let (expected_metadata_account, bump) = Pubkey::find_program_address(
+                &[METADATA_SEED, self.mint.key().as_ref()],
+                &crate::id(),
+            );
+            if metadata_account.key() != expected_metadata_account {
+                return Err(MetadataErrors::InvalidMetadataAccount.into());
+            }
+            Some((metadata_account, bump))
+        } else {
+            None
+        };
+        let metadata_address = external_metadata_account
+            .map(|(metadata_account, _)| metadata_account.key())
+            .unwrap_or(self.mint.key());
+
+        // create the mint with its extensions
+        self.initialize_mint(&args, member_address, metadata_address, mint_signer_seeds)?;
+
+        // additional metadata followed by the content hash, royalty basis points and creators
+        // zero royalty mints have nothing to enforce in the transfer hook
+        let has_royalties = args
+            .royalties
+            .as_ref()
+            .is_some_and(|royalties| royalties.royalty_basis_points > 0);
+        let mut additional_metadata: Vec<(String, String)> = args
+            .additional_metadata
+            .into_iter()
+            .map(|metadata| (metadata.field, metadata.value))
+            .collect();
+        if let Some(content_hash) = args.content_hash {
+            additional_metadata.push((
+                CONTENT_HASH_FIELD.to_owned(),
+                encode_content_hash(&content_hash),
+            ));
+        }
+        if let Some(royalties) = args.royalties {
+            additional_metadata.push((
+                ROYALTY_BASIS_POINTS_FIELD.to_owned(),
+                royalties.royalty_basis_points.to_string(),
+            ));
+            for creator in royalties.creators {
+                additional_metadata.push((creator.address, creator.share.to_string()));
+            }
+        }
+        // metadata updates are handed over once all creation time fields are written
+        let update_authority =
+            (!args.immutable).then(|| args.update_authority.unwrap_or(self.authority.key()));
+
+        if let Some((metadata_account, bump)) = external_metadata_account {
+            // write the whole metadata at once
+            let metadata = TokenMetadata {
+                update_authority: OptionalNonZeroPubkey::try_from(update_authority)?,
+                mint: self.mint.key(),
+                name: args.name,
+                symbol: args.symbol,
+                uri: args.uri,
+                additional_metadata,
+            };
+            self.initialize_external_metadata_account(metadata_account, bump, &metadata)?;
+        } else {
+            // initialize token metadata
+            self.initialize_token_metadata(TokenMetadataInitializeArgs {
+                name: args.name,
+                symbol: args.symbol,
+                uri: args.uri,
+            })?;
+
+            // add additional metadata, rent was already funded when creating the mint
+            for (field, value) in additional_metadata {
+                self.update_token_metadata_field(Field::Key(field), value)?;
+            }
+
+            if update_authority != Some(self.authority.key()) {
+                self.update_token_metadata_update_authority(update_authority)?;
+            }
+        }
+
+        // mint to receiver, unminted mints are delivered later through `mint_to_claimer`
+        if let (Some(receiver), Some(mint_token_account)) =
+            (&self.receiver, &self.mint_token_account)
+        {
+            self.create_mint_token_account(receiver, mint_token_account)?;
+            if args.frozen {
+                // the receiver's token account is created frozen, thaw it for the mint and freeze it back
+                let signer_seeds: &[&[&[u8]]] =
+                    &[&[MANAGER_SEED, manager_namespace, &[manager_bump]]];
+                self.thaw_mint_token_account(mint_token_account, signer_seeds)?;
+                self.mint_to_receiver(mint_token_account, initial_supply)?;
+                self.freeze_mint_token_account(mint_token_account, signer_seeds)?;
+            } else {
+                self.mint_to_receiver(mint_token_account, initial_supply)?;
+            }
+        }
+
+        // move mint authority to Manager
+        self.update_mint_authority(self.manager.key())?;
+        // the Manager's mint authority is revoked through `finalize_mint`
+
+        // record the mint provenance
+        if args.mint_record {
+            let mint_record = self
+                .mint_record
+                .as_ref()
+                .ok_or(MetadataErrors::InvalidMintRecordAccount)?;
+            let (expected_mint_record, bump) = Pubkey::find_program_address(
+                &[MINT_RECORD_SEED, self.mint.key().as_ref()],
+                &crate::id(),
+            );
+            if mint_record.key() != expected_mint_record {
+                return Err(MetadataErrors::InvalidMintRecordAccount.into());
+            }
+            self.initialize_mint_record(mint_record, bump, group)?;
+        }
+
+        // initialize the extra metas account, soulbound mints can't be transferred so they don't need one
+        if !args.soulbound {
+            self.initialize_extra_metas_account(
+                extra_metas_bump,
+                has_royalties,
+                member_address.and(group),
+            )?;
+        }
+
+        Ok(())
+    }
 }
 
-pub fn handler(ctx: Context<CreateMintAccount>, args: CreateMintAccountArgs) -> Result<()> {
-    // initialize token metadata
-    ctx.accounts
-        .initialize_token_metadata(TokenMetadataInitializeArgs {
-            name: args.name,
-            symbol: args.symbol,
-            uri: args.uri,
-        })?;
-
-    // mint to receiver
-    ctx.accounts.mint_to_receiver()?;
-
-    let manager_pubkey = ctx.accounts.manager.key();
-    // move mint authority to Manager
-    ctx.accounts.update_mint_authority(manager_pubkey)?;
-    // TODO: Once Token Extension program supports Group/Member accounts natively, should lock Mint Authority
-
-    // initialize the extra metas account
-    let extra_metas_account = &ctx.accounts.extra_metas_account;
-    let metas = get_meta_list(None);
-    let mut data = extra_metas_account.try_borrow_mut_data()?;
-    ExtraAccountMetaList::init::<ExecuteInstruction>(&mut data, &metas)?;
-
-    // transfer minimum rent to mint account
-    update_account_lamports_to_minimum_balance(
-        ctx.accounts.mint.to_account_info(),
-        ctx.accounts.payer.to_account_info(),
-        ctx.accounts.system_program.to_account_info(),
+pub fn handler(
+    ctx: Context<CreateMintAccount>,
+    args: CreateMintAccountArgs,
+) -> Result<CreatedMintAccount> {
+    let event = MintCreated {
+        mint: ctx.accounts.mint.key(),
+        authority: ctx.accounts.authority.key(),
+        receiver: ctx.accounts.receiver.key(),
+        name: args.name.clone(),
+        symbol: args.symbol.clone(),
+        uri: args.uri.clone(),
+    };
+
+    ctx.accounts.mint_accounts().create(
+        args,
+        None,
+        None,
+        ctx.bumps.extra_metas_account,
+        ctx.bumps.manager,
+        &[],
     )?;
 
-    Ok(())
+    emit_cpi!(event);
+
+    Ok(CreatedMintAccount {
+        mint: ctx.accounts.mint.key(),
+        extra_metas_account: ctx.accounts.extra_metas_account.key(),
+        manager: ctx.accounts.manager.key(),
+        metadata_address: get_metadata_address(&ctx.accounts.mint.to_account_info())?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use spl_type_length_value::variable_len_pack::VariableLenPack;
+
+    use super::*;
+    use crate::CreatorWithShare;
+
+    fn args(full: bool) -> CreateMintAccountArgs {
+        CreateMintAccountArgs {
+            name: "name".to_string(),
+            symbol: "SYM".to_string(),
+            uri: "https://wns.example/mint.json".to_string(),
+            soulbound: false,
+            permanent_delegate: None,
+            frozen: false,
+            freeze_authority: None,
+            decimals: None,
+            initial_supply: None,
+            additional_metadata: if full {
+                vec![AddMetadataArgs {
+                    field: "trait".to_string(),
+                    value: "value".to_string(),
+                }]
+            } else {
+                vec![]
+            },
+            royalties: full.then(|| AddRoyaltiesArgs {
+                royalty_basis_points: 500,
+                creators: vec![
+                    CreatorWithShare {
+                        address: Pubkey::new_unique().to_string(),
+                        share: 60,
+                    },
+                    CreatorWithShare {
+                        address: Pubkey::new_unique().to_string(),
+                        share: 40,
+                    },
+                ],
+            }),
+            update_authority: None,
+            immutable: false,
+            allow_pda_receiver: false,
+            external_metadata: false,
+            content_hash: full.then_some([7; 32]),
+            mint_record: false,
+            manager_authority: None,
+            provenance: false,
+        }
+    }
+
+    /// Token metadata written in the mint by `create` for `args`
+    fn written_metadata(args: &CreateMintAccountArgs) -> TokenMetadata {
+        let mut additional_metadata: Vec<(String, String)> = args
+            .additional_metadata
+            .iter()
+            .map(|metadata| (metadata.field.clone(), metadata.value.clone()))
+            .collect();
+        if let Some(content_hash) = &args.content_hash {
+            additional_metadata.push((
+                CONTENT_HASH_FIELD.to_owned(),
+                encode_content_hash(content_hash),
+            ));
+        }
+        if let Some(royalties) = &args.royalties {
+            additional_metadata.push((
+                ROYALTY_BASIS_POINTS_FIELD.to_owned(),
+                royalties.royalty_basis_points.to_string(),
+            ));
+            for creator in royalties.creators.iter() {
+                additional_metadata.push((creator.address.clone(), creator.share.to_string()));
+            }
+        }
+        TokenMetadata {
+            update_authority: OptionalNonZeroPubkey(Pubkey::new_unique()),
+            mint: Pubkey::new_unique(),
+            name: args.name.clone(),
+            symbol: args.symbol.clone(),
+            uri: args.uri.clone(),
+            additional_metadata,
+        }
+    }
+
+    #[test]
+    fn metadata_len_matches_the_written_metadata() {
+        for full in [false, true] {
+            let args = args(full);
+            // the extension TLV entry holds a 2 byte type and a 2 byte length
+            assert_eq!(
+                args.metadata_len(),
+                4 + written_metadata(&args).get_packed_len().unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn args_of_older_clients_take_the_default_options() {
+        let legacy = ("name".to_string(), "SYM".to_string(), "uri".to_string())
+            .try_to_vec()
+            .unwrap();
+        let args = CreateMintAccountArgs::try_from_slice(&legacy).unwrap();
+        assert_eq!(
+            args.try_to_vec().unwrap(),
+            CreateMintAccountArgs {
+                name: "name".to_string(),
+                symbol: "SYM".to_string(),
+                uri: "uri".to_string(),
+                ..CreateMintAccountArgs::default()
+            }
+            .try_to_vec()
+            .unwrap()
+        );
+
+        // args with every option round trip, truncated ones are rejected
+        let full = args(true).try_to_vec().unwrap();
+        let decoded = CreateMintAccountArgs::try_from_slice(&full).unwrap();
+        assert_eq!(decoded.try_to_vec().unwrap(), full);
+        assert!(CreateMintAccountArgs::try_from_slice(&full[..full.len() - 1]).is_err());
+    }
 }
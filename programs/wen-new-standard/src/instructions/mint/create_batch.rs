@@ -0,0 +1,126 @@
+use anchor_lang::prelude::*;
+
+use anchor_spl::{
+    associated_token::{get_associated_token_address_with_program_id, AssociatedToken},
+    token_interface::Token2022,
+};
+
+use crate::{
+    CreateMintAccountArgs, Manager, MetadataErrors, MintAccounts, MANAGER_SEED,
+    META_LIST_ACCOUNT_SEED,
+};
+
+/// Number of remaining accounts expected per mint: mint, mint token account, extra metas account.
+pub const BATCH_MINT_ACCOUNTS_CHUNK_SIZE: usize = 3;
+
+/// Creates one WNS mint per entry of `args`, equivalent to calling `create_mint_account`
+/// once per entry with the same `authority` and `receiver`.
+///
+/// `remaining_accounts` must hold `BATCH_MINT_ACCOUNTS_CHUNK_SIZE` accounts per entry, in order:
+/// the mint (signer, writable), the receiver's associated token account (writable) and the
+/// extra metas account PDA (writable).
+///
+/// Each entry performs the same CPIs as `create_mint_account` plus the account creations
+/// anchor would otherwise perform, roughly 55-60k compute units per mint. Callers should
+/// request a compute budget of about `60_000 * args.len()` and keep batches small enough to
+/// fit the transaction account limit. Batched mints can't use `external_metadata` nor `mint_record`.
+/// Every mint is held by the manager of `manager_authority`, which each `args.manager_authority`
+/// must match.
+#[derive(Accounts)]
+#[instruction(args: Vec<CreateMintAccountArgs>)]
+pub struct CreateMintAccountsBatch<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub authority: Signer<'info>,
+    #[account()]
+    /// CHECK: can be any account
+    pub receiver: UncheckedAccount<'info>,
+    /// CHECK: authority the manager is namespaced to, omitted for the global manager
+    pub manager_authority: Option<UncheckedAccount<'info>>,
+    #[account(
+        seeds = [
+            MANAGER_SEED,
+            Manager::namespace(manager_authority.as_ref().map(|authority| authority.key)),
+        ],
+        bump
+    )]
+    pub manager: Account<'info, Manager>,
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+impl<'info> CreateMintAccountsBatch<'info> {
+    fn create_mint_account(
+        &self,
+        accounts: &[AccountInfo<'info>],
+        args: CreateMintAccountArgs,
+        manager_bump: u8,
+    ) -> Result<()> {
+        let mint = &accounts[0];
+        let mint_token_account = &accounts[1];
+        let extra_metas_account = &accounts[2];
+
+        if !mint.is_signer {
+            return Err(anchor_lang::error::ErrorCode::AccountNotSigner.into());
+        }
+        let expected_token_account = get_associated_token_address_with_program_id(
+            &self.receiver.key(),
+            &mint.key(),
+            &self.token_program.key(),
+        );
+        if mint_token_account.key() != expected_token_account {
+            return Err(MetadataErrors::InvalidMintTokenAccount.into());
+        }
+        let (expected_extra_metas_account, bump) = Pubkey::find_program_address(
+            &[META_LIST_ACCOUNT_SEED, mint.key().as_ref()],
+            &crate::id(),
+        );
+        if extra_metas_account.key() != expected_extra_metas_account {
+            return Err(MetadataErrors::InvalidExtraMetasAccount.into());
+        }
+
+        let mint_accounts = MintAccounts {
+            payer: self.payer.to_account_info(),
+            authority: self.authority.to_account_info(),
+            receiver: Some(self.receiver.to_account_info()),
+            mint: mint.clone(),
+            mint_token_account: Some(mint_token_account.clone()),
+            extra_metas_account: extra_metas_account.clone(),
+            metadata_account: None,
+            mint_record: None,
+            manager: self.manager.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+            associated_token_program: self.associated_token_program.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+        };
+        mint_accounts.create(args, None, None, bump, manager_bump, &[])
+    }
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CreateMintAccountsBatch<'info>>,
+    args: Vec<CreateMintAccountArgs>,
+) -> Result<()> {
+    let remaining_accounts = ctx.remaining_accounts;
+    if remaining_accounts.len() % BATCH_MINT_ACCOUNTS_CHUNK_SIZE != 0
+        || remaining_accounts.len() / BATCH_MINT_ACCOUNTS_CHUNK_SIZE != args.len()
+    {
+        return Err(MetadataErrors::InvalidBatchAccounts.into());
+    }
+
+    for (index, (accounts, args)) in remaining_accounts
+        .chunks(BATCH_MINT_ACCOUNTS_CHUNK_SIZE)
+        .zip(args)
+        .enumerate()
+    {
+        ctx.accounts
+            .create_mint_account(accounts, args, ctx.bumps.manager)
+            .map_err(|err| {
+                msg!("Failed to create mint at batch index {}", index);
+                err
+            })?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,135 @@
+use anchor_lang::prelude::*;
+
+use anchor_spl::{
+    associated_token::{get_associated_token_address_with_program_id, AssociatedToken},
+    token_interface::Token2022,
+};
+
+use crate::{
+    get_metadata_address, validate_user_seed, CreateMintAccountArgs, CreatedMintAccount, Manager,
+    MetadataErrors, MintAccounts, MintCreated, MANAGER_SEED, META_LIST_ACCOUNT_SEED, MINT_SEED,
+};
+
+/// Same as `CreateMintAccount` with the mint derived from `[MINT_SEED, authority, user_seed]`
+/// instead of a keypair signer, so its address is known before the transaction lands.
+/// See `get_mint_pda`.
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(args: CreateMintAccountArgs, user_seed: String)]
+pub struct CreateMintAccountPda<'info> {
+    /// `user_seed` is checked here, before the mint is derived from it
+    #[account(
+        mut,
+        constraint = validate_user_seed(&user_seed).is_ok() @ MetadataErrors::UserSeedTooLong,
+    )]
+    pub payer: Signer<'info>,
+    /// mint authority, can be a PDA signing through CPI
+    pub authority: Signer<'info>,
+    #[account()]
+    /// CHECK: can be any account
+    pub receiver: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [MINT_SEED, authority.key().as_ref(), user_seed.as_bytes()],
+        bump,
+    )]
+    /// CHECK: initialized in the handler, extensions depend on args
+    pub mint: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = get_associated_token_address_with_program_id(
+            &receiver.key(),
+            &mint.key(),
+            &token_program.key(),
+        ),
+    )]
+    /// CHECK: initialized in the handler as the receiver's associated token account
+    pub mint_token_account: UncheckedAccount<'info>,
+    /// CHECK: This account's data is a buffer of TLV data, initialized in the handler
+    #[account(
+        mut,
+        seeds = [META_LIST_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub extra_metas_account: UncheckedAccount<'info>,
+    /// CHECK: metadata PDA of mints with external metadata, validated in the handler
+    #[account(mut)]
+    pub metadata_account: Option<UncheckedAccount<'info>>,
+    /// CHECK: mint record PDA, only needed when `args.mint_record` is set
+    #[account(mut)]
+    pub mint_record: Option<UncheckedAccount<'info>>,
+    /// global manager, or the manager namespaced to `args.manager_authority`
+    #[account(
+        seeds = [MANAGER_SEED, Manager::namespace(args.manager_authority.as_ref())],
+        bump
+    )]
+    pub manager: Account<'info, Manager>,
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+impl<'info> CreateMintAccountPda<'info> {
+    fn mint_accounts(&self) -> MintAccounts<'info> {
+        MintAccounts {
+            payer: self.payer.to_account_info(),
+            authority: self.authority.to_account_info(),
+            receiver: Some(self.receiver.to_account_info()),
+            mint: self.mint.to_account_info(),
+            mint_token_account: Some(self.mint_token_account.to_account_info()),
+            extra_metas_account: self.extra_metas_account.to_account_info(),
+            metadata_account: self
+                .metadata_account
+                .as_ref()
+                .map(|metadata_account| metadata_account.to_account_info()),
+            mint_record: self
+                .mint_record
+                .as_ref()
+                .map(|mint_record| mint_record.to_account_info()),
+            manager: self.manager.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+            associated_token_program: self.associated_token_program.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+        }
+    }
+}
+
+pub fn handler(
+    ctx: Context<CreateMintAccountPda>,
+    args: CreateMintAccountArgs,
+    user_seed: String,
+) -> Result<CreatedMintAccount> {
+    let event = MintCreated {
+        mint: ctx.accounts.mint.key(),
+        authority: ctx.accounts.authority.key(),
+        receiver: ctx.accounts.receiver.key(),
+        name: args.name.clone(),
+        symbol: args.symbol.clone(),
+        uri: args.uri.clone(),
+    };
+
+    let authority = ctx.accounts.authority.key();
+    let mint_signer_seeds: &[&[&[u8]]] = &[&[
+        MINT_SEED,
+        authority.as_ref(),
+        user_seed.as_bytes(),
+        &[ctx.bumps.mint],
+    ]];
+    ctx.accounts.mint_accounts().create(
+        args,
+        None,
+        None,
+        ctx.bumps.extra_metas_account,
+        ctx.bumps.manager,
+        mint_signer_seeds,
+    )?;
+
+    emit_cpi!(event);
+
+    Ok(CreatedMintAccount {
+        mint: ctx.accounts.mint.key(),
+        extra_metas_account: ctx.accounts.extra_metas_account.key(),
+        manager: ctx.accounts.manager.key(),
+        metadata_address: get_metadata_address(&ctx.accounts.mint.to_account_info())?,
+    })
+}
@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+
+use anchor_spl::{associated_token::AssociatedToken, token_interface::Token2022};
+
+use crate::{CreateMintAccountArgs, Manager, MintAccounts, MANAGER_SEED, META_LIST_ACCOUNT_SEED};
+
+/// Same as `CreateMintAccount` without a receiver: nothing is minted and the mint authority
+/// stays with the manager until the token is delivered through `mint_to_claimer`.
+/// `initial_supply` is ignored, claims always mint a single token.
+#[derive(Accounts)]
+#[instruction(args: CreateMintAccountArgs)]
+pub struct CreateMintAccountUnminted<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub authority: Signer<'info>,
+    #[account(mut, signer)]
+    /// CHECK: initialized in the handler, extensions depend on args
+    pub mint: UncheckedAccount<'info>,
+    /// CHECK: This account's data is a buffer of TLV data, initialized in the handler
+    #[account(
+        mut,
+        seeds = [META_LIST_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub extra_metas_account: UncheckedAccount<'info>,
+    /// CHECK: metadata PDA of mints with external metadata, validated in the handler
+    #[account(mut)]
+    pub metadata_account: Option<UncheckedAccount<'info>>,
+    /// CHECK: mint record PDA, only needed when `args.mint_record` is set
+    #[account(mut)]
+    pub mint_record: Option<UncheckedAccount<'info>>,
+    /// global manager, or the manager namespaced to `args.manager_authority`
+    #[account(
+        seeds = [MANAGER_SEED, Manager::namespace(args.manager_authority.as_ref())],
+        bump
+    )]
+    pub manager: Account<'info, Manager>,
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+impl<'info> CreateMintAccountUnminted<'info> {
+    fn mint_accounts(&self) -> MintAccounts<'info> {
+        MintAccounts {
+            payer: self.payer.to_account_info(),
+            authority: self.authority.to_account_info(),
+            receiver: None,
+            mint: self.mint.to_account_info(),
+            mint_token_account: None,
+            extra_metas_account: self.extra_metas_account.to_account_info(),
+            metadata_account: self
+                .metadata_account
+                .as_ref()
+                .map(|metadata_account| metadata_account.to_account_info()),
+            mint_record: self
+                .mint_record
+                .as_ref()
+                .map(|mint_record| mint_record.to_account_info()),
+            manager: self.manager.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+            associated_token_program: self.associated_token_program.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+        }
+    }
+}
+
+pub fn handler(ctx: Context<CreateMintAccountUnminted>, args: CreateMintAccountArgs) -> Result<()> {
+    ctx.accounts.mint_accounts().create(
+        args,
+        None,
+        None,
+        ctx.bumps.extra_metas_account,
+        ctx.bumps.manager,
+        &[],
+    )
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use anchor_spl::token_interface::{Mint, Token2022};
+
+use crate::{
+    get_external_metadata_account, get_metadata, MasterEdition, MetadataErrors, MASTER_EDITION_SEED,
+};
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct EnableEditionsArgs {
+    pub max_supply: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(args: EnableEditionsArgs)]
+pub struct EnableEditions<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// metadata update authority of the master mint
+    pub authority: Signer<'info>,
+    #[account(
+        mint::token_program = token_program,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    /// CHECK: account referenced by the metadata pointer, only needed for external metadata
+    pub metadata_account: Option<UncheckedAccount<'info>>,
+    #[account(
+        init,
+        seeds = [MASTER_EDITION_SEED, mint.key().as_ref()],
+        bump,
+        payer = payer,
+        space = MasterEdition::LEN
+    )]
+    pub master_edition: Account<'info, MasterEdition>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+/// Makes the mint a master of `max_supply` numbered editions
+pub fn handler(ctx: Context<EnableEditions>, args: EnableEditionsArgs) -> Result<()> {
+    if args.max_supply == 0 {
+        return Err(MetadataErrors::InvalidMaxEditions.into());
+    }
+
+    // only the metadata update authority can enable editions
+    let mint = ctx.accounts.mint.to_account_info();
+    let metadata_account = ctx
+        .accounts
+        .metadata_account
+        .as_ref()
+        .map(|metadata_account| metadata_account.to_account_info());
+    let external_metadata_account =
+        get_external_metadata_account(&mint, metadata_account.as_ref())?;
+    let metadata = get_metadata(&mint, external_metadata_account)?;
+    if Option::<Pubkey>::from(metadata.update_authority) != Some(ctx.accounts.authority.key()) {
+        return Err(MetadataErrors::InvalidMetadataAuthority.into());
+    }
+
+    let master_edition = &mut ctx.accounts.master_edition;
+    master_edition.mint = ctx.accounts.mint.key();
+    master_edition.max_supply = args.max_supply;
+    master_edition.current_supply = 0;
+
+    Ok(())
+}
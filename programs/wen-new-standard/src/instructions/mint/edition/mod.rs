@@ -0,0 +1,5 @@
+pub mod enable;
+pub mod print;
+
+pub use enable::*;
+pub use print::*;
@@ -0,0 +1,281 @@
+use std::str::FromStr;
+
+use anchor_lang::prelude::*;
+
+use anchor_spl::{
+    associated_token::{get_associated_token_address_with_program_id, AssociatedToken},
+    token_interface::{
+        spl_token_2022::extension::non_transferable::NonTransferable,
+        spl_token_metadata_interface::state::TokenMetadata, Mint, Token2022,
+    },
+};
+
+use crate::{
+    decode_content_hash, get_extension_data, get_external_metadata_account, get_metadata,
+    get_royalty_config, index_member, validate_group_policy, AddMetadataArgs,
+    CreateMintAccountArgs, GroupFinalization, GroupMemberCounter, GroupSizeChanged, Manager,
+    MasterEdition, MemberAdded, MetadataErrors, MintAccounts, MintCreated, TokenGroup,
+    TokenGroupMember, CONTENT_HASH_FIELD, EDITION_NUMBER_FIELD, GROUP_FINALIZATION_SEED,
+    GROUP_MEMBER_COUNTER_SEED, MANAGER_SEED, MASTER_EDITION_SEED, MEMBER_ACCOUNT_SEED,
+    META_LIST_ACCOUNT_SEED, POLICY_SEED, ROYALTY_BASIS_POINTS_FIELD,
+};
+
+/// Creates the next numbered edition of a master mint. The edition copies the master
+/// metadata and royalties and joins the group of the master.
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction()]
+pub struct PrintEdition<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// metadata update authority of the master mint, mint authority of the edition
+    pub authority: Signer<'info>,
+    #[account()]
+    /// CHECK: can be any account
+    pub receiver: UncheckedAccount<'info>,
+    #[account(
+        mint::token_program = token_program,
+    )]
+    pub master_mint: Box<InterfaceAccount<'info, Mint>>,
+    /// CHECK: account referenced by the master metadata pointer, only needed for external metadata
+    pub master_metadata_account: Option<UncheckedAccount<'info>>,
+    #[account(
+        mut,
+        seeds = [MASTER_EDITION_SEED, master_mint.key().as_ref()],
+        bump,
+    )]
+    pub master_edition: Account<'info, MasterEdition>,
+    #[account(mut)]
+    pub group: Account<'info, TokenGroup>,
+    #[account(
+        seeds = [MEMBER_ACCOUNT_SEED, master_mint.key().as_ref()],
+        bump,
+        constraint = master_member.group == group.key(),
+    )]
+    pub master_member: Account<'info, TokenGroupMember>,
+    #[account(
+        init,
+        seeds = [MEMBER_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+        payer = payer,
+        space = TokenGroupMember::LEN
+    )]
+    pub member: Account<'info, TokenGroupMember>,
+    /// last member number assigned in the group
+    #[account(
+        init_if_needed,
+        seeds = [GROUP_MEMBER_COUNTER_SEED, group.key().as_ref()],
+        bump,
+        payer = payer,
+        space = GroupMemberCounter::LEN
+    )]
+    pub member_counter: Account<'info, GroupMemberCounter>,
+    /// CHECK: member index page PDA of the new member number, created in the handler
+    #[account(mut)]
+    pub member_index: UncheckedAccount<'info>,
+    /// CHECK: finalization PDA of the group, uninitialized until the group is finalized
+    #[account(
+        seeds = [GROUP_FINALIZATION_SEED, group.key().as_ref()],
+        bump,
+    )]
+    pub finalization: UncheckedAccount<'info>,
+    /// CHECK: group extension policy PDA, uninitialized when the group has no policy
+    #[account(
+        seeds = [POLICY_SEED, group.key().as_ref()],
+        bump,
+    )]
+    pub policy: UncheckedAccount<'info>,
+    #[account(mut, signer)]
+    /// CHECK: initialized in the handler, extensions depend on the master
+    pub mint: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = get_associated_token_address_with_program_id(
+            &receiver.key(),
+            &mint.key(),
+            &token_program.key(),
+        ),
+    )]
+    /// CHECK: initialized in the handler as the receiver's associated token account
+    pub mint_token_account: UncheckedAccount<'info>,
+    /// CHECK: This account's data is a buffer of TLV data, initialized in the handler
+    #[account(
+        mut,
+        seeds = [META_LIST_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub extra_metas_account: UncheckedAccount<'info>,
+    /// CHECK: authority the manager is namespaced to, omitted for the global manager
+    pub manager_authority: Option<UncheckedAccount<'info>>,
+    #[account(
+        seeds = [
+            MANAGER_SEED,
+            Manager::namespace(manager_authority.as_ref().map(|authority| authority.key)),
+        ],
+        bump
+    )]
+    pub manager: Account<'info, Manager>,
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+impl<'info> PrintEdition<'info> {
+    fn mint_accounts(&self) -> MintAccounts<'info> {
+        MintAccounts {
+            payer: self.payer.to_account_info(),
+            authority: self.authority.to_account_info(),
+            receiver: Some(self.receiver.to_account_info()),
+            mint: self.mint.to_account_info(),
+            mint_token_account: Some(self.mint_token_account.to_account_info()),
+            extra_metas_account: self.extra_metas_account.to_account_info(),
+            metadata_account: None,
+            mint_record: None,
+            manager: self.manager.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+            associated_token_program: self.associated_token_program.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+        }
+    }
+}
+
+/// Creation args of an edition, the master metadata followed by the edition number
+fn edition_args(
+    metadata: TokenMetadata,
+    edition_number: u64,
+    soulbound: bool,
+    manager_authority: Option<Pubkey>,
+) -> CreateMintAccountArgs {
+    // royalty fields are written back from the royalties args
+    let royalties = get_royalty_config(&metadata);
+    let mut additional_metadata = vec![];
+    let mut content_hash = None;
+    for (field, value) in metadata.additional_metadata {
+        if field == CONTENT_HASH_FIELD {
+            content_hash = decode_content_hash(&value);
+        } else if field != ROYALTY_BASIS_POINTS_FIELD
+            && field != EDITION_NUMBER_FIELD
+            && Pubkey::from_str(&field).is_err()
+        {
+            additional_metadata.push(AddMetadataArgs { field, value });
+        }
+    }
+    additional_metadata.push(AddMetadataArgs {
+        field: EDITION_NUMBER_FIELD.to_owned(),
+        value: edition_number.to_string(),
+    });
+
+    CreateMintAccountArgs {
+        name: metadata.name,
+        symbol: metadata.symbol,
+        uri: metadata.uri,
+        soulbound,
+        permanent_delegate: None,
+        frozen: false,
+        freeze_authority: None,
+        decimals: None,
+        initial_supply: None,
+        additional_metadata,
+        royalties,
+        update_authority: None,
+        immutable: false,
+        allow_pda_receiver: false,
+        external_metadata: false,
+        content_hash,
+        mint_record: false,
+        manager_authority,
+        provenance: false,
+    }
+}
+
+pub fn handler(ctx: Context<PrintEdition>) -> Result<()> {
+    if ctx.accounts.group.is_full() {
+        return Err(MetadataErrors::GroupFull.into());
+    }
+    if GroupFinalization::load(&ctx.accounts.finalization.to_account_info())?.is_some() {
+        return Err(MetadataErrors::GroupFinalized.into());
+    }
+
+    // only the master metadata update authority can print editions
+    let master_mint = ctx.accounts.master_mint.to_account_info();
+    let master_metadata_account = ctx
+        .accounts
+        .master_metadata_account
+        .as_ref()
+        .map(|metadata_account| metadata_account.to_account_info());
+    let external_metadata_account =
+        get_external_metadata_account(&master_mint, master_metadata_account.as_ref())?;
+    let metadata = get_metadata(&master_mint, external_metadata_account)?;
+    if Option::<Pubkey>::from(metadata.update_authority) != Some(ctx.accounts.authority.key()) {
+        return Err(MetadataErrors::InvalidMetadataAuthority.into());
+    }
+    let soulbound = get_extension_data::<NonTransferable>(&mut master_mint.clone()).is_ok();
+
+    // fails once all editions are printed
+    let edition_number = ctx.accounts.master_edition.increment_supply()?;
+
+    // add the edition to the group of the master
+    let group = &mut ctx.accounts.group;
+    let member_counter = &mut ctx.accounts.member_counter;
+    member_counter.init(group.key(), group.size);
+    group.increment_size()?;
+
+    let member = &mut ctx.accounts.member;
+    member.group = group.key();
+    member.mint = ctx.accounts.mint.key();
+    member.member_number = member_counter.next_member_number()?;
+    index_member(
+        ctx.accounts.member_index.to_account_info(),
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.group.key(),
+        ctx.accounts.member.member_number,
+        ctx.accounts.mint.key(),
+    )?;
+
+    let manager_authority = ctx
+        .accounts
+        .manager_authority
+        .as_ref()
+        .map(|authority| authority.key());
+    let args = edition_args(metadata, edition_number, soulbound, manager_authority);
+    let event = MintCreated {
+        mint: ctx.accounts.mint.key(),
+        authority: ctx.accounts.authority.key(),
+        receiver: ctx.accounts.receiver.key(),
+        name: args.name.clone(),
+        symbol: args.symbol.clone(),
+        uri: args.uri.clone(),
+    };
+
+    let member_address = ctx.accounts.member.key();
+    ctx.accounts.mint_accounts().create(
+        args,
+        Some(member_address),
+        Some(ctx.accounts.group.key()),
+        ctx.bumps.extra_metas_account,
+        ctx.bumps.manager,
+        &[],
+    )?;
+
+    // the edition must satisfy the group extension policy
+    validate_group_policy(
+        &ctx.accounts.policy.to_account_info(),
+        &ctx.accounts.mint.to_account_info(),
+        None,
+    )?;
+
+    emit_cpi!(event);
+    emit_cpi!(MemberAdded {
+        group: ctx.accounts.group.key(),
+        mint: ctx.accounts.mint.key(),
+        member_number: ctx.accounts.member.member_number,
+    });
+    emit_cpi!(GroupSizeChanged {
+        group: ctx.accounts.group.key(),
+        size: ctx.accounts.group.size,
+        max_size: ctx.accounts.group.max_size,
+    });
+
+    Ok(())
+}
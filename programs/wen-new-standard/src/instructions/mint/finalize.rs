@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+
+use anchor_spl::token_interface::{
+    set_authority,
+    spl_token_2022::{
+        extension::group_member_pointer::GroupMemberPointer, instruction::AuthorityType,
+    },
+    Mint, SetAuthority, Token2022,
+};
+
+use crate::{
+    get_extension_data, Manager, MetadataErrors, TokenGroup, TokenGroupMember, MANAGER_SEED,
+    MEMBER_ACCOUNT_SEED,
+};
+
+/// Revokes the mint authority held by the `Manager` PDA, no more supply can be minted afterwards.
+///
+/// `authority` is either the original authority of the mint (its group member pointer authority)
+/// or, once the mint has been added to a group, the group authority passing `group` and `member`.
+#[derive(Accounts)]
+#[instruction()]
+pub struct FinalizeMint<'info> {
+    pub authority: Signer<'info>,
+    pub group: Option<Account<'info, TokenGroup>>,
+    #[account(
+        seeds = [MEMBER_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub member: Option<Account<'info, TokenGroupMember>>,
+    #[account(
+        mut,
+        mint::token_program = token_program,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    /// CHECK: authority the manager of the mint is namespaced to, omitted for the global manager
+    pub manager_authority: Option<UncheckedAccount<'info>>,
+    #[account(
+        seeds = [
+            MANAGER_SEED,
+            Manager::namespace(manager_authority.as_ref().map(|authority| authority.key)),
+        ],
+        bump
+    )]
+    pub manager: Account<'info, Manager>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+impl FinalizeMint<'_> {
+    fn is_group_authority(&self) -> bool {
+        match (&self.group, &self.member) {
+            (Some(group), Some(member)) => {
+                member.group == group.key() && group.update_authority == self.authority.key()
+            }
+            _ => false,
+        }
+    }
+
+    fn is_mint_original_authority(&self) -> Result<bool> {
+        let group_member_pointer =
+            get_extension_data::<GroupMemberPointer>(&mut self.mint.to_account_info())?;
+        Ok(Option::<Pubkey>::from(group_member_pointer.authority) == Some(self.authority.key()))
+    }
+
+    fn revoke_mint_authority(&self, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        let cpi_accounts = SetAuthority {
+            current_authority: self.manager.to_account_info(),
+            account_or_mint: self.mint.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        set_authority(cpi_ctx, AuthorityType::MintTokens, None)?;
+        Ok(())
+    }
+}
+
+pub fn handler(ctx: Context<FinalizeMint>) -> Result<()> {
+    if ctx.accounts.mint.mint_authority.is_none() {
+        return Err(MetadataErrors::MintAuthorityAlreadyRevoked.into());
+    }
+    if !ctx.accounts.is_group_authority() && !ctx.accounts.is_mint_original_authority()? {
+        return Err(MetadataErrors::InvalidFinalizeAuthority.into());
+    }
+
+    let manager_namespace = Manager::namespace(
+        ctx.accounts
+            .manager_authority
+            .as_ref()
+            .map(|authority| authority.key),
+    );
+    let signer_seeds: &[&[&[u8]]] = &[&[MANAGER_SEED, manager_namespace, &[ctx.bumps.manager]]];
+    ctx.accounts.revoke_mint_authority(signer_seeds)
+}
@@ -1,23 +1,44 @@
 use anchor_lang::prelude::*;
 
 use anchor_spl::token_interface::{
-    group_member_pointer_update, GroupMemberPointerUpdate, Mint, Token2022,
+    group_member_pointer_update, transfer_hook_update, GroupMemberPointerUpdate, Mint, Token2022,
+    TransferHookUpdate,
 };
 
-use crate::{TokenGroup, TokenGroupMember, MEMBER_ACCOUNT_SEED, TOKEN22};
+use crate::{
+    get_external_metadata_account, get_metadata, get_royalty_basis_points, has_wns_transfer_hook,
+    index_member, update_account_lamports_to_minimum_balance, validate_group_member_authority,
+    validate_group_policy, validate_group_soulbound, validate_member_mint, write_meta_list,
+    GroupFinalization, GroupMemberCounter, GroupSizeChanged, MemberAdded, MetadataErrors,
+    TokenGroup, TokenGroupMember, GROUP_DELEGATES_SEED, GROUP_FINALIZATION_SEED,
+    GROUP_MEMBER_COUNTER_SEED, GROUP_PERMISSION_SEED, GROUP_SOULBOUND_SEED, MEMBER_ACCOUNT_SEED,
+    META_LIST_ACCOUNT_SEED, POLICY_SEED, TOKEN22,
+};
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction()]
 pub struct AddGroup<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
+    /// group authority, one of its delegates or a delegate granted `ADD_MEMBER`, also the
+    /// transfer hook authority of mints whose transfer hook doesn't invoke WNS yet
     #[account(mut)]
     pub authority: Signer<'info>,
+    #[account(mut)]
+    pub group: Account<'info, TokenGroup>,
+    /// CHECK: delegates PDA of the group, uninitialized when the group has no delegates
     #[account(
-        mut,
-        constraint = group.update_authority == authority.key(),
+        seeds = [GROUP_DELEGATES_SEED, group.key().as_ref()],
+        bump,
     )]
-    pub group: Account<'info, TokenGroup>,
+    pub group_delegates: UncheckedAccount<'info>,
+    /// CHECK: permission PDA of the authority in the group, uninitialized without a grant
+    #[account(
+        seeds = [GROUP_PERMISSION_SEED, group.key().as_ref(), authority.key().as_ref()],
+        bump,
+    )]
+    pub group_permission: UncheckedAccount<'info>,
     #[account(
         init,
         seeds = [MEMBER_ACCOUNT_SEED, mint.key().as_ref()],
@@ -26,10 +47,49 @@ pub struct AddGroup<'info> {
         space = TokenGroupMember::LEN
     )]
     pub member: Account<'info, TokenGroupMember>,
+    /// last member number assigned in the group
+    #[account(
+        init_if_needed,
+        seeds = [GROUP_MEMBER_COUNTER_SEED, group.key().as_ref()],
+        bump,
+        payer = payer,
+        space = GroupMemberCounter::LEN
+    )]
+    pub member_counter: Account<'info, GroupMemberCounter>,
+    /// CHECK: member index page PDA of the new member number, created in the handler
+    #[account(mut)]
+    pub member_index: UncheckedAccount<'info>,
     #[account(
         mint::token_program = TOKEN22
     )]
     pub mint: Box<InterfaceAccount<'info, Mint>>,
+    /// CHECK: finalization PDA of the group, uninitialized until the group is finalized
+    #[account(
+        seeds = [GROUP_FINALIZATION_SEED, group.key().as_ref()],
+        bump,
+    )]
+    pub finalization: UncheckedAccount<'info>,
+    /// CHECK: soulbound PDA of the group, uninitialized for transferable groups
+    #[account(
+        seeds = [GROUP_SOULBOUND_SEED, group.key().as_ref()],
+        bump,
+    )]
+    pub soulbound: UncheckedAccount<'info>,
+    /// CHECK: group extension policy PDA, uninitialized when the group has no policy
+    #[account(
+        seeds = [POLICY_SEED, group.key().as_ref()],
+        bump,
+    )]
+    pub policy: UncheckedAccount<'info>,
+    /// CHECK: account referenced by the metadata pointer, only needed for external metadata
+    pub metadata_account: Option<UncheckedAccount<'info>>,
+    /// CHECK: This account's data is a buffer of TLV data
+    #[account(
+        mut,
+        seeds = [META_LIST_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub extra_metas_account: UncheckedAccount<'info>,
     pub system_program: Program<'info, System>,
     pub token_program: Program<'info, Token2022>,
 }
@@ -45,20 +105,105 @@ impl AddGroup<'_> {
         group_member_pointer_update(cpi_ctx, Some(member))?;
         Ok(())
     }
+
+    fn update_transfer_hook_program_id(&self) -> Result<()> {
+        let cpi_accounts = TransferHookUpdate {
+            token_program_id: self.token_program.to_account_info(),
+            mint: self.mint.to_account_info(),
+            authority: self.authority.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
+        transfer_hook_update(cpi_ctx, Some(crate::id()))?;
+        Ok(())
+    }
 }
 
-pub fn handler(ctx: Context<AddGroup>) -> Result<()> {
+/// Returns the member number assigned to the mint
+pub fn handler(ctx: Context<AddGroup>) -> Result<u32> {
+    validate_group_member_authority(
+        &ctx.accounts.group,
+        &ctx.accounts.group_delegates.to_account_info(),
+        &ctx.accounts.group_permission.to_account_info(),
+        ctx.accounts.authority.key,
+    )?;
+    if ctx.accounts.group.is_full() {
+        return Err(MetadataErrors::GroupFull.into());
+    }
+    if GroupFinalization::load(&ctx.accounts.finalization.to_account_info())?.is_some() {
+        return Err(MetadataErrors::GroupFinalized.into());
+    }
+
+    // the mint must be a WNS mint not claiming another membership
+    let mint = ctx.accounts.mint.to_account_info();
+    validate_member_mint(&mint, &ctx.accounts.member.key())?;
+    validate_group_soulbound(&ctx.accounts.soulbound.to_account_info(), &mint)?;
+
+    // the mint must satisfy the group extension policy
+    let metadata_account = ctx
+        .accounts
+        .metadata_account
+        .as_ref()
+        .map(|metadata_account| metadata_account.to_account_info());
+    let external_metadata_account =
+        get_external_metadata_account(&mint, metadata_account.as_ref())?;
+    validate_group_policy(
+        &ctx.accounts.policy.to_account_info(),
+        &mint,
+        external_metadata_account,
+    )?;
+
     let group = &mut ctx.accounts.group;
+    let member_counter = &mut ctx.accounts.member_counter;
+    member_counter.init(group.key(), group.size);
     group.increment_size()?;
 
     let member = &mut ctx.accounts.member;
     member.group = group.key();
     member.mint = ctx.accounts.mint.key();
-    member.member_number = group.size;
+    member.member_number = member_counter.next_member_number()?;
 
+    let member_number = member.member_number;
     let member_address = member.key();
+    index_member(
+        ctx.accounts.member_index.to_account_info(),
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.group.key(),
+        member_number,
+        ctx.accounts.mint.key(),
+    )?;
     ctx.accounts
         .update_group_member_pointer_member_address(member_address)?;
 
-    Ok(())
+    // members resolve the group accounts in the transfer hook, with or without royalties
+    let extra_metas_account = ctx.accounts.extra_metas_account.to_account_info();
+    let metadata = get_metadata(&mint, external_metadata_account)?;
+    if extra_metas_account.owner == &crate::ID {
+        write_meta_list(
+            &extra_metas_account,
+            get_royalty_basis_points(&metadata) > 0,
+            Some(ctx.accounts.group.key()),
+        )?;
+        if !has_wns_transfer_hook(&mint)? {
+            ctx.accounts.update_transfer_hook_program_id()?;
+        }
+        update_account_lamports_to_minimum_balance(
+            extra_metas_account,
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        )?;
+    }
+
+    emit_cpi!(MemberAdded {
+        group: ctx.accounts.group.key(),
+        mint: ctx.accounts.mint.key(),
+        member_number,
+    });
+    emit_cpi!(GroupSizeChanged {
+        group: ctx.accounts.group.key(),
+        size: ctx.accounts.group.size,
+        max_size: ctx.accounts.group.max_size,
+    });
+
+    Ok(member_number)
 }
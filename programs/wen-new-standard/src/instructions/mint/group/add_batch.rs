@@ -0,0 +1,255 @@
+use anchor_lang::prelude::*;
+
+use anchor_spl::token_interface::{
+    group_member_pointer_update, transfer_hook_update, GroupMemberPointerUpdate, Token2022,
+    TransferHookUpdate,
+};
+
+use crate::{
+    create_account_with_minimum_balance, get_external_metadata_account, get_metadata,
+    get_royalty_basis_points, has_wns_transfer_hook, index_member,
+    update_account_lamports_to_minimum_balance, validate_group_member_authority,
+    validate_group_policy, validate_group_soulbound, validate_member_mint, write_meta_list,
+    GroupFinalization, GroupMemberCounter, GroupSizeChanged, MemberAdded, MetadataErrors,
+    TokenGroup, TokenGroupMember, GROUP_DELEGATES_SEED, GROUP_FINALIZATION_SEED,
+    GROUP_MEMBER_COUNTER_SEED, GROUP_PERMISSION_SEED, GROUP_SOULBOUND_SEED, MEMBER_ACCOUNT_SEED,
+    META_LIST_ACCOUNT_SEED, POLICY_SEED, TOKEN22,
+};
+
+/// Number of remaining accounts expected per member: mint, member account, extra metas account, member index page.
+pub const BATCH_MEMBER_ACCOUNTS_CHUNK_SIZE: usize = 4;
+/// Maximum number of mints added by a single `add_group_members_batch`, bounded by the transaction account limit
+pub const MAX_MEMBER_BATCH_SIZE: usize = 10;
+
+/// Adds several mints to a group, equivalent to calling `add_group_to_mint` once per mint
+/// with the same `authority`, which is also the transfer hook authority of mints whose transfer
+/// hook doesn't invoke WNS yet.
+///
+/// `remaining_accounts` must hold `BATCH_MEMBER_ACCOUNTS_CHUNK_SIZE` accounts per mint, in order:
+/// the mint (writable), its member account PDA (writable), its extra metas account PDA (writable)
+/// and the member index page of its member number (writable), which is shared by consecutive
+/// members. At most `MAX_MEMBER_BATCH_SIZE` mints can be added at once, and any invalid entry
+/// fails the whole batch.
+///
+/// Each entry performs the same validation and CPIs as `add_group_to_mint` plus the member
+/// account creation anchor would otherwise perform, roughly 30k compute units per mint. Callers
+/// should request a compute budget of about `30_000` per mint and use an address lookup table
+/// for larger batches. Batched mints can't use external metadata.
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction()]
+pub struct AddGroupMembersBatch<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// group authority, one of its delegates or a delegate granted `ADD_MEMBER`
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub group: Account<'info, TokenGroup>,
+    /// CHECK: delegates PDA of the group, uninitialized when the group has no delegates
+    #[account(
+        seeds = [GROUP_DELEGATES_SEED, group.key().as_ref()],
+        bump,
+    )]
+    pub group_delegates: UncheckedAccount<'info>,
+    /// CHECK: permission PDA of the authority in the group, uninitialized without a grant
+    #[account(
+        seeds = [GROUP_PERMISSION_SEED, group.key().as_ref(), authority.key().as_ref()],
+        bump,
+    )]
+    pub group_permission: UncheckedAccount<'info>,
+    /// last member number assigned in the group
+    #[account(
+        init_if_needed,
+        seeds = [GROUP_MEMBER_COUNTER_SEED, group.key().as_ref()],
+        bump,
+        payer = payer,
+        space = GroupMemberCounter::LEN
+    )]
+    pub member_counter: Account<'info, GroupMemberCounter>,
+    /// CHECK: finalization PDA of the group, uninitialized until the group is finalized
+    #[account(
+        seeds = [GROUP_FINALIZATION_SEED, group.key().as_ref()],
+        bump,
+    )]
+    pub finalization: UncheckedAccount<'info>,
+    /// CHECK: soulbound PDA of the group, uninitialized for transferable groups
+    #[account(
+        seeds = [GROUP_SOULBOUND_SEED, group.key().as_ref()],
+        bump,
+    )]
+    pub soulbound: UncheckedAccount<'info>,
+    /// CHECK: group extension policy PDA, uninitialized when the group has no policy
+    #[account(
+        seeds = [POLICY_SEED, group.key().as_ref()],
+        bump,
+    )]
+    pub policy: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+impl<'info> AddGroupMembersBatch<'info> {
+    /// Adds a single mint to the group, returns its member number
+    fn add_member(&mut self, accounts: &[AccountInfo<'info>]) -> Result<u32> {
+        let mint = &accounts[0];
+        let member = &accounts[1];
+        let extra_metas_account = &accounts[2];
+        let member_index = &accounts[3];
+
+        if mint.owner != &TOKEN22 {
+            return Err(anchor_lang::error::ErrorCode::ConstraintMintTokenProgram.into());
+        }
+        let (expected_member, member_bump) =
+            Pubkey::find_program_address(&[MEMBER_ACCOUNT_SEED, mint.key.as_ref()], &crate::id());
+        if member.key() != expected_member {
+            return Err(anchor_lang::error::ErrorCode::ConstraintSeeds.into());
+        }
+        if !member.data_is_empty() {
+            return Err(anchor_lang::error::ErrorCode::AccountDiscriminatorAlreadySet.into());
+        }
+        let (expected_extra_metas_account, _) = Pubkey::find_program_address(
+            &[META_LIST_ACCOUNT_SEED, mint.key.as_ref()],
+            &crate::id(),
+        );
+        if extra_metas_account.key() != expected_extra_metas_account {
+            return Err(MetadataErrors::InvalidExtraMetasAccount.into());
+        }
+        if self.group.is_full() {
+            return Err(MetadataErrors::GroupFull.into());
+        }
+
+        // the mint must be a WNS mint not claiming another membership
+        validate_member_mint(mint, member.key)?;
+        validate_group_soulbound(&self.soulbound.to_account_info(), mint)?;
+
+        // the mint must satisfy the group extension policy
+        let external_metadata_account = get_external_metadata_account(mint, None)?;
+        validate_group_policy(
+            &self.policy.to_account_info(),
+            mint,
+            external_metadata_account,
+        )?;
+
+        let group_key = self.group.key();
+        self.member_counter.init(group_key, self.group.size);
+        self.group.increment_size()?;
+        let member_number = self.member_counter.next_member_number()?;
+
+        create_account_with_minimum_balance(
+            member.clone(),
+            self.payer.to_account_info(),
+            self.system_program.to_account_info(),
+            TokenGroupMember::LEN,
+            0,
+            &crate::id(),
+            &[&[MEMBER_ACCOUNT_SEED, mint.key.as_ref(), &[member_bump]]],
+        )?;
+        TokenGroupMember {
+            mint: mint.key(),
+            group: group_key,
+            member_number,
+        }
+        .try_serialize(&mut &mut member.try_borrow_mut_data()?[..])?;
+
+        index_member(
+            member_index.clone(),
+            self.payer.to_account_info(),
+            self.system_program.to_account_info(),
+            group_key,
+            member_number,
+            mint.key(),
+        )?;
+
+        let cpi_accounts = GroupMemberPointerUpdate {
+            token_program_id: self.token_program.to_account_info(),
+            mint: mint.clone(),
+            authority: self.authority.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
+        group_member_pointer_update(cpi_ctx, Some(member.key()))?;
+
+        // members resolve the group accounts in the transfer hook, with or without royalties
+        let metadata = get_metadata(mint, external_metadata_account)?;
+        if extra_metas_account.owner == &crate::ID {
+            write_meta_list(
+                extra_metas_account,
+                get_royalty_basis_points(&metadata) > 0,
+                Some(group_key),
+            )?;
+            if !has_wns_transfer_hook(mint)? {
+                let cpi_accounts = TransferHookUpdate {
+                    token_program_id: self.token_program.to_account_info(),
+                    mint: mint.clone(),
+                    authority: self.authority.to_account_info(),
+                };
+                let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
+                transfer_hook_update(cpi_ctx, Some(crate::id()))?;
+            }
+            update_account_lamports_to_minimum_balance(
+                extra_metas_account.clone(),
+                self.payer.to_account_info(),
+                self.system_program.to_account_info(),
+            )?;
+        }
+
+        Ok(member_number)
+    }
+}
+
+/// Returns the member numbers assigned to the mints, in order
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, AddGroupMembersBatch<'info>>,
+) -> Result<Vec<u32>> {
+    validate_group_member_authority(
+        &ctx.accounts.group,
+        &ctx.accounts.group_delegates.to_account_info(),
+        &ctx.accounts.group_permission.to_account_info(),
+        ctx.accounts.authority.key,
+    )?;
+    if GroupFinalization::load(&ctx.accounts.finalization.to_account_info())?.is_some() {
+        return Err(MetadataErrors::GroupFinalized.into());
+    }
+
+    let remaining_accounts = ctx.remaining_accounts;
+    if remaining_accounts.is_empty()
+        || remaining_accounts.len() % BATCH_MEMBER_ACCOUNTS_CHUNK_SIZE != 0
+    {
+        return Err(MetadataErrors::InvalidMemberBatchAccounts.into());
+    }
+    if remaining_accounts.len() / BATCH_MEMBER_ACCOUNTS_CHUNK_SIZE > MAX_MEMBER_BATCH_SIZE {
+        return Err(MetadataErrors::TooManyBatchMembers.into());
+    }
+
+    let mut member_numbers = vec![];
+    for (index, accounts) in remaining_accounts
+        .chunks(BATCH_MEMBER_ACCOUNTS_CHUNK_SIZE)
+        .enumerate()
+    {
+        let member_number = ctx.accounts.add_member(accounts).map_err(|err| {
+            msg!("Failed to add member at batch index {}", index);
+            err
+        })?;
+        member_numbers.push(member_number);
+    }
+
+    let group = ctx.accounts.group.key();
+    for (accounts, member_number) in remaining_accounts
+        .chunks(BATCH_MEMBER_ACCOUNTS_CHUNK_SIZE)
+        .zip(member_numbers.iter())
+    {
+        emit_cpi!(MemberAdded {
+            group,
+            mint: accounts[0].key(),
+            member_number: *member_number,
+        });
+    }
+
+    // a single size change for the whole batch
+    emit_cpi!(GroupSizeChanged {
+        group: ctx.accounts.group.key(),
+        size: ctx.accounts.group.size,
+        max_size: ctx.accounts.group.max_size,
+    });
+
+    Ok(member_numbers)
+}
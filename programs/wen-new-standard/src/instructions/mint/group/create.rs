@@ -0,0 +1,333 @@
+use anchor_lang::prelude::*;
+
+use anchor_spl::{
+    associated_token::{get_associated_token_address_with_program_id, AssociatedToken},
+    token_interface::Token2022,
+};
+
+use crate::{
+    get_external_metadata_account, get_parent_royalty_defaults, index_member,
+    validate_group_member_authority, validate_group_policy, verify_allowlist_proof, AllowlistClaim,
+    CreateMintAccountArgs, GroupFinalization, GroupMemberCounter, GroupRoyaltyDefaults,
+    GroupSizeChanged, GroupSoulbound, Manager, MemberAdded, MetadataErrors, MintAccounts,
+    MintAllowlist, MintCreated, TokenGroup, TokenGroupMember, ALLOWLIST_CLAIM_SEED, ALLOWLIST_SEED,
+    GROUP_DELEGATES_SEED, GROUP_FINALIZATION_SEED, GROUP_HIERARCHY_SEED, GROUP_MEMBER_COUNTER_SEED,
+    GROUP_PERMISSION_SEED, GROUP_ROYALTY_DEFAULTS_SEED, GROUP_SOULBOUND_SEED, MANAGER_SEED,
+    MEMBER_ACCOUNT_SEED, META_LIST_ACCOUNT_SEED, POLICY_SEED,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(args: CreateMintAccountArgs)]
+pub struct CreateGroupMemberMint<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// mint authority, must be the group authority, one of its delegates or a delegate granted
+    /// `ADD_MEMBER` since the mint joins the group
+    pub authority: Signer<'info>,
+    #[account()]
+    /// CHECK: can be any account
+    pub receiver: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub group: Account<'info, TokenGroup>,
+    /// CHECK: delegates PDA of the group, uninitialized when the group has no delegates
+    #[account(
+        seeds = [GROUP_DELEGATES_SEED, group.key().as_ref()],
+        bump,
+    )]
+    pub group_delegates: UncheckedAccount<'info>,
+    /// CHECK: permission PDA of the authority in the group, uninitialized without a grant
+    #[account(
+        seeds = [GROUP_PERMISSION_SEED, group.key().as_ref(), authority.key().as_ref()],
+        bump,
+    )]
+    pub group_permission: UncheckedAccount<'info>,
+    #[account(
+        init,
+        seeds = [MEMBER_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+        payer = payer,
+        space = TokenGroupMember::LEN
+    )]
+    pub member: Account<'info, TokenGroupMember>,
+    /// last member number assigned in the group
+    #[account(
+        init_if_needed,
+        seeds = [GROUP_MEMBER_COUNTER_SEED, group.key().as_ref()],
+        bump,
+        payer = payer,
+        space = GroupMemberCounter::LEN
+    )]
+    pub member_counter: Account<'info, GroupMemberCounter>,
+    /// CHECK: member index page PDA of the new member number, created in the handler
+    #[account(mut)]
+    pub member_index: UncheckedAccount<'info>,
+    /// CHECK: finalization PDA of the group, uninitialized until the group is finalized
+    #[account(
+        seeds = [GROUP_FINALIZATION_SEED, group.key().as_ref()],
+        bump,
+    )]
+    pub finalization: UncheckedAccount<'info>,
+    /// CHECK: soulbound PDA of the group, uninitialized for transferable groups
+    #[account(
+        seeds = [GROUP_SOULBOUND_SEED, group.key().as_ref()],
+        bump,
+    )]
+    pub soulbound: UncheckedAccount<'info>,
+    /// CHECK: group extension policy PDA, uninitialized when the group has no policy
+    #[account(
+        seeds = [POLICY_SEED, group.key().as_ref()],
+        bump,
+    )]
+    pub policy: UncheckedAccount<'info>,
+    /// CHECK: group mint allowlist PDA, uninitialized when the group has no allowlist
+    #[account(
+        seeds = [ALLOWLIST_SEED, group.key().as_ref()],
+        bump,
+    )]
+    pub allowlist: UncheckedAccount<'info>,
+    /// receiver mint count, only needed when the group has an allowlist
+    #[account(
+        init_if_needed,
+        seeds = [ALLOWLIST_CLAIM_SEED, group.key().as_ref(), receiver.key().as_ref()],
+        bump,
+        payer = payer,
+        space = AllowlistClaim::LEN
+    )]
+    pub allowlist_claim: Option<Account<'info, AllowlistClaim>>,
+    /// CHECK: group royalty defaults PDA, uninitialized when the group has no defaults
+    #[account(
+        seeds = [GROUP_ROYALTY_DEFAULTS_SEED, group.key().as_ref()],
+        bump,
+    )]
+    pub royalty_defaults: UncheckedAccount<'info>,
+    /// CHECK: hierarchy PDA of the group, uninitialized when the group has no parent
+    #[account(
+        seeds = [GROUP_HIERARCHY_SEED, group.key().as_ref()],
+        bump,
+    )]
+    pub hierarchy: UncheckedAccount<'info>,
+    /// CHECK: royalty defaults PDA of the parent group, only needed for sub-groups
+    pub parent_royalty_defaults: Option<UncheckedAccount<'info>>,
+    /// CHECK: group mint, only needed to write the native group member extension of groups
+    /// migrated to the native token group extension, behind the `native-group` feature
+    #[account(
+        mut,
+        address = group.mint,
+    )]
+    pub group_mint: Option<UncheckedAccount<'info>>,
+    #[account(mut, signer)]
+    /// CHECK: initialized in the handler, extensions depend on args
+    pub mint: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = get_associated_token_address_with_program_id(
+            &receiver.key(),
+            &mint.key(),
+            &token_program.key(),
+        ),
+    )]
+    /// CHECK: initialized in the handler as the receiver's associated token account
+    pub mint_token_account: UncheckedAccount<'info>,
+    /// CHECK: This account's data is a buffer of TLV data, initialized in the handler
+    #[account(
+        mut,
+        seeds = [META_LIST_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub extra_metas_account: UncheckedAccount<'info>,
+    /// CHECK: metadata PDA of mints with external metadata, validated in the handler
+    #[account(mut)]
+    pub metadata_account: Option<UncheckedAccount<'info>>,
+    /// CHECK: mint record PDA, only needed when `args.mint_record` is set
+    #[account(mut)]
+    pub mint_record: Option<UncheckedAccount<'info>>,
+    /// global manager, or the manager namespaced to `args.manager_authority`
+    #[account(
+        seeds = [MANAGER_SEED, Manager::namespace(args.manager_authority.as_ref())],
+        bump
+    )]
+    pub manager: Account<'info, Manager>,
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+impl<'info> CreateGroupMemberMint<'info> {
+    fn mint_accounts(&self) -> MintAccounts<'info> {
+        MintAccounts {
+            payer: self.payer.to_account_info(),
+            authority: self.authority.to_account_info(),
+            receiver: Some(self.receiver.to_account_info()),
+            mint: self.mint.to_account_info(),
+            mint_token_account: Some(self.mint_token_account.to_account_info()),
+            extra_metas_account: self.extra_metas_account.to_account_info(),
+            metadata_account: self
+                .metadata_account
+                .as_ref()
+                .map(|metadata_account| metadata_account.to_account_info()),
+            mint_record: self
+                .mint_record
+                .as_ref()
+                .map(|mint_record| mint_record.to_account_info()),
+            manager: self.manager.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+            associated_token_program: self.associated_token_program.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+        }
+    }
+}
+
+/// Creates a mint and registers it as a member of `group` in a single instruction.
+/// The group membership is written before anything is minted, so a full group
+/// fails the whole instruction and no orphaned mint is left pointing at the member PDA.
+/// `proof` is only needed when the group has an allowlist. Members of soulbound groups are
+/// always created soulbound.
+/// Mints created without royalties inherit the group royalty defaults, or those of its parent group.
+pub fn handler(
+    ctx: Context<CreateGroupMemberMint>,
+    mut args: CreateMintAccountArgs,
+    proof: Option<Vec<[u8; 32]>>,
+) -> Result<()> {
+    validate_group_member_authority(
+        &ctx.accounts.group,
+        &ctx.accounts.group_delegates.to_account_info(),
+        &ctx.accounts.group_permission.to_account_info(),
+        ctx.accounts.authority.key,
+    )?;
+    if ctx.accounts.group.is_full() {
+        return Err(MetadataErrors::GroupFull.into());
+    }
+    if GroupFinalization::load(&ctx.accounts.finalization.to_account_info())?.is_some() {
+        return Err(MetadataErrors::GroupFinalized.into());
+    }
+
+    // members of soulbound groups are created non-transferable
+    if GroupSoulbound::load(&ctx.accounts.soulbound.to_account_info())?.is_some() {
+        args.soulbound = true;
+    }
+
+    // allowlisted groups only mint to receivers in the allowlist, up to the per-wallet limit
+    if let Some(allowlist) = MintAllowlist::load(&ctx.accounts.allowlist.to_account_info())? {
+        let proof = proof.ok_or(MetadataErrors::InvalidAllowlistProof)?;
+        if !verify_allowlist_proof(&proof, &allowlist.root, ctx.accounts.receiver.key) {
+            return Err(MetadataErrors::InvalidAllowlistProof.into());
+        }
+        let allowlist_claim = ctx
+            .accounts
+            .allowlist_claim
+            .as_mut()
+            .ok_or(MetadataErrors::InvalidAllowlistClaimAccount)?;
+        if allowlist_claim.count >= allowlist.limit_per_wallet {
+            return Err(MetadataErrors::AllowlistLimitReached.into());
+        }
+        allowlist_claim.count += 1;
+    }
+
+    // the group defaults apply first, then the defaults of its parent
+    if args.royalties.is_none() {
+        let royalty_defaults =
+            match GroupRoyaltyDefaults::load(&ctx.accounts.royalty_defaults.to_account_info())? {
+                Some(royalty_defaults) => Some(royalty_defaults),
+                None => {
+                    let parent_royalty_defaults =
+                        ctx.accounts.parent_royalty_defaults.as_ref().map(
+                            |parent_royalty_defaults| parent_royalty_defaults.to_account_info(),
+                        );
+                    get_parent_royalty_defaults(
+                        &ctx.accounts.hierarchy.to_account_info(),
+                        parent_royalty_defaults.as_ref(),
+                    )?
+                }
+            };
+        args.royalties = royalty_defaults.map(|royalty_defaults| royalty_defaults.royalties());
+    }
+
+    // add member to group
+    let group = &mut ctx.accounts.group;
+    let member_counter = &mut ctx.accounts.member_counter;
+    member_counter.init(group.key(), group.size);
+    group.increment_size()?;
+
+    let member = &mut ctx.accounts.member;
+    member.group = group.key();
+    member.mint = ctx.accounts.mint.key();
+    member.member_number = member_counter.next_member_number()?;
+    index_member(
+        ctx.accounts.member_index.to_account_info(),
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.group.key(),
+        ctx.accounts.member.member_number,
+        ctx.accounts.mint.key(),
+    )?;
+
+    let event = MintCreated {
+        mint: ctx.accounts.mint.key(),
+        authority: ctx.accounts.authority.key(),
+        receiver: ctx.accounts.receiver.key(),
+        name: args.name.clone(),
+        symbol: args.symbol.clone(),
+        uri: args.uri.clone(),
+    };
+
+    #[cfg(feature = "native-group")]
+    let manager_authority = args.manager_authority;
+    // create the mint with its group member pointer set to the member account
+    let member_address = ctx.accounts.member.key();
+    ctx.accounts.mint_accounts().create(
+        args,
+        Some(member_address),
+        Some(ctx.accounts.group.key()),
+        ctx.bumps.extra_metas_account,
+        ctx.bumps.manager,
+        &[],
+    )?;
+
+    // the created mint must satisfy the group extension policy
+    let mint = ctx.accounts.mint.to_account_info();
+    let metadata_account = ctx
+        .accounts
+        .metadata_account
+        .as_ref()
+        .map(|metadata_account| metadata_account.to_account_info());
+    let external_metadata_account =
+        get_external_metadata_account(&mint, metadata_account.as_ref())?;
+    validate_group_policy(
+        &ctx.accounts.policy.to_account_info(),
+        &mint,
+        external_metadata_account,
+    )?;
+
+    #[cfg(feature = "native-group")]
+    if let Some(group_mint) = ctx.accounts.group_mint.as_ref() {
+        crate::initialize_native_member(
+            mint.clone(),
+            group_mint.to_account_info(),
+            ctx.accounts.authority.to_account_info(),
+            ctx.accounts.manager.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            Manager::namespace(manager_authority.as_ref()),
+            ctx.bumps.manager,
+        )?;
+        crate::update_account_lamports_to_minimum_balance(
+            mint,
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        )?;
+    }
+
+    emit_cpi!(event);
+    emit_cpi!(MemberAdded {
+        group: ctx.accounts.group.key(),
+        mint: ctx.accounts.mint.key(),
+        member_number: ctx.accounts.member.member_number,
+    });
+    emit_cpi!(GroupSizeChanged {
+        group: ctx.accounts.group.key(),
+        size: ctx.accounts.group.size,
+        max_size: ctx.accounts.group.max_size,
+    });
+
+    Ok(())
+}
@@ -1,3 +1,15 @@
 pub mod add;
+pub mod add_batch;
+pub mod create;
+pub mod move_member;
+pub mod native;
+pub mod public;
+pub mod remove;
 
 pub use add::*;
+pub use add_batch::*;
+pub use create::*;
+pub use move_member::*;
+pub use native::*;
+pub use public::*;
+pub use remove::*;
@@ -0,0 +1,247 @@
+use anchor_lang::prelude::*;
+
+use anchor_spl::token_interface::{Mint, Token2022};
+
+use crate::{
+    get_external_metadata_account, get_metadata, get_royalty_basis_points, index_member,
+    unindex_member, update_account_lamports_to_minimum_balance, validate_group_member_authority,
+    validate_group_policy, validate_group_soulbound, validate_member_mint, write_meta_list,
+    GroupFinalization, GroupMemberCounter, GroupSizeChanged, MemberAdded, MemberRemoved,
+    MetadataErrors, TokenGroup, TokenGroupMember, GROUP_DELEGATES_SEED, GROUP_FINALIZATION_SEED,
+    GROUP_MEMBER_COUNTER_SEED, GROUP_PERMISSION_SEED, GROUP_SOULBOUND_SEED, MEMBER_ACCOUNT_SEED,
+    META_LIST_ACCOUNT_SEED, POLICY_SEED, TOKEN22,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction()]
+pub struct MoveMember<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// source group authority, one of its delegates or a delegate granted `ADD_MEMBER`
+    pub source_authority: Signer<'info>,
+    #[account(mut)]
+    pub source_group: Box<Account<'info, TokenGroup>>,
+    /// CHECK: delegates PDA of the source group, uninitialized when the group has no delegates
+    #[account(
+        seeds = [GROUP_DELEGATES_SEED, source_group.key().as_ref()],
+        bump,
+    )]
+    pub source_group_delegates: UncheckedAccount<'info>,
+    /// CHECK: permission PDA of the source authority, uninitialized without a grant
+    #[account(
+        seeds = [
+            GROUP_PERMISSION_SEED,
+            source_group.key().as_ref(),
+            source_authority.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub source_group_permission: UncheckedAccount<'info>,
+    /// last member number assigned in the source group, created before the size shrinks
+    #[account(
+        init_if_needed,
+        seeds = [GROUP_MEMBER_COUNTER_SEED, source_group.key().as_ref()],
+        bump,
+        payer = payer,
+        space = GroupMemberCounter::LEN
+    )]
+    pub source_member_counter: Box<Account<'info, GroupMemberCounter>>,
+    /// CHECK: source member index page PDA of the current member number
+    #[account(mut)]
+    pub source_member_index: UncheckedAccount<'info>,
+    /// destination group authority, one of its delegates or a delegate granted `ADD_MEMBER`
+    pub destination_authority: Signer<'info>,
+    #[account(mut)]
+    pub destination_group: Box<Account<'info, TokenGroup>>,
+    /// CHECK: delegates PDA of the destination group, uninitialized when the group has no delegates
+    #[account(
+        seeds = [GROUP_DELEGATES_SEED, destination_group.key().as_ref()],
+        bump,
+    )]
+    pub destination_group_delegates: UncheckedAccount<'info>,
+    /// CHECK: permission PDA of the destination authority, uninitialized without a grant
+    #[account(
+        seeds = [
+            GROUP_PERMISSION_SEED,
+            destination_group.key().as_ref(),
+            destination_authority.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub destination_group_permission: UncheckedAccount<'info>,
+    /// last member number assigned in the destination group
+    #[account(
+        init_if_needed,
+        seeds = [GROUP_MEMBER_COUNTER_SEED, destination_group.key().as_ref()],
+        bump,
+        payer = payer,
+        space = GroupMemberCounter::LEN
+    )]
+    pub destination_member_counter: Box<Account<'info, GroupMemberCounter>>,
+    /// CHECK: destination member index page PDA of the new member number, created in the handler
+    #[account(mut)]
+    pub destination_member_index: UncheckedAccount<'info>,
+    /// CHECK: finalization PDA of the destination group, uninitialized until the group is finalized
+    #[account(
+        seeds = [GROUP_FINALIZATION_SEED, destination_group.key().as_ref()],
+        bump,
+    )]
+    pub destination_finalization: UncheckedAccount<'info>,
+    /// CHECK: soulbound PDA of the destination group, uninitialized for transferable groups
+    #[account(
+        seeds = [GROUP_SOULBOUND_SEED, destination_group.key().as_ref()],
+        bump,
+    )]
+    pub destination_soulbound: UncheckedAccount<'info>,
+    /// CHECK: extension policy PDA of the destination group, uninitialized when the group has no policy
+    #[account(
+        seeds = [POLICY_SEED, destination_group.key().as_ref()],
+        bump,
+    )]
+    pub destination_policy: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [MEMBER_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+        constraint = member.group == source_group.key() @ MetadataErrors::InvalidGroupMemberAccount,
+    )]
+    pub member: Box<Account<'info, TokenGroupMember>>,
+    #[account(
+        mint::token_program = TOKEN22
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    /// CHECK: account referenced by the metadata pointer, only needed for external metadata
+    pub metadata_account: Option<UncheckedAccount<'info>>,
+    /// CHECK: This account's data is a buffer of TLV data
+    #[account(
+        mut,
+        seeds = [META_LIST_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub extra_metas_account: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+/// Moves a member mint from its group to another group in a single instruction, both group
+/// authorities or their delegates must sign. The mint gets the next member number of the
+/// destination group and must pass the same checks as `add_group_to_mint`. The group member
+/// pointer of the mint keeps pointing at the same member account, which is derived from the
+/// mint alone. Returns the new member number.
+pub fn handler(ctx: Context<MoveMember>) -> Result<u32> {
+    validate_group_member_authority(
+        &ctx.accounts.source_group,
+        &ctx.accounts.source_group_delegates.to_account_info(),
+        &ctx.accounts.source_group_permission.to_account_info(),
+        ctx.accounts.source_authority.key,
+    )?;
+    validate_group_member_authority(
+        &ctx.accounts.destination_group,
+        &ctx.accounts.destination_group_delegates.to_account_info(),
+        &ctx.accounts.destination_group_permission.to_account_info(),
+        ctx.accounts.destination_authority.key,
+    )?;
+    let source_group = ctx.accounts.source_group.key();
+    let destination_group = ctx.accounts.destination_group.key();
+    if source_group == destination_group {
+        return Err(MetadataErrors::InvalidDestinationGroup.into());
+    }
+    if ctx.accounts.destination_group.is_full() {
+        return Err(MetadataErrors::GroupFull.into());
+    }
+    if GroupFinalization::load(&ctx.accounts.destination_finalization.to_account_info())?.is_some()
+    {
+        return Err(MetadataErrors::GroupFinalized.into());
+    }
+
+    // the mint must satisfy the destination group like a newly added member
+    let mint = ctx.accounts.mint.to_account_info();
+    validate_member_mint(&mint, &ctx.accounts.member.key())?;
+    validate_group_soulbound(&ctx.accounts.destination_soulbound.to_account_info(), &mint)?;
+    let metadata_account = ctx
+        .accounts
+        .metadata_account
+        .as_ref()
+        .map(|metadata_account| metadata_account.to_account_info());
+    let external_metadata_account =
+        get_external_metadata_account(&mint, metadata_account.as_ref())?;
+    validate_group_policy(
+        &ctx.accounts.destination_policy.to_account_info(),
+        &mint,
+        external_metadata_account,
+    )?;
+
+    // leave the source group, its member numbers are never reused
+    let old_member_number = ctx.accounts.member.member_number;
+    let size = ctx.accounts.source_group.size;
+    ctx.accounts.source_member_counter.init(source_group, size);
+    ctx.accounts.source_group.decrement_size()?;
+    unindex_member(
+        &ctx.accounts.source_member_index.to_account_info(),
+        source_group,
+        old_member_number,
+        ctx.accounts.mint.key(),
+    )?;
+
+    // join the destination group
+    let size = ctx.accounts.destination_group.size;
+    ctx.accounts
+        .destination_member_counter
+        .init(destination_group, size);
+    ctx.accounts.destination_group.increment_size()?;
+    let member_number = ctx
+        .accounts
+        .destination_member_counter
+        .next_member_number()?;
+    let member = &mut ctx.accounts.member;
+    member.group = destination_group;
+    member.member_number = member_number;
+    index_member(
+        ctx.accounts.destination_member_index.to_account_info(),
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        destination_group,
+        member_number,
+        ctx.accounts.mint.key(),
+    )?;
+
+    // members resolve the accounts of the new group in the transfer hook, with or without royalties
+    let extra_metas_account = ctx.accounts.extra_metas_account.to_account_info();
+    let metadata = get_metadata(&mint, external_metadata_account)?;
+    if extra_metas_account.owner == &crate::ID {
+        write_meta_list(
+            &extra_metas_account,
+            get_royalty_basis_points(&metadata) > 0,
+            Some(destination_group),
+        )?;
+        update_account_lamports_to_minimum_balance(
+            extra_metas_account,
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        )?;
+    }
+
+    emit_cpi!(MemberRemoved {
+        group: source_group,
+        mint: ctx.accounts.mint.key(),
+        member_number: old_member_number,
+    });
+    emit_cpi!(GroupSizeChanged {
+        group: source_group,
+        size: ctx.accounts.source_group.size,
+        max_size: ctx.accounts.source_group.max_size,
+    });
+    emit_cpi!(MemberAdded {
+        group: destination_group,
+        mint: ctx.accounts.mint.key(),
+        member_number,
+    });
+    emit_cpi!(GroupSizeChanged {
+        group: destination_group,
+        size: ctx.accounts.destination_group.size,
+        max_size: ctx.accounts.destination_group.max_size,
+    });
+
+    Ok(member_number)
+}
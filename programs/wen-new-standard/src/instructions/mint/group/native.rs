@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, Token2022};
+
+use crate::{
+    initialize_native_member, update_account_lamports_to_minimum_balance, Manager, TokenGroup,
+    TokenGroupMember, MANAGER_SEED, MEMBER_ACCOUNT_SEED,
+};
+
+#[derive(Accounts)]
+#[instruction()]
+pub struct MigrateMemberToNative<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub authority: Signer<'info>,
+    /// group member pointer authority of the mint, usually its creator
+    pub member_pointer_authority: Signer<'info>,
+    #[account(
+        constraint = group.update_authority == authority.key(),
+    )]
+    pub group: Account<'info, TokenGroup>,
+    #[account(
+        seeds = [MEMBER_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+        has_one = group,
+    )]
+    pub member: Account<'info, TokenGroupMember>,
+    #[account(
+        mut,
+        mint::token_program = token_program,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    /// group mint, migrated with `migrate_group_to_native`
+    #[account(
+        mut,
+        address = group.mint,
+        mint::token_program = token_program,
+    )]
+    pub group_mint: Box<InterfaceAccount<'info, Mint>>,
+    /// CHECK: authority the manager is namespaced to, omitted for the global manager
+    pub manager_authority: Option<UncheckedAccount<'info>>,
+    #[account(
+        seeds = [
+            MANAGER_SEED,
+            Manager::namespace(manager_authority.as_ref().map(|authority| authority.key)),
+        ],
+        bump
+    )]
+    pub manager: Account<'info, Manager>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+/// Initializes the native token group member extension in a member mint of a migrated group.
+/// The WNS member account is kept and stays authoritative, the native member number follows
+/// the migration order so members should be migrated by member number.
+pub fn handler(ctx: Context<MigrateMemberToNative>) -> Result<()> {
+    let manager_namespace = Manager::namespace(
+        ctx.accounts
+            .manager_authority
+            .as_ref()
+            .map(|authority| authority.key),
+    );
+    initialize_native_member(
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.group_mint.to_account_info(),
+        ctx.accounts.member_pointer_authority.to_account_info(),
+        ctx.accounts.manager.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        manager_namespace,
+        ctx.bumps.manager,
+    )?;
+
+    // update mint lamports to minimum rent balance
+    update_account_lamports_to_minimum_balance(
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,373 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+
+use anchor_spl::{
+    associated_token::{get_associated_token_address_with_program_id, AssociatedToken},
+    token_interface::{
+        set_authority, spl_token_2022::instruction::AuthorityType, transfer_checked, Mint,
+        SetAuthority, Token2022, TokenAccount, TokenInterface, TransferChecked,
+    },
+};
+
+use crate::{
+    get_parent_royalty_defaults, index_member, validate_group_policy, CreateMintAccountArgs,
+    GroupFinalization, GroupMemberCounter, GroupRoyaltyDefaults, GroupSizeChanged, GroupSoulbound,
+    Manager, MemberAdded, MetadataErrors, MintAccounts, MintConfig, MintCreated, PublicMintClaim,
+    TokenGroup, TokenGroupMember, GROUP_FINALIZATION_SEED, GROUP_HIERARCHY_SEED,
+    GROUP_MEMBER_COUNTER_SEED, GROUP_ROYALTY_DEFAULTS_SEED, GROUP_SOULBOUND_SEED, MANAGER_SEED,
+    MEMBER_ACCOUNT_SEED, META_LIST_ACCOUNT_SEED, MINT_CONFIG_SEED, POLICY_SEED,
+    PUBLIC_MINT_CLAIM_SEED,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction()]
+pub struct PublicMint<'info> {
+    /// pays the price and receives the member mint
+    #[account(mut)]
+    pub minter: Signer<'info>,
+    #[account(mut)]
+    pub group: Box<Account<'info, TokenGroup>>,
+    #[account(
+        seeds = [MINT_CONFIG_SEED, group.key().as_ref()],
+        bump,
+        has_one = group,
+    )]
+    pub mint_config: Box<Account<'info, MintConfig>>,
+    /// member mints received by the minter through the public mint
+    #[account(
+        init_if_needed,
+        seeds = [PUBLIC_MINT_CLAIM_SEED, group.key().as_ref(), minter.key().as_ref()],
+        bump,
+        payer = minter,
+        space = PublicMintClaim::LEN
+    )]
+    pub public_mint_claim: Box<Account<'info, PublicMintClaim>>,
+    /// CHECK: treasury of the public mint, receives SOL prices
+    #[account(
+        mut,
+        address = mint_config.treasury,
+    )]
+    pub treasury: UncheckedAccount<'info>,
+    /// only needed for token prices
+    #[account(
+        address = mint_config.payment_mint,
+        mint::token_program = payment_token_program,
+    )]
+    pub payment_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+    /// token account of the minter, only needed for token prices
+    #[account(
+        mut,
+        token::mint = payment_mint,
+        token::authority = minter,
+        token::token_program = payment_token_program,
+    )]
+    pub minter_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    /// associated token account of the treasury, only needed for token prices
+    #[account(
+        mut,
+        associated_token::mint = payment_mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = payment_token_program,
+    )]
+    pub treasury_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    /// token program of the payment mint, unused for SOL prices
+    pub payment_token_program: Interface<'info, TokenInterface>,
+    #[account(
+        init,
+        seeds = [MEMBER_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+        payer = minter,
+        space = TokenGroupMember::LEN
+    )]
+    pub member: Box<Account<'info, TokenGroupMember>>,
+    /// last member number assigned in the group
+    #[account(
+        init_if_needed,
+        seeds = [GROUP_MEMBER_COUNTER_SEED, group.key().as_ref()],
+        bump,
+        payer = minter,
+        space = GroupMemberCounter::LEN
+    )]
+    pub member_counter: Box<Account<'info, GroupMemberCounter>>,
+    /// CHECK: member index page PDA of the new member number, created in the handler
+    #[account(mut)]
+    pub member_index: UncheckedAccount<'info>,
+    /// CHECK: finalization PDA of the group, uninitialized until the group is finalized
+    #[account(
+        seeds = [GROUP_FINALIZATION_SEED, group.key().as_ref()],
+        bump,
+    )]
+    pub finalization: UncheckedAccount<'info>,
+    /// CHECK: soulbound PDA of the group, uninitialized for transferable groups
+    #[account(
+        seeds = [GROUP_SOULBOUND_SEED, group.key().as_ref()],
+        bump,
+    )]
+    pub soulbound: UncheckedAccount<'info>,
+    /// CHECK: group extension policy PDA, uninitialized when the group has no policy
+    #[account(
+        seeds = [POLICY_SEED, group.key().as_ref()],
+        bump,
+    )]
+    pub policy: UncheckedAccount<'info>,
+    /// CHECK: group royalty defaults PDA, uninitialized when the group has no defaults
+    #[account(
+        seeds = [GROUP_ROYALTY_DEFAULTS_SEED, group.key().as_ref()],
+        bump,
+    )]
+    pub royalty_defaults: UncheckedAccount<'info>,
+    /// CHECK: hierarchy PDA of the group, uninitialized when the group has no parent
+    #[account(
+        seeds = [GROUP_HIERARCHY_SEED, group.key().as_ref()],
+        bump,
+    )]
+    pub hierarchy: UncheckedAccount<'info>,
+    /// CHECK: royalty defaults PDA of the parent group, only needed for sub-groups
+    pub parent_royalty_defaults: Option<UncheckedAccount<'info>>,
+    #[account(mut, signer)]
+    /// CHECK: initialized in the handler
+    pub mint: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = get_associated_token_address_with_program_id(
+            &minter.key(),
+            &mint.key(),
+            &token_program.key(),
+        ),
+    )]
+    /// CHECK: initialized in the handler as the minter's associated token account
+    pub mint_token_account: UncheckedAccount<'info>,
+    /// CHECK: This account's data is a buffer of TLV data, initialized in the handler
+    #[account(
+        mut,
+        seeds = [META_LIST_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub extra_metas_account: UncheckedAccount<'info>,
+    /// CHECK: authority the manager is namespaced to, omitted for the global manager
+    pub manager_authority: Option<UncheckedAccount<'info>>,
+    #[account(
+        seeds = [
+            MANAGER_SEED,
+            Manager::namespace(manager_authority.as_ref().map(|authority| authority.key)),
+        ],
+        bump
+    )]
+    pub manager: Box<Account<'info, Manager>>,
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+impl<'info> PublicMint<'info> {
+    fn mint_accounts(&self) -> MintAccounts<'info> {
+        MintAccounts {
+            payer: self.minter.to_account_info(),
+            authority: self.minter.to_account_info(),
+            receiver: Some(self.minter.to_account_info()),
+            mint: self.mint.to_account_info(),
+            mint_token_account: Some(self.mint_token_account.to_account_info()),
+            extra_metas_account: self.extra_metas_account.to_account_info(),
+            metadata_account: None,
+            mint_record: None,
+            manager: self.manager.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+            associated_token_program: self.associated_token_program.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+        }
+    }
+
+    fn pay_price(&self) -> Result<()> {
+        let price = self.mint_config.price;
+        if price == 0 {
+            return Ok(());
+        }
+        if self.mint_config.payment_mint == Pubkey::default() {
+            let cpi_accounts = Transfer {
+                from: self.minter.to_account_info(),
+                to: self.treasury.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new(self.system_program.to_account_info(), cpi_accounts);
+            return transfer(cpi_ctx, price);
+        }
+        let (Some(mint), Some(from), Some(to)) = (
+            self.payment_mint.as_ref(),
+            self.minter_token_account.as_ref(),
+            self.treasury_token_account.as_ref(),
+        ) else {
+            return Err(MetadataErrors::InvalidPaymentAccounts.into());
+        };
+        let cpi_accounts = TransferChecked {
+            from: from.to_account_info(),
+            mint: mint.to_account_info(),
+            to: to.to_account_info(),
+            authority: self.minter.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(self.payment_token_program.to_account_info(), cpi_accounts);
+        transfer_checked(cpi_ctx, price, mint.decimals)
+    }
+
+    /// The minter creates the mint, its member pointer and transfer hook are handed to the
+    /// group authority like the metadata
+    fn update_mint_pointer_authority(&self, authority_type: AuthorityType) -> Result<()> {
+        let cpi_accounts = SetAuthority {
+            current_authority: self.minter.to_account_info(),
+            account_or_mint: self.mint.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
+        set_authority(cpi_ctx, authority_type, Some(self.group.update_authority))
+    }
+}
+
+/// Creates a member mint of the group for any wallet paying the price of the group
+/// public mint to its treasury. The mint is named and located after its member number,
+/// inherits the group royalty defaults, or those of its parent group, and its metadata
+/// is updated by the group authority. The group allowlist doesn't apply to public mints.
+pub fn handler(ctx: Context<PublicMint>) -> Result<()> {
+    let mint_config = &ctx.accounts.mint_config;
+    let now = Clock::get()?.unix_timestamp;
+    if mint_config
+        .start_time
+        .is_some_and(|start_time| now < start_time)
+    {
+        return Err(MetadataErrors::MintNotStarted.into());
+    }
+    if mint_config.end_time.is_some_and(|end_time| now >= end_time) {
+        return Err(MetadataErrors::MintEnded.into());
+    }
+    if ctx.accounts.group.is_full() {
+        return Err(MetadataErrors::GroupFull.into());
+    }
+    if GroupFinalization::load(&ctx.accounts.finalization.to_account_info())?.is_some() {
+        return Err(MetadataErrors::GroupFinalized.into());
+    }
+
+    let limit_per_wallet = mint_config.limit_per_wallet;
+    let public_mint_claim = &mut ctx.accounts.public_mint_claim;
+    if limit_per_wallet != 0 && public_mint_claim.count >= limit_per_wallet {
+        return Err(MetadataErrors::PublicMintLimitReached.into());
+    }
+    public_mint_claim.count += 1;
+
+    ctx.accounts.pay_price()?;
+
+    // members of soulbound groups are created non-transferable, without royalties to enforce
+    let soulbound = GroupSoulbound::load(&ctx.accounts.soulbound.to_account_info())?.is_some();
+    let royalty_defaults = if soulbound {
+        None
+    } else {
+        match GroupRoyaltyDefaults::load(&ctx.accounts.royalty_defaults.to_account_info())? {
+            Some(royalty_defaults) => Some(royalty_defaults),
+            None => {
+                let parent_royalty_defaults = ctx
+                    .accounts
+                    .parent_royalty_defaults
+                    .as_ref()
+                    .map(|parent_royalty_defaults| parent_royalty_defaults.to_account_info());
+                get_parent_royalty_defaults(
+                    &ctx.accounts.hierarchy.to_account_info(),
+                    parent_royalty_defaults.as_ref(),
+                )?
+            }
+        }
+    };
+    let royalties = royalty_defaults.map(|royalty_defaults| royalty_defaults.royalties());
+
+    // add member to group
+    let group = &mut ctx.accounts.group;
+    let member_counter = &mut ctx.accounts.member_counter;
+    member_counter.init(group.key(), group.size);
+    group.increment_size()?;
+
+    let member = &mut ctx.accounts.member;
+    member.group = group.key();
+    member.mint = ctx.accounts.mint.key();
+    member.member_number = member_counter.next_member_number()?;
+    index_member(
+        ctx.accounts.member_index.to_account_info(),
+        ctx.accounts.minter.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.group.key(),
+        ctx.accounts.member.member_number,
+        ctx.accounts.mint.key(),
+    )?;
+
+    let (name, uri) = ctx
+        .accounts
+        .mint_config
+        .member_metadata(ctx.accounts.member.member_number);
+    let args = CreateMintAccountArgs {
+        name,
+        symbol: ctx.accounts.mint_config.symbol.clone(),
+        uri,
+        soulbound,
+        permanent_delegate: None,
+        frozen: false,
+        freeze_authority: None,
+        decimals: None,
+        initial_supply: None,
+        additional_metadata: vec![],
+        royalties,
+        update_authority: Some(ctx.accounts.group.update_authority),
+        immutable: false,
+        allow_pda_receiver: false,
+        external_metadata: false,
+        content_hash: None,
+        mint_record: false,
+        manager_authority: ctx
+            .accounts
+            .manager_authority
+            .as_ref()
+            .map(|authority| authority.key()),
+        provenance: false,
+    };
+    let event = MintCreated {
+        mint: ctx.accounts.mint.key(),
+        authority: ctx.accounts.minter.key(),
+        receiver: ctx.accounts.minter.key(),
+        name: args.name.clone(),
+        symbol: args.symbol.clone(),
+        uri: args.uri.clone(),
+    };
+
+    // create the mint with its group member pointer set to the member account
+    let member_address = ctx.accounts.member.key();
+    ctx.accounts.mint_accounts().create(
+        args,
+        Some(member_address),
+        Some(ctx.accounts.group.key()),
+        ctx.bumps.extra_metas_account,
+        ctx.bumps.manager,
+        &[],
+    )?;
+    ctx.accounts
+        .update_mint_pointer_authority(AuthorityType::GroupMemberPointer)?;
+    if !soulbound {
+        ctx.accounts
+            .update_mint_pointer_authority(AuthorityType::TransferHookProgramId)?;
+    }
+
+    // the created mint must satisfy the group extension policy
+    validate_group_policy(
+        &ctx.accounts.policy.to_account_info(),
+        &ctx.accounts.mint.to_account_info(),
+        None,
+    )?;
+
+    emit_cpi!(event);
+    emit_cpi!(MemberAdded {
+        group: ctx.accounts.group.key(),
+        mint: ctx.accounts.mint.key(),
+        member_number: ctx.accounts.member.member_number,
+    });
+    emit_cpi!(GroupSizeChanged {
+        group: ctx.accounts.group.key(),
+        size: ctx.accounts.group.size,
+        max_size: ctx.accounts.group.max_size,
+    });
+
+    Ok(())
+}
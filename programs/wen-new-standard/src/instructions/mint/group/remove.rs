@@ -0,0 +1,140 @@
+use anchor_lang::prelude::*;
+
+use anchor_spl::token_interface::{
+    group_member_pointer_update, GroupMemberPointerUpdate, Mint, Token2022,
+};
+
+use crate::{
+    get_external_metadata_account, get_metadata, get_royalty_basis_points,
+    refund_account_lamports_above_minimum_balance, unindex_member, write_meta_list,
+    GroupMemberCounter, GroupSizeChanged, MemberRemoved, TokenGroup, TokenGroupMember,
+    GROUP_MEMBER_COUNTER_SEED, MEMBER_ACCOUNT_SEED, META_LIST_ACCOUNT_SEED, TOKEN22,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction()]
+pub struct RemoveGroup<'info> {
+    /// group update authority and group member pointer authority of the mint,
+    /// refunded the freed rent by default
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        constraint = group.update_authority == authority.key(),
+    )]
+    pub group: Account<'info, TokenGroup>,
+    #[account(
+        mut,
+        seeds = [MEMBER_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+        has_one = group,
+    )]
+    pub member: Account<'info, TokenGroupMember>,
+    /// last member number assigned in the group, created before the size shrinks
+    #[account(
+        init_if_needed,
+        seeds = [GROUP_MEMBER_COUNTER_SEED, group.key().as_ref()],
+        bump,
+        payer = authority,
+        space = GroupMemberCounter::LEN
+    )]
+    pub member_counter: Account<'info, GroupMemberCounter>,
+    /// CHECK: member index page PDA of the member number
+    #[account(mut)]
+    pub member_index: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        mint::token_program = TOKEN22
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    /// CHECK: account referenced by the metadata pointer, only needed for external metadata
+    pub metadata_account: Option<UncheckedAccount<'info>>,
+    /// CHECK: This account's data is a buffer of TLV data
+    #[account(
+        mut,
+        seeds = [META_LIST_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub extra_metas_account: UncheckedAccount<'info>,
+    /// CHECK: receives the freed rent, defaults to the authority
+    #[account(mut)]
+    pub rent_destination: Option<UncheckedAccount<'info>>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+impl RemoveGroup<'_> {
+    fn clear_group_member_pointer_member_address(&self) -> Result<()> {
+        let cpi_accounts = GroupMemberPointerUpdate {
+            token_program_id: self.token_program.to_account_info(),
+            mint: self.mint.to_account_info(),
+            authority: self.authority.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
+        group_member_pointer_update(cpi_ctx, None)?;
+        Ok(())
+    }
+}
+
+/// Removes the mint from its group, closing the member account. The mint no longer
+/// points to a member, so it's treated as outside any group and can be added again.
+pub fn handler(ctx: Context<RemoveGroup>) -> Result<()> {
+    // member numbers keep counting from the current size, never reusing the removed number
+    let member_number = ctx.accounts.member.member_number;
+    let group = ctx.accounts.group.key();
+    let size = ctx.accounts.group.size;
+    ctx.accounts.member_counter.init(group, size);
+    ctx.accounts.group.decrement_size()?;
+    unindex_member(
+        &ctx.accounts.member_index.to_account_info(),
+        group,
+        member_number,
+        ctx.accounts.mint.key(),
+    )?;
+
+    // the mint no longer claims membership
+    ctx.accounts.clear_group_member_pointer_member_address()?;
+
+    let rent_destination = ctx
+        .accounts
+        .rent_destination
+        .as_ref()
+        .map(|rent_destination| rent_destination.to_account_info())
+        .unwrap_or(ctx.accounts.authority.to_account_info());
+
+    // the mint no longer resolves the group accounts in the transfer hook
+    let mint = ctx.accounts.mint.to_account_info();
+    let metadata_account = ctx
+        .accounts
+        .metadata_account
+        .as_ref()
+        .map(|metadata_account| metadata_account.to_account_info());
+    let external_metadata_account =
+        get_external_metadata_account(&mint, metadata_account.as_ref())?;
+    let metadata = get_metadata(&mint, external_metadata_account)?;
+    let extra_metas_account = ctx.accounts.extra_metas_account.to_account_info();
+    if extra_metas_account.owner == &crate::ID {
+        write_meta_list(
+            &extra_metas_account,
+            get_royalty_basis_points(&metadata) > 0,
+            None,
+        )?;
+        refund_account_lamports_above_minimum_balance(&extra_metas_account, &rent_destination)?;
+    }
+
+    ctx.accounts.member.close(rent_destination)?;
+
+    emit_cpi!(MemberRemoved {
+        group: ctx.accounts.group.key(),
+        mint: ctx.accounts.mint.key(),
+        member_number,
+    });
+    emit_cpi!(GroupSizeChanged {
+        group: ctx.accounts.group.key(),
+        size: ctx.accounts.group.size,
+        max_size: ctx.accounts.group.max_size,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::{Manager, ManagerInitialized, MANAGER_SEED};
+
+/// Creates the `Manager` PDA namespaced to `authority`, derived from `[MANAGER_SEED, authority]`.
+///
+/// Mints created with `manager_authority` set to `authority` hold their mint, freeze and close
+/// authorities through this manager instead of the global one shared by every mint.
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction()]
+pub struct InitManagerForAuthority<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub authority: Signer<'info>,
+    #[account(
+        init,
+        seeds = [MANAGER_SEED, authority.key().as_ref()],
+        bump,
+        payer = payer,
+        space = Manager::LEN
+    )]
+    pub manager: Account<'info, Manager>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitManagerForAuthority>) -> Result<()> {
+    emit_cpi!(ManagerInitialized {
+        manager: ctx.accounts.manager.key(),
+        authority: ctx.accounts.authority.key(),
+    });
+
+    Ok(())
+}
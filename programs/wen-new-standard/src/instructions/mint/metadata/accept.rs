@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+
+use anchor_spl::token_interface::{
+    token_metadata_update_authority, Mint, Token2022, TokenMetadataUpdateAuthority,
+};
+use spl_pod::optional_keys::OptionalNonZeroPubkey;
+
+use crate::{
+    get_external_metadata_account, get_metadata, pack_external_metadata,
+    MetadataAuthorityNomination, MetadataErrors, METADATA_NOMINATION_SEED,
+};
+
+#[derive(Accounts)]
+#[instruction()]
+pub struct AcceptMetadataAuthority<'info> {
+    pub nominee: Signer<'info>,
+    /// CHECK: nominator refunded the nomination rent, checked against the nomination
+    #[account(mut)]
+    pub authority: UncheckedAccount<'info>,
+    #[account(
+        mint::token_program = token_program,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    /// CHECK: account referenced by the metadata pointer, only needed for external metadata
+    #[account(mut)]
+    pub metadata_account: Option<UncheckedAccount<'info>>,
+    #[account(
+        mut,
+        seeds = [METADATA_NOMINATION_SEED, mint.key().as_ref()],
+        bump = nomination.bump,
+        has_one = nominee,
+        has_one = authority,
+        close = authority,
+    )]
+    pub nomination: Account<'info, MetadataAuthorityNomination>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+impl AcceptMetadataAuthority<'_> {
+    fn update_token_metadata_update_authority(&self) -> Result<()> {
+        let mint_key = self.mint.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            METADATA_NOMINATION_SEED,
+            mint_key.as_ref(),
+            &[self.nomination.bump],
+        ]];
+        let cpi_accounts = TokenMetadataUpdateAuthority {
+            token_program_id: self.token_program.to_account_info(),
+            metadata: self.mint.to_account_info(),
+            current_authority: self.nomination.to_account_info(),
+            new_authority: self.nominee.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        token_metadata_update_authority(
+            cpi_ctx,
+            OptionalNonZeroPubkey::try_from(Some(self.nominee.key()))?,
+        )?;
+        Ok(())
+    }
+}
+
+/// Makes the nominee the metadata update authority
+pub fn handler(ctx: Context<AcceptMetadataAuthority>) -> Result<()> {
+    let mint = ctx.accounts.mint.to_account_info();
+    let metadata_account = ctx
+        .accounts
+        .metadata_account
+        .as_ref()
+        .map(|metadata_account| metadata_account.to_account_info());
+    let external_metadata_account =
+        get_external_metadata_account(&mint, metadata_account.as_ref())?;
+    let mut metadata = get_metadata(&mint, external_metadata_account)?;
+
+    // the metadata update authority must not have changed since the nomination
+    let current_authority = Option::<Pubkey>::from(metadata.update_authority);
+    if let Some(metadata_account) = external_metadata_account {
+        if current_authority != Some(ctx.accounts.nomination.authority) {
+            return Err(MetadataErrors::StaleNomination.into());
+        }
+        metadata.update_authority =
+            OptionalNonZeroPubkey::try_from(Some(ctx.accounts.nominee.key()))?;
+        pack_external_metadata(metadata_account, &metadata)?;
+    } else {
+        if current_authority != Some(ctx.accounts.nomination.key()) {
+            return Err(MetadataErrors::StaleNomination.into());
+        }
+        ctx.accounts.update_token_metadata_update_authority()?;
+    }
+
+    Ok(())
+}
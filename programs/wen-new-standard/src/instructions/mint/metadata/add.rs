@@ -0,0 +1,146 @@
+use anchor_lang::{prelude::*, solana_program::entrypoint::ProgramResult};
+
+use anchor_spl::token_interface::{
+    spl_token_metadata_interface::state::Field, token_metadata_update_field, Mint, Token2022,
+    TokenMetadataUpdateField, TokenMetadataUpdateFieldArgs,
+};
+
+use crate::{
+    get_external_metadata_account, get_metadata, get_metadata_value,
+    update_account_lamports_to_minimum_balance, validate_additional_metadata_field,
+    validate_group_metadata_size, validate_metadata_mutable, validate_metadata_update_authority,
+    write_external_metadata, AddMetadataArgs, MetadataErrors, MetadataUpdated, TokenGroupMember,
+    MEMBER_ACCOUNT_SEED,
+};
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MetadataWriteMode {
+    /// Fails if the key already exists
+    Insert,
+    /// Fails if the key doesn't exist
+    Update,
+    /// Writes the key whether it exists or not
+    #[default]
+    Upsert,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(args: AddMetadataArgs)]
+pub struct AddMetadata<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// metadata update authority of the mint
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        mint::token_program = token_program,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    /// CHECK: account referenced by the metadata pointer, only needed for external metadata
+    #[account(mut)]
+    pub metadata_account: Option<UncheckedAccount<'info>>,
+    #[account(
+        seeds = [MEMBER_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub member: Option<Account<'info, TokenGroupMember>>,
+    /// CHECK: extension policy PDA of the member group, only needed for group members
+    pub policy: Option<UncheckedAccount<'info>>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+impl<'info> AddMetadata<'info> {
+    fn update_token_metadata_field(&self, field: Field, value: String) -> ProgramResult {
+        let cpi_accounts = TokenMetadataUpdateField {
+            token_program_id: self.token_program.to_account_info(),
+            metadata: self.mint.to_account_info(),
+            update_authority: self.authority.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
+        token_metadata_update_field(cpi_ctx, TokenMetadataUpdateFieldArgs { field, value })?;
+        Ok(())
+    }
+}
+
+/// `mode` defaults to `MetadataWriteMode::Upsert`
+pub fn handler(
+    ctx: Context<AddMetadata>,
+    args: AddMetadataArgs,
+    mode: Option<MetadataWriteMode>,
+) -> Result<()> {
+    validate_additional_metadata_field(&args.field, &args.value)?;
+
+    let mint = ctx.accounts.mint.to_account_info();
+    let metadata_account = ctx
+        .accounts
+        .metadata_account
+        .as_ref()
+        .map(|metadata_account| metadata_account.to_account_info());
+    let external_metadata_account =
+        get_external_metadata_account(&mint, metadata_account.as_ref())?;
+    let mut metadata = get_metadata(&mint, external_metadata_account)?;
+    validate_metadata_mutable(&metadata)?;
+
+    let key_exists = metadata
+        .additional_metadata
+        .iter()
+        .any(|(key, _)| *key == args.field);
+    match mode.unwrap_or_default() {
+        MetadataWriteMode::Insert if key_exists => {
+            return Err(MetadataErrors::MetadataKeyExists.into());
+        }
+        MetadataWriteMode::Update if !key_exists => {
+            return Err(MetadataErrors::MetadataKeyNotFound.into());
+        }
+        _ => {}
+    }
+
+    // group members can't grow past the group metadata size limit
+    let policy = ctx
+        .accounts
+        .policy
+        .as_ref()
+        .map(|policy| policy.to_account_info());
+    let mut updated_metadata = metadata.clone();
+    updated_metadata.update(Field::Key(args.field.clone()), args.value.clone());
+    validate_group_metadata_size(
+        &mint,
+        ctx.accounts.member.as_deref(),
+        policy.as_ref(),
+        &updated_metadata,
+    )?;
+
+    let event = MetadataUpdated {
+        mint: ctx.accounts.mint.key(),
+        key: args.field.clone(),
+        old_value: get_metadata_value(&metadata, &args.field),
+        new_value: Some(args.value.clone()),
+        authority: ctx.accounts.authority.key(),
+    };
+    if let Some(metadata_account) = external_metadata_account {
+        validate_metadata_update_authority(&metadata, ctx.accounts.authority.key)?;
+        metadata.update(Field::Key(args.field), args.value);
+        write_external_metadata(
+            metadata_account.clone(),
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            &metadata,
+        )?;
+    } else {
+        ctx.accounts
+            .update_token_metadata_field(Field::Key(args.field), args.value)?;
+
+        // transfer minimum rent to mint account
+        update_account_lamports_to_minimum_balance(
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        )?;
+    }
+
+    emit_cpi!(event);
+
+    Ok(())
+}
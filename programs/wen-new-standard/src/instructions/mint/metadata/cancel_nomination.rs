@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+
+use anchor_spl::token_interface::{
+    token_metadata_update_authority, Mint, Token2022, TokenMetadataUpdateAuthority,
+};
+use spl_pod::optional_keys::OptionalNonZeroPubkey;
+
+use crate::{
+    get_external_metadata_account, get_metadata, MetadataAuthorityNomination,
+    METADATA_NOMINATION_SEED,
+};
+
+#[derive(Accounts)]
+#[instruction()]
+pub struct CancelNomination<'info> {
+    /// metadata update authority at nomination
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mint::token_program = token_program,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    /// CHECK: account referenced by the metadata pointer, only needed for external metadata
+    pub metadata_account: Option<UncheckedAccount<'info>>,
+    #[account(
+        mut,
+        seeds = [METADATA_NOMINATION_SEED, mint.key().as_ref()],
+        bump = nomination.bump,
+        has_one = authority,
+        close = authority,
+    )]
+    pub nomination: Account<'info, MetadataAuthorityNomination>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+impl CancelNomination<'_> {
+    fn restore_token_metadata_update_authority(&self) -> Result<()> {
+        let mint_key = self.mint.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            METADATA_NOMINATION_SEED,
+            mint_key.as_ref(),
+            &[self.nomination.bump],
+        ]];
+        let cpi_accounts = TokenMetadataUpdateAuthority {
+            token_program_id: self.token_program.to_account_info(),
+            metadata: self.mint.to_account_info(),
+            current_authority: self.nomination.to_account_info(),
+            new_authority: self.authority.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        token_metadata_update_authority(
+            cpi_ctx,
+            OptionalNonZeroPubkey::try_from(Some(self.authority.key()))?,
+        )?;
+        Ok(())
+    }
+}
+
+/// Cancels a pending nomination, giving the metadata update authority back to the nominator
+pub fn handler(ctx: Context<CancelNomination>) -> Result<()> {
+    let mint = ctx.accounts.mint.to_account_info();
+    let metadata_account = ctx
+        .accounts
+        .metadata_account
+        .as_ref()
+        .map(|metadata_account| metadata_account.to_account_info());
+    let external_metadata_account =
+        get_external_metadata_account(&mint, metadata_account.as_ref())?;
+    let metadata = get_metadata(&mint, external_metadata_account)?;
+
+    // metadata stored in the mint is held by the nomination until cancelled
+    if external_metadata_account.is_none()
+        && Option::<Pubkey>::from(metadata.update_authority) == Some(ctx.accounts.nomination.key())
+    {
+        ctx.accounts.restore_token_metadata_update_authority()?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+
+use anchor_spl::token_interface::{
+    set_authority, spl_token_2022::instruction::AuthorityType, token_metadata_update_authority,
+    Mint, SetAuthority, Token2022, TokenMetadataUpdateAuthority,
+};
+use spl_pod::optional_keys::OptionalNonZeroPubkey;
+
+use crate::{
+    get_external_metadata_account, get_metadata, pack_external_metadata, validate_metadata_mutable,
+    validate_metadata_update_authority, MetadataErrors, MetadataFrozen,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction()]
+pub struct MakeImmutable<'info> {
+    /// metadata update authority and metadata pointer authority of the mint
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        mint::token_program = token_program,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    /// CHECK: account referenced by the metadata pointer, only needed for external metadata
+    #[account(mut)]
+    pub metadata_account: Option<UncheckedAccount<'info>>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+impl<'info> MakeImmutable<'info> {
+    fn remove_token_metadata_update_authority(&self) -> Result<()> {
+        let cpi_accounts = TokenMetadataUpdateAuthority {
+            token_program_id: self.token_program.to_account_info(),
+            metadata: self.mint.to_account_info(),
+            current_authority: self.authority.to_account_info(),
+            new_authority: self.authority.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
+        token_metadata_update_authority(cpi_ctx, OptionalNonZeroPubkey::default())?;
+        Ok(())
+    }
+
+    fn remove_metadata_pointer_authority(&self) -> Result<()> {
+        let cpi_accounts = SetAuthority {
+            current_authority: self.authority.to_account_info(),
+            account_or_mint: self.mint.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
+        set_authority(cpi_ctx, AuthorityType::MetadataPointer, None)?;
+        Ok(())
+    }
+}
+
+pub fn handler(ctx: Context<MakeImmutable>) -> Result<()> {
+    let mint = ctx.accounts.mint.to_account_info();
+    let metadata_account = ctx
+        .accounts
+        .metadata_account
+        .as_ref()
+        .map(|metadata_account| metadata_account.to_account_info());
+    let external_metadata_account =
+        get_external_metadata_account(&mint, metadata_account.as_ref())?;
+    let mut metadata = get_metadata(&mint, external_metadata_account)?;
+    validate_metadata_mutable(&metadata)?;
+
+    // metadata can no longer be updated, nor moved to another account
+    if let Some(metadata_account) = external_metadata_account {
+        validate_metadata_update_authority(&metadata, ctx.accounts.authority.key)?;
+        metadata.update_authority = OptionalNonZeroPubkey::default();
+        pack_external_metadata(metadata_account, &metadata)?;
+    } else {
+        ctx.accounts.remove_token_metadata_update_authority()?;
+    }
+    ctx.accounts.remove_metadata_pointer_authority()?;
+
+    // make sure the update authority now reads as none
+    let metadata = get_metadata(&mint, external_metadata_account)?;
+    if Option::<Pubkey>::from(metadata.update_authority).is_some() {
+        return Err(MetadataErrors::MetadataNotFrozen.into());
+    }
+
+    emit_cpi!(MetadataFrozen {
+        mint: ctx.accounts.mint.key(),
+    });
+
+    Ok(())
+}
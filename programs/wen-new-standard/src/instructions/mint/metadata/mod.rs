@@ -0,0 +1,21 @@
+pub mod accept;
+pub mod add;
+pub mod add_batch;
+pub mod cancel_nomination;
+pub mod immutable;
+pub mod nominate;
+pub mod remove;
+pub mod set_content_hash;
+pub mod update;
+pub mod verify_content_hash;
+
+pub use accept::*;
+pub use add::*;
+pub use add_batch::*;
+pub use cancel_nomination::*;
+pub use immutable::*;
+pub use nominate::*;
+pub use remove::*;
+pub use set_content_hash::*;
+pub use update::*;
+pub use verify_content_hash::*;
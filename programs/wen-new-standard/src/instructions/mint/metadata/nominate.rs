@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+
+use anchor_spl::token_interface::{
+    token_metadata_update_authority, Mint, Token2022, TokenMetadataUpdateAuthority,
+};
+use spl_pod::optional_keys::OptionalNonZeroPubkey;
+
+use crate::{
+    get_external_metadata_account, get_metadata, validate_metadata_update_authority,
+    MetadataAuthorityNomination, METADATA_NOMINATION_SEED,
+};
+
+#[derive(Accounts)]
+#[instruction(nominee: Pubkey)]
+pub struct NominateMetadataAuthority<'info> {
+    /// metadata update authority of the mint
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mint::token_program = token_program,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    /// CHECK: account referenced by the metadata pointer, only needed for external metadata
+    pub metadata_account: Option<UncheckedAccount<'info>>,
+    #[account(
+        init,
+        seeds = [METADATA_NOMINATION_SEED, mint.key().as_ref()],
+        bump,
+        payer = authority,
+        space = MetadataAuthorityNomination::LEN
+    )]
+    pub nomination: Account<'info, MetadataAuthorityNomination>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+impl NominateMetadataAuthority<'_> {
+    fn escrow_token_metadata_update_authority(&self) -> Result<()> {
+        let cpi_accounts = TokenMetadataUpdateAuthority {
+            token_program_id: self.token_program.to_account_info(),
+            metadata: self.mint.to_account_info(),
+            current_authority: self.authority.to_account_info(),
+            new_authority: self.nomination.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
+        token_metadata_update_authority(
+            cpi_ctx,
+            OptionalNonZeroPubkey::try_from(Some(self.nomination.key()))?,
+        )?;
+        Ok(())
+    }
+}
+
+/// Nominates `nominee` as metadata update authority, effective once accepted
+pub fn handler(ctx: Context<NominateMetadataAuthority>, nominee: Pubkey) -> Result<()> {
+    let mint = ctx.accounts.mint.to_account_info();
+    let metadata_account = ctx
+        .accounts
+        .metadata_account
+        .as_ref()
+        .map(|metadata_account| metadata_account.to_account_info());
+    let external_metadata_account =
+        get_external_metadata_account(&mint, metadata_account.as_ref())?;
+    let metadata = get_metadata(&mint, external_metadata_account)?;
+    validate_metadata_update_authority(&metadata, ctx.accounts.authority.key)?;
+
+    let nomination = &mut ctx.accounts.nomination;
+    nomination.mint = ctx.accounts.mint.key();
+    nomination.authority = ctx.accounts.authority.key();
+    nomination.nominee = nominee;
+    nomination.bump = ctx.bumps.nomination;
+
+    // external metadata is written by this program, which checks the nominator on accept
+    if external_metadata_account.is_none() {
+        ctx.accounts.escrow_token_metadata_update_authority()?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,129 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{entrypoint::ProgramResult, program::invoke},
+};
+
+use anchor_spl::token_interface::{
+    spl_token_metadata_interface::instruction::remove_key, Mint, Token2022,
+};
+
+use crate::{
+    get_external_metadata_account, get_metadata, get_metadata_value, pack_external_metadata,
+    refund_account_lamports_above_minimum_balance, refund_mint_lamports_above_minimum_balance,
+    validate_metadata_mutable, validate_metadata_update_authority, Manager, MetadataErrors,
+    MetadataUpdated, MANAGER_SEED,
+};
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct RemoveMetadataArgs {
+    pub field: String,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(args: RemoveMetadataArgs)]
+pub struct RemoveMetadata<'info> {
+    /// metadata update authority of the mint, refunded the freed rent by default
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        mint::token_program = token_program,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    /// CHECK: account referenced by the metadata pointer, only needed for external metadata
+    #[account(mut)]
+    pub metadata_account: Option<UncheckedAccount<'info>>,
+    /// CHECK: receives the freed rent, defaults to the authority
+    #[account(mut)]
+    pub rent_destination: Option<UncheckedAccount<'info>>,
+    /// CHECK: authority the manager is namespaced to, omitted for the global manager
+    pub manager_authority: Option<UncheckedAccount<'info>>,
+    #[account(
+        seeds = [
+            MANAGER_SEED,
+            Manager::namespace(manager_authority.as_ref().map(|authority| authority.key)),
+        ],
+        bump
+    )]
+    pub manager: Account<'info, Manager>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+impl<'info> RemoveMetadata<'info> {
+    fn remove_token_metadata_field(&self, field: String) -> ProgramResult {
+        let ix = remove_key(
+            self.token_program.key,
+            &self.mint.key(),
+            self.authority.key,
+            field,
+            false,
+        );
+        invoke(
+            &ix,
+            &[
+                self.mint.to_account_info(),
+                self.authority.to_account_info(),
+            ],
+        )
+    }
+}
+
+pub fn handler(ctx: Context<RemoveMetadata>, args: RemoveMetadataArgs) -> Result<()> {
+    let manager_namespace = Manager::namespace(
+        ctx.accounts
+            .manager_authority
+            .as_ref()
+            .map(|authority| authority.key),
+    );
+    let mint = ctx.accounts.mint.to_account_info();
+    let metadata_account = ctx
+        .accounts
+        .metadata_account
+        .as_ref()
+        .map(|metadata_account| metadata_account.to_account_info());
+    let external_metadata_account =
+        get_external_metadata_account(&mint, metadata_account.as_ref())?;
+    let mut metadata = get_metadata(&mint, external_metadata_account)?;
+    validate_metadata_mutable(&metadata)?;
+
+    let event = MetadataUpdated {
+        mint: ctx.accounts.mint.key(),
+        key: args.field.clone(),
+        old_value: get_metadata_value(&metadata, &args.field),
+        new_value: None,
+        authority: ctx.accounts.authority.key(),
+    };
+    let rent_destination = ctx
+        .accounts
+        .rent_destination
+        .as_ref()
+        .map(|rent_destination| rent_destination.to_account_info())
+        .unwrap_or(ctx.accounts.authority.to_account_info());
+    if let Some(metadata_account) = external_metadata_account {
+        validate_metadata_update_authority(&metadata, ctx.accounts.authority.key)?;
+        if !metadata.remove_key(&args.field) {
+            return Err(MetadataErrors::MissingMetadataField.into());
+        }
+        pack_external_metadata(metadata_account, &metadata)?;
+
+        // the metadata account is owned by this program, refund the freed rent directly
+        refund_account_lamports_above_minimum_balance(metadata_account, &rent_destination)?;
+    } else {
+        ctx.accounts.remove_token_metadata_field(args.field)?;
+
+        // the token program lets the manager withdraw the freed rent as mint authority
+        refund_mint_lamports_above_minimum_balance(
+            ctx.accounts.mint.to_account_info(),
+            rent_destination,
+            ctx.accounts.manager.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            manager_namespace,
+            ctx.bumps.manager,
+        )?;
+    }
+
+    emit_cpi!(event);
+
+    Ok(())
+}
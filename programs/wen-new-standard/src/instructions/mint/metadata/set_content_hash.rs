@@ -0,0 +1,128 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{entrypoint::ProgramResult, program::invoke},
+};
+
+use anchor_spl::token_interface::{
+    spl_token_metadata_interface::{instruction::remove_key, state::Field},
+    token_metadata_update_field, Mint, Token2022, TokenMetadataUpdateField,
+    TokenMetadataUpdateFieldArgs,
+};
+
+use crate::{
+    encode_content_hash, get_external_metadata_account, get_metadata, get_metadata_value,
+    update_account_lamports_to_minimum_balance, validate_metadata_mutable,
+    validate_metadata_update_authority, write_external_metadata, MetadataUpdated,
+    CONTENT_HASH_FIELD,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(content_hash: Option<[u8; 32]>)]
+pub struct SetContentHash<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// metadata update authority of the mint
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        mint::token_program = token_program,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    /// CHECK: account referenced by the metadata pointer, only needed for external metadata
+    #[account(mut)]
+    pub metadata_account: Option<UncheckedAccount<'info>>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+impl<'info> SetContentHash<'info> {
+    fn update_token_metadata_field(&self, value: String) -> ProgramResult {
+        let cpi_accounts = TokenMetadataUpdateField {
+            token_program_id: self.token_program.to_account_info(),
+            metadata: self.mint.to_account_info(),
+            update_authority: self.authority.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
+        token_metadata_update_field(
+            cpi_ctx,
+            TokenMetadataUpdateFieldArgs {
+                field: Field::Key(CONTENT_HASH_FIELD.to_owned()),
+                value,
+            },
+        )?;
+        Ok(())
+    }
+
+    fn remove_token_metadata_field(&self) -> ProgramResult {
+        let ix = remove_key(
+            self.token_program.key,
+            &self.mint.key(),
+            self.authority.key,
+            CONTENT_HASH_FIELD.to_owned(),
+            true,
+        );
+        invoke(
+            &ix,
+            &[
+                self.mint.to_account_info(),
+                self.authority.to_account_info(),
+            ],
+        )
+    }
+}
+
+/// Sets the content hash of a mint, `None` clears it
+pub fn handler(ctx: Context<SetContentHash>, content_hash: Option<[u8; 32]>) -> Result<()> {
+    let mint = ctx.accounts.mint.to_account_info();
+    let metadata_account = ctx
+        .accounts
+        .metadata_account
+        .as_ref()
+        .map(|metadata_account| metadata_account.to_account_info());
+    let external_metadata_account =
+        get_external_metadata_account(&mint, metadata_account.as_ref())?;
+    let mut metadata = get_metadata(&mint, external_metadata_account)?;
+    validate_metadata_mutable(&metadata)?;
+
+    let value = content_hash.as_ref().map(encode_content_hash);
+    let event = MetadataUpdated {
+        mint: ctx.accounts.mint.key(),
+        key: CONTENT_HASH_FIELD.to_owned(),
+        old_value: get_metadata_value(&metadata, CONTENT_HASH_FIELD),
+        new_value: value.clone(),
+        authority: ctx.accounts.authority.key(),
+    };
+    if let Some(metadata_account) = external_metadata_account {
+        validate_metadata_update_authority(&metadata, ctx.accounts.authority.key)?;
+        match value {
+            Some(value) => metadata.update(Field::Key(CONTENT_HASH_FIELD.to_owned()), value),
+            None => {
+                metadata.remove_key(CONTENT_HASH_FIELD);
+            }
+        }
+        write_external_metadata(
+            metadata_account.clone(),
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            &metadata,
+        )?;
+    } else {
+        match value {
+            Some(value) => ctx.accounts.update_token_metadata_field(value)?,
+            // idempotent, clearing a mint without content hash succeeds
+            None => ctx.accounts.remove_token_metadata_field()?,
+        }
+
+        // transfer minimum rent to mint account
+        update_account_lamports_to_minimum_balance(
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        )?;
+    }
+
+    emit_cpi!(event);
+
+    Ok(())
+}
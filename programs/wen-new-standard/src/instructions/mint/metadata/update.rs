@@ -0,0 +1,211 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{entrypoint::ProgramResult, program::invoke},
+};
+
+use anchor_spl::token_interface::{
+    spl_token_metadata_interface::{instruction::remove_key, state::Field},
+    token_metadata_update_field, Mint, Token2022, TokenMetadataUpdateField,
+    TokenMetadataUpdateFieldArgs,
+};
+
+use crate::{
+    encode_content_hash, get_content_hash, get_external_metadata_account, get_metadata,
+    get_metadata_value, pack_external_metadata, refund_account_lamports_above_minimum_balance,
+    refund_mint_lamports_above_minimum_balance, update_account_lamports_to_minimum_balance,
+    validate_metadata, validate_metadata_mutable, validate_metadata_update_authority, Manager,
+    MetadataErrors, MetadataUpdated, CONTENT_HASH_FIELD, MANAGER_SEED,
+};
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct UpdateMintMetadataArgs {
+    pub name: Option<String>,
+    pub symbol: Option<String>,
+    pub uri: Option<String>,
+    /// New content hash, required along a new uri when the mint has a content hash
+    pub content_hash: Option<[u8; 32]>,
+    /// Clear the content hash instead of setting a new one
+    pub clear_content_hash: bool,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(args: UpdateMintMetadataArgs)]
+pub struct UpdateMintMetadata<'info> {
+    /// pays for metadata growth and is refunded when it shrinks
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// metadata update authority of the mint
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        mint::token_program = token_program,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    /// CHECK: account referenced by the metadata pointer, only needed for external metadata
+    #[account(mut)]
+    pub metadata_account: Option<UncheckedAccount<'info>>,
+    /// CHECK: authority the manager is namespaced to, omitted for the global manager
+    pub manager_authority: Option<UncheckedAccount<'info>>,
+    #[account(
+        seeds = [
+            MANAGER_SEED,
+            Manager::namespace(manager_authority.as_ref().map(|authority| authority.key)),
+        ],
+        bump
+    )]
+    pub manager: Account<'info, Manager>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+impl<'info> UpdateMintMetadata<'info> {
+    fn update_token_metadata_field(&self, field: Field, value: String) -> ProgramResult {
+        let cpi_accounts = TokenMetadataUpdateField {
+            token_program_id: self.token_program.to_account_info(),
+            metadata: self.mint.to_account_info(),
+            update_authority: self.authority.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
+        token_metadata_update_field(cpi_ctx, TokenMetadataUpdateFieldArgs { field, value })?;
+        Ok(())
+    }
+
+    fn remove_token_metadata_field(&self, field: String) -> ProgramResult {
+        let ix = remove_key(
+            self.token_program.key,
+            &self.mint.key(),
+            self.authority.key,
+            field,
+            true,
+        );
+        invoke(
+            &ix,
+            &[
+                self.mint.to_account_info(),
+                self.authority.to_account_info(),
+            ],
+        )
+    }
+}
+
+/// Updates the name, symbol and uri of a mint, fields left to `None` are unchanged.
+/// The metadata account rent is adjusted to its new size.
+pub fn handler(ctx: Context<UpdateMintMetadata>, args: UpdateMintMetadataArgs) -> Result<()> {
+    let manager_namespace = Manager::namespace(
+        ctx.accounts
+            .manager_authority
+            .as_ref()
+            .map(|authority| authority.key),
+    );
+    let mint = ctx.accounts.mint.to_account_info();
+    let metadata_account = ctx
+        .accounts
+        .metadata_account
+        .as_ref()
+        .map(|metadata_account| metadata_account.to_account_info());
+    let external_metadata_account =
+        get_external_metadata_account(&mint, metadata_account.as_ref())?;
+    let mut metadata = get_metadata(&mint, external_metadata_account)?;
+    validate_metadata_mutable(&metadata)?;
+
+    // same validation as creation, on the updated values
+    validate_metadata(
+        args.name.as_ref().unwrap_or(&metadata.name),
+        args.symbol.as_ref().unwrap_or(&metadata.symbol),
+        args.uri.as_ref().unwrap_or(&metadata.uri),
+    )?;
+
+    // the content hash commits to the uri content, it must change along the uri
+    if args.uri.is_some()
+        && get_content_hash(&metadata).is_some()
+        && args.content_hash.is_none()
+        && !args.clear_content_hash
+    {
+        return Err(MetadataErrors::ContentHashRequired.into());
+    }
+
+    let updates: Vec<(Field, &str, String)> = [
+        (Field::Name, "name", args.name),
+        (Field::Symbol, "symbol", args.symbol),
+        (Field::Uri, "uri", args.uri),
+        (
+            Field::Key(CONTENT_HASH_FIELD.to_owned()),
+            CONTENT_HASH_FIELD,
+            args.content_hash.as_ref().map(encode_content_hash),
+        ),
+    ]
+    .into_iter()
+    .filter_map(|(field, key, value)| value.map(|value| (field, key, value)))
+    .collect();
+
+    let mut events: Vec<MetadataUpdated> = updates
+        .iter()
+        .map(|(_, key, value)| MetadataUpdated {
+            mint: ctx.accounts.mint.key(),
+            key: (*key).to_owned(),
+            old_value: get_metadata_value(&metadata, key),
+            new_value: Some(value.clone()),
+            authority: ctx.accounts.authority.key(),
+        })
+        .collect();
+    if args.clear_content_hash {
+        events.push(MetadataUpdated {
+            mint: ctx.accounts.mint.key(),
+            key: CONTENT_HASH_FIELD.to_owned(),
+            old_value: get_metadata_value(&metadata, CONTENT_HASH_FIELD),
+            new_value: None,
+            authority: ctx.accounts.authority.key(),
+        });
+    }
+    if let Some(metadata_account) = external_metadata_account {
+        validate_metadata_update_authority(&metadata, ctx.accounts.authority.key)?;
+        for (field, _, value) in updates {
+            metadata.update(field, value);
+        }
+        if args.clear_content_hash {
+            metadata.remove_key(CONTENT_HASH_FIELD);
+        }
+        pack_external_metadata(metadata_account, &metadata)?;
+
+        // adjust rent to the new metadata size
+        update_account_lamports_to_minimum_balance(
+            metadata_account.clone(),
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        )?;
+        refund_account_lamports_above_minimum_balance(
+            metadata_account,
+            &ctx.accounts.payer.to_account_info(),
+        )?;
+    } else {
+        for (field, _, value) in updates {
+            ctx.accounts.update_token_metadata_field(field, value)?;
+        }
+        if args.clear_content_hash {
+            ctx.accounts
+                .remove_token_metadata_field(CONTENT_HASH_FIELD.to_owned())?;
+        }
+
+        // adjust rent to the new mint size
+        update_account_lamports_to_minimum_balance(
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        )?;
+        refund_mint_lamports_above_minimum_balance(
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.manager.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            manager_namespace,
+            ctx.bumps.manager,
+        )?;
+    }
+
+    for event in events {
+        emit_cpi!(event);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use anchor_spl::token_interface::{Mint, Token2022};
+
+use crate::{get_content_hash, get_external_metadata_account, get_metadata};
+
+#[derive(Accounts)]
+#[instruction(content_hash: [u8; 32])]
+pub struct VerifyContentHash<'info> {
+    #[account(
+        mint::token_program = token_program,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    /// CHECK: account referenced by the metadata pointer, only needed for external metadata
+    pub metadata_account: Option<UncheckedAccount<'info>>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+/// Returns whether `content_hash` matches the content hash of the mint, false when it has none
+pub fn handler(ctx: Context<VerifyContentHash>, content_hash: [u8; 32]) -> Result<bool> {
+    let mint = ctx.accounts.mint.to_account_info();
+    let metadata_account = ctx
+        .accounts
+        .metadata_account
+        .as_ref()
+        .map(|metadata_account| metadata_account.to_account_info());
+    let external_metadata_account =
+        get_external_metadata_account(&mint, metadata_account.as_ref())?;
+    let metadata = get_metadata(&mint, external_metadata_account)?;
+
+    Ok(get_content_hash(&metadata) == Some(content_hash))
+}
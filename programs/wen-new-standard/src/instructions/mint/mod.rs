@@ -1,7 +1,31 @@
+pub mod burn;
+pub mod claim;
+pub mod clawback;
+pub mod close_record;
 pub mod create;
+pub mod create_batch;
+pub mod create_pda;
+pub mod create_unminted;
+pub mod edition;
+pub mod finalize;
 pub mod group;
+pub mod init_manager;
+pub mod metadata;
 pub mod royalties;
+pub mod thaw;
 
+pub use burn::*;
+pub use claim::*;
+pub use clawback::*;
+pub use close_record::*;
 pub use create::*;
+pub use create_batch::*;
+pub use create_pda::*;
+pub use create_unminted::*;
+pub use edition::*;
+pub use finalize::*;
 pub use group::*;
+pub use init_manager::*;
+pub use metadata::*;
 pub use royalties::*;
+pub use thaw::*;
@@ -1,5 +1,4 @@
 use anchor_lang::{prelude::*, solana_program::entrypoint::ProgramResult};
-use spl_tlv_account_resolution::state::ExtraAccountMetaList;
 
 use anchor_spl::{
     associated_token::AssociatedToken,
@@ -9,26 +8,56 @@ use anchor_spl::{
         TokenMetadataUpdateFieldArgs, TransferHookUpdate,
     },
 };
-use spl_transfer_hook_interface::instruction::ExecuteInstruction;
 
 use crate::{
-    get_approve_account_pda, get_meta_list, get_meta_list_size,
-    update_account_lamports_to_minimum_balance, MetadataErrors, META_LIST_ACCOUNT_SEED,
-    ROYALTY_BASIS_POINTS_FIELD,
+    get_mint_group, update_account_lamports_to_minimum_balance, validate_group_royalties,
+    write_meta_list, MetadataErrors, RoyaltiesUpdated, TokenGroupMember, MAX_CREATORS,
+    MEMBER_ACCOUNT_SEED, META_LIST_ACCOUNT_SEED, ROYALTY_BASIS_POINTS_FIELD,
 };
 
-#[derive(AnchorDeserialize, AnchorSerialize)]
+#[derive(AnchorDeserialize, AnchorSerialize, Clone)]
 pub struct CreatorWithShare {
     pub address: String,
     pub share: u8,
 }
 
-#[derive(AnchorDeserialize, AnchorSerialize)]
+#[derive(AnchorDeserialize, AnchorSerialize, Clone)]
 pub struct AddRoyaltiesArgs {
     pub royalty_basis_points: u16,
     pub creators: Vec<CreatorWithShare>,
 }
 
+impl AddRoyaltiesArgs {
+    /// Validates the creators, shared by every instruction writing royalties
+    pub fn validate(&self) -> Result<()> {
+        if self.creators.len() > MAX_CREATORS {
+            return Err(MetadataErrors::TooManyCreators.into());
+        }
+
+        let mut total_share: u8 = 0;
+        for (index, creator) in self.creators.iter().enumerate() {
+            if creator.share == 0 {
+                return Err(MetadataErrors::ZeroCreatorShare.into());
+            }
+            // duplicates would be paid twice from a single metadata entry
+            if self.creators[..index]
+                .iter()
+                .any(|other| other.address == creator.address)
+            {
+                return Err(MetadataErrors::DuplicateCreator.into());
+            }
+            total_share = total_share
+                .checked_add(creator.share)
+                .ok_or(MetadataErrors::InvalidCreatorShareTotal)?;
+        }
+        if total_share != 100 {
+            return Err(MetadataErrors::InvalidCreatorShareTotal.into());
+        }
+        Ok(())
+    }
+}
+
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(args: AddRoyaltiesArgs)]
 pub struct AddRoyalties<'info> {
@@ -43,12 +72,19 @@ pub struct AddRoyalties<'info> {
     pub mint: Box<InterfaceAccount<'info, Mint>>,
     /// CHECK: This account's data is a buffer of TLV data
     #[account(
+        mut,
         seeds = [META_LIST_ACCOUNT_SEED, mint.key().as_ref()],
         bump,
     )]
     pub extra_metas_account: UncheckedAccount<'info>,
+    #[account(
+        seeds = [MEMBER_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub member: Option<Account<'info, TokenGroupMember>>,
+    /// CHECK: extension policy PDA of the member group, only needed for group members
+    pub policy: Option<UncheckedAccount<'info>>,
     pub system_program: Program<'info, System>,
-    pub rent: Sysvar<'info, Rent>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub token_program: Program<'info, Token2022>,
 }
@@ -78,37 +114,50 @@ impl<'info> AddRoyalties<'info> {
 }
 
 pub fn handler(ctx: Context<AddRoyalties>, args: AddRoyaltiesArgs) -> Result<()> {
+    args.validate()?;
+    // group members are bound by the royalties of the group policy
+    let policy = ctx
+        .accounts
+        .policy
+        .as_ref()
+        .map(|policy| policy.to_account_info());
+    validate_group_royalties(
+        &ctx.accounts.mint.to_account_info(),
+        ctx.accounts.member.as_deref(),
+        policy.as_ref(),
+        args.royalty_basis_points,
+    )?;
+    let event = RoyaltiesUpdated {
+        mint: ctx.accounts.mint.key(),
+        royalty_basis_points: args.royalty_basis_points,
+        creators: args
+            .creators
+            .iter()
+            .map(|creator| creator.address.clone())
+            .collect(),
+    };
+
     // add royalty basis points to metadata
     ctx.accounts.update_token_metadata_field(
         Field::Key(ROYALTY_BASIS_POINTS_FIELD.to_owned()),
         args.royalty_basis_points.to_string(),
     )?;
 
-    let mut total_share: u8 = 0;
     // add creators and their respective shares to metadata
     for creator in args.creators {
-        total_share = total_share
-            .checked_add(creator.share)
-            .ok_or(MetadataErrors::CreatorShareInvalid)?;
         ctx.accounts
             .update_token_metadata_field(Field::Key(creator.address), creator.share.to_string())?;
     }
 
-    if total_share != 100 {
-        return Err(MetadataErrors::CreatorShareInvalid.into());
-    }
-
-    // update the extra metas account to include the approve account
-    let extra_metas_account = &ctx.accounts.extra_metas_account;
-    let approve_account = get_approve_account_pda(ctx.accounts.mint.to_account_info().key());
-    let new_size = get_meta_list_size(Some(approve_account));
-    extra_metas_account.realloc(new_size, false)?;
-    let metas = get_meta_list(Some(approve_account));
-    let mut data = vec![0u8; new_size as usize];
-    ExtraAccountMetaList::init::<ExecuteInstruction>(&mut data, &metas)?;
-    extra_metas_account
-        .try_borrow_mut_data()?
-        .copy_from_slice(&data);
+    // update the extra metas account to include the approve account and the group exemptions,
+    // zero royalty mints keep the minimal meta list since there is nothing to enforce
+    let mint = ctx.accounts.mint.to_account_info();
+    let group = get_mint_group(&mint, ctx.accounts.member.as_deref())?;
+    write_meta_list(
+        &ctx.accounts.extra_metas_account.to_account_info(),
+        event.royalty_basis_points > 0,
+        group,
+    )?;
 
     // add metadata program as the transfer hook program
     ctx.accounts.update_transfer_hook_program_id()?;
@@ -127,5 +176,88 @@ pub fn handler(ctx: Context<AddRoyalties>, args: AddRoyaltiesArgs) -> Result<()>
         ctx.accounts.system_program.to_account_info(),
     )?;
 
+    emit_cpi!(event);
+
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn royalties(shares: &[u8]) -> AddRoyaltiesArgs {
+        AddRoyaltiesArgs {
+            royalty_basis_points: 500,
+            creators: shares
+                .iter()
+                .map(|share| CreatorWithShare {
+                    address: Pubkey::new_unique().to_string(),
+                    share: *share,
+                })
+                .collect(),
+        }
+    }
+
+    fn validation_error(args: &AddRoyaltiesArgs) -> Error {
+        args.validate().unwrap_err()
+    }
+
+    #[test]
+    fn shares_summing_to_100_are_valid() {
+        royalties(&[100]).validate().unwrap();
+        royalties(&[33, 33, 34]).validate().unwrap();
+    }
+
+    #[test]
+    fn shares_must_sum_to_100() {
+        assert_eq!(
+            validation_error(&royalties(&[])),
+            Error::from(MetadataErrors::InvalidCreatorShareTotal)
+        );
+        assert_eq!(
+            validation_error(&royalties(&[50, 40])),
+            Error::from(MetadataErrors::InvalidCreatorShareTotal)
+        );
+        assert_eq!(
+            validation_error(&royalties(&[60, 50])),
+            Error::from(MetadataErrors::InvalidCreatorShareTotal)
+        );
+        // the total can't overflow past 255 back to 100
+        assert_eq!(
+            validation_error(&royalties(&[200, 156])),
+            Error::from(MetadataErrors::InvalidCreatorShareTotal)
+        );
+    }
+
+    #[test]
+    fn shares_must_not_be_zero() {
+        assert_eq!(
+            validation_error(&royalties(&[100, 0])),
+            Error::from(MetadataErrors::ZeroCreatorShare)
+        );
+    }
+
+    #[test]
+    fn creators_must_be_unique() {
+        let mut args = royalties(&[50, 50]);
+        args.creators[1].address = args.creators[0].address.clone();
+        assert_eq!(
+            validation_error(&args),
+            Error::from(MetadataErrors::DuplicateCreator)
+        );
+    }
+
+    #[test]
+    fn creators_are_bounded() {
+        let mut shares = vec![1; MAX_CREATORS];
+        shares[0] += 100 - MAX_CREATORS as u8;
+        royalties(&shares).validate().unwrap();
+
+        shares.push(1);
+        shares[0] -= 1;
+        assert_eq!(
+            validation_error(&royalties(&shares)),
+            Error::from(MetadataErrors::TooManyCreators)
+        );
+    }
+}
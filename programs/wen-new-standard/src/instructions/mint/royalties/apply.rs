@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+use crate::{update_royalties, MetadataErrors, ModifyRoyalties};
+
+/// Applies the royalties queued by `modify_royalties` once the group timelock elapsed. The
+/// metadata update authority still signs, only it can update the token metadata.
+pub fn handler(ctx: Context<ModifyRoyalties>) -> Result<()> {
+    let pending_royalty_update = ctx
+        .accounts
+        .pending_royalty_update
+        .as_ref()
+        .ok_or(MetadataErrors::InvalidPendingRoyaltyUpdateAccount)?;
+    if pending_royalty_update.mint != ctx.accounts.mint.key() {
+        return Err(MetadataErrors::InvalidPendingRoyaltyUpdateAccount.into());
+    }
+    if Clock::get()?.unix_timestamp < pending_royalty_update.effective_after {
+        return Err(MetadataErrors::RoyaltyUpdateTimelocked.into());
+    }
+
+    // the pending update is closed so it can only be applied once
+    let args = pending_royalty_update.royalties.clone();
+    pending_royalty_update.close(ctx.accounts.payer.to_account_info())?;
+
+    update_royalties(ctx, args, true)
+}
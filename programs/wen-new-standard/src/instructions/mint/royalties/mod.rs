@@ -1,3 +1,13 @@
 pub mod add;
+pub mod apply;
+pub mod modify;
+pub mod remove;
+pub mod unverify;
+pub mod verify;
 
 pub use add::*;
+pub use apply::*;
+pub use modify::*;
+pub use remove::*;
+pub use unverify::*;
+pub use verify::*;
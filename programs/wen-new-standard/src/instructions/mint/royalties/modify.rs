@@ -0,0 +1,280 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{entrypoint::ProgramResult, program::invoke},
+};
+
+use anchor_spl::token_interface::{
+    spl_token_metadata_interface::{instruction::remove_key, state::Field},
+    token_metadata_update_field, Mint, Token2022, TokenMetadataUpdateField,
+    TokenMetadataUpdateFieldArgs,
+};
+
+use crate::{
+    get_external_metadata_account, get_group_royalty_timelock, get_metadata, get_mint_group,
+    get_royalty_config, remove_verified_creators, update_account_lamports_to_minimum_balance,
+    validate_group_royalties, validate_group_royalty_authority, validate_metadata_mutable,
+    validate_metadata_update_authority, write_external_metadata, write_meta_list, AddRoyaltiesArgs,
+    MetadataErrors, PendingRoyaltyUpdate, RoyaltiesUpdated, RoyaltyUpdateQueued, TokenGroup,
+    TokenGroupMember, MEMBER_ACCOUNT_SEED, META_LIST_ACCOUNT_SEED, PENDING_ROYALTY_UPDATE_SEED,
+    ROYALTY_BASIS_POINTS_FIELD, VERIFIED_CREATORS_SEED,
+};
+
+/// Shared by `modify_royalties` and `apply_royalty_update`
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction()]
+pub struct ModifyRoyalties<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// metadata update authority of the mint
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        mint::token_program = token_program,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    /// CHECK: account referenced by the metadata pointer, only needed for external metadata
+    #[account(mut)]
+    pub metadata_account: Option<UncheckedAccount<'info>>,
+    /// CHECK: This account's data is a buffer of TLV data
+    #[account(
+        mut,
+        seeds = [META_LIST_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub extra_metas_account: UncheckedAccount<'info>,
+    /// CHECK: verified creators PDA of the mint, removed verified creators must sign
+    #[account(
+        mut,
+        seeds = [VERIFIED_CREATORS_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub verified_creators: UncheckedAccount<'info>,
+    #[account(
+        seeds = [MEMBER_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub member: Option<Account<'info, TokenGroupMember>>,
+    /// group of the member, only needed for group members
+    pub group: Option<Account<'info, TokenGroup>>,
+    /// CHECK: royalty defaults PDA of the member group, only needed for group members
+    pub royalty_defaults: Option<UncheckedAccount<'info>>,
+    /// CHECK: extension policy PDA of the member group, only needed for group members
+    pub policy: Option<UncheckedAccount<'info>>,
+    /// CHECK: royalty timelock PDA of the member group, only needed for group members
+    pub royalty_timelock: Option<UncheckedAccount<'info>>,
+    /// royalties queued for timelocked group members
+    #[account(
+        init_if_needed,
+        seeds = [PENDING_ROYALTY_UPDATE_SEED, mint.key().as_ref()],
+        bump,
+        payer = payer,
+        space = PendingRoyaltyUpdate::LEN
+    )]
+    pub pending_royalty_update: Option<Box<Account<'info, PendingRoyaltyUpdate>>>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+impl<'info> ModifyRoyalties<'info> {
+    fn update_token_metadata_field(&self, field: Field, value: String) -> ProgramResult {
+        let cpi_accounts = TokenMetadataUpdateField {
+            token_program_id: self.token_program.to_account_info(),
+            metadata: self.mint.to_account_info(),
+            update_authority: self.authority.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
+        token_metadata_update_field(cpi_ctx, TokenMetadataUpdateFieldArgs { field, value })?;
+        Ok(())
+    }
+
+    fn remove_token_metadata_field(&self, field: String) -> ProgramResult {
+        let ix = remove_key(
+            self.token_program.key,
+            &self.mint.key(),
+            self.authority.key,
+            field,
+            false,
+        );
+        invoke(
+            &ix,
+            &[
+                self.mint.to_account_info(),
+                self.authority.to_account_info(),
+            ],
+        )
+    }
+}
+
+/// Replaces the royalty basis points and creators of a mint that already has royalties,
+/// timelocked group members queue them until `apply_royalty_update`
+pub fn handler(ctx: Context<ModifyRoyalties>, args: AddRoyaltiesArgs) -> Result<()> {
+    update_royalties(ctx, args, false)
+}
+
+/// Writes the royalties, or queues them for timelocked group members unless they are `applied`
+pub fn update_royalties(
+    ctx: Context<ModifyRoyalties>,
+    args: AddRoyaltiesArgs,
+    applied: bool,
+) -> Result<()> {
+    args.validate()?;
+    let event = RoyaltiesUpdated {
+        mint: ctx.accounts.mint.key(),
+        royalty_basis_points: args.royalty_basis_points,
+        creators: args
+            .creators
+            .iter()
+            .map(|creator| creator.address.clone())
+            .collect(),
+    };
+
+    let mint = ctx.accounts.mint.to_account_info();
+    let metadata_account = ctx
+        .accounts
+        .metadata_account
+        .as_ref()
+        .map(|metadata_account| metadata_account.to_account_info());
+    let external_metadata_account =
+        get_external_metadata_account(&mint, metadata_account.as_ref())?;
+    let mut metadata = get_metadata(&mint, external_metadata_account)?;
+    validate_metadata_mutable(&metadata)?;
+    let royalties = get_royalty_config(&metadata).ok_or(MetadataErrors::MissingRoyalties)?;
+    let royalty_defaults = ctx
+        .accounts
+        .royalty_defaults
+        .as_ref()
+        .map(|royalty_defaults| royalty_defaults.to_account_info());
+    validate_group_royalty_authority(
+        &mint,
+        ctx.accounts.member.as_deref(),
+        ctx.accounts.group.as_ref(),
+        royalty_defaults.as_ref(),
+        ctx.accounts.authority.key,
+    )?;
+
+    // group members are bound by the royalties of the group policy
+    let policy = ctx
+        .accounts
+        .policy
+        .as_ref()
+        .map(|policy| policy.to_account_info());
+    validate_group_royalties(
+        &mint,
+        ctx.accounts.member.as_deref(),
+        policy.as_ref(),
+        args.royalty_basis_points,
+    )?;
+
+    // holders get a chance to react before the new royalties apply
+    let royalty_timelock = ctx
+        .accounts
+        .royalty_timelock
+        .as_ref()
+        .map(|royalty_timelock| royalty_timelock.to_account_info());
+    let delay = get_group_royalty_timelock(
+        &mint,
+        ctx.accounts.member.as_deref(),
+        royalty_timelock.as_ref(),
+    )?;
+    if delay > 0 && !applied {
+        // nothing is written to the metadata, so the update authority is checked here
+        validate_metadata_update_authority(&metadata, ctx.accounts.authority.key)?;
+        let effective_after = Clock::get()?.unix_timestamp + delay;
+        let payer = ctx.accounts.payer.key();
+        let pending_royalty_update = ctx
+            .accounts
+            .pending_royalty_update
+            .as_mut()
+            .ok_or(MetadataErrors::InvalidPendingRoyaltyUpdateAccount)?;
+        pending_royalty_update.mint = mint.key();
+        pending_royalty_update.payer = payer;
+        pending_royalty_update.effective_after = effective_after;
+        pending_royalty_update.royalties = args;
+
+        emit_cpi!(RoyaltyUpdateQueued {
+            mint: event.mint,
+            royalty_basis_points: event.royalty_basis_points,
+            creators: event.creators,
+            effective_after,
+        });
+
+        return Ok(());
+    }
+
+    // creators that are no longer part of the royalties
+    let removed_creators: Vec<String> = royalties
+        .creators
+        .into_iter()
+        .map(|creator| creator.address)
+        .filter(|address| {
+            !args
+                .creators
+                .iter()
+                .any(|creator| &creator.address == address)
+        })
+        .collect();
+    // verified creators sign their removal as remaining accounts
+    remove_verified_creators(
+        &ctx.accounts.verified_creators.to_account_info(),
+        &removed_creators,
+        ctx.remaining_accounts,
+    )?;
+
+    // royalty basis points followed by new or updated creators and their respective shares
+    let mut updated_fields = vec![(
+        ROYALTY_BASIS_POINTS_FIELD.to_owned(),
+        args.royalty_basis_points.to_string(),
+    )];
+    for creator in args.creators {
+        updated_fields.push((creator.address, creator.share.to_string()));
+    }
+
+    if let Some(metadata_account) = external_metadata_account {
+        validate_metadata_update_authority(&metadata, ctx.accounts.authority.key)?;
+        for key in removed_creators.iter() {
+            metadata.remove_key(key);
+        }
+        for (field, value) in updated_fields {
+            metadata.update(Field::Key(field), value);
+        }
+        write_external_metadata(
+            metadata_account.clone(),
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            &metadata,
+        )?;
+    } else {
+        for key in removed_creators {
+            ctx.accounts.remove_token_metadata_field(key)?;
+        }
+        for (field, value) in updated_fields {
+            ctx.accounts
+                .update_token_metadata_field(Field::Key(field), value)?;
+        }
+
+        // transfer minimum rent to mint account
+        update_account_lamports_to_minimum_balance(
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        )?;
+    }
+
+    // rebuild the extra metas resolved by the transfer hook, soulbound mints have none and
+    // zero royalty mints only need the minimal meta list
+    let extra_metas_account = ctx.accounts.extra_metas_account.to_account_info();
+    if extra_metas_account.owner == &crate::ID {
+        let group = get_mint_group(&mint, ctx.accounts.member.as_deref())?;
+        write_meta_list(&extra_metas_account, event.royalty_basis_points > 0, group)?;
+        update_account_lamports_to_minimum_balance(
+            extra_metas_account,
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        )?;
+    }
+
+    emit_cpi!(event);
+
+    Ok(())
+}
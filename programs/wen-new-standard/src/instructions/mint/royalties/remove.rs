@@ -0,0 +1,222 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{entrypoint::ProgramResult, program::invoke},
+};
+
+use anchor_spl::token_interface::{
+    spl_token_2022::extension::transfer_hook::TransferHook,
+    spl_token_metadata_interface::instruction::remove_key, transfer_hook_update, Mint, Token2022,
+    TransferHookUpdate,
+};
+
+use crate::{
+    get_extension_data, get_external_metadata_account, get_metadata, get_mint_group,
+    get_royalty_config, has_provenance_meta, pack_external_metadata,
+    refund_account_lamports_above_minimum_balance, refund_mint_lamports_above_minimum_balance,
+    remove_verified_creators, validate_group_royalties, validate_group_royalty_authority,
+    validate_metadata_mutable, validate_metadata_update_authority, write_meta_list, Manager,
+    MetadataErrors, RoyaltiesRemoved, TokenGroup, TokenGroupMember, MANAGER_SEED,
+    MEMBER_ACCOUNT_SEED, META_LIST_ACCOUNT_SEED, ROYALTY_BASIS_POINTS_FIELD,
+    VERIFIED_CREATORS_SEED,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction()]
+pub struct RemoveRoyalties<'info> {
+    /// metadata update authority of the mint, refunded the freed rent by default
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        mint::token_program = token_program,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    /// CHECK: account referenced by the metadata pointer, only needed for external metadata
+    #[account(mut)]
+    pub metadata_account: Option<UncheckedAccount<'info>>,
+    /// CHECK: This account's data is a buffer of TLV data
+    #[account(
+        mut,
+        seeds = [META_LIST_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub extra_metas_account: UncheckedAccount<'info>,
+    /// CHECK: verified creators PDA of the mint, removed verified creators must sign
+    #[account(
+        mut,
+        seeds = [VERIFIED_CREATORS_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub verified_creators: UncheckedAccount<'info>,
+    #[account(
+        seeds = [MEMBER_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub member: Option<Account<'info, TokenGroupMember>>,
+    /// group of the member, only needed for group members
+    pub group: Option<Account<'info, TokenGroup>>,
+    /// CHECK: royalty defaults PDA of the member group, only needed for group members
+    pub royalty_defaults: Option<UncheckedAccount<'info>>,
+    /// CHECK: extension policy PDA of the member group, only needed for group members
+    pub policy: Option<UncheckedAccount<'info>>,
+    /// CHECK: receives the freed rent, defaults to the authority
+    #[account(mut)]
+    pub rent_destination: Option<UncheckedAccount<'info>>,
+    /// CHECK: authority the manager is namespaced to, omitted for the global manager
+    pub manager_authority: Option<UncheckedAccount<'info>>,
+    #[account(
+        seeds = [
+            MANAGER_SEED,
+            Manager::namespace(manager_authority.as_ref().map(|authority| authority.key)),
+        ],
+        bump
+    )]
+    pub manager: Account<'info, Manager>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+impl<'info> RemoveRoyalties<'info> {
+    fn remove_token_metadata_field(&self, field: String) -> ProgramResult {
+        let ix = remove_key(
+            self.token_program.key,
+            &self.mint.key(),
+            self.authority.key,
+            field,
+            false,
+        );
+        invoke(
+            &ix,
+            &[
+                self.mint.to_account_info(),
+                self.authority.to_account_info(),
+            ],
+        )
+    }
+
+    fn remove_transfer_hook_program_id(&self) -> Result<()> {
+        let cpi_accounts = TransferHookUpdate {
+            token_program_id: self.token_program.to_account_info(),
+            mint: self.mint.to_account_info(),
+            authority: self.authority.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
+        transfer_hook_update(cpi_ctx, None)?;
+        Ok(())
+    }
+}
+
+/// Removes the royalties of a mint and the extra metas resolved by the transfer hook.
+/// The transfer hook program is also removed when the authority is the transfer hook authority,
+/// unless the mint is a group member or logs its provenance.
+pub fn handler(ctx: Context<RemoveRoyalties>) -> Result<()> {
+    let manager_namespace = Manager::namespace(
+        ctx.accounts
+            .manager_authority
+            .as_ref()
+            .map(|authority| authority.key),
+    );
+    let mint = ctx.accounts.mint.to_account_info();
+    let metadata_account = ctx
+        .accounts
+        .metadata_account
+        .as_ref()
+        .map(|metadata_account| metadata_account.to_account_info());
+    let external_metadata_account =
+        get_external_metadata_account(&mint, metadata_account.as_ref())?;
+    let mut metadata = get_metadata(&mint, external_metadata_account)?;
+    validate_metadata_mutable(&metadata)?;
+    let royalties = get_royalty_config(&metadata).ok_or(MetadataErrors::MissingRoyalties)?;
+
+    // group members can't drop their royalties below the group policy, nor when they are locked
+    let royalty_defaults = ctx
+        .accounts
+        .royalty_defaults
+        .as_ref()
+        .map(|royalty_defaults| royalty_defaults.to_account_info());
+    validate_group_royalty_authority(
+        &mint,
+        ctx.accounts.member.as_deref(),
+        ctx.accounts.group.as_ref(),
+        royalty_defaults.as_ref(),
+        ctx.accounts.authority.key,
+    )?;
+    let policy = ctx
+        .accounts
+        .policy
+        .as_ref()
+        .map(|policy| policy.to_account_info());
+    validate_group_royalties(&mint, ctx.accounts.member.as_deref(), policy.as_ref(), 0)?;
+
+    // verified creators sign their removal as remaining accounts
+    let removed_creators: Vec<String> = royalties
+        .creators
+        .into_iter()
+        .map(|creator| creator.address)
+        .collect();
+    remove_verified_creators(
+        &ctx.accounts.verified_creators.to_account_info(),
+        &removed_creators,
+        ctx.remaining_accounts,
+    )?;
+
+    // royalty basis points followed by the creators
+    let mut removed_fields = vec![ROYALTY_BASIS_POINTS_FIELD.to_owned()];
+    removed_fields.extend(removed_creators);
+
+    let rent_destination = ctx
+        .accounts
+        .rent_destination
+        .as_ref()
+        .map(|rent_destination| rent_destination.to_account_info())
+        .unwrap_or(ctx.accounts.authority.to_account_info());
+    if let Some(metadata_account) = external_metadata_account {
+        validate_metadata_update_authority(&metadata, ctx.accounts.authority.key)?;
+        for field in removed_fields.iter() {
+            metadata.remove_key(field);
+        }
+        pack_external_metadata(metadata_account, &metadata)?;
+        refund_account_lamports_above_minimum_balance(metadata_account, &rent_destination)?;
+    } else {
+        for field in removed_fields {
+            ctx.accounts.remove_token_metadata_field(field)?;
+        }
+    }
+
+    // the transfer hook no longer resolves the approve account, group members keep resolving
+    // the accounts of their group
+    let group = get_mint_group(&mint, ctx.accounts.member.as_deref())?;
+    let extra_metas_account = ctx.accounts.extra_metas_account.to_account_info();
+    if extra_metas_account.owner == &crate::ID {
+        write_meta_list(&extra_metas_account, false, group)?;
+        refund_account_lamports_above_minimum_balance(&extra_metas_account, &rent_destination)?;
+    }
+
+    // transfers skip the transfer hook entirely once it's removed, group members keep it for
+    // the enforcement mode of their group and provenance logged mints for their log
+    let transfer_hook = get_extension_data::<TransferHook>(&mut mint.clone())?;
+    if group.is_none()
+        && !has_provenance_meta(&extra_metas_account)?
+        && Option::<Pubkey>::from(transfer_hook.authority) == Some(ctx.accounts.authority.key())
+    {
+        ctx.accounts.remove_transfer_hook_program_id()?;
+    }
+
+    if external_metadata_account.is_none() {
+        // the token program lets the manager withdraw the freed rent as mint authority
+        refund_mint_lamports_above_minimum_balance(
+            ctx.accounts.mint.to_account_info(),
+            rent_destination,
+            ctx.accounts.manager.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            manager_namespace,
+            ctx.bumps.manager,
+        )?;
+    }
+
+    emit_cpi!(RoyaltiesRemoved {
+        mint: ctx.accounts.mint.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use anchor_spl::token_interface::{Mint, Token2022};
+
+use crate::{CreatorUnverified, MetadataErrors, VerifiedCreators, VERIFIED_CREATORS_SEED};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction()]
+pub struct UnverifyCreator<'info> {
+    /// verified creator of the mint
+    pub creator: Signer<'info>,
+    #[account(
+        mint::token_program = token_program,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        seeds = [VERIFIED_CREATORS_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub verified_creators: Account<'info, VerifiedCreators>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+/// Clears the verification of a creator, who can be removed from the royalties afterwards
+pub fn handler(ctx: Context<UnverifyCreator>) -> Result<()> {
+    let creator = ctx.accounts.creator.key();
+    let verified_creators = &mut ctx.accounts.verified_creators;
+    if !verified_creators.creators.contains(&creator) {
+        return Err(MetadataErrors::CreatorNotVerified.into());
+    }
+    verified_creators
+        .creators
+        .retain(|verified_creator| verified_creator != &creator);
+
+    emit_cpi!(CreatorUnverified {
+        mint: ctx.accounts.mint.key(),
+        creator,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+
+use anchor_spl::token_interface::{Mint, Token2022};
+
+use crate::{
+    get_external_metadata_account, get_metadata, get_royalty_config, CreatorVerified,
+    MetadataErrors, VerifiedCreators, VERIFIED_CREATORS_SEED,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction()]
+pub struct VerifyCreator<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// creator listed in the mint royalties
+    pub creator: Signer<'info>,
+    #[account(
+        mint::token_program = token_program,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    /// CHECK: account referenced by the metadata pointer, only needed for external metadata
+    pub metadata_account: Option<UncheckedAccount<'info>>,
+    #[account(
+        init_if_needed,
+        seeds = [VERIFIED_CREATORS_SEED, mint.key().as_ref()],
+        bump,
+        payer = payer,
+        space = VerifiedCreators::LEN
+    )]
+    pub verified_creators: Account<'info, VerifiedCreators>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+/// Verifies a creator of the mint royalties, only the creator can endorse the mint
+pub fn handler(ctx: Context<VerifyCreator>) -> Result<()> {
+    let mint = ctx.accounts.mint.to_account_info();
+    let metadata_account = ctx
+        .accounts
+        .metadata_account
+        .as_ref()
+        .map(|metadata_account| metadata_account.to_account_info());
+    let external_metadata_account =
+        get_external_metadata_account(&mint, metadata_account.as_ref())?;
+    let metadata = get_metadata(&mint, external_metadata_account)?;
+    let royalties = get_royalty_config(&metadata).ok_or(MetadataErrors::MissingRoyalties)?;
+    let creator = ctx.accounts.creator.key();
+    if !royalties
+        .creators
+        .iter()
+        .any(|royalty_creator| royalty_creator.address == creator.to_string())
+    {
+        return Err(MetadataErrors::CreatorNotFound.into());
+    }
+
+    let verified_creators = &mut ctx.accounts.verified_creators;
+    if verified_creators.creators.contains(&creator) {
+        return Err(MetadataErrors::CreatorAlreadyVerified.into());
+    }
+    verified_creators.mint = ctx.accounts.mint.key();
+    verified_creators.creators.push(creator);
+
+    emit_cpi!(CreatorVerified {
+        mint: ctx.accounts.mint.key(),
+        creator,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use anchor_lang::{prelude::*, solana_program::program_option::COption};
+
+use anchor_spl::token_interface::{thaw_account, Mint, ThawAccount, Token2022, TokenAccount};
+
+use crate::{
+    Manager, MetadataErrors, TokenGroup, TokenGroupMember, MANAGER_SEED, MEMBER_ACCOUNT_SEED,
+};
+
+#[derive(Accounts)]
+#[instruction()]
+pub struct ThawMintAccount<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        constraint = group.update_authority == authority.key(),
+    )]
+    pub group: Account<'info, TokenGroup>,
+    #[account(
+        seeds = [MEMBER_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+        constraint = member.group == group.key(),
+    )]
+    pub member: Account<'info, TokenGroupMember>,
+    #[account(
+        mint::token_program = token_program,
+        constraint = mint.freeze_authority == COption::Some(manager.key()) @ MetadataErrors::NoFreezeAuthority,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub mint_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: authority the manager of the mint is namespaced to, omitted for the global manager
+    pub manager_authority: Option<UncheckedAccount<'info>>,
+    #[account(
+        seeds = [
+            MANAGER_SEED,
+            Manager::namespace(manager_authority.as_ref().map(|authority| authority.key)),
+        ],
+        bump
+    )]
+    pub manager: Account<'info, Manager>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+impl ThawMintAccount<'_> {
+    fn thaw(&self, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        let cpi_accounts = ThawAccount {
+            account: self.mint_token_account.to_account_info(),
+            mint: self.mint.to_account_info(),
+            authority: self.manager.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        thaw_account(cpi_ctx)?;
+        Ok(())
+    }
+}
+
+pub fn handler(ctx: Context<ThawMintAccount>) -> Result<()> {
+    let manager_namespace = Manager::namespace(
+        ctx.accounts
+            .manager_authority
+            .as_ref()
+            .map(|authority| authority.key),
+    );
+    let signer_seeds: &[&[&[u8]]] = &[&[MANAGER_SEED, manager_namespace, &[ctx.bumps.manager]]];
+    ctx.accounts.thaw(signer_seeds)
+}
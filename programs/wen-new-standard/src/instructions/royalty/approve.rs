@@ -1,5 +1,3 @@
-use std::str::FromStr;
-
 use anchor_lang::{
     accounts::{interface_account::InterfaceAccount, program::Program},
     prelude::*,
@@ -10,23 +8,31 @@ use anchor_spl::{
     associated_token::AssociatedToken,
     token_interface::{
         spl_token_2022::{
-            extension::{BaseStateWithExtensions, StateWithExtensions},
+            extension::{
+                non_transferable::NonTransferable, BaseStateWithExtensions, StateWithExtensions,
+            },
             state::Mint as BaseStateMint,
         },
-        spl_token_metadata_interface::state::TokenMetadata,
-        Mint, Token2022,
+        Mint, TokenAccount, TokenInterface,
     },
 };
 use wen_royalty_distribution::{
     cpi::{accounts::UpdateDistribution, update_distribution},
     program::WenRoyaltyDistribution,
-    UpdateDistributionArgs,
+    UpdateDistributionArgs, DISTRIBUTION_SEED,
 };
 
-use crate::{ApproveAccount, APPROVE_ACCOUNT_SEED, ROYALTY_BASIS_POINTS_FIELD};
+use crate::{
+    create_account_with_minimum_balance, get_distribution_collection,
+    get_external_metadata_account, get_metadata, get_royalty_basis_points,
+    update_account_lamports_to_minimum_balance, ApproveAccount, MetadataErrors, TokenGroup,
+    TokenGroupMember, TransferApproved, APPROVE_ACCOUNT_SEED, MAX_APPROVAL_SLOTS,
+    MEMBER_ACCOUNT_SEED,
+};
 
+#[event_cpi]
 #[derive(Accounts)]
-#[instruction(amount: u64)]
+#[instruction(amount: u64, expiry_slot: Option<u64>, transfer_amount: u64, destination: Pubkey, seller: Pubkey)]
 pub struct ApproveTransfer<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
@@ -36,15 +42,14 @@ pub struct ApproveTransfer<'info> {
         mint::token_program = anchor_spl::token_interface::spl_token_2022::id(),
     )]
     pub mint: Box<InterfaceAccount<'info, Mint>>,
+    /// CHECK: approve PDA of the mint, created or grown from the previous layout in the handler
     #[account(
-        init_if_needed,
+        mut,
         seeds = [APPROVE_ACCOUNT_SEED, mint.key().as_ref()],
         bump,
-        payer = payer,
-        space = ApproveAccount::LEN
     )]
-    pub approve_account: Account<'info, ApproveAccount>,
-    /// CHECK: This account can be any mint or SOL
+    pub approve_account: UncheckedAccount<'info>,
+    /// CHECK: This account can be any mint or SOL, checked by the cpi
     pub payment_mint: UncheckedAccount<'info>,
     #[account(mut)]
     /// CHECK: This account can be either the token account from which the tokens are being transferred or sol account from which the sol is being transferred
@@ -55,13 +60,69 @@ pub struct ApproveTransfer<'info> {
     /// CHECK: cpi checks
     #[account(mut)]
     pub distribution: UncheckedAccount<'info>,
+    /// CHECK: event authority PDA of the distribution program, checked by the cpi
+    pub distribution_event_authority: UncheckedAccount<'info>,
     pub system_program: Program<'info, System>,
     pub distribution_program: Program<'info, WenRoyaltyDistribution>,
-    pub token_program: Program<'info, Token2022>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
+    /// CHECK: account referenced by the metadata pointer, only needed for external metadata
+    pub metadata_account: Option<UncheckedAccount<'info>>,
+    #[account(
+        seeds = [MEMBER_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub member: Option<Account<'info, TokenGroupMember>>,
+    /// group of the member, only needed for group members
+    pub group: Option<Account<'info, TokenGroup>>,
+    /// CHECK: collection distribution PDA of the member group, only needed for group members
+    pub collection_distribution: Option<UncheckedAccount<'info>>,
+    /// CHECK: royalty defaults PDA of the member group, only needed for group members
+    pub royalty_defaults: Option<UncheckedAccount<'info>>,
+    /// token account the approved transfer moves from, records its owner as seller when
+    /// approved by its delegate
+    #[account(
+        token::mint = mint,
+        token::token_program = anchor_spl::token_interface::spl_token_2022::id(),
+    )]
+    pub source_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 }
 
 impl ApproveTransfer<'_> {
+    /// Creates the approve account, or grows an approve account of a previous layout.
+    /// Returns the payer of the account rent.
+    fn init_approve_account(&self, bump: u8) -> Result<Pubkey> {
+        let approve_account = self.approve_account.to_account_info();
+        let mint = self.mint.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[APPROVE_ACCOUNT_SEED, mint.as_ref(), &[bump]]];
+        if approve_account.data_is_empty() {
+            create_account_with_minimum_balance(
+                approve_account,
+                self.payer.to_account_info(),
+                self.system_program.to_account_info(),
+                ApproveAccount::LEN,
+                0,
+                &crate::id(),
+                signer_seeds,
+            )?;
+            return Ok(self.payer.key());
+        }
+        if approve_account.owner != &crate::id() {
+            return Err(ErrorCode::AccountOwnedByWrongProgram.into());
+        }
+        if approve_account.data_len() < ApproveAccount::LEN {
+            approve_account.realloc(ApproveAccount::LEN, false)?;
+            update_account_lamports_to_minimum_balance(
+                approve_account,
+                self.payer.to_account_info(),
+                self.system_program.to_account_info(),
+            )?;
+            return Ok(self.payer.key());
+        }
+        let data = approve_account.try_borrow_data()?;
+        Ok(ApproveAccount::try_deserialize(&mut &data[..])?.payer)
+    }
+
     pub fn distribute_royalties(&self, amount: u64) -> Result<()> {
         let cpi_accounts = UpdateDistribution {
             payer: self.payer.to_account_info(),
@@ -73,6 +134,15 @@ impl ApproveTransfer<'_> {
             system_program: self.system_program.to_account_info(),
             associated_token_program: self.associated_token_program.to_account_info(),
             token_program: self.token_program.to_account_info(),
+            metadata_account: self
+                .metadata_account
+                .as_ref()
+                .map(|metadata_account| metadata_account.to_account_info()),
+            payment_mint: (self.payment_mint.key() != Pubkey::default())
+                .then(|| self.payment_mint.to_account_info()),
+            member: self.member.as_ref().map(|member| member.to_account_info()),
+            event_authority: self.distribution_event_authority.to_account_info(),
+            program: self.distribution_program.to_account_info(),
         };
         let cpi_ctx = CpiContext::new(self.distribution_program.to_account_info(), cpi_accounts);
         update_distribution(
@@ -85,29 +155,132 @@ impl ApproveTransfer<'_> {
     }
 }
 
-pub fn handler(ctx: Context<ApproveTransfer>, amount: u64) -> Result<()> {
+/// `amount` is the sale amount paid for the tokens being transferred, whatever their
+/// number, royalties are taken as `royalty_basis_points` of it.
+/// Royalties are paid in SOL when `payment_mint` is `Pubkey::default()`, otherwise in the
+/// payment mint from the `payer_address` token account to the distribution token account.
+/// The approval can be consumed by a transfer until `expiry_slot`, the current slot by default,
+/// at most `MAX_APPROVAL_SLOTS` ahead.
+/// Only a transfer of `transfer_amount` tokens to a token account owned by `destination`
+/// can consume the approval. `seller` and the paying authority are recorded for reconciliation,
+/// the owner of `source_token_account` is recorded as seller instead when it is passed.
+pub fn handler(
+    ctx: Context<ApproveTransfer>,
+    amount: u64,
+    expiry_slot: Option<u64>,
+    transfer_amount: u64,
+    destination: Pubkey,
+    seller: Pubkey,
+) -> Result<()> {
     let mint_account = ctx.accounts.mint.to_account_info();
-    let mint_account_data = mint_account.try_borrow_data()?;
-    let mint_data = StateWithExtensions::<BaseStateMint>::unpack(&mint_account_data)?;
-    let metadata = mint_data.get_variable_len_extension::<TokenMetadata>()?;
+    {
+        let mint_account_data = mint_account.try_borrow_data()?;
+        let mint_data = StateWithExtensions::<BaseStateMint>::unpack(&mint_account_data)?;
+
+        // soulbound mints can never be transferred, so there is nothing to approve
+        if mint_data.get_extension::<NonTransferable>().is_ok() {
+            return Err(MetadataErrors::SoulboundMint.into());
+        }
+    }
 
-    // Load clock and write slot
+    // royalties are read from the account referenced by the metadata pointer
+    let metadata_account = ctx
+        .accounts
+        .metadata_account
+        .as_ref()
+        .map(|metadata_account| metadata_account.to_account_info());
+    let external_metadata_account =
+        get_external_metadata_account(&mint_account, metadata_account.as_ref())?;
+    let metadata = get_metadata(&mint_account, external_metadata_account)?;
+
+    // collection-wide groups pay royalties of their members to the group mint distribution
+    let collection_distribution = ctx
+        .accounts
+        .collection_distribution
+        .as_ref()
+        .map(|collection_distribution| collection_distribution.to_account_info());
+    let royalty_defaults = ctx
+        .accounts
+        .royalty_defaults
+        .as_ref()
+        .map(|royalty_defaults| royalty_defaults.to_account_info());
+    if let Some(collection) = get_distribution_collection(
+        &mint_account,
+        &metadata,
+        ctx.accounts.member.as_deref(),
+        ctx.accounts.group.as_ref(),
+        collection_distribution.as_ref(),
+        royalty_defaults.as_ref(),
+    )? {
+        let expected_distribution = Pubkey::find_program_address(
+            &[
+                DISTRIBUTION_SEED,
+                collection.as_ref(),
+                ctx.accounts.payment_mint.key().as_ref(),
+            ],
+            &wen_royalty_distribution::ID,
+        )
+        .0;
+        if ctx.accounts.distribution.key() != expected_distribution {
+            return Err(MetadataErrors::InvalidDistributionAccount.into());
+        }
+    }
+
+    // Load clock and write slot along the expiry
     let clock = Clock::get()?;
-    ctx.accounts.approve_account.slot = clock.slot;
+    let expiry_slot = expiry_slot.unwrap_or(clock.slot);
+    if expiry_slot < clock.slot || expiry_slot > clock.slot.saturating_add(MAX_APPROVAL_SLOTS) {
+        return Err(MetadataErrors::InvalidApprovalExpiry.into());
+    }
+    let royalty_basis_points = u64::from(get_royalty_basis_points(&metadata));
 
-    // get royalty basis points from metadata Vec(String, String)
-    let royalty_basis_points = metadata
-        .additional_metadata
-        .iter()
-        .find(|(key, _)| key == ROYALTY_BASIS_POINTS_FIELD)
-        .map(|(_, value)| value)
-        .map(|value| u64::from_str(value).unwrap())
-        .unwrap_or(0);
+    // u128 intermediate so large sale amounts can't overflow, rounded down
+    let royalty_amount = u64::try_from(
+        u128::from(amount)
+            .checked_mul(u128::from(royalty_basis_points))
+            .ok_or(MetadataErrors::ArithmeticOverflow)?
+            / 10000,
+    )
+    .map_err(|_| MetadataErrors::ArithmeticOverflow)?;
 
-    let royalty_amount = (amount * royalty_basis_points) / 10000;
+    // bind the approval to the transfer it pays royalties for, delegates approve on behalf
+    // of the owner of the source token account
+    let seller = ctx
+        .accounts
+        .source_token_account
+        .as_ref()
+        .map_or(seller, |source_token_account| source_token_account.owner);
+    let payer = ctx
+        .accounts
+        .init_approve_account(ctx.bumps.approve_account)?;
+    let approve_account = ApproveAccount {
+        slot: clock.slot,
+        expiry_slot,
+        payer,
+        amount: transfer_amount,
+        destination,
+        seller,
+        buyer: ctx.accounts.authority.key(),
+        sale_price: amount,
+        payment_mint: ctx.accounts.payment_mint.key(),
+        royalty_amount,
+    };
+    approve_account
+        .try_serialize(&mut &mut ctx.accounts.approve_account.try_borrow_mut_data()?[..])?;
 
     // transfer royalty amount to distribution pda
     ctx.accounts.distribute_royalties(royalty_amount)?;
 
+    emit_cpi!(TransferApproved {
+        mint: ctx.accounts.mint.key(),
+        seller,
+        buyer: ctx.accounts.authority.key(),
+        destination,
+        amount: transfer_amount,
+        sale_price: amount,
+        payment_mint: ctx.accounts.payment_mint.key(),
+        royalty_amount,
+    });
+
     Ok(())
 }
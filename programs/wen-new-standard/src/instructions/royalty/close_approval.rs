@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use anchor_spl::token_interface::Mint;
+
+use crate::{ApproveAccount, MetadataErrors, APPROVE_ACCOUNT_SEED};
+
+#[derive(Accounts)]
+#[instruction()]
+pub struct CloseExpiredApproval<'info> {
+    #[account(
+        mint::token_program = anchor_spl::token_interface::spl_token_2022::id(),
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        seeds = [APPROVE_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+        close = payer,
+    )]
+    pub approve_account: Account<'info, ApproveAccount>,
+    /// CHECK: funded the approve account, refunded its rent
+    #[account(
+        mut,
+        address = approve_account.payer,
+    )]
+    pub payer: UncheckedAccount<'info>,
+}
+
+/// Closes a consumed or expired approval, anyone can reclaim the rent to its payer
+pub fn handler(ctx: Context<CloseExpiredApproval>) -> Result<()> {
+    let approve_account = &ctx.accounts.approve_account;
+    if approve_account.slot != 0 && Clock::get()?.slot <= approve_account.expiry_slot {
+        return Err(MetadataErrors::ApprovalNotExpired.into());
+    }
+
+    Ok(())
+}
@@ -1,14 +1,31 @@
-use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{Mint, TokenAccount};
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        instruction::AccountMeta,
+        program::invoke,
+        program_option::COption,
+        sysvar::instructions::{load_current_index_checked, load_instruction_at_checked},
+    },
+    Discriminator,
+};
+use anchor_spl::token_interface::{
+    spl_token_2022::extension::permanent_delegate::PermanentDelegate, Mint, TokenAccount,
+};
+use spl_transfer_hook_interface::instruction::execute;
 
-use crate::{hook_in_cpi, ApproveAccount, MetadataErrors, META_LIST_ACCOUNT_SEED};
+use crate::{
+    assert_is_transferring, get_extension_data, get_metadata, get_metadata_address,
+    get_royalty_basis_points, has_preceding_memo, hook_in_cpi, ApproveAccount,
+    ApprovedTransferExecuted, EnforcementMode, MetadataErrors, ProvenanceEntry, ProvenanceRecord,
+    RoyaltyEnforcement, RoyaltyExemptions, SecondaryHook, GROUP_META_LIST_LEN,
+    META_LIST_ACCOUNT_SEED,
+};
 
 #[derive(Accounts)]
 #[instruction(amount: u64)]
 pub struct ExecuteTransferHook<'info> {
     #[account(
         token::mint = mint,
-        token::authority = owner_delegate,
         token::token_program = anchor_spl::token_interface::spl_token_2022::id(),
     )]
     pub source_account: Box<InterfaceAccount<'info, TokenAccount>>,
@@ -21,7 +38,7 @@ pub struct ExecuteTransferHook<'info> {
         token::token_program = anchor_spl::token_interface::spl_token_2022::id(),
     )]
     pub destination_account: Box<InterfaceAccount<'info, TokenAccount>>,
-    /// CHECK: can be any type of account, checked with a constraint above
+    /// CHECK: can be any type of account, checked in the handler
     pub owner_delegate: UncheckedAccount<'info>,
     /// CHECK: meta list account
     #[account(
@@ -31,27 +48,302 @@ pub struct ExecuteTransferHook<'info> {
     pub extra_metas_account: UncheckedAccount<'info>,
 }
 
-pub fn handler(ctx: Context<ExecuteTransferHook>) -> Result<()> {
-    // if transfer is a cpi, enforce royalties if applicable, else do nothing
-    if hook_in_cpi() {
-        if ctx.remaining_accounts.is_empty() {
-            return Err(MetadataErrors::MissingApproveAccount.into());
-        }
-        let mut approve_account: ApproveAccount = AnchorDeserialize::deserialize(
-            &mut &ctx.remaining_accounts[0].try_borrow_mut_data()?[8..],
-        )?;
-        if approve_account.slot == Clock::get()?.slot {
-            // mark approve account as used by setting slot to 0
-            approve_account.slot = 0;
-            AnchorSerialize::serialize(
-                &approve_account,
-                &mut &mut ctx.remaining_accounts[0].try_borrow_mut_data()?[8..],
-            )?;
-            Ok(())
-        } else {
-            Err(MetadataErrors::ExpiredApproveAccount.into())
+impl<'info> ExecuteTransferHook<'info> {
+    /// Appends the transfer to the provenance record of the mint when it opted in, the record
+    /// is the only provenance account of the mint among the hook accounts
+    fn log_provenance(
+        &self,
+        remaining_accounts: &[AccountInfo<'info>],
+        amount: u64,
+        sale_price: u64,
+    ) -> Result<()> {
+        let Some(provenance) = remaining_accounts.iter().find(|account| {
+            account.owner == &crate::id()
+                && account.is_writable
+                && account.try_borrow_data().map_or(false, |data| {
+                    data.starts_with(&ProvenanceRecord::DISCRIMINATOR)
+                })
+        }) else {
+            return Ok(());
+        };
+        let mut provenance_record = Account::<ProvenanceRecord>::try_from(provenance)?;
+        if provenance_record.mint != self.mint.key() {
+            return Err(MetadataErrors::InvalidProvenanceAccount.into());
+        }
+        provenance_record.append(ProvenanceEntry {
+            slot: Clock::get()?.slot,
+            from_owner: self.source_account.owner,
+            to_owner: self.destination_account.owner,
+            amount,
+            sale_price,
+        });
+        provenance_record.exit(&crate::id())
+    }
+
+    /// Invokes the secondary hook registered by the group of the mint, resolved after the
+    /// group metas, with its registered accounts only. It's skipped as soon as the group
+    /// unregisters it, and until the extra metas of the mint include it.
+    fn invoke_secondary_hook(
+        &self,
+        remaining_accounts: &[AccountInfo<'info>],
+        amount: u64,
+    ) -> Result<()> {
+        let Some([secondary_hook, program, validation]) =
+            remaining_accounts.get(GROUP_META_LIST_LEN..GROUP_META_LIST_LEN + 3)
+        else {
+            return Ok(());
+        };
+        let Some(secondary_hook) = SecondaryHook::load(secondary_hook)? else {
+            return Ok(());
+        };
+        if program.key() != secondary_hook.program_id {
+            return Ok(());
         }
-    } else {
+        // accounts after the registered ones, e.g. the provenance record, aren't forwarded
+        let accounts_start = GROUP_META_LIST_LEN + 3;
+        let Some(accounts) =
+            remaining_accounts.get(accounts_start..accounts_start + secondary_hook.accounts.len())
+        else {
+            return Ok(());
+        };
+
+        let mut ix = execute(
+            &secondary_hook.program_id,
+            &self.source_account.key(),
+            &self.mint.key(),
+            &self.destination_account.key(),
+            &self.owner_delegate.key(),
+            &validation.key(),
+            amount,
+        );
+        ix.accounts
+            .extend(accounts.iter().map(|account| AccountMeta {
+                pubkey: account.key(),
+                is_signer: false,
+                is_writable: account.is_writable,
+            }));
+        let mut account_infos = vec![
+            self.source_account.to_account_info(),
+            self.mint.to_account_info(),
+            self.destination_account.to_account_info(),
+            self.owner_delegate.to_account_info(),
+            validation.clone(),
+        ];
+        account_infos.extend_from_slice(accounts);
+        account_infos.push(program.clone());
+        invoke(&ix, &account_infos)?;
         Ok(())
     }
+
+    fn is_permanent_delegate_transfer(&self) -> bool {
+        get_extension_data::<PermanentDelegate>(&mut self.mint.to_account_info())
+            .ok()
+            .and_then(|extension| Option::<Pubkey>::from(extension.delegate))
+            .map_or(false, |delegate| delegate == self.owner_delegate.key())
+    }
+
+    fn is_owner_or_delegate_transfer(&self) -> bool {
+        let authority = self.owner_delegate.key();
+        self.source_account.owner == authority
+            || self.source_account.delegate == COption::Some(authority)
+    }
+
+    /// Transfers are exempt when the source or destination owner, or the program invoked by
+    /// the transaction, is in the royalty exemptions of the group
+    fn is_royalty_exempt(
+        &self,
+        royalty_exemptions: &AccountInfo,
+        instructions: &AccountInfo,
+    ) -> Result<bool> {
+        let royalty_exemptions = match RoyaltyExemptions::load(royalty_exemptions)? {
+            Some(royalty_exemptions) => royalty_exemptions,
+            None => return Ok(false),
+        };
+        let current_index = load_current_index_checked(instructions)?;
+        let invoking_program =
+            load_instruction_at_checked(current_index as usize, instructions)?.program_id;
+        Ok([
+            self.source_account.owner,
+            self.destination_account.owner,
+            invoking_program,
+        ]
+        .iter()
+        .any(|address| royalty_exemptions.exemptions.contains(address)))
+    }
+
+    /// Consumes the approve account when its approval hasn't expired and matches the transfer
+    /// amount and destination owner, fails when the transfer is enforced and it isn't approved.
+    /// Returns the sale price of the consumed approval, 0 when none is consumed.
+    fn consume_approve_account(
+        &self,
+        approve_account: &AccountInfo,
+        enforced: bool,
+        amount: u64,
+    ) -> Result<u64> {
+        // approve accounts of previous layouts predate any approval still valid
+        let approve: Option<ApproveAccount> = if approve_account.data_len() < ApproveAccount::LEN {
+            None
+        } else {
+            Some(AnchorDeserialize::deserialize(
+                &mut &approve_account.try_borrow_data()?[8..],
+            )?)
+        };
+        let slot = Clock::get()?.slot;
+        match approve {
+            Some(mut approve) if approve.slot != 0 && slot <= approve.expiry_slot => {
+                // a live approval paid for another transfer can't be consumed by this one
+                if approve.amount != amount || approve.destination != self.destination_account.owner
+                {
+                    return if enforced {
+                        Err(MetadataErrors::ApprovalMismatch.into())
+                    } else {
+                        Ok(0)
+                    };
+                }
+                // mark approve account as used by setting slot to 0, the rent payer isn't among
+                // the hook accounts so its rent is reclaimed with `close_expired_approval`
+                approve.slot = 0;
+                AnchorSerialize::serialize(
+                    &approve,
+                    &mut &mut approve_account.try_borrow_mut_data()?[8..],
+                )?;
+                // the hook can't take the event authority, the event is logged instead
+                emit!(ApprovedTransferExecuted {
+                    mint: self.mint.key(),
+                    source: self.source_account.key(),
+                    destination: self.destination_account.key(),
+                    amount,
+                    seller: approve.seller,
+                    buyer: approve.buyer,
+                    sale_price: approve.sale_price,
+                    payment_mint: approve.payment_mint,
+                });
+                Ok(approve.sale_price)
+            }
+            Some(approve) if approve.slot != 0 && enforced => {
+                Err(MetadataErrors::ApprovalExpired.into())
+            }
+            _ if enforced => Err(MetadataErrors::ExpiredApproveAccount.into()),
+            _ => Ok(0),
+        }
+    }
+}
+
+/// Royalty enforcement of the group, resolved after the approve account, the royalty
+/// exemptions and the instructions sysvar of group members
+fn get_royalty_enforcement(remaining_accounts: &[AccountInfo]) -> Result<RoyaltyEnforcement> {
+    let royalty_enforcement = match remaining_accounts.get(3) {
+        Some(royalty_enforcement) => RoyaltyEnforcement::load(royalty_enforcement)?,
+        None => None,
+    };
+    Ok(royalty_enforcement.unwrap_or(RoyaltyEnforcement {
+        group: Pubkey::default(),
+        mode: EnforcementMode::default(),
+        memo_required: false,
+    }))
+}
+
+/// Enforces the royalties of the transfer in the enforcement mode of the group, returns the
+/// sale price of the consumed approval, 0 without one
+fn enforce_royalties(
+    ctx: &Context<ExecuteTransferHook>,
+    enforcement_mode: EnforcementMode,
+    amount: u64,
+) -> Result<u64> {
+    // zero amount transfers (e.g. account cleanups) move nothing to pay royalties on
+    if amount == 0 {
+        return Ok(0);
+    }
+
+    // permanent delegate moves (e.g. clawbacks) are not royalty enforced
+    if ctx.accounts.is_permanent_delegate_transfer() {
+        return Ok(0);
+    }
+
+    // the owner or its approved delegate moves the tokens, both are enforced the same way
+    if !ctx.accounts.is_owner_or_delegate_transfer() {
+        return Err(anchor_lang::error::ErrorCode::ConstraintTokenOwner.into());
+    }
+
+    // mints without royalties have no extra metas, or hold the system program in place of the
+    // approve account when they are group members, they aren't royalty enforced
+    let Some((approve_account, group_accounts)) = ctx.remaining_accounts.split_first() else {
+        return Ok(0);
+    };
+    if approve_account.key() == system_program::ID {
+        return Ok(0);
+    }
+
+    // zero royalty mints whose extra metas predate the minimal meta list have nothing to
+    // enforce either, external metadata isn't available to the hook so it's always enforced
+    let mint = ctx.accounts.mint.to_account_info();
+    if get_metadata_address(&mint)? == mint.key()
+        && get_royalty_basis_points(&get_metadata(&mint, None)?) == 0
+    {
+        return Ok(0);
+    }
+
+    // group members also resolve the group royalty exemptions and the instructions sysvar
+    if let [royalty_exemptions, instructions, ..] = group_accounts {
+        if ctx
+            .accounts
+            .is_royalty_exempt(royalty_exemptions, instructions)?
+        {
+            return Ok(0);
+        }
+    }
+
+    match enforcement_mode {
+        EnforcementMode::Strict => {
+            ctx.accounts
+                .consume_approve_account(approve_account, true, amount)
+        }
+        // if transfer is a cpi, enforce royalties, else do nothing
+        EnforcementMode::Standard if hook_in_cpi() => {
+            ctx.accounts
+                .consume_approve_account(approve_account, true, amount)
+        }
+        EnforcementMode::Standard => Ok(0),
+        EnforcementMode::Permissive => {
+            ctx.accounts
+                .consume_approve_account(approve_account, false, amount)
+        }
+        EnforcementMode::Frozen => Err(MetadataErrors::TransfersFrozen.into()),
+    }
+}
+
+/// Token-2022 only invokes the hook on transfers, burns never need an approval.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ExecuteTransferHook<'info>>,
+    amount: u64,
+) -> Result<()> {
+    // only a real transfer can consume approvals, not a direct invocation of the hook
+    assert_is_transferring(&ctx.accounts.source_account.to_account_info())?;
+
+    // frozen groups reject every transfer of their members
+    let royalty_enforcement = get_royalty_enforcement(ctx.remaining_accounts)?;
+    if royalty_enforcement.mode == EnforcementMode::Frozen {
+        return Err(MetadataErrors::TransfersFrozen.into());
+    }
+
+    // the memo is looked up at the top level so transfers invoked by programs are annotated
+    // by the transaction that invoked them, every group member resolves the instructions sysvar
+    if royalty_enforcement.memo_required {
+        let instructions = ctx
+            .remaining_accounts
+            .get(2)
+            .ok_or(MetadataErrors::MemoRequired)?;
+        if !has_preceding_memo(instructions)? {
+            return Err(MetadataErrors::MemoRequired.into());
+        }
+    }
+
+    let sale_price = enforce_royalties(&ctx, royalty_enforcement.mode, amount)?;
+    ctx.accounts
+        .log_provenance(ctx.remaining_accounts, amount, sale_price)?;
+
+    // the secondary hook of the group runs once royalties are enforced, its failures fail
+    // the transfer
+    ctx.accounts
+        .invoke_secondary_hook(ctx.remaining_accounts, amount)
 }
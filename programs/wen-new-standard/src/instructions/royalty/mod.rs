@@ -1,5 +1,11 @@
 pub mod approve;
+pub mod close_approval;
 pub mod execute;
+pub mod provenance;
+pub mod update_metas;
 
 pub use approve::*;
+pub use close_approval::*;
 pub use execute::*;
+pub use provenance::*;
+pub use update_metas::*;
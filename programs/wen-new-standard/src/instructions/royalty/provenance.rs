@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+
+use anchor_spl::token_interface::Mint;
+
+use spl_tlv_account_resolution::state::ExtraAccountMetaList;
+use spl_transfer_hook_interface::instruction::ExecuteInstruction;
+
+use crate::{
+    get_provenance_meta, get_unenforced_approve_meta, get_written_metas, has_wns_transfer_hook,
+    update_account_lamports_to_minimum_balance, MetadataErrors, ProvenanceRecord,
+    META_LIST_ACCOUNT_SEED, PROVENANCE_SEED,
+};
+
+#[derive(Accounts)]
+#[instruction()]
+pub struct InitProvenance<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mint::token_program = anchor_spl::token_interface::spl_token_2022::id(),
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        init,
+        seeds = [PROVENANCE_SEED, mint.key().as_ref()],
+        bump,
+        payer = payer,
+        space = ProvenanceRecord::LEN
+    )]
+    pub provenance: Account<'info, ProvenanceRecord>,
+    /// CHECK: This account's data is a buffer of TLV data
+    #[account(
+        mut,
+        seeds = [META_LIST_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub extra_metas_account: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Opts a mint whose transfer hook invokes WNS in to the provenance log of its last transfers,
+/// with or without royalties, anyone can pay for it. The transfer hook resolves the record
+/// from then on.
+pub fn handler(ctx: Context<InitProvenance>) -> Result<()> {
+    let extra_metas_account = ctx.accounts.extra_metas_account.to_account_info();
+    if extra_metas_account.owner != &crate::ID {
+        return Err(MetadataErrors::InvalidExtraMetasAccount.into());
+    }
+    if !has_wns_transfer_hook(&ctx.accounts.mint.to_account_info())? {
+        return Err(MetadataErrors::MissingTransferHook.into());
+    }
+    // the hook only resolves the record after the approve account, mints without royalties
+    // hold the system program in its place
+    let mut metas = get_written_metas(&extra_metas_account)?;
+    if metas.is_empty() {
+        metas.push(get_unenforced_approve_meta());
+    }
+
+    ctx.accounts.provenance.mint = ctx.accounts.mint.key();
+
+    // the meta is kept by later rebuilds of the extra metas
+    let provenance_meta = get_provenance_meta()?;
+    if !metas.contains(&provenance_meta) {
+        metas.push(provenance_meta);
+        let new_size = ExtraAccountMetaList::size_of(metas.len())?;
+        extra_metas_account.realloc(new_size, false)?;
+        let mut data = vec![0u8; new_size];
+        ExtraAccountMetaList::init::<ExecuteInstruction>(&mut data, &metas)?;
+        extra_metas_account
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data);
+        update_account_lamports_to_minimum_balance(
+            extra_metas_account,
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        )?;
+    }
+
+    Ok(())
+}
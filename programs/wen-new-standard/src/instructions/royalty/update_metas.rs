@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+
+use anchor_spl::token_interface::Mint;
+
+use crate::{
+    get_external_metadata_account, get_meta_list_data, get_metadata, get_mint_group,
+    get_royalty_basis_points, get_secondary_hook_metas, get_secondary_hook_pda,
+    has_provenance_meta, refund_account_lamports_above_minimum_balance,
+    update_account_lamports_to_minimum_balance, ExtraMetasUpdated, MetadataErrors, SecondaryHook,
+    TokenGroupMember, MEMBER_ACCOUNT_SEED, META_LIST_ACCOUNT_SEED,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction()]
+pub struct UpdateExtraMetasList<'info> {
+    /// funds the growth of the extra metas account and is refunded when it shrinks
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mint::token_program = anchor_spl::token_interface::spl_token_2022::id(),
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    /// CHECK: account referenced by the metadata pointer, only needed for external metadata
+    pub metadata_account: Option<UncheckedAccount<'info>>,
+    /// CHECK: This account's data is a buffer of TLV data
+    #[account(
+        mut,
+        seeds = [META_LIST_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub extra_metas_account: UncheckedAccount<'info>,
+    #[account(
+        seeds = [MEMBER_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub member: Option<Account<'info, TokenGroupMember>>,
+    /// CHECK: secondary hook PDA of the member group, only needed for group members
+    pub secondary_hook: Option<UncheckedAccount<'info>>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Rewrites the extra metas of a mint to the canonical list of its current royalties and
+/// group, including the secondary hook of the group, anyone can bring a mint up to date.
+/// Succeeds without changes when it already is.
+pub fn handler(ctx: Context<UpdateExtraMetasList>) -> Result<()> {
+    // soulbound mints never had extra metas, there is nothing to update
+    let extra_metas_account = ctx.accounts.extra_metas_account.to_account_info();
+    if extra_metas_account.owner != &crate::ID {
+        return Err(MetadataErrors::InvalidExtraMetasAccount.into());
+    }
+
+    let mint = ctx.accounts.mint.to_account_info();
+    let metadata_account = ctx
+        .accounts
+        .metadata_account
+        .as_ref()
+        .map(|metadata_account| metadata_account.to_account_info());
+    let external_metadata_account =
+        get_external_metadata_account(&mint, metadata_account.as_ref())?;
+    let metadata = get_metadata(&mint, external_metadata_account)?;
+    let group = get_mint_group(&mint, ctx.accounts.member.as_deref())?;
+
+    // group members follow the secondary hook currently registered by their group
+    let mut secondary_hook_metas = vec![];
+    if let Some(group) = group {
+        let secondary_hook = ctx
+            .accounts
+            .secondary_hook
+            .as_ref()
+            .filter(|secondary_hook| secondary_hook.key() == get_secondary_hook_pda(group))
+            .ok_or(MetadataErrors::InvalidSecondaryHookAccount)?;
+        if let Some(secondary_hook) = SecondaryHook::load(&secondary_hook.to_account_info())? {
+            secondary_hook_metas = get_secondary_hook_metas(&secondary_hook)?;
+        }
+    }
+    let data = get_meta_list_data(
+        get_royalty_basis_points(&metadata) > 0,
+        group,
+        secondary_hook_metas,
+        has_provenance_meta(&extra_metas_account)?,
+    )?;
+    if *extra_metas_account.try_borrow_data()? == data[..] {
+        return Ok(());
+    }
+
+    extra_metas_account.realloc(data.len(), false)?;
+    extra_metas_account
+        .try_borrow_mut_data()?
+        .copy_from_slice(&data);
+
+    // adjust rent to the new list size
+    update_account_lamports_to_minimum_balance(
+        extra_metas_account.clone(),
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+    )?;
+    refund_account_lamports_above_minimum_balance(
+        &extra_metas_account,
+        &ctx.accounts.payer.to_account_info(),
+    )?;
+
+    emit_cpi!(ExtraMetasUpdated {
+        mint: ctx.accounts.mint.key(),
+        group,
+    });
+
+    Ok(())
+}
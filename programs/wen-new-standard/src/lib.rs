@@ -3,11 +3,13 @@
 use anchor_lang::prelude::*;
 
 pub mod errors;
+pub mod events;
 pub mod instructions;
 pub mod state;
 pub mod utils;
 
 pub use errors::*;
+pub use events::*;
 pub use instructions::*;
 pub use state::*;
 pub use utils::*;
@@ -37,6 +39,191 @@ pub mod wen_new_standard {
         instructions::group::update::handler(ctx, args)
     }
 
+    /// update the name, symbol and uri of the group mint
+    pub fn update_group_metadata(
+        ctx: Context<UpdateGroupMetadata>,
+        args: UpdateGroupMetadataArgs,
+    ) -> Result<()> {
+        instructions::group::metadata::handler(ctx, args)
+    }
+
+    /// return a page of the group member index
+    pub fn get_members_page(ctx: Context<GetMembersPage>, page: u32) -> Result<Vec<Pubkey>> {
+        instructions::group::members::get::handler(ctx, page)
+    }
+
+    /// return the size, max size and finalization of a group
+    pub fn get_group_supply(ctx: Context<GetGroupSupply>) -> Result<GroupSupply> {
+        instructions::group::supply::handler(ctx)
+    }
+
+    /// index a member added before the group member index existed
+    pub fn index_group_member(ctx: Context<IndexGroupMember>) -> Result<()> {
+        instructions::group::members::index::handler(ctx)
+    }
+
+    /// close an empty group, and its mint once the supply is burned
+    pub fn close_group(ctx: Context<CloseGroup>) -> Result<()> {
+        instructions::group::close::handler(ctx)
+    }
+
+    /// permanently lock the membership of a group
+    pub fn finalize_group(ctx: Context<FinalizeGroup>) -> Result<()> {
+        instructions::group::finalize::handler(ctx)
+    }
+
+    /// add a group as a sub-group of a parent group
+    pub fn add_group_to_group(ctx: Context<AddGroupToGroup>) -> Result<()> {
+        instructions::group::parent::handler(ctx)
+    }
+
+    /// let a delegate add members to the group on behalf of the group authority
+    pub fn approve_group_delegate(
+        ctx: Context<ApproveGroupDelegate>,
+        delegate: Pubkey,
+    ) -> Result<()> {
+        instructions::group::delegates::approve::handler(ctx, delegate)
+    }
+
+    /// revoke a group delegate
+    pub fn revoke_group_delegate(
+        ctx: Context<RevokeGroupDelegate>,
+        delegate: Pubkey,
+    ) -> Result<()> {
+        instructions::group::delegates::revoke::handler(ctx, delegate)
+    }
+
+    /// let a delegate perform some group operations on behalf of the group authority, until the grant expires
+    pub fn grant_group_permission(
+        ctx: Context<GrantGroupPermission>,
+        delegate: Pubkey,
+        args: GroupPermissionArgs,
+    ) -> Result<()> {
+        instructions::group::permissions::grant::handler(ctx, delegate, args)
+    }
+
+    /// revoke the permissions of a delegate
+    pub fn revoke_group_permission(
+        ctx: Context<RevokeGroupPermission>,
+        delegate: Pubkey,
+    ) -> Result<()> {
+        instructions::group::permissions::revoke::handler(ctx, delegate)
+    }
+
+    /// initialize the native token group extension in the group mint
+    pub fn migrate_group_to_native(ctx: Context<MigrateGroupToNative>) -> Result<()> {
+        instructions::group::native::handler(ctx)
+    }
+
+    /// nominate a new group update authority
+    pub fn nominate_group_authority(
+        ctx: Context<NominateGroupAuthority>,
+        nominee: Pubkey,
+    ) -> Result<()> {
+        instructions::group::authority::nominate::handler(ctx, nominee)
+    }
+
+    /// accept a group update authority nomination
+    pub fn accept_group_authority(ctx: Context<AcceptGroupAuthority>) -> Result<()> {
+        instructions::group::authority::accept::handler(ctx)
+    }
+
+    /// cancel a group update authority nomination
+    pub fn cancel_group_authority_nomination(
+        ctx: Context<CancelGroupAuthorityNomination>,
+    ) -> Result<()> {
+        instructions::group::authority::cancel::handler(ctx)
+    }
+
+    /// set the extension policy of group members
+    pub fn set_group_extension_policy(
+        ctx: Context<SetGroupExtensionPolicy>,
+        args: GroupExtensionPolicyArgs,
+    ) -> Result<()> {
+        instructions::group::policy::handler(ctx, args)
+    }
+
+    /// set the price, treasury and window of the public mint of a group
+    pub fn set_mint_config(ctx: Context<SetMintConfig>, args: MintConfigArgs) -> Result<()> {
+        instructions::group::mint_config::set::handler(ctx, args)
+    }
+
+    /// end the public mint of a group
+    pub fn close_mint_config(ctx: Context<CloseMintConfig>) -> Result<()> {
+        instructions::group::mint_config::close::handler(ctx)
+    }
+
+    /// set the allowlist of member mint receivers
+    pub fn set_mint_allowlist(
+        ctx: Context<SetMintAllowlist>,
+        args: MintAllowlistArgs,
+    ) -> Result<()> {
+        instructions::group::allowlist::handler(ctx, args)
+    }
+
+    /// set the royalties inherited by group member mints
+    pub fn set_group_royalty_defaults(
+        ctx: Context<SetGroupRoyaltyDefaults>,
+        args: GroupRoyaltyDefaultsArgs,
+    ) -> Result<()> {
+        instructions::group::royalties::handler(ctx, args)
+    }
+
+    /// set how strictly royalties are enforced on transfers of group members, or freeze them
+    pub fn set_enforcement_mode(
+        ctx: Context<SetEnforcementMode>,
+        mode: EnforcementMode,
+    ) -> Result<()> {
+        instructions::group::enforcement::handler(ctx, mode)
+    }
+
+    /// register a transfer hook program invoked after royalties on transfers of group members
+    pub fn register_secondary_hook(
+        ctx: Context<RegisterSecondaryHook>,
+        program_id: Pubkey,
+        accounts: Vec<SecondaryHookAccount>,
+    ) -> Result<()> {
+        instructions::group::secondary_hook::register::handler(ctx, program_id, accounts)
+    }
+
+    /// unregister the secondary transfer hook of a group
+    pub fn unregister_secondary_hook(ctx: Context<UnregisterSecondaryHook>) -> Result<()> {
+        instructions::group::secondary_hook::unregister::handler(ctx)
+    }
+
+    /// require transfers of group members to be annotated with a memo
+    pub fn set_memo_required(ctx: Context<SetMemoRequired>, required: bool) -> Result<()> {
+        instructions::group::memo::handler(ctx, required)
+    }
+
+    /// route royalties of group members to a single distribution of the group mint
+    pub fn set_collection_distribution(
+        ctx: Context<SetCollectionDistribution>,
+        enabled: bool,
+    ) -> Result<()> {
+        instructions::group::distribution::handler(ctx, enabled)
+    }
+
+    /// set the delay before royalty updates of group members take effect
+    pub fn set_royalty_timelock(ctx: Context<SetRoyaltyTimelock>, delay: i64) -> Result<()> {
+        instructions::group::timelock::set::handler(ctx, delay)
+    }
+
+    /// cancel a pending royalty update of a group member
+    pub fn cancel_royalty_update(ctx: Context<CancelRoyaltyUpdate>) -> Result<()> {
+        instructions::group::timelock::cancel::handler(ctx)
+    }
+
+    /// exempt an owner or program from royalties on transfers of group members
+    pub fn add_exemption(ctx: Context<AddExemption>, address: Pubkey) -> Result<()> {
+        instructions::group::exemptions::add::handler(ctx, address)
+    }
+
+    /// remove a royalty exemption of the group
+    pub fn remove_exemption(ctx: Context<RemoveExemption>, address: Pubkey) -> Result<()> {
+        instructions::group::exemptions::remove::handler(ctx, address)
+    }
+
     /*
         Token mint instructions
     */
@@ -44,31 +231,268 @@ pub mod wen_new_standard {
     pub fn create_mint_account(
         ctx: Context<CreateMintAccount>,
         args: CreateMintAccountArgs,
-    ) -> Result<()> {
+    ) -> Result<CreatedMintAccount> {
         instructions::mint::create::handler(ctx, args)
     }
 
+    /// create mint at a PDA derived from the authority and a user seed
+    pub fn create_mint_account_pda(
+        ctx: Context<CreateMintAccountPda>,
+        args: CreateMintAccountArgs,
+        user_seed: String,
+    ) -> Result<CreatedMintAccount> {
+        instructions::mint::create_pda::handler(ctx, args, user_seed)
+    }
+
+    /// create multiple mints
+    pub fn create_mint_accounts_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CreateMintAccountsBatch<'info>>,
+        args: Vec<CreateMintAccountArgs>,
+    ) -> Result<()> {
+        instructions::mint::create_batch::handler(ctx, args)
+    }
+
+    /// claw back a mint from its holder using the manager permanent delegate
+    pub fn clawback<'info>(ctx: Context<'_, '_, 'info, 'info, Clawback<'info>>) -> Result<()> {
+        instructions::mint::clawback::handler(ctx)
+    }
+
+    /// create a manager namespaced to the authority
+    pub fn init_manager_for_authority(ctx: Context<InitManagerForAuthority>) -> Result<()> {
+        instructions::mint::init_manager::handler(ctx)
+    }
+
+    /// thaw a mint token account
+    pub fn thaw_mint_account(ctx: Context<ThawMintAccount>) -> Result<()> {
+        instructions::mint::thaw::handler(ctx)
+    }
+
+    /// burn a mint token account of the holder, no transfer approval needed
+    pub fn burn_mint_account(ctx: Context<BurnMintAccount>) -> Result<()> {
+        instructions::mint::burn::handler(ctx)
+    }
+
+    /// close the record of a burned mint, signed by the record payer until the mint authority is revoked
+    pub fn close_mint_record(ctx: Context<CloseMintRecord>) -> Result<()> {
+        instructions::mint::close_record::handler(ctx)
+    }
+
+    /// create mint without minting it
+    pub fn create_mint_account_unminted(
+        ctx: Context<CreateMintAccountUnminted>,
+        args: CreateMintAccountArgs,
+    ) -> Result<()> {
+        instructions::mint::create_unminted::handler(ctx, args)
+    }
+
+    /// mint an unminted mint to its claimer
+    pub fn mint_to_claimer(ctx: Context<MintToClaimer>) -> Result<()> {
+        instructions::mint::claim::handler(ctx)
+    }
+
+    /// revoke the manager mint authority
+    pub fn finalize_mint(ctx: Context<FinalizeMint>) -> Result<()> {
+        instructions::mint::finalize::handler(ctx)
+    }
+
     /// add mint to group
-    pub fn add_group_to_mint(ctx: Context<AddGroup>) -> Result<()> {
+    pub fn add_group_to_mint(ctx: Context<AddGroup>) -> Result<u32> {
         instructions::mint::group::add::handler(ctx)
     }
 
+    /// add up to `MAX_MEMBER_BATCH_SIZE` mints to group
+    pub fn add_group_members_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, AddGroupMembersBatch<'info>>,
+    ) -> Result<Vec<u32>> {
+        instructions::mint::group::add_batch::handler(ctx)
+    }
+
+    /// move a member mint to another group, signed by both group authorities
+    pub fn move_member(ctx: Context<MoveMember>) -> Result<u32> {
+        instructions::mint::group::move_member::handler(ctx)
+    }
+
+    /// create a member mint of a group for the price of its public mint
+    pub fn public_mint(ctx: Context<PublicMint>) -> Result<()> {
+        instructions::mint::group::public::handler(ctx)
+    }
+
+    /// initialize the native token group member extension in a member mint
+    pub fn migrate_member_to_native(ctx: Context<MigrateMemberToNative>) -> Result<()> {
+        instructions::mint::group::native::handler(ctx)
+    }
+
+    /// remove mint from its group, closing the member account
+    pub fn remove_group_from_mint(ctx: Context<RemoveGroup>) -> Result<()> {
+        instructions::mint::group::remove::handler(ctx)
+    }
+
+    /// create mint and add it to group
+    pub fn create_group_member_mint(
+        ctx: Context<CreateGroupMemberMint>,
+        args: CreateMintAccountArgs,
+        proof: Option<Vec<[u8; 32]>>,
+    ) -> Result<()> {
+        instructions::mint::group::create::handler(ctx, args, proof)
+    }
+
+    /// enable numbered editions of a master mint
+    pub fn enable_editions(ctx: Context<EnableEditions>, args: EnableEditionsArgs) -> Result<()> {
+        instructions::mint::edition::enable::handler(ctx, args)
+    }
+
+    /// print the next edition of a master mint
+    pub fn print_edition(ctx: Context<PrintEdition>) -> Result<()> {
+        instructions::mint::edition::print::handler(ctx)
+    }
+
+    /// add metadata to mint
+    pub fn add_metadata(
+        ctx: Context<AddMetadata>,
+        args: AddMetadataArgs,
+        mode: Option<MetadataWriteMode>,
+    ) -> Result<()> {
+        instructions::mint::metadata::add::handler(ctx, args, mode)
+    }
+
+    /// add multiple metadata fields to mint
+    pub fn add_metadata_batch(
+        ctx: Context<AddMetadataBatch>,
+        args: Vec<AddMetadataArgs>,
+    ) -> Result<()> {
+        instructions::mint::metadata::add_batch::handler(ctx, args)
+    }
+
+    /// remove metadata from mint
+    pub fn remove_metadata(ctx: Context<RemoveMetadata>, args: RemoveMetadataArgs) -> Result<()> {
+        instructions::mint::metadata::remove::handler(ctx, args)
+    }
+
+    /// update the name, symbol and uri of mint
+    pub fn update_mint_metadata(
+        ctx: Context<UpdateMintMetadata>,
+        args: UpdateMintMetadataArgs,
+    ) -> Result<()> {
+        instructions::mint::metadata::update::handler(ctx, args)
+    }
+
+    /// nominate a new metadata update authority
+    pub fn nominate_metadata_authority(
+        ctx: Context<NominateMetadataAuthority>,
+        nominee: Pubkey,
+    ) -> Result<()> {
+        instructions::mint::metadata::nominate::handler(ctx, nominee)
+    }
+
+    /// accept a metadata update authority nomination
+    pub fn accept_metadata_authority(ctx: Context<AcceptMetadataAuthority>) -> Result<()> {
+        instructions::mint::metadata::accept::handler(ctx)
+    }
+
+    /// cancel a metadata update authority nomination
+    pub fn cancel_nomination(ctx: Context<CancelNomination>) -> Result<()> {
+        instructions::mint::metadata::cancel_nomination::handler(ctx)
+    }
+
+    /// set or clear the content hash of mint
+    pub fn set_content_hash(
+        ctx: Context<SetContentHash>,
+        content_hash: Option<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::mint::metadata::set_content_hash::handler(ctx, content_hash)
+    }
+
+    /// check a hash against the content hash of mint
+    pub fn verify_content_hash(
+        ctx: Context<VerifyContentHash>,
+        content_hash: [u8; 32],
+    ) -> Result<bool> {
+        instructions::mint::metadata::verify_content_hash::handler(ctx, content_hash)
+    }
+
+    /// make mint metadata immutable
+    pub fn make_immutable(ctx: Context<MakeImmutable>) -> Result<()> {
+        instructions::mint::metadata::immutable::handler(ctx)
+    }
+
+    /// remove the metadata update authority once the collection is revealed, same as `make_immutable`
+    pub fn freeze_metadata(ctx: Context<MakeImmutable>) -> Result<()> {
+        instructions::mint::metadata::immutable::handler(ctx)
+    }
+
     /// add royalties to mint
     pub fn add_royalties_to_mint(ctx: Context<AddRoyalties>, args: AddRoyaltiesArgs) -> Result<()> {
         instructions::mint::royalties::add::handler(ctx, args)
     }
 
+    /// modify royalties of mint
+    pub fn modify_royalties(ctx: Context<ModifyRoyalties>, args: AddRoyaltiesArgs) -> Result<()> {
+        instructions::mint::royalties::modify::handler(ctx, args)
+    }
+
+    /// apply pending royalties of a timelocked group member
+    pub fn apply_royalty_update(ctx: Context<ModifyRoyalties>) -> Result<()> {
+        instructions::mint::royalties::apply::handler(ctx)
+    }
+
+    /// remove royalties of mint
+    pub fn remove_royalties(ctx: Context<RemoveRoyalties>) -> Result<()> {
+        instructions::mint::royalties::remove::handler(ctx)
+    }
+
+    /// verify the signing creator of mint royalties
+    pub fn verify_creator(ctx: Context<VerifyCreator>) -> Result<()> {
+        instructions::mint::royalties::verify::handler(ctx)
+    }
+
+    /// unverify the signing creator of mint royalties
+    pub fn unverify_creator(ctx: Context<UnverifyCreator>) -> Result<()> {
+        instructions::mint::royalties::unverify::handler(ctx)
+    }
+
     /*
         Royalty distribution + enforcement instructions
     */
+    /// close a consumed or expired approval
+    pub fn close_expired_approval(ctx: Context<CloseExpiredApproval>) -> Result<()> {
+        instructions::royalty::close_approval::handler(ctx)
+    }
+
+    /// opt a mint in to the provenance log of its transfers
+    pub fn init_provenance(ctx: Context<InitProvenance>) -> Result<()> {
+        instructions::royalty::provenance::handler(ctx)
+    }
+
+    /// rewrite the extra metas of a mint to its canonical list
+    pub fn update_extra_metas_list(ctx: Context<UpdateExtraMetasList>) -> Result<()> {
+        instructions::royalty::update_metas::handler(ctx)
+    }
+
     /// validate transfer
     #[interface(spl_transfer_hook_interface::execute)]
-    pub fn execute(ctx: Context<ExecuteTransferHook>, _amount: u64) -> Result<()> {
-        instructions::royalty::execute::handler(ctx)
+    pub fn execute<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExecuteTransferHook<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::royalty::execute::handler(ctx, amount)
     }
 
     /// approve transfer
-    pub fn approve(ctx: Context<ApproveTransfer>, amount: u64) -> Result<()> {
-        instructions::royalty::approve::handler(ctx, amount)
+    pub fn approve(
+        ctx: Context<ApproveTransfer>,
+        amount: u64,
+        expiry_slot: Option<u64>,
+        transfer_amount: u64,
+        destination: Pubkey,
+        seller: Pubkey,
+    ) -> Result<()> {
+        instructions::royalty::approve::handler(
+            ctx,
+            amount,
+            expiry_slot,
+            transfer_amount,
+            destination,
+            seller,
+        )
     }
 }
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+/// Merkle allowlist of the receivers of member mints, set by the group authority
+#[account()]
+pub struct MintAllowlist {
+    /// The pubkey of the `TokenGroup`
+    pub group: Pubkey,
+    /// Root of the Merkle tree of allowed receivers, see `verify_allowlist_proof`
+    pub root: [u8; 32],
+    /// Maximum number of member mints per receiver
+    pub limit_per_wallet: u32,
+}
+
+impl MintAllowlist {
+    pub const LEN: usize = 8 + 32 + 32 + 4;
+
+    /// Loads the allowlist from its PDA, `None` when the group authority never set one
+    pub fn load(account: &AccountInfo) -> Result<Option<Self>> {
+        if account.data_is_empty() {
+            return Ok(None);
+        }
+        if account.owner != &crate::id() {
+            return Err(ErrorCode::AccountOwnedByWrongProgram.into());
+        }
+        let data = account.try_borrow_data()?;
+        Ok(Some(Self::try_deserialize(&mut &data[..])?))
+    }
+}
+
+/// Number of member mints received by a wallet through the group allowlist
+#[account()]
+pub struct AllowlistClaim {
+    pub count: u32,
+}
+
+impl AllowlistClaim {
+    pub const LEN: usize = 8 + 4;
+}
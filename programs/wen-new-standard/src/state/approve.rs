@@ -2,9 +2,28 @@ use anchor_lang::prelude::*;
 
 #[account()]
 pub struct ApproveAccount {
+    /// Slot of the approval, 0 once consumed by a transfer
     pub slot: u64,
+    /// Last slot in which the approval can be consumed
+    pub expiry_slot: u64,
+    /// Funded the account rent, refunded when the expired approval is closed
+    pub payer: Pubkey,
+    /// Token amount of the approved transfer
+    pub amount: u64,
+    /// Destination owner of the approved transfer
+    pub destination: Pubkey,
+    /// Source owner of the approved transfer, as declared by the marketplace
+    pub seller: Pubkey,
+    /// Authority that paid the sale and its royalties
+    pub buyer: Pubkey,
+    /// Sale amount the royalties were taken from
+    pub sale_price: u64,
+    /// Mint the royalties were paid in, `Pubkey::default()` for SOL
+    pub payment_mint: Pubkey,
+    /// Royalty amount paid for the approved transfer
+    pub royalty_amount: u64,
 }
 
 impl ApproveAccount {
-    pub const LEN: usize = 8 + 8;
+    pub const LEN: usize = 8 + 8 + 8 + 32 + 8 + 32 + 32 + 32 + 8 + 32 + 8;
 }
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+/// Routes the royalties of group members to a single distribution of the group mint, set by
+/// the group authority
+#[account()]
+pub struct CollectionDistribution {
+    /// The pubkey of the `TokenGroup`
+    pub group: Pubkey,
+    pub enabled: bool,
+}
+
+impl CollectionDistribution {
+    pub const LEN: usize = 8 + 32 + 1;
+
+    /// Loads the setting from its PDA, `None` when the group authority never set it
+    pub fn load(account: &AccountInfo) -> Result<Option<Self>> {
+        if account.data_is_empty() {
+            return Ok(None);
+        }
+        if account.owner != &crate::id() {
+            return Err(ErrorCode::AccountOwnedByWrongProgram.into());
+        }
+        let data = account.try_borrow_data()?;
+        Ok(Some(Self::try_deserialize(&mut &data[..])?))
+    }
+}
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+/// Numbered copies authorized for a master mint, printed through `print_edition`
+#[account()]
+pub struct MasterEdition {
+    /// The master mint
+    pub mint: Pubkey,
+    /// Maximum number of editions
+    pub max_supply: u64,
+    /// Number of editions printed, also the number of the last edition
+    pub current_supply: u64,
+}
+
+impl MasterEdition {
+    pub const LEN: usize = 8 + 32 + 8 + 8;
+
+    /// Returns the number of the next edition, fails once all editions are printed
+    pub fn increment_supply(&mut self) -> Result<u64> {
+        if self.current_supply >= self.max_supply {
+            return Err(crate::MetadataErrors::MaxEditionsReached.into());
+        }
+        self.current_supply += 1;
+        Ok(self.current_supply)
+    }
+}
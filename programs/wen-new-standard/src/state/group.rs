@@ -11,9 +11,10 @@ pub struct TokenGroup {
     /// The associated mint, used to counter spoofing to be sure that group
     /// belongs to a particular mint
     pub mint: Pubkey,
-    /// The current number of group members
+    /// The current number of group members. Counts member mints, not the
+    /// supply of each mint
     pub size: u32,
-    /// The maximum number of group members
+    /// The maximum number of group members, 0 for unlimited
     pub max_size: u32,
 }
 
@@ -30,10 +31,15 @@ impl TokenGroup {
         }
     }
 
+    /// Whether the group can't take another member
+    pub fn is_full(&self) -> bool {
+        self.max_size != 0 && self.size >= self.max_size
+    }
+
     /// Updates the max size for a group
     pub fn update_max_size(&mut self, new_max_size: u32) -> Result<()> {
-        // The new max size cannot be less than the current size
-        if new_max_size < self.size {
+        // The new max size cannot be less than the current size, unless unlimited
+        if new_max_size != 0 && new_max_size < self.size {
             return Err(MetadataErrors::MaxSizeBelowCurrentSize.into());
         }
         self.max_size = new_max_size;
@@ -43,20 +49,20 @@ impl TokenGroup {
     /// Increment the size for a group, returning the new size
     pub fn increment_size(&mut self) -> Result<u32> {
         // The new size cannot be greater than the max size
+        if self.is_full() {
+            return Err(MetadataErrors::GroupFull.into());
+        }
         let new_size = self
             .size
             .checked_add(1)
             .ok_or::<ProgramError>(PodSliceError::CalculationFailure.into())?;
-        if new_size > self.max_size {
-            return Err(MetadataErrors::SizeExceedsMaxSize.into());
-        }
         self.size = new_size;
         Ok(new_size)
     }
 
-    /// Increment the size for a group, returning the new size
+    /// Decrement the size for a group, returning the new size
     pub fn decrement_size(&mut self) -> Result<u32> {
-        // The new size cannot be greater than the max size
+        // The size cannot go below zero
         let new_size = self
             .size
             .checked_sub(1)
@@ -65,3 +71,96 @@ impl TokenGroup {
         Ok(new_size)
     }
 }
+
+/// Marks a group as finalized, no member can ever be added once it exists
+#[account()]
+pub struct GroupFinalization {
+    /// The pubkey of the `TokenGroup`
+    pub group: Pubkey,
+    /// Size of the group at finalization
+    pub size: u32,
+}
+
+impl GroupFinalization {
+    pub const LEN: usize = 8 + 32 + 4;
+
+    /// Loads the finalization from its PDA, `None` while the group isn't finalized
+    pub fn load(account: &AccountInfo) -> Result<Option<Self>> {
+        if account.data_is_empty() {
+            return Ok(None);
+        }
+        if account.owner != &crate::id() {
+            return Err(ErrorCode::AccountOwnedByWrongProgram.into());
+        }
+        let data = account.try_borrow_data()?;
+        Ok(Some(Self::try_deserialize(&mut &data[..])?))
+    }
+}
+
+/// Marks a group as soulbound, set at group creation and never removed. Members of soulbound
+/// groups must be non-transferable and the transfer hook treats the group as `Frozen`
+#[account()]
+pub struct GroupSoulbound {
+    /// The pubkey of the `TokenGroup`
+    pub group: Pubkey,
+}
+
+impl GroupSoulbound {
+    pub const LEN: usize = 8 + 32;
+
+    /// Loads the soulbound marker from its PDA, `None` for transferable groups
+    pub fn load(account: &AccountInfo) -> Result<Option<Self>> {
+        if account.data_is_empty() {
+            return Ok(None);
+        }
+        if account.owner != &crate::id() {
+            return Err(ErrorCode::AccountOwnedByWrongProgram.into());
+        }
+        let data = account.try_borrow_data()?;
+        Ok(Some(Self::try_deserialize(&mut &data[..])?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn group(size: u32, max_size: u32) -> TokenGroup {
+        TokenGroup {
+            size,
+            ..TokenGroup::new(&Pubkey::new_unique(), Pubkey::new_unique(), max_size)
+        }
+    }
+
+    #[test]
+    fn max_size_can_shrink_to_the_current_size() {
+        let mut group = group(3, 10);
+        group.update_max_size(3).unwrap();
+        assert_eq!(group.max_size, 3);
+        assert!(group.is_full());
+        assert!(group.increment_size().is_err());
+    }
+
+    #[test]
+    fn max_size_cannot_shrink_below_the_current_size() {
+        let mut group = group(3, 10);
+        assert_eq!(
+            group.update_max_size(2).unwrap_err(),
+            anchor_lang::error::Error::from(MetadataErrors::MaxSizeBelowCurrentSize)
+        );
+        assert_eq!(group.max_size, 10);
+    }
+
+    #[test]
+    fn max_size_can_grow_or_become_unlimited() {
+        let mut group = group(3, 3);
+        group.update_max_size(4).unwrap();
+        assert_eq!(group.max_size, 4);
+        assert!(!group.is_full());
+        assert_eq!(group.increment_size().unwrap(), 4);
+
+        group.update_max_size(0).unwrap();
+        assert_eq!(group.max_size, 0);
+        assert!(!group.is_full());
+    }
+}
@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+/// Maximum number of entries of `GroupDelegates`
+pub const MAX_GROUP_DELEGATES: usize = 8;
+
+/// Signers allowed to add members to a group on behalf of the group authority, e.g. launchpads
+#[account()]
+pub struct GroupDelegates {
+    /// The pubkey of the `TokenGroup`
+    pub group: Pubkey,
+    /// Wallets or program PDAs signing through CPI
+    pub delegates: Vec<Pubkey>,
+}
+
+impl GroupDelegates {
+    pub const LEN: usize = 8 + 32 + 4 + 32 * MAX_GROUP_DELEGATES;
+
+    /// Loads the delegates from their PDA, `None` when the group authority never approved any
+    pub fn load(account: &AccountInfo) -> Result<Option<Self>> {
+        if account.data_is_empty() {
+            return Ok(None);
+        }
+        if account.owner != &crate::id() {
+            return Err(ErrorCode::AccountOwnedByWrongProgram.into());
+        }
+        let data = account.try_borrow_data()?;
+        Ok(Some(Self::try_deserialize(&mut &data[..])?))
+    }
+}
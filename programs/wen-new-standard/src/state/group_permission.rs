@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+
+/// Operations a delegate may perform on a group on behalf of the group authority, until
+/// the grant expires or is revoked
+#[account()]
+pub struct GroupPermission {
+    /// The pubkey of the `TokenGroup`
+    pub group: Pubkey,
+    pub delegate: Pubkey,
+    /// Bitmask of the `GroupPermission` operation flags
+    pub permissions: u8,
+    /// Unix timestamp the grant expires at, never expires when `None`
+    pub expires_at: Option<i64>,
+}
+
+impl GroupPermission {
+    pub const LEN: usize = 8 + 32 + 32 + 1 + 9;
+
+    /// `update_group_metadata`, once the group mint metadata update authority is the `Manager` PDA
+    pub const UPDATE_METADATA: u8 = 1 << 0;
+    /// `set_group_royalty_defaults`
+    pub const UPDATE_ROYALTY_DEFAULTS: u8 = 1 << 1;
+    /// Adding and creating member mints
+    pub const ADD_MEMBER: u8 = 1 << 2;
+    /// `set_group_extension_policy`
+    pub const SET_POLICY: u8 = 1 << 3;
+    pub const ALL: u8 =
+        Self::UPDATE_METADATA | Self::UPDATE_ROYALTY_DEFAULTS | Self::ADD_MEMBER | Self::SET_POLICY;
+
+    /// Loads the grant from its PDA, `None` when the delegate was never granted permissions
+    pub fn load(account: &AccountInfo) -> Result<Option<Self>> {
+        if account.data_is_empty() {
+            return Ok(None);
+        }
+        if account.owner != &crate::id() {
+            return Err(ErrorCode::AccountOwnedByWrongProgram.into());
+        }
+        let data = account.try_borrow_data()?;
+        Ok(Some(Self::try_deserialize(&mut &data[..])?))
+    }
+
+    /// Whether the grant covers `permission` at `now`
+    pub fn allows(&self, permission: u8, now: i64) -> bool {
+        self.permissions & permission == permission
+            && self.expires_at.map_or(true, |expires_at| now < expires_at)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grant(permissions: u8, expires_at: Option<i64>) -> GroupPermission {
+        GroupPermission {
+            group: Pubkey::new_unique(),
+            delegate: Pubkey::new_unique(),
+            permissions,
+            expires_at,
+        }
+    }
+
+    #[test]
+    fn grant_allows_its_permissions_only() {
+        let grant = grant(
+            GroupPermission::ADD_MEMBER | GroupPermission::SET_POLICY,
+            None,
+        );
+        assert!(grant.allows(GroupPermission::ADD_MEMBER, 0));
+        assert!(grant.allows(GroupPermission::SET_POLICY, 0));
+        assert!(grant.allows(GroupPermission::ADD_MEMBER | GroupPermission::SET_POLICY, 0));
+        assert!(!grant.allows(GroupPermission::UPDATE_METADATA, 0));
+        // every flag of a combined permission must be granted
+        assert!(!grant.allows(
+            GroupPermission::ADD_MEMBER | GroupPermission::UPDATE_ROYALTY_DEFAULTS,
+            0
+        ));
+        assert!(!grant.allows(GroupPermission::ALL, 0));
+    }
+
+    #[test]
+    fn grant_without_expiry_never_expires() {
+        let grant = grant(GroupPermission::ALL, None);
+        assert!(grant.allows(GroupPermission::ALL, i64::MIN));
+        assert!(grant.allows(GroupPermission::ALL, i64::MAX));
+    }
+
+    #[test]
+    fn grant_expires_at_its_expiry() {
+        let grant = grant(GroupPermission::ADD_MEMBER, Some(1_000));
+        assert!(grant.allows(GroupPermission::ADD_MEMBER, 999));
+        assert!(!grant.allows(GroupPermission::ADD_MEMBER, 1_000));
+        assert!(!grant.allows(GroupPermission::ADD_MEMBER, 1_001));
+    }
+}
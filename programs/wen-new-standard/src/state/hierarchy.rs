@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+/// Maximum number of ancestors of a sub-group
+pub const MAX_GROUP_DEPTH: usize = 3;
+
+/// Position of a group in a hierarchy of groups, e.g. seasons of a parent collection
+#[account()]
+pub struct GroupHierarchy {
+    /// The pubkey of the `TokenGroup`, unset until the group joins a hierarchy
+    pub group: Pubkey,
+    /// The parent group, `None` for top level groups
+    pub parent: Option<Pubkey>,
+    /// All the ancestors of the group, parent first
+    pub ancestors: Vec<Pubkey>,
+    /// Number of sub-groups of the group
+    pub children: u32,
+}
+
+impl GroupHierarchy {
+    pub const LEN: usize = 8 + 32 + 1 + 32 + 4 + 32 * MAX_GROUP_DEPTH + 4;
+
+    /// Loads the hierarchy from its PDA, `None` when the group never joined a hierarchy
+    pub fn load(account: &AccountInfo) -> Result<Option<Self>> {
+        if account.data_is_empty() {
+            return Ok(None);
+        }
+        if account.owner != &crate::id() {
+            return Err(ErrorCode::AccountOwnedByWrongProgram.into());
+        }
+        let data = account.try_borrow_data()?;
+        Ok(Some(Self::try_deserialize(&mut &data[..])?))
+    }
+}
@@ -9,6 +9,15 @@ impl Manager {
     pub fn new() -> Self {
         Self {}
     }
+
+    /// Seed following `MANAGER_SEED` for the manager namespaced to `authority`, empty for the
+    /// global manager. Empty seeds don't change the derived address, so `[MANAGER_SEED, &[]]`
+    /// derives the global manager and its bump.
+    pub fn namespace(authority: Option<&Pubkey>) -> &[u8] {
+        authority
+            .map(|authority| authority.as_ref())
+            .unwrap_or_default()
+    }
 }
 
 impl Default for Manager {
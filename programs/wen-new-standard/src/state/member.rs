@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::MetadataErrors;
+
 /// Data struct for a `TokenGroupMember`
 #[account()]
 pub struct TokenGroupMember {
@@ -22,3 +24,35 @@ impl TokenGroupMember {
         }
     }
 }
+
+/// Last member number assigned in a group. Numbers are never reused, so members
+/// keep a stable number when others are removed.
+#[account()]
+pub struct GroupMemberCounter {
+    /// The pubkey of the `TokenGroup`, unset until the first member number is assigned
+    pub group: Pubkey,
+    /// The last assigned member number
+    pub last_member_number: u32,
+}
+
+impl GroupMemberCounter {
+    pub const LEN: usize = 8 + 32 + 4;
+
+    /// Starts counting from the group size when the counter is created for an existing group,
+    /// members added before the counter were numbered by the group size
+    pub fn init(&mut self, group: Pubkey, size: u32) {
+        if self.group == Pubkey::default() {
+            self.group = group;
+            self.last_member_number = size;
+        }
+    }
+
+    /// Assigns the next member number
+    pub fn next_member_number(&mut self) -> Result<u32> {
+        self.last_member_number = self
+            .last_member_number
+            .checked_add(1)
+            .ok_or(MetadataErrors::ArithmeticOverflow)?;
+        Ok(self.last_member_number)
+    }
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+/// Number of members per `GroupMemberIndex` page, a full page fits in return data
+pub const MEMBER_INDEX_PAGE_SIZE: usize = 25;
+
+/// Page of the members of a group ordered by member number, removed members are left as
+/// `Pubkey::default()` so members keep their position
+#[account()]
+pub struct GroupMemberIndex {
+    /// The pubkey of the `TokenGroup`
+    pub group: Pubkey,
+    /// The page number, page `n` holds members `n * MEMBER_INDEX_PAGE_SIZE + 1` onwards
+    pub page: u32,
+    /// Member mints
+    pub members: Vec<Pubkey>,
+}
+
+impl GroupMemberIndex {
+    pub const LEN: usize = 8 + 32 + 4 + 4 + 32 * MEMBER_INDEX_PAGE_SIZE;
+
+    /// Page and position in the page of a member number, member numbers start at 1
+    pub fn position(member_number: u32) -> (u32, usize) {
+        let offset = member_number.saturating_sub(1) as usize;
+        (
+            (offset / MEMBER_INDEX_PAGE_SIZE) as u32,
+            offset % MEMBER_INDEX_PAGE_SIZE,
+        )
+    }
+
+    /// Loads the page from its PDA, `None` when no member of the page was indexed
+    pub fn load(account: &AccountInfo) -> Result<Option<Self>> {
+        if account.data_is_empty() {
+            return Ok(None);
+        }
+        if account.owner != &crate::id() {
+            return Err(ErrorCode::AccountOwnedByWrongProgram.into());
+        }
+        let data = account.try_borrow_data()?;
+        Ok(Some(Self::try_deserialize(&mut &data[..])?))
+    }
+}
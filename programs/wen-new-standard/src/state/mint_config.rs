@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::{MAX_NAME_LEN, MAX_SYMBOL_LEN, MAX_URI_LEN};
+
+/// Public mint of member mints of a group, set by the group authority
+#[account()]
+pub struct MintConfig {
+    /// The pubkey of the `TokenGroup`
+    pub group: Pubkey,
+    /// Mint of the price, `Pubkey::default()` for SOL
+    pub payment_mint: Pubkey,
+    /// Price of a member mint in lamports or base units of `payment_mint`
+    pub price: u64,
+    /// Receives the price, or owns the associated token account receiving it for tokens
+    pub treasury: Pubkey,
+    /// Unix timestamp the public mint opens at, open right away when `None`
+    pub start_time: Option<i64>,
+    /// Unix timestamp the public mint closes at, never closes when `None`
+    pub end_time: Option<i64>,
+    /// Maximum number of member mints per wallet, 0 for unlimited
+    pub limit_per_wallet: u32,
+    /// Name of the member mints, followed by ` #<member number>`
+    pub name: String,
+    pub symbol: String,
+    /// Uri of the member mints, followed by the member number
+    pub base_uri: String,
+}
+
+impl MintConfig {
+    pub const LEN: usize =
+        8 + 32 + 32 + 8 + 32 + 9 + 9 + 4 + 4 + MAX_NAME_LEN + 4 + MAX_SYMBOL_LEN + 4 + MAX_URI_LEN;
+
+    /// Name and uri of the mint of `member_number`
+    pub fn member_metadata(&self, member_number: u32) -> (String, String) {
+        (
+            format!("{} #{}", self.name, member_number),
+            format!("{}{}", self.base_uri, member_number),
+        )
+    }
+}
+
+/// Number of member mints received by a wallet through the public mint of the group
+#[account()]
+pub struct PublicMintClaim {
+    pub count: u32,
+}
+
+impl PublicMintClaim {
+    pub const LEN: usize = 8 + 4;
+}
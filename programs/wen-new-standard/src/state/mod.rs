@@ -1,21 +1,107 @@
-use anchor_lang::solana_program::pubkey::Pubkey;
+use anchor_lang::{prelude::constant, solana_program::pubkey::Pubkey};
 
 pub const ROYALTY_BASIS_POINTS_FIELD: &str = "royalty_basis_points";
+/// Additional metadata key holding the number of a printed edition
+pub const EDITION_NUMBER_FIELD: &str = "edition_number";
+/// Additional metadata key holding the hex encoded hash of the off-chain JSON
+pub const CONTENT_HASH_FIELD: &str = "content_hash";
+/// Maximum number of creators sharing royalties, bounded by the distribution account size
+pub const MAX_CREATORS: usize = 10;
+/// Maximum number of slots an approval stays valid for past its approval slot, about a minute
+pub const MAX_APPROVAL_SLOTS: u64 = 150;
+/// Maximum number of fields written by a single `add_metadata_batch`, bounded by compute
+pub const MAX_METADATA_BATCH_SIZE: usize = 20;
+/// Keys of the core metadata fields, which can't be used as additional metadata keys
+pub const RESERVED_METADATA_FIELDS: [&str; 3] = ["name", "symbol", "uri"];
+
+#[constant]
+pub const MAX_NAME_LEN: usize = 32;
+#[constant]
+pub const MAX_SYMBOL_LEN: usize = 10;
+#[constant]
+pub const MAX_URI_LEN: usize = 200;
+#[constant]
+pub const MAX_METADATA_KEY_LEN: usize = 32;
+#[constant]
+pub const MAX_METADATA_VALUE_LEN: usize = 256;
 
 pub const MANAGER_SEED: &[u8] = b"manager";
+pub const MINT_SEED: &[u8] = b"mint";
 pub const GROUP_ACCOUNT_SEED: &[u8] = b"group";
 pub const MEMBER_ACCOUNT_SEED: &[u8] = b"member";
 pub const APPROVE_ACCOUNT_SEED: &[u8] = b"approve-account";
 pub const META_LIST_ACCOUNT_SEED: &[u8] = b"extra-account-metas";
+pub const METADATA_SEED: &[u8] = b"metadata";
+pub const POLICY_SEED: &[u8] = b"policy";
+pub const MINT_RECORD_SEED: &[u8] = b"mint_record";
+pub const MASTER_EDITION_SEED: &[u8] = b"master_edition";
+pub const ALLOWLIST_SEED: &[u8] = b"allowlist";
+pub const ALLOWLIST_CLAIM_SEED: &[u8] = b"allowlist_claim";
+pub const METADATA_NOMINATION_SEED: &[u8] = b"metadata_nomination";
+pub const GROUP_ROYALTY_DEFAULTS_SEED: &[u8] = b"group_royalty_defaults";
+pub const ROYALTY_EXEMPTIONS_SEED: &[u8] = b"royalty_exemptions";
+pub const ROYALTY_ENFORCEMENT_SEED: &[u8] = b"royalty_enforcement";
+pub const VERIFIED_CREATORS_SEED: &[u8] = b"verified_creators";
+pub const ROYALTY_TIMELOCK_SEED: &[u8] = b"royalty_timelock";
+pub const PENDING_ROYALTY_UPDATE_SEED: &[u8] = b"pending_royalty_update";
+pub const COLLECTION_DISTRIBUTION_SEED: &[u8] = b"collection_distribution";
+pub const SECONDARY_HOOK_SEED: &[u8] = b"secondary_hook";
+pub const PROVENANCE_SEED: &[u8] = b"provenance";
+pub const GROUP_NOMINATION_SEED: &[u8] = b"group_nomination";
+pub const GROUP_MEMBER_COUNTER_SEED: &[u8] = b"group_member_counter";
+pub const GROUP_FINALIZATION_SEED: &[u8] = b"group_finalization";
+pub const GROUP_HIERARCHY_SEED: &[u8] = b"group_hierarchy";
+pub const GROUP_DELEGATES_SEED: &[u8] = b"group_delegates";
+pub const MEMBER_INDEX_SEED: &[u8] = b"member_index";
+pub const GROUP_SOULBOUND_SEED: &[u8] = b"group_soulbound";
+pub const MINT_CONFIG_SEED: &[u8] = b"mint_config";
+pub const PUBLIC_MINT_CLAIM_SEED: &[u8] = b"public_mint_claim";
+pub const GROUP_PERMISSION_SEED: &[u8] = b"group_permission";
 
 pub const TOKEN22: Pubkey = anchor_spl::token_2022::ID;
 
+pub mod allowlist;
 pub mod approve;
+pub mod collection_distribution;
+pub mod edition;
 pub mod group;
+pub mod group_delegates;
+pub mod group_permission;
+pub mod hierarchy;
 pub mod manager;
 pub mod member;
+pub mod member_index;
+pub mod mint_config;
+pub mod nomination;
+pub mod policy;
+pub mod provenance;
+pub mod record;
+pub mod royalty_defaults;
+pub mod royalty_enforcement;
+pub mod royalty_exemptions;
+pub mod royalty_timelock;
+pub mod secondary_hook;
+pub mod verified_creators;
 
+pub use allowlist::*;
 pub use approve::*;
+pub use collection_distribution::*;
+pub use edition::*;
 pub use group::*;
+pub use group_delegates::*;
+pub use group_permission::*;
+pub use hierarchy::*;
 pub use manager::*;
 pub use member::*;
+pub use member_index::*;
+pub use mint_config::*;
+pub use nomination::*;
+pub use policy::*;
+pub use provenance::*;
+pub use record::*;
+pub use royalty_defaults::*;
+pub use royalty_enforcement::*;
+pub use royalty_exemptions::*;
+pub use royalty_timelock::*;
+pub use secondary_hook::*;
+pub use verified_creators::*;
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+/// Pending transfer of the metadata update authority of a mint.
+/// While pending, the metadata update authority of mints storing metadata in the mint
+/// is this PDA, so the nomination can be accepted without the nominator signing.
+#[account()]
+pub struct MetadataAuthorityNomination {
+    /// The mint
+    pub mint: Pubkey,
+    /// The metadata update authority at nomination, refunded the rent on close
+    pub authority: Pubkey,
+    /// The nominated metadata update authority
+    pub nominee: Pubkey,
+    pub bump: u8,
+}
+
+impl MetadataAuthorityNomination {
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 1;
+}
+
+/// Pending transfer of the update authority of a group. The group keeps its update
+/// authority until the nominee accepts, so every group instruction still requires it.
+#[account()]
+pub struct GroupAuthorityNomination {
+    /// The group
+    pub group: Pubkey,
+    /// The group update authority at nomination, refunded the rent on close
+    pub authority: Pubkey,
+    /// The nominated group update authority
+    pub nominee: Pubkey,
+    pub bump: u8,
+}
+
+impl GroupAuthorityNomination {
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 1;
+}
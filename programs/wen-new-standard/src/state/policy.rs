@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::spl_token_2022::extension::ExtensionType;
+
+use crate::MetadataErrors;
+
+/// Maximum number of extension types in each list of a `GroupExtensionPolicy`
+pub const MAX_POLICY_EXTENSIONS: usize = 32;
+
+/// Constraints on the mints joining a group, set by the group authority
+#[account()]
+pub struct GroupExtensionPolicy {
+    /// The pubkey of the `TokenGroup`
+    pub group: Pubkey,
+    /// Extension types member mints may have, any extension is allowed when empty
+    pub allowed_extensions: Vec<u16>,
+    /// Extension types member mints must have
+    pub required_extensions: Vec<u16>,
+    /// Bounds of the member royalty basis points, mints without royalties have 0
+    pub min_royalty_basis_points: u16,
+    pub max_royalty_basis_points: u16,
+    /// Maximum size of the additional metadata of members, uncapped when 0
+    pub max_additional_metadata_bytes: u32,
+}
+
+impl GroupExtensionPolicy {
+    pub const LEN: usize = 8 + 32 + (4 + 2 * MAX_POLICY_EXTENSIONS) * 2 + 2 + 2 + 4;
+
+    /// Loads the policy from its PDA, `None` when the group authority never set one
+    pub fn load(account: &AccountInfo) -> Result<Option<Self>> {
+        if account.data_is_empty() {
+            return Ok(None);
+        }
+        if account.owner != &crate::id() {
+            return Err(ErrorCode::AccountOwnedByWrongProgram.into());
+        }
+        let data = account.try_borrow_data()?;
+        Ok(Some(Self::try_deserialize(&mut &data[..])?))
+    }
+
+    /// Validates the extensions and royalties of a member mint
+    pub fn validate_member(
+        &self,
+        extensions: &[ExtensionType],
+        royalty_basis_points: u16,
+    ) -> Result<()> {
+        for extension in extensions {
+            if !self.allowed_extensions.is_empty()
+                && !self.allowed_extensions.contains(&u16::from(*extension))
+            {
+                msg!(
+                    "Extension {:?} is not allowed by the group policy",
+                    extension
+                );
+                return Err(MetadataErrors::ExtensionNotAllowed.into());
+            }
+        }
+        for required_extension in self.required_extensions.iter() {
+            if !extensions
+                .iter()
+                .any(|extension| u16::from(*extension) == *required_extension)
+            {
+                msg!(
+                    "Extension {:?} is required by the group policy",
+                    ExtensionType::try_from(*required_extension)
+                );
+                return Err(MetadataErrors::MissingRequiredExtension.into());
+            }
+        }
+        self.validate_member_royalties(royalty_basis_points)
+    }
+
+    /// Validates the royalty basis points of a member mint
+    pub fn validate_member_royalties(&self, royalty_basis_points: u16) -> Result<()> {
+        if royalty_basis_points < self.min_royalty_basis_points
+            || royalty_basis_points > self.max_royalty_basis_points
+        {
+            return Err(MetadataErrors::RoyaltiesOutOfPolicyBounds.into());
+        }
+        Ok(())
+    }
+
+    /// Validates the additional metadata size of a member mint
+    pub fn validate_member_metadata(&self, additional_metadata_len: usize) -> Result<()> {
+        if self.max_additional_metadata_bytes != 0
+            && additional_metadata_len > self.max_additional_metadata_bytes as usize
+        {
+            return Err(MetadataErrors::MetadataSizeLimitExceeded.into());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+/// Number of transfers kept by a `ProvenanceRecord`, older ones are overwritten
+pub const MAX_PROVENANCE_ENTRIES: usize = 16;
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy)]
+pub struct ProvenanceEntry {
+    pub slot: u64,
+    pub from_owner: Pubkey,
+    pub to_owner: Pubkey,
+    pub amount: u64,
+    /// Sale price of the approval consumed by the transfer, 0 without one
+    pub sale_price: u64,
+}
+
+impl ProvenanceEntry {
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 8;
+}
+
+/// Last transfers of a mint, appended by the transfer hook once the mint opted in
+#[account()]
+pub struct ProvenanceRecord {
+    pub mint: Pubkey,
+    /// Index of the entry overwritten by the next transfer once the log is full
+    pub next: u16,
+    pub entries: Vec<ProvenanceEntry>,
+}
+
+impl ProvenanceRecord {
+    pub const LEN: usize = 8 + 32 + 2 + 4 + ProvenanceEntry::LEN * MAX_PROVENANCE_ENTRIES;
+
+    /// Appends a transfer, overwriting the oldest one when the log is full
+    pub fn append(&mut self, entry: ProvenanceEntry) {
+        if self.entries.len() < MAX_PROVENANCE_ENTRIES {
+            self.entries.push(entry);
+        } else {
+            self.entries[usize::from(self.next)] = entry;
+        }
+        self.next = ((usize::from(self.next) + 1) % MAX_PROVENANCE_ENTRIES) as u16;
+    }
+}
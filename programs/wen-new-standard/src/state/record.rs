@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+/// Provenance of a mint, written once at creation
+#[account()]
+pub struct MintRecord {
+    /// The mint authority at creation
+    pub creator: Pubkey,
+    /// Paid the record rent, refunded when the record is closed
+    pub payer: Pubkey,
+    /// Slot the mint was created at
+    pub slot: u64,
+    /// The `TokenGroup` the mint was created in, if any
+    pub group: Option<Pubkey>,
+    /// The `Manager` holding the mint authorities, global or namespaced to an authority
+    pub manager: Pubkey,
+    pub bump: u8,
+}
+
+impl MintRecord {
+    pub const LEN: usize = 8 + 32 + 32 + 8 + (1 + 32) + 32 + 1;
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::{AddRoyaltiesArgs, CreatorWithShare, MAX_CREATORS};
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone)]
+pub struct DefaultCreator {
+    pub address: Pubkey,
+    pub share: u8,
+}
+
+/// Royalties applied to member mints created without royalties, set by the group authority
+#[account()]
+pub struct GroupRoyaltyDefaults {
+    /// The pubkey of the `TokenGroup`
+    pub group: Pubkey,
+    pub royalty_basis_points: u16,
+    pub creators: Vec<DefaultCreator>,
+    /// Only the group authority can modify the royalties of members when set
+    pub locked: bool,
+}
+
+impl GroupRoyaltyDefaults {
+    pub const LEN: usize = 8 + 32 + 2 + 4 + (32 + 1) * MAX_CREATORS + 1;
+
+    /// Loads the defaults from their PDA, `None` when the group authority never set them
+    pub fn load(account: &AccountInfo) -> Result<Option<Self>> {
+        if account.data_is_empty() {
+            return Ok(None);
+        }
+        if account.owner != &crate::id() {
+            return Err(ErrorCode::AccountOwnedByWrongProgram.into());
+        }
+        let data = account.try_borrow_data()?;
+        Ok(Some(Self::try_deserialize(&mut &data[..])?))
+    }
+
+    /// Royalties args of a member mint inheriting the defaults
+    pub fn royalties(&self) -> AddRoyaltiesArgs {
+        AddRoyaltiesArgs {
+            royalty_basis_points: self.royalty_basis_points,
+            creators: self
+                .creators
+                .iter()
+                .map(|creator| CreatorWithShare {
+                    address: creator.address.to_string(),
+                    share: creator.share,
+                })
+                .collect(),
+        }
+    }
+}
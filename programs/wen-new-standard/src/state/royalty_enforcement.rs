@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+/// How the transfer hook enforces royalties on transfers of group members. Every group member
+/// resolves the mode, with or without royalties, but only `Frozen` applies to members without
+/// royalties as they have no approval to require. Existing mints resolve the mode once their extra metas are rebuilt, e.g. by
+/// `update_extra_metas_list`, and are `Standard` until then.
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, Default, PartialEq, Eq)]
+pub enum EnforcementMode {
+    /// Every transfer of a non zero amount requires an approval, wallet to wallet included
+    Strict,
+    /// Transfers through programs require an approval, wallet to wallet transfers don't
+    #[default]
+    Standard,
+    /// Approvals are consumed when present but never required
+    Permissive,
+    /// Every transfer is rejected, e.g. while an exploit is being handled
+    Frozen,
+}
+
+/// Royalty enforcement mode of a group, set by the group authority
+#[account()]
+pub struct RoyaltyEnforcement {
+    /// The pubkey of the `TokenGroup`
+    pub group: Pubkey,
+    pub mode: EnforcementMode,
+    /// Transfers must follow a memo instruction in their transaction
+    pub memo_required: bool,
+}
+
+impl RoyaltyEnforcement {
+    pub const LEN: usize = 8 + 32 + 1 + 1;
+
+    /// Loads the enforcement from its PDA, `None` when the group authority never set it.
+    /// Accounts of the previous layout read as not requiring memos.
+    pub fn load(account: &AccountInfo) -> Result<Option<Self>> {
+        if account.data_is_empty() {
+            return Ok(None);
+        }
+        if account.owner != &crate::id() {
+            return Err(ErrorCode::AccountOwnedByWrongProgram.into());
+        }
+        let mut data = account.try_borrow_data()?.to_vec();
+        data.resize(data.len().max(Self::LEN), 0);
+        Ok(Some(Self::try_deserialize(&mut &data[..])?))
+    }
+}
@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+/// Maximum number of entries of `RoyaltyExemptions`
+pub const MAX_ROYALTY_EXEMPTIONS: usize = 16;
+
+/// Owners and programs whose transfers of group members don't pay royalties, set by the group authority
+#[account()]
+pub struct RoyaltyExemptions {
+    /// The pubkey of the `TokenGroup`
+    pub group: Pubkey,
+    /// Token account owners or invoking programs
+    pub exemptions: Vec<Pubkey>,
+}
+
+impl RoyaltyExemptions {
+    pub const LEN: usize = 8 + 32 + 4 + 32 * MAX_ROYALTY_EXEMPTIONS;
+
+    /// Loads the exemptions from their PDA, `None` when the group authority never set any
+    pub fn load(account: &AccountInfo) -> Result<Option<Self>> {
+        if account.data_is_empty() {
+            return Ok(None);
+        }
+        if account.owner != &crate::id() {
+            return Err(ErrorCode::AccountOwnedByWrongProgram.into());
+        }
+        let data = account.try_borrow_data()?;
+        Ok(Some(Self::try_deserialize(&mut &data[..])?))
+    }
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::{AddRoyaltiesArgs, MAX_CREATORS};
+
+/// Delay before royalty updates of group members take effect, set by the group authority
+#[account()]
+pub struct RoyaltyTimelock {
+    /// The pubkey of the `TokenGroup`
+    pub group: Pubkey,
+    /// Seconds between `modify_royalties` and `apply_royalty_update`, 0 when disabled
+    pub delay: i64,
+}
+
+impl RoyaltyTimelock {
+    pub const LEN: usize = 8 + 32 + 8;
+
+    /// Loads the timelock from its PDA, `None` when the group authority never set it
+    pub fn load(account: &AccountInfo) -> Result<Option<Self>> {
+        if account.data_is_empty() {
+            return Ok(None);
+        }
+        if account.owner != &crate::id() {
+            return Err(ErrorCode::AccountOwnedByWrongProgram.into());
+        }
+        let data = account.try_borrow_data()?;
+        Ok(Some(Self::try_deserialize(&mut &data[..])?))
+    }
+}
+
+/// Royalties queued by `modify_royalties` for a timelocked group member
+#[account()]
+pub struct PendingRoyaltyUpdate {
+    /// The pubkey of the mint
+    pub mint: Pubkey,
+    /// Refunded the rent when the update is applied or cancelled
+    pub payer: Pubkey,
+    /// Unix timestamp after which the update can be applied
+    pub effective_after: i64,
+    pub royalties: AddRoyaltiesArgs,
+}
+
+impl PendingRoyaltyUpdate {
+    /// creator addresses are base58 encoded pubkeys, at most 44 characters
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 2 + 4 + MAX_CREATORS * (4 + 44 + 1);
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+/// Maximum number of accounts of a `SecondaryHook`
+pub const MAX_SECONDARY_HOOK_ACCOUNTS: usize = 8;
+
+/// Account passed to the secondary hook program after its validation account
+#[derive(AnchorDeserialize, AnchorSerialize, Clone)]
+pub struct SecondaryHookAccount {
+    pub address: Pubkey,
+    pub is_writable: bool,
+}
+
+/// Transfer hook program invoked by the transfer hook after royalties are enforced on
+/// transfers of group members, set by the group authority
+#[account()]
+pub struct SecondaryHook {
+    /// The pubkey of the `TokenGroup`
+    pub group: Pubkey,
+    pub program_id: Pubkey,
+    /// Static extra accounts of the secondary hook program, in the order it resolves them
+    pub accounts: Vec<SecondaryHookAccount>,
+}
+
+impl SecondaryHook {
+    pub const LEN: usize = 8 + 32 + 32 + 4 + (32 + 1) * MAX_SECONDARY_HOOK_ACCOUNTS;
+
+    /// Loads the secondary hook from its PDA, `None` when the group has none registered
+    pub fn load(account: &AccountInfo) -> Result<Option<Self>> {
+        if account.data_is_empty() {
+            return Ok(None);
+        }
+        if account.owner != &crate::id() {
+            return Err(ErrorCode::AccountOwnedByWrongProgram.into());
+        }
+        let data = account.try_borrow_data()?;
+        Ok(Some(Self::try_deserialize(&mut &data[..])?))
+    }
+}
@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+use crate::MAX_CREATORS;
+
+/// Royalty creators of a mint that signed to endorse it
+#[account()]
+pub struct VerifiedCreators {
+    /// The pubkey of the mint
+    pub mint: Pubkey,
+    pub creators: Vec<Pubkey>,
+}
+
+impl VerifiedCreators {
+    pub const LEN: usize = 8 + 32 + 4 + 32 * MAX_CREATORS;
+
+    /// Loads the verified creators from their PDA, `None` when no creator ever verified
+    pub fn load(account: &AccountInfo) -> Result<Option<Self>> {
+        if account.data_is_empty() {
+            return Ok(None);
+        }
+        if account.owner != &crate::id() {
+            return Err(ErrorCode::AccountOwnedByWrongProgram.into());
+        }
+        let data = account.try_borrow_data()?;
+        Ok(Some(Self::try_deserialize(&mut &data[..])?))
+    }
+}
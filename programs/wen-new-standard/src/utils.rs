@@ -1,34 +1,75 @@
+use std::str::FromStr;
+
 use anchor_lang::{
-    prelude::Result,
+    prelude::{msg, system_program, Account, Clock, CpiContext, Key, Result},
     solana_program::{
         account_info::AccountInfo,
         instruction::{get_stack_height, TRANSACTION_LEVEL_STACK_HEIGHT},
-        program::invoke,
-        pubkey::Pubkey,
+        keccak::hashv,
+        program::{invoke, invoke_signed},
+        program_option::COption,
+        pubkey::{Pubkey, MAX_SEED_LEN},
         rent::Rent,
         system_instruction::transfer,
-        sysvar::Sysvar,
+        sysvar::{
+            instructions::{self, load_current_index_checked, load_instruction_at_checked},
+            Sysvar,
+        },
     },
-    Lamports,
+    system_program::{allocate, assign, create_account, Allocate, Assign, CreateAccount},
+    AccountSerialize, Lamports,
 };
-use anchor_spl::token_interface::{
-    spl_token_2022::{
-        extension::{BaseStateWithExtensions, Extension, StateWithExtensions},
-        solana_zk_token_sdk::zk_token_proof_instruction::Pod,
-        state::Mint,
+use anchor_spl::{
+    memo::spl_memo,
+    token_interface::{
+        spl_token_2022::{
+            extension::{
+                group_member_pointer::{self, GroupMemberPointer},
+                group_pointer,
+                metadata_pointer::MetadataPointer,
+                transfer_hook::{TransferHook, TransferHookAccount},
+                BaseStateWithExtensions, Extension, ExtensionType, StateWithExtensions,
+            },
+            instruction::withdraw_excess_lamports,
+            solana_zk_token_sdk::{
+                curve25519::edwards::{validate_edwards, PodEdwardsPoint},
+                zk_token_proof_instruction::Pod,
+            },
+            state::{Account as TokenAccountState, Mint},
+        },
+        spl_token_metadata_interface::state::TokenMetadata,
     },
-    spl_token_metadata_interface::state::TokenMetadata,
 };
-use spl_tlv_account_resolution::{account::ExtraAccountMeta, state::ExtraAccountMetaList};
+use spl_tlv_account_resolution::{
+    account::ExtraAccountMeta, seeds::Seed, state::ExtraAccountMetaList,
+};
+use spl_token_group_interface::instruction::{initialize_group, initialize_member};
+use spl_transfer_hook_interface::instruction::ExecuteInstruction;
+use spl_type_length_value::state::{
+    realloc_and_pack_first_variable_len, TlvState, TlvStateBorrowed,
+};
 
-use crate::{APPROVE_ACCOUNT_SEED, META_LIST_ACCOUNT_SEED};
+use crate::{
+    AddRoyaltiesArgs, CollectionDistribution, CreatorWithShare, EnforcementMode, GroupDelegates,
+    GroupExtensionPolicy, GroupHierarchy, GroupMemberCounter, GroupMemberIndex, GroupPermission,
+    GroupRoyaltyDefaults, GroupSoulbound, MetadataErrors, RoyaltyEnforcement, RoyaltyTimelock,
+    SecondaryHook, TokenGroup, TokenGroupMember, VerifiedCreators, APPROVE_ACCOUNT_SEED,
+    COLLECTION_DISTRIBUTION_SEED, CONTENT_HASH_FIELD, GROUP_MEMBER_COUNTER_SEED,
+    GROUP_ROYALTY_DEFAULTS_SEED, MANAGER_SEED, MAX_METADATA_KEY_LEN, MAX_METADATA_VALUE_LEN,
+    MAX_NAME_LEN, MAX_SYMBOL_LEN, MAX_URI_LEN, MEMBER_INDEX_SEED, META_LIST_ACCOUNT_SEED,
+    MINT_EXTENSIONS, MINT_SEED, POLICY_SEED, PROVENANCE_SEED, RESERVED_METADATA_FIELDS,
+    ROYALTY_BASIS_POINTS_FIELD, ROYALTY_ENFORCEMENT_SEED, ROYALTY_EXEMPTIONS_SEED,
+    ROYALTY_TIMELOCK_SEED, SECONDARY_HOOK_SEED,
+};
 
 pub fn update_account_lamports_to_minimum_balance<'info>(
     account: AccountInfo<'info>,
     payer: AccountInfo<'info>,
     system_program: AccountInfo<'info>,
 ) -> Result<()> {
-    let extra_lamports = Rent::get()?.minimum_balance(account.data_len()) - account.get_lamports();
+    let extra_lamports = Rent::get()?
+        .minimum_balance(account.data_len())
+        .saturating_sub(account.get_lamports());
     if extra_lamports > 0 {
         invoke(
             &transfer(payer.key, account.key, extra_lamports),
@@ -38,11 +79,635 @@ pub fn update_account_lamports_to_minimum_balance<'info>(
     Ok(())
 }
 
-pub fn get_mint_metadata(account: &mut AccountInfo) -> Result<TokenMetadata> {
-    let mint_data = account.data.borrow();
+/// Moves the lamports of `account` above its minimum rent balance to `destination`,
+/// `account` must be owned by this program
+pub fn refund_account_lamports_above_minimum_balance(
+    account: &AccountInfo,
+    destination: &AccountInfo,
+) -> Result<()> {
+    let excess_lamports = account
+        .get_lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(account.data_len()));
+    if excess_lamports > 0 {
+        account.sub_lamports(excess_lamports)?;
+        destination.add_lamports(excess_lamports)?;
+    }
+    Ok(())
+}
+
+/// Withdraws the lamports of `mint` above its minimum rent balance to `destination`.
+/// The token program only lets the mint authority withdraw, so nothing is refunded
+/// once the manager mint authority has been revoked.
+pub fn refund_mint_lamports_above_minimum_balance<'info>(
+    mint: AccountInfo<'info>,
+    destination: AccountInfo<'info>,
+    manager: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    manager_namespace: &[u8],
+    manager_bump: u8,
+) -> Result<()> {
+    let mint_authority = {
+        let mint_data = mint.try_borrow_data()?;
+        StateWithExtensions::<Mint>::unpack(&mint_data)?
+            .base
+            .mint_authority
+    };
+    if mint_authority != COption::Some(*manager.key) {
+        return Ok(());
+    }
+    let excess_lamports = mint
+        .get_lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(mint.data_len()));
+    if excess_lamports == 0 {
+        return Ok(());
+    }
+    let signer_seeds: &[&[&[u8]]] = &[&[MANAGER_SEED, manager_namespace, &[manager_bump]]];
+    invoke_signed(
+        &withdraw_excess_lamports(
+            token_program.key,
+            mint.key,
+            destination.key,
+            manager.key,
+            &[],
+        )?,
+        &[mint, destination, manager],
+        signer_seeds,
+    )?;
+    Ok(())
+}
+
+/// Points the group pointer of a WNS group mint at the mint and initializes the native token
+/// group extension in it, the manager being its update authority so the WNS group stays
+/// authoritative. The mint must be topped up to its new minimum balance afterwards.
+pub fn initialize_native_group<'info>(
+    mint: AccountInfo<'info>,
+    manager: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    manager_namespace: &[u8],
+    manager_bump: u8,
+    max_size: u32,
+) -> Result<()> {
+    let signer_seeds: &[&[&[u8]]] = &[&[MANAGER_SEED, manager_namespace, &[manager_bump]]];
+    invoke_signed(
+        &group_pointer::instruction::update(
+            token_program.key,
+            mint.key,
+            manager.key,
+            &[],
+            Some(*mint.key),
+        )?,
+        &[mint.clone(), manager.clone()],
+        signer_seeds,
+    )?;
+    // a native max size of 0 allows no members, WNS groups use 0 for unlimited
+    let max_size = if max_size == 0 { u32::MAX } else { max_size };
+    invoke_signed(
+        &initialize_group(
+            token_program.key,
+            mint.key,
+            mint.key,
+            manager.key,
+            Some(*manager.key),
+            max_size,
+        ),
+        &[mint, manager],
+        signer_seeds,
+    )?;
+    Ok(())
+}
+
+/// Points the group member pointer of a WNS member mint at the mint and initializes the native
+/// token group member extension in it, `group_mint` must carry the native group extension.
+/// `member_pointer_authority` must sign, the mint must be topped up to its new minimum
+/// balance afterwards.
+pub fn initialize_native_member<'info>(
+    mint: AccountInfo<'info>,
+    group_mint: AccountInfo<'info>,
+    member_pointer_authority: AccountInfo<'info>,
+    manager: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    manager_namespace: &[u8],
+    manager_bump: u8,
+) -> Result<()> {
+    invoke(
+        &group_member_pointer::instruction::update(
+            token_program.key,
+            mint.key,
+            member_pointer_authority.key,
+            &[],
+            Some(*mint.key),
+        )?,
+        &[mint.clone(), member_pointer_authority],
+    )?;
+    // the manager is the mint authority of the member and the update authority of the group
+    let signer_seeds: &[&[&[u8]]] = &[&[MANAGER_SEED, manager_namespace, &[manager_bump]]];
+    invoke_signed(
+        &initialize_member(
+            token_program.key,
+            mint.key,
+            mint.key,
+            manager.key,
+            group_mint.key,
+            manager.key,
+        ),
+        &[mint, manager, group_mint],
+        signer_seeds,
+    )?;
+    Ok(())
+}
+
+/// Creates `account` with `space` bytes owned by `owner`, funded to the minimum rent balance
+/// of `funded_space` bytes, which can exceed `space` for accounts growing right after creation.
+/// Accounts that were already funded (e.g. someone sent lamports to the address) are topped up,
+/// allocated and assigned instead, since the system program rejects creating them.
+pub fn create_account_with_minimum_balance<'info>(
+    account: AccountInfo<'info>,
+    payer: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    space: usize,
+    funded_space: usize,
+    owner: &Pubkey,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let lamports = Rent::get()?.minimum_balance(space.max(funded_space));
+    let current_lamports = account.get_lamports();
+    if current_lamports == 0 {
+        let cpi_accounts = CreateAccount {
+            from: payer,
+            to: account,
+        };
+        let cpi_ctx = CpiContext::new_with_signer(system_program, cpi_accounts, signer_seeds);
+        create_account(cpi_ctx, lamports, space as u64, owner)?;
+    } else {
+        let extra_lamports = lamports.saturating_sub(current_lamports);
+        if extra_lamports > 0 {
+            invoke(
+                &transfer(payer.key, account.key, extra_lamports),
+                &[payer, account.clone(), system_program.clone()],
+            )?;
+        }
+        let cpi_accounts = Allocate {
+            account_to_allocate: account.clone(),
+        };
+        let cpi_ctx =
+            CpiContext::new_with_signer(system_program.clone(), cpi_accounts, signer_seeds);
+        allocate(cpi_ctx, space as u64)?;
+        let cpi_accounts = Assign {
+            account_to_assign: account,
+        };
+        let cpi_ctx = CpiContext::new_with_signer(system_program, cpi_accounts, signer_seeds);
+        assign(cpi_ctx, owner)?;
+    }
+    Ok(())
+}
+
+/// Reads the metadata stored in a WNS mint. Mints with external metadata are read
+/// through `get_metadata` with the account referenced by their metadata pointer.
+pub fn get_mint_metadata(mint: &AccountInfo) -> Result<TokenMetadata> {
+    let mint_data = mint.try_borrow_data()?;
     let mint_with_extension = StateWithExtensions::<Mint>::unpack(&mint_data)?;
-    let extension_data = mint_with_extension.get_variable_len_extension::<TokenMetadata>()?;
-    Ok(extension_data)
+    let metadata_pointer = mint_with_extension
+        .get_extension::<MetadataPointer>()
+        .map_err(|_| MetadataErrors::MissingMetadata)?;
+    if Option::<Pubkey>::from(metadata_pointer.metadata_address) != Some(*mint.key) {
+        return Err(MetadataErrors::InvalidMetadataAccount.into());
+    }
+    let metadata = mint_with_extension
+        .get_variable_len_extension::<TokenMetadata>()
+        .map_err(|_| MetadataErrors::MissingMetadata)?;
+    Ok(metadata)
+}
+
+/// Fails once the metadata update authority has been removed
+pub fn validate_metadata_mutable(metadata: &TokenMetadata) -> Result<()> {
+    if Option::<Pubkey>::from(metadata.update_authority).is_none() {
+        return Err(MetadataErrors::MetadataImmutable.into());
+    }
+    Ok(())
+}
+
+/// Checks `authority` can update the metadata, the token program checks it for metadata
+/// stored in the mint but not for external metadata accounts
+pub fn validate_metadata_update_authority(
+    metadata: &TokenMetadata,
+    authority: &Pubkey,
+) -> Result<()> {
+    validate_metadata_mutable(metadata)?;
+    if Option::<Pubkey>::from(metadata.update_authority) != Some(*authority) {
+        return Err(MetadataErrors::InvalidMetadataAuthority.into());
+    }
+    Ok(())
+}
+
+/// Address referenced by the mint metadata pointer, the mint itself unless metadata is external
+pub fn get_metadata_address(mint: &AccountInfo) -> Result<Pubkey> {
+    let mint_data = mint.try_borrow_data()?;
+    let mint_with_extension = StateWithExtensions::<Mint>::unpack(&mint_data)?;
+    let metadata_pointer = mint_with_extension.get_extension::<MetadataPointer>()?;
+    Ok(Option::<Pubkey>::from(metadata_pointer.metadata_address).unwrap_or(*mint.key))
+}
+
+/// Returns the account holding the metadata of `mint`, `None` when it is the mint itself.
+/// `metadata_account` must be the account referenced by the metadata pointer otherwise.
+pub fn get_external_metadata_account<'a, 'info>(
+    mint: &AccountInfo<'info>,
+    metadata_account: Option<&'a AccountInfo<'info>>,
+) -> Result<Option<&'a AccountInfo<'info>>> {
+    let metadata_address = get_metadata_address(mint)?;
+    if metadata_address == *mint.key {
+        return Ok(None);
+    }
+    match metadata_account {
+        Some(metadata_account) if *metadata_account.key == metadata_address => {
+            Ok(Some(metadata_account))
+        }
+        _ => Err(MetadataErrors::InvalidMetadataAccount.into()),
+    }
+}
+
+pub fn get_external_metadata(account: &AccountInfo) -> Result<TokenMetadata> {
+    let data = account.try_borrow_data()?;
+    let state = TlvStateBorrowed::unpack(&data).map_err(|_| MetadataErrors::MissingMetadata)?;
+    let metadata = state
+        .get_first_variable_len_value::<TokenMetadata>()
+        .map_err(|_| MetadataErrors::MissingMetadata)?;
+    Ok(metadata)
+}
+
+/// Reads the metadata of `mint`, from `external_metadata_account` when it isn't stored in the mint
+pub fn get_metadata(
+    mint: &AccountInfo,
+    external_metadata_account: Option<&AccountInfo>,
+) -> Result<TokenMetadata> {
+    match external_metadata_account {
+        Some(metadata_account) => get_external_metadata(metadata_account),
+        None => get_mint_metadata(mint),
+    }
+}
+
+/// Writes `metadata` to an external metadata account of this program, resizing it
+pub fn pack_external_metadata(account: &AccountInfo, metadata: &TokenMetadata) -> Result<()> {
+    if account.owner != &crate::id() {
+        return Err(MetadataErrors::InvalidMetadataAccount.into());
+    }
+    realloc_and_pack_first_variable_len(account, metadata)?;
+    Ok(())
+}
+
+/// Writes `metadata` to an external metadata account of this program and tops up its rent
+pub fn write_external_metadata<'info>(
+    account: AccountInfo<'info>,
+    payer: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    metadata: &TokenMetadata,
+) -> Result<()> {
+    pack_external_metadata(&account, metadata)?;
+    update_account_lamports_to_minimum_balance(account, payer, system_program)
+}
+
+/// Current value of a metadata field, the core fields are read by their key
+pub fn get_metadata_value(metadata: &TokenMetadata, key: &str) -> Option<String> {
+    match key {
+        "name" => Some(metadata.name.clone()),
+        "symbol" => Some(metadata.symbol.clone()),
+        "uri" => Some(metadata.uri.clone()),
+        _ => metadata
+            .additional_metadata
+            .iter()
+            .find(|(field, _)| field == key)
+            .map(|(_, value)| value.clone()),
+    }
+}
+
+/// Hex encoding of a content hash, as stored in the `CONTENT_HASH_FIELD` metadata field
+pub fn encode_content_hash(content_hash: &[u8; 32]) -> String {
+    content_hash
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+/// Decodes a `CONTENT_HASH_FIELD` metadata value, `None` if it isn't a hex encoded hash
+pub fn decode_content_hash(value: &str) -> Option<[u8; 32]> {
+    if value.len() != 64 || !value.is_ascii() {
+        return None;
+    }
+    let mut content_hash = [0u8; 32];
+    for (index, byte) in content_hash.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&value[index * 2..index * 2 + 2], 16).ok()?;
+    }
+    Some(content_hash)
+}
+
+/// Content hash committed in the metadata of a mint, if any
+pub fn get_content_hash(metadata: &TokenMetadata) -> Option<[u8; 32]> {
+    metadata
+        .additional_metadata
+        .iter()
+        .find(|(key, _)| key == CONTENT_HASH_FIELD)
+        .and_then(|(_, value)| decode_content_hash(value))
+}
+
+/// Royalty basis points and creators of a mint, `None` for mints without royalties.
+/// Creators are the additional metadata keys parsing as pubkeys.
+pub fn get_royalty_config(metadata: &TokenMetadata) -> Option<AddRoyaltiesArgs> {
+    let royalty_basis_points = metadata
+        .additional_metadata
+        .iter()
+        .find(|(key, _)| key == ROYALTY_BASIS_POINTS_FIELD)
+        .and_then(|(_, value)| u16::from_str(value).ok())?;
+    let creators = metadata
+        .additional_metadata
+        .iter()
+        .filter(|(key, _)| Pubkey::from_str(key).is_ok())
+        .map(|(key, value)| CreatorWithShare {
+            address: key.clone(),
+            share: u8::from_str(value).unwrap_or(0),
+        })
+        .collect();
+    Some(AddRoyaltiesArgs {
+        royalty_basis_points,
+        creators,
+    })
+}
+
+/// Drops creators removed from the royalties from the verified creators of the mint,
+/// verified creators can only be removed when they are one of the `signers`
+pub fn remove_verified_creators(
+    verified_creators: &AccountInfo,
+    removed_creators: &[String],
+    signers: &[AccountInfo],
+) -> Result<()> {
+    let Some(mut verified) = VerifiedCreators::load(verified_creators)? else {
+        return Ok(());
+    };
+    let verified_len = verified.creators.len();
+    for removed_creator in removed_creators {
+        let Ok(address) = Pubkey::from_str(removed_creator) else {
+            continue;
+        };
+        if !verified.creators.contains(&address) {
+            continue;
+        }
+        if !signers
+            .iter()
+            .any(|signer| signer.is_signer && signer.key == &address)
+        {
+            return Err(MetadataErrors::VerifiedCreatorRemoval.into());
+        }
+        verified.creators.retain(|creator| creator != &address);
+    }
+    if verified.creators.len() != verified_len {
+        let mut data = verified_creators.try_borrow_mut_data()?;
+        verified.try_serialize(&mut &mut data[..])?;
+    }
+    Ok(())
+}
+
+/// Royalty basis points of a mint, 0 for mints without royalties
+pub fn get_royalty_basis_points(metadata: &TokenMetadata) -> u16 {
+    get_royalty_config(metadata).map_or(0, |royalties| royalties.royalty_basis_points)
+}
+
+/// Validates a member mint against the extension policy of its group, if the group has one
+pub fn validate_group_policy(
+    policy: &AccountInfo,
+    mint: &AccountInfo,
+    external_metadata_account: Option<&AccountInfo>,
+) -> Result<()> {
+    let policy = match GroupExtensionPolicy::load(policy)? {
+        Some(policy) => policy,
+        None => return Ok(()),
+    };
+    let extensions = {
+        let mint_data = mint.try_borrow_data()?;
+        StateWithExtensions::<Mint>::unpack(&mint_data)?.get_extension_types()?
+    };
+    let metadata = get_metadata(mint, external_metadata_account)?;
+    policy.validate_member(&extensions, get_royalty_basis_points(&metadata))
+}
+
+/// Validates a mint joining a group as `member`. The mint must carry the extensions of a WNS
+/// mint, soulbound mints being non-transferable instead of hooked, and its group member pointer
+/// must be unset or already point at `member`.
+pub fn validate_member_mint(mint: &AccountInfo, member: &Pubkey) -> Result<()> {
+    let mint_data = mint.try_borrow_data()?;
+    let mint_with_extension = StateWithExtensions::<Mint>::unpack(&mint_data)?;
+    let extensions = mint_with_extension.get_extension_types()?;
+    let soulbound = extensions.contains(&ExtensionType::NonTransferable);
+    for extension in MINT_EXTENSIONS {
+        let required = match extension {
+            ExtensionType::TransferHook if soulbound => ExtensionType::NonTransferable,
+            extension => extension,
+        };
+        if !extensions.contains(&required) {
+            return Err(MetadataErrors::MissingMintExtension.into());
+        }
+    }
+
+    let member_pointer = mint_with_extension.get_extension::<GroupMemberPointer>()?;
+    match Option::<Pubkey>::from(member_pointer.member_address) {
+        Some(member_address) if member_address != *member => {
+            Err(MetadataErrors::MemberPointerMismatch.into())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Validates that `mint` is non-transferable when the group is soulbound
+pub fn validate_group_soulbound(soulbound: &AccountInfo, mint: &AccountInfo) -> Result<()> {
+    if GroupSoulbound::load(soulbound)?.is_none() {
+        return Ok(());
+    }
+    let mint_data = mint.try_borrow_data()?;
+    let mint_with_extension = StateWithExtensions::<Mint>::unpack(&mint_data)?;
+    if !mint_with_extension
+        .get_extension_types()?
+        .contains(&ExtensionType::NonTransferable)
+    {
+        return Err(MetadataErrors::SoulboundRequired.into());
+    }
+    Ok(())
+}
+
+/// Borsh size of the additional metadata of `metadata`
+pub fn get_additional_metadata_len(metadata: &TokenMetadata) -> usize {
+    metadata
+        .additional_metadata
+        .iter()
+        .fold(4, |len, (key, value)| len + 4 + key.len() + 4 + value.len())
+}
+
+/// Validates the additional metadata size of a mint against its group policy, mints not
+/// in any group are uncapped. `member` and `policy` are required for group members.
+pub fn validate_group_metadata_size(
+    mint: &AccountInfo,
+    member: Option<&TokenGroupMember>,
+    policy: Option<&AccountInfo>,
+    metadata: &TokenMetadata,
+) -> Result<()> {
+    let member_pointer = get_extension_data::<GroupMemberPointer>(&mut mint.clone())?;
+    if Option::<Pubkey>::from(member_pointer.member_address).is_none() {
+        return Ok(());
+    }
+    let (member, policy) = match (member, policy) {
+        (Some(member), Some(policy)) => (member, policy),
+        _ => return Err(MetadataErrors::InvalidGroupPolicyAccount.into()),
+    };
+    let expected_policy =
+        Pubkey::find_program_address(&[POLICY_SEED, member.group.as_ref()], &crate::id()).0;
+    if *policy.key != expected_policy {
+        return Err(MetadataErrors::InvalidGroupPolicyAccount.into());
+    }
+    match GroupExtensionPolicy::load(policy)? {
+        Some(policy) => policy.validate_member_metadata(get_additional_metadata_len(metadata)),
+        None => Ok(()),
+    }
+}
+
+/// Members of groups with locked royalty defaults can only have their royalties changed by the
+/// group authority. `member`, `group` and `royalty_defaults` are required for group members.
+pub fn validate_group_royalty_authority(
+    mint: &AccountInfo,
+    member: Option<&TokenGroupMember>,
+    group: Option<&Account<TokenGroup>>,
+    royalty_defaults: Option<&AccountInfo>,
+    authority: &Pubkey,
+) -> Result<()> {
+    let member_pointer = get_extension_data::<GroupMemberPointer>(&mut mint.clone())?;
+    if Option::<Pubkey>::from(member_pointer.member_address).is_none() {
+        return Ok(());
+    }
+    let (Some(member), Some(group), Some(royalty_defaults)) = (member, group, royalty_defaults)
+    else {
+        return Err(MetadataErrors::InvalidGroupRoyaltyDefaultsAccount.into());
+    };
+    let expected_royalty_defaults = Pubkey::find_program_address(
+        &[GROUP_ROYALTY_DEFAULTS_SEED, group.key().as_ref()],
+        &crate::id(),
+    )
+    .0;
+    if member.group != group.key() || *royalty_defaults.key != expected_royalty_defaults {
+        return Err(MetadataErrors::InvalidGroupRoyaltyDefaultsAccount.into());
+    }
+    match GroupRoyaltyDefaults::load(royalty_defaults)? {
+        Some(royalty_defaults)
+            if royalty_defaults.locked && group.update_authority != *authority =>
+        {
+            Err(MetadataErrors::RoyaltiesLocked.into())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Collection of the distribution receiving the royalties of a mint, `None` when unconstrained.
+/// Members of groups routing royalties collection-wide use the group mint unless their creators
+/// differ from the group royalty defaults, in which case they keep their own mint.
+pub fn get_distribution_collection(
+    mint: &AccountInfo,
+    metadata: &TokenMetadata,
+    member: Option<&TokenGroupMember>,
+    group: Option<&Account<TokenGroup>>,
+    collection_distribution: Option<&AccountInfo>,
+    royalty_defaults: Option<&AccountInfo>,
+) -> Result<Option<Pubkey>> {
+    let member_pointer = get_extension_data::<GroupMemberPointer>(&mut mint.clone())?;
+    if Option::<Pubkey>::from(member_pointer.member_address).is_none() {
+        return Ok(None);
+    }
+    let (Some(member), Some(group), Some(collection_distribution), Some(royalty_defaults)) =
+        (member, group, collection_distribution, royalty_defaults)
+    else {
+        return Err(MetadataErrors::InvalidCollectionDistributionAccount.into());
+    };
+    let expected_collection_distribution = Pubkey::find_program_address(
+        &[COLLECTION_DISTRIBUTION_SEED, group.key().as_ref()],
+        &crate::id(),
+    )
+    .0;
+    let expected_royalty_defaults = Pubkey::find_program_address(
+        &[GROUP_ROYALTY_DEFAULTS_SEED, group.key().as_ref()],
+        &crate::id(),
+    )
+    .0;
+    if member.group != group.key()
+        || *collection_distribution.key != expected_collection_distribution
+        || *royalty_defaults.key != expected_royalty_defaults
+    {
+        return Err(MetadataErrors::InvalidCollectionDistributionAccount.into());
+    }
+    if !CollectionDistribution::load(collection_distribution)?
+        .is_some_and(|collection_distribution| collection_distribution.enabled)
+    {
+        return Ok(None);
+    }
+
+    // creators overriding the group defaults are paid through the mint distribution
+    let creators = get_royalty_config(metadata).map_or(vec![], |royalties| royalties.creators);
+    let overridden = GroupRoyaltyDefaults::load(royalty_defaults)?.is_some_and(|defaults| {
+        defaults.creators.len() != creators.len()
+            || defaults
+                .creators
+                .iter()
+                .zip(creators.iter())
+                .any(|(default, creator)| {
+                    default.address.to_string() != creator.address || default.share != creator.share
+                })
+    });
+    Ok(Some(if overridden { *mint.key } else { group.mint }))
+}
+
+/// Royalty update delay of the group of a mint, 0 for mints not in any group or groups
+/// without a timelock. `member` and `royalty_timelock` are required for group members.
+pub fn get_group_royalty_timelock(
+    mint: &AccountInfo,
+    member: Option<&TokenGroupMember>,
+    royalty_timelock: Option<&AccountInfo>,
+) -> Result<i64> {
+    let member_pointer = get_extension_data::<GroupMemberPointer>(&mut mint.clone())?;
+    if Option::<Pubkey>::from(member_pointer.member_address).is_none() {
+        return Ok(0);
+    }
+    let (Some(member), Some(royalty_timelock)) = (member, royalty_timelock) else {
+        return Err(MetadataErrors::InvalidRoyaltyTimelockAccount.into());
+    };
+    let expected_royalty_timelock = Pubkey::find_program_address(
+        &[ROYALTY_TIMELOCK_SEED, member.group.as_ref()],
+        &crate::id(),
+    )
+    .0;
+    if *royalty_timelock.key != expected_royalty_timelock {
+        return Err(MetadataErrors::InvalidRoyaltyTimelockAccount.into());
+    }
+    Ok(RoyaltyTimelock::load(royalty_timelock)?
+        .map_or(0, |royalty_timelock| royalty_timelock.delay))
+}
+
+/// Validates the royalty basis points of a mint against its group policy, mints not in any
+/// group are unconstrained. `member` and `policy` are required for group members.
+pub fn validate_group_royalties(
+    mint: &AccountInfo,
+    member: Option<&TokenGroupMember>,
+    policy: Option<&AccountInfo>,
+    royalty_basis_points: u16,
+) -> Result<()> {
+    let member_pointer = get_extension_data::<GroupMemberPointer>(&mut mint.clone())?;
+    if Option::<Pubkey>::from(member_pointer.member_address).is_none() {
+        return Ok(());
+    }
+    let (member, policy) = match (member, policy) {
+        (Some(member), Some(policy)) => (member, policy),
+        _ => return Err(MetadataErrors::InvalidGroupPolicyAccount.into()),
+    };
+    let expected_policy =
+        Pubkey::find_program_address(&[POLICY_SEED, member.group.as_ref()], &crate::id()).0;
+    if *policy.key != expected_policy {
+        return Err(MetadataErrors::InvalidGroupPolicyAccount.into());
+    }
+    match GroupExtensionPolicy::load(policy)? {
+        Some(policy) => policy.validate_member_royalties(royalty_basis_points),
+        None => Ok(()),
+    }
 }
 
 pub fn get_extension_data<T: Extension + Pod>(account: &mut AccountInfo) -> Result<T> {
@@ -52,14 +717,118 @@ pub fn get_extension_data<T: Extension + Pod>(account: &mut AccountInfo) -> Resu
     Ok(extension_data)
 }
 
+/// Whether the transfer hook of the mint invokes the WNS program, false for soulbound mints
+/// which have no transfer hook
+pub fn has_wns_transfer_hook(mint: &AccountInfo) -> Result<bool> {
+    let Ok(transfer_hook) = get_extension_data::<TransferHook>(&mut mint.clone()) else {
+        return Ok(false);
+    };
+    Ok(Option::<Pubkey>::from(transfer_hook.program_id) == Some(crate::id()))
+}
+
+/// Validates the core metadata fields, name and uri can't be empty
+pub fn validate_metadata(name: &str, symbol: &str, uri: &str) -> Result<()> {
+    if name.is_empty() || uri.is_empty() {
+        return Err(MetadataErrors::EmptyField.into());
+    }
+    if name.len() > MAX_NAME_LEN {
+        return Err(MetadataErrors::NameTooLong.into());
+    }
+    if symbol.len() > MAX_SYMBOL_LEN {
+        return Err(MetadataErrors::SymbolTooLong.into());
+    }
+    if uri.len() > MAX_URI_LEN {
+        return Err(MetadataErrors::UriTooLong.into());
+    }
+    Ok(())
+}
+
+/// Rejects additional metadata keys colliding with the core metadata fields or the
+/// royalty keys, basis points and creator addresses, written by the royalty instructions.
+/// Keys and values are valid UTF-8 once deserialized, they also can't contain control
+/// characters or exceed `MAX_METADATA_KEY_LEN` and `MAX_METADATA_VALUE_LEN` bytes.
+pub fn validate_additional_metadata_field(field: &str, value: &str) -> Result<()> {
+    if field.len() > MAX_METADATA_KEY_LEN {
+        return Err(MetadataErrors::MetadataKeyTooLong.into());
+    }
+    if value.len() > MAX_METADATA_VALUE_LEN {
+        return Err(MetadataErrors::MetadataValueTooLong.into());
+    }
+    if field.chars().any(char::is_control) || value.chars().any(char::is_control) {
+        return Err(MetadataErrors::InvalidMetadataCharacters.into());
+    }
+    if RESERVED_METADATA_FIELDS.contains(&field)
+        || field == ROYALTY_BASIS_POINTS_FIELD
+        || field == CONTENT_HASH_FIELD
+        || Pubkey::from_str(field).is_ok()
+    {
+        msg!("Metadata key {} is reserved", field);
+        return Err(MetadataErrors::ReservedMetadataKey.into());
+    }
+    Ok(())
+}
+
+/// Determine if the account is a wallet, system owned and on curve
+pub fn is_wallet_account(account: &AccountInfo) -> bool {
+    account.owner == &system_program::ID
+        && validate_edwards(&PodEdwardsPoint(account.key.to_bytes()))
+}
+
+/// Verifies a Merkle proof of `receiver` against an allowlist root. Leaves are the keccak
+/// hash of the receiver pubkey and pairs are hashed sorted, as built by common Merkle tree libraries.
+pub fn verify_allowlist_proof(proof: &[[u8; 32]], root: &[u8; 32], receiver: &Pubkey) -> bool {
+    let mut node = hashv(&[receiver.as_ref()]).0;
+    for sibling in proof {
+        node = if node <= *sibling {
+            hashv(&[&node, sibling]).0
+        } else {
+            hashv(&[sibling, &node]).0
+        };
+    }
+    node == *root
+}
+
 pub fn get_extra_meta_list_account_pda(mint: Pubkey) -> Pubkey {
     Pubkey::find_program_address(&[META_LIST_ACCOUNT_SEED, mint.as_ref()], &crate::id()).0
 }
 
+/// Validates that `user_seed` fits in a single PDA seed
+pub fn validate_user_seed(user_seed: &str) -> Result<()> {
+    if user_seed.len() > MAX_SEED_LEN {
+        return Err(MetadataErrors::UserSeedTooLong.into());
+    }
+    Ok(())
+}
+
+/// Address of a mint created through `create_mint_account_pda`
+pub fn get_mint_pda(authority: Pubkey, user_seed: &str) -> Result<Pubkey> {
+    validate_user_seed(user_seed)?;
+    Ok(Pubkey::find_program_address(
+        &[MINT_SEED, authority.as_ref(), user_seed.as_bytes()],
+        &crate::id(),
+    )
+    .0)
+}
+
 pub fn get_approve_account_pda(mint: Pubkey) -> Pubkey {
     Pubkey::find_program_address(&[APPROVE_ACCOUNT_SEED, mint.as_ref()], &crate::id()).0
 }
 
+/// Fails unless the token account is the source of an ongoing token-2022 transfer, the
+/// token program only sets the transferring flag while it invokes the transfer hook
+pub fn assert_is_transferring(token_account: &AccountInfo) -> Result<()> {
+    let token_account_data = token_account.try_borrow_data()?;
+    let token_account_state =
+        StateWithExtensions::<TokenAccountState>::unpack(&token_account_data)?;
+    let transfer_hook_account = token_account_state
+        .get_extension::<TransferHookAccount>()
+        .map_err(|_| MetadataErrors::NotInTransferContext)?;
+    if !bool::from(transfer_hook_account.transferring) {
+        return Err(MetadataErrors::NotInTransferContext.into());
+    }
+    Ok(())
+}
+
 /// Determine if we are in CPI
 pub fn hook_in_cpi() -> bool {
     let stack_height = get_stack_height();
@@ -69,19 +838,678 @@ pub fn hook_in_cpi() -> bool {
     stack_height > hook_height
 }
 
-pub fn get_meta_list(approve_account: Option<Pubkey>) -> Vec<ExtraAccountMeta> {
-    if let Some(approve_account) = approve_account {
-        return vec![ExtraAccountMeta {
+pub fn get_royalty_exemptions_pda(group: Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[ROYALTY_EXEMPTIONS_SEED, group.as_ref()], &crate::id()).0
+}
+
+pub fn get_royalty_enforcement_pda(group: Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[ROYALTY_ENFORCEMENT_SEED, group.as_ref()], &crate::id()).0
+}
+
+/// Creates the royalty enforcement PDA of a group, or grows one of the previous layout, and
+/// returns its current settings
+pub fn init_royalty_enforcement<'info>(
+    royalty_enforcement: AccountInfo<'info>,
+    payer: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    group: Pubkey,
+    bump: u8,
+) -> Result<RoyaltyEnforcement> {
+    if royalty_enforcement.data_is_empty() {
+        create_account_with_minimum_balance(
+            royalty_enforcement,
+            payer,
+            system_program,
+            RoyaltyEnforcement::LEN,
+            0,
+            &crate::id(),
+            &[&[ROYALTY_ENFORCEMENT_SEED, group.as_ref(), &[bump]]],
+        )?;
+        return Ok(RoyaltyEnforcement {
+            group,
+            mode: EnforcementMode::default(),
+            memo_required: false,
+        });
+    }
+    let current = RoyaltyEnforcement::load(&royalty_enforcement)?
+        .ok_or(MetadataErrors::InvalidRoyaltyEnforcementAccount)?;
+    if royalty_enforcement.data_len() < RoyaltyEnforcement::LEN {
+        royalty_enforcement.realloc(RoyaltyEnforcement::LEN, false)?;
+        update_account_lamports_to_minimum_balance(royalty_enforcement, payer, system_program)?;
+    }
+    Ok(current)
+}
+
+/// Creates the member counter PDA of a group that has none yet, counting from the group `size`
+/// like `GroupMemberCounter::init`, before the size shrinks. Existing counters are kept.
+pub fn init_member_counter<'info>(
+    member_counter: AccountInfo<'info>,
+    payer: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    group: Pubkey,
+    size: u32,
+) -> Result<()> {
+    let (expected_member_counter, bump) =
+        Pubkey::find_program_address(&[GROUP_MEMBER_COUNTER_SEED, group.as_ref()], &crate::id());
+    if *member_counter.key != expected_member_counter {
+        return Err(MetadataErrors::InvalidMemberCounterAccount.into());
+    }
+    if !member_counter.data_is_empty() {
+        return Ok(());
+    }
+    create_account_with_minimum_balance(
+        member_counter.clone(),
+        payer,
+        system_program,
+        GroupMemberCounter::LEN,
+        0,
+        &crate::id(),
+        &[&[GROUP_MEMBER_COUNTER_SEED, group.as_ref(), &[bump]]],
+    )?;
+    let mut counter = GroupMemberCounter {
+        group: Pubkey::default(),
+        last_member_number: 0,
+    };
+    counter.init(group, size);
+    counter.try_serialize(&mut &mut member_counter.try_borrow_mut_data()?[..])?;
+    Ok(())
+}
+
+/// Whether the transaction has a memo instruction before the current top level instruction,
+/// which is the transfer or the instruction that invoked it
+pub fn has_preceding_memo(instructions: &AccountInfo) -> Result<bool> {
+    let current_index = load_current_index_checked(instructions)?;
+    for index in 0..current_index {
+        let program_id = load_instruction_at_checked(index as usize, instructions)?.program_id;
+        if program_id == spl_memo::ID || program_id == spl_memo::v1::ID {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// Group of a mint, `member` is required for group members
+pub fn get_mint_group(
+    mint: &AccountInfo,
+    member: Option<&TokenGroupMember>,
+) -> Result<Option<Pubkey>> {
+    let member_pointer = get_extension_data::<GroupMemberPointer>(&mut mint.clone())?;
+    if Option::<Pubkey>::from(member_pointer.member_address).is_none() {
+        return Ok(None);
+    }
+    let member = member.ok_or(MetadataErrors::InvalidGroupMemberAccount)?;
+    Ok(Some(member.group))
+}
+
+/// Members are added by the group authority, one of its delegates or a holder of the
+/// `ADD_MEMBER` permission, `group_delegates` is the delegates PDA of the group and
+/// `group_permission` the permission PDA of `authority` in the group
+pub fn validate_group_member_authority(
+    group: &TokenGroup,
+    group_delegates: &AccountInfo,
+    group_permission: &AccountInfo,
+    authority: &Pubkey,
+) -> Result<()> {
+    if group.update_authority == *authority {
+        return Ok(());
+    }
+    match GroupDelegates::load(group_delegates)? {
+        Some(group_delegates) if group_delegates.delegates.contains(authority) => Ok(()),
+        _ => validate_group_permission(
+            group,
+            group_permission,
+            authority,
+            GroupPermission::ADD_MEMBER,
+        ),
+    }
+}
+
+/// Validates that `authority` is the group authority or was granted `permission` by it,
+/// `group_permission` is the permission PDA of `authority` in the group
+pub fn validate_group_permission(
+    group: &TokenGroup,
+    group_permission: &AccountInfo,
+    authority: &Pubkey,
+    permission: u8,
+) -> Result<()> {
+    if group.update_authority == *authority {
+        return Ok(());
+    }
+    let grant = match GroupPermission::load(group_permission)? {
+        Some(grant) if grant.delegate == *authority => grant,
+        _ => return Err(MetadataErrors::GroupAuthoritySignatureRequired.into()),
+    };
+    if grant.permissions & permission != permission {
+        return Err(MetadataErrors::GroupPermissionDenied.into());
+    }
+    if !grant.allows(permission, Clock::get()?.unix_timestamp) {
+        return Err(MetadataErrors::GroupPermissionExpired.into());
+    }
+    Ok(())
+}
+
+/// Member index page `page` of `group`, see `GroupMemberIndex::position` for the page of a member
+pub fn get_member_index_pda(group: Pubkey, page: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[MEMBER_INDEX_SEED, group.as_ref(), &page.to_le_bytes()],
+        &crate::id(),
+    )
+}
+
+/// Records `mint` as `member_number` in the member index of `group`, creating its page
+pub fn index_member<'info>(
+    member_index: AccountInfo<'info>,
+    payer: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    group: Pubkey,
+    member_number: u32,
+    mint: Pubkey,
+) -> Result<()> {
+    let (page, position) = GroupMemberIndex::position(member_number);
+    let (expected_member_index, bump) = get_member_index_pda(group, page);
+    if *member_index.key != expected_member_index {
+        return Err(MetadataErrors::InvalidMemberIndexAccount.into());
+    }
+    let mut index = match GroupMemberIndex::load(&member_index)? {
+        Some(index) => index,
+        None => {
+            create_account_with_minimum_balance(
+                member_index.clone(),
+                payer,
+                system_program,
+                GroupMemberIndex::LEN,
+                0,
+                &crate::id(),
+                &[&[
+                    MEMBER_INDEX_SEED,
+                    group.as_ref(),
+                    &page.to_le_bytes(),
+                    &[bump],
+                ]],
+            )?;
+            GroupMemberIndex {
+                group,
+                page,
+                members: vec![],
+            }
+        }
+    };
+    // members added before the index existed are left unindexed
+    if index.members.len() <= position {
+        index.members.resize(position, Pubkey::default());
+        index.members.push(mint);
+    } else {
+        index.members[position] = mint;
+    }
+    index.try_serialize(&mut &mut member_index.try_borrow_mut_data()?[..])?;
+    Ok(())
+}
+
+/// Removes `mint` from the member index of `group`, leaving its position empty.
+/// Members added before the index existed have nothing to remove.
+pub fn unindex_member(
+    member_index: &AccountInfo,
+    group: Pubkey,
+    member_number: u32,
+    mint: Pubkey,
+) -> Result<()> {
+    let (page, position) = GroupMemberIndex::position(member_number);
+    if *member_index.key != get_member_index_pda(group, page).0 {
+        return Err(MetadataErrors::InvalidMemberIndexAccount.into());
+    }
+    let Some(mut index) = GroupMemberIndex::load(member_index)? else {
+        return Ok(());
+    };
+    if index.members.get(position) == Some(&mint) {
+        index.members[position] = Pubkey::default();
+        index.try_serialize(&mut &mut member_index.try_borrow_mut_data()?[..])?;
+    }
+    Ok(())
+}
+
+/// Royalty defaults of the parent of a sub-group, `None` for top level groups and parents
+/// without defaults. `parent_royalty_defaults` is required for sub-groups.
+pub fn get_parent_royalty_defaults(
+    hierarchy: &AccountInfo,
+    parent_royalty_defaults: Option<&AccountInfo>,
+) -> Result<Option<GroupRoyaltyDefaults>> {
+    let Some(parent) = GroupHierarchy::load(hierarchy)?.and_then(|hierarchy| hierarchy.parent)
+    else {
+        return Ok(None);
+    };
+    let expected_royalty_defaults = Pubkey::find_program_address(
+        &[GROUP_ROYALTY_DEFAULTS_SEED, parent.as_ref()],
+        &crate::id(),
+    )
+    .0;
+    match parent_royalty_defaults {
+        Some(royalty_defaults) if *royalty_defaults.key == expected_royalty_defaults => {
+            GroupRoyaltyDefaults::load(royalty_defaults)
+        }
+        _ => Err(MetadataErrors::InvalidParentRoyaltyDefaultsAccount.into()),
+    }
+}
+
+/// Index of the mint in the accounts of the transfer hook execute instruction
+pub const EXECUTE_MINT_ACCOUNT_INDEX: u8 = 1;
+/// Number of accounts of the transfer hook execute instruction before its extra metas
+pub const EXECUTE_ACCOUNTS_LEN: usize = 5;
+/// Number of metas `get_meta_list` resolves for group members, with or without royalties,
+/// before the secondary hook metas
+pub const GROUP_META_LIST_LEN: usize = 4;
+
+/// Metas resolved by the transfer hook. Royalty enforced mints start with their approve
+/// account, derived from the mint during resolution so the SPL offchain helpers resolve it.
+/// Group members, with or without royalties, then include the royalty exemptions of their
+/// group, the instructions sysvar and the group enforcement mode. Members without royalties
+/// hold the system program in place of the approve account, so the group metas keep their
+/// index. The distribution isn't resolved, it depends on the payment mint and is only used by
+/// approve. None of them is a signer, a transfer only needs the signature of its owner or
+/// delegate.
+pub fn get_meta_list(royalties: bool, group: Option<Pubkey>) -> Result<Vec<ExtraAccountMeta>> {
+    let mut metas = vec![];
+    if royalties {
+        metas.push(ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal {
+                    bytes: APPROVE_ACCOUNT_SEED.to_vec(),
+                },
+                Seed::AccountKey {
+                    index: EXECUTE_MINT_ACCOUNT_INDEX,
+                },
+            ],
+            false,
+            true,
+        )?);
+    } else if group.is_some() {
+        metas.push(get_unenforced_approve_meta());
+    }
+    if let Some(group) = group {
+        metas.push(ExtraAccountMeta {
+            discriminator: 0,
+            address_config: get_royalty_exemptions_pda(group).to_bytes(),
+            is_signer: false.into(),
+            is_writable: false.into(),
+        });
+        metas.push(ExtraAccountMeta {
+            discriminator: 0,
+            address_config: instructions::ID.to_bytes(),
+            is_signer: false.into(),
+            is_writable: false.into(),
+        });
+        metas.push(ExtraAccountMeta {
             discriminator: 0,
-            address_config: approve_account.to_bytes(),
+            address_config: get_royalty_enforcement_pda(group).to_bytes(),
             is_signer: false.into(),
-            is_writable: true.into(),
-        }];
+            is_writable: false.into(),
+        });
+    }
+    Ok(metas)
+}
+
+/// Meta held in place of the approve account by mints without royalties, the transfer hook
+/// never enforces royalties when it resolves the system program there
+pub fn get_unenforced_approve_meta() -> ExtraAccountMeta {
+    ExtraAccountMeta {
+        discriminator: 0,
+        address_config: system_program::ID.to_bytes(),
+        is_signer: false.into(),
+        is_writable: false.into(),
+    }
+}
+
+pub fn get_meta_list_size(royalties: bool, group: Option<Pubkey>) -> Result<usize> {
+    Ok(ExtraAccountMetaList::size_of(
+        get_meta_list(royalties, group)?.len(),
+    )?)
+}
+
+pub fn get_secondary_hook_pda(group: Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[SECONDARY_HOOK_SEED, group.as_ref()], &crate::id()).0
+}
+
+/// Metas of the secondary hook of a group, resolved after the group metas: the secondary hook
+/// PDA, the secondary hook program, its validation account and its static accounts
+pub fn get_secondary_hook_metas(secondary_hook: &SecondaryHook) -> Result<Vec<ExtraAccountMeta>> {
+    // the secondary hook program follows the execute accounts, the group metas and the
+    // secondary hook PDA
+    let program_index =
+        EXECUTE_ACCOUNTS_LEN + get_meta_list(true, Some(secondary_hook.group))?.len() + 1;
+    let mut metas = vec![
+        ExtraAccountMeta {
+            discriminator: 0,
+            address_config: get_secondary_hook_pda(secondary_hook.group).to_bytes(),
+            is_signer: false.into(),
+            is_writable: false.into(),
+        },
+        ExtraAccountMeta {
+            discriminator: 0,
+            address_config: secondary_hook.program_id.to_bytes(),
+            is_signer: false.into(),
+            is_writable: false.into(),
+        },
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            program_index as u8,
+            &[
+                Seed::Literal {
+                    bytes: META_LIST_ACCOUNT_SEED.to_vec(),
+                },
+                Seed::AccountKey {
+                    index: EXECUTE_MINT_ACCOUNT_INDEX,
+                },
+            ],
+            false,
+            false,
+        )?,
+    ];
+    for account in secondary_hook.accounts.iter() {
+        metas.push(ExtraAccountMeta {
+            discriminator: 0,
+            address_config: account.address.to_bytes(),
+            is_signer: false.into(),
+            is_writable: account.is_writable.into(),
+        });
+    }
+    Ok(metas)
+}
+
+/// Secondary hook metas currently written in an extra metas account of a member of `group`
+fn get_written_secondary_hook_metas(
+    extra_metas_account: &AccountInfo,
+    group: Pubkey,
+) -> Result<Vec<ExtraAccountMeta>> {
+    let metas = get_written_metas(extra_metas_account)?;
+    let secondary_hook = get_secondary_hook_pda(group).to_bytes();
+    let provenance = get_provenance_meta()?;
+    Ok(metas
+        .iter()
+        .position(|meta| meta.discriminator == 0 && meta.address_config == secondary_hook)
+        .map(|index| {
+            metas[index..]
+                .iter()
+                .filter(|meta| **meta != provenance)
+                .copied()
+                .collect()
+        })
+        .unwrap_or_default())
+}
+
+/// Metas currently written in an initialized extra metas account
+pub fn get_written_metas(extra_metas_account: &AccountInfo) -> Result<Vec<ExtraAccountMeta>> {
+    let data = extra_metas_account.try_borrow_data()?;
+    let state = TlvStateBorrowed::unpack(&data)?;
+    let metas = ExtraAccountMetaList::unpack_with_tlv_state::<ExecuteInstruction>(&state)?;
+    Ok(metas.data().to_vec())
+}
+
+/// Provenance record of the mint, derived from the mint during resolution
+pub fn get_provenance_meta() -> Result<ExtraAccountMeta> {
+    Ok(ExtraAccountMeta::new_with_seeds(
+        &[
+            Seed::Literal {
+                bytes: PROVENANCE_SEED.to_vec(),
+            },
+            Seed::AccountKey {
+                index: EXECUTE_MINT_ACCOUNT_INDEX,
+            },
+        ],
+        false,
+        true,
+    )?)
+}
+
+/// Whether the mint opted in to the provenance log in its extra metas account
+pub fn has_provenance_meta(extra_metas_account: &AccountInfo) -> Result<bool> {
+    if extra_metas_account.data_is_empty() {
+        return Ok(false);
+    }
+    Ok(get_written_metas(extra_metas_account)?.contains(&get_provenance_meta()?))
+}
+
+/// Extra metas account data of a mint with or without `royalties`, in `group`, followed by
+/// the metas of the secondary hook of the group and the provenance record of the mint
+pub fn get_meta_list_data(
+    royalties: bool,
+    group: Option<Pubkey>,
+    secondary_hook_metas: Vec<ExtraAccountMeta>,
+    provenance: bool,
+) -> Result<Vec<u8>> {
+    let mut metas = get_meta_list(royalties, group)?;
+    // group members resolve the group metas the secondary hook follows
+    if group.is_some() {
+        metas.extend(secondary_hook_metas);
     }
-    vec![]
+    // the provenance record is found by the hook wherever it is, after the approve account or
+    // the system program held in its place by mints without royalties
+    if provenance {
+        if metas.is_empty() {
+            metas.push(get_unenforced_approve_meta());
+        }
+        metas.push(get_provenance_meta()?);
+    }
+    let mut data = vec![0u8; ExtraAccountMetaList::size_of(metas.len())?];
+    ExtraAccountMetaList::init::<ExecuteInstruction>(&mut data, &metas)?;
+    Ok(data)
+}
+
+/// Reallocates and rewrites an initialized extra metas account with the metas of a mint
+/// with or without `royalties`, in `group`. Secondary hook metas already written for the
+/// group are kept, `update_extra_metas_list` rewrites them, and so is the provenance record.
+pub fn write_meta_list(
+    extra_metas_account: &AccountInfo,
+    royalties: bool,
+    group: Option<Pubkey>,
+) -> Result<()> {
+    let secondary_hook_metas = match group {
+        Some(group) if extra_metas_account.data_len() > 0 => {
+            get_written_secondary_hook_metas(extra_metas_account, group)?
+        }
+        _ => vec![],
+    };
+    let provenance = has_provenance_meta(extra_metas_account)?;
+    let data = get_meta_list_data(royalties, group, secondary_hook_metas, provenance)?;
+    extra_metas_account.realloc(data.len(), false)?;
+    extra_metas_account
+        .try_borrow_mut_data()?
+        .copy_from_slice(&data);
+    Ok(())
 }
 
-pub fn get_meta_list_size(approve_account: Option<Pubkey>) -> usize {
-    // safe because it's either 0 or 1
-    ExtraAccountMetaList::size_of(get_meta_list(approve_account).len()).unwrap()
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SecondaryHookAccount;
+
+    fn hash_pair(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+        if left <= right {
+            hashv(&[left, right]).0
+        } else {
+            hashv(&[right, left]).0
+        }
+    }
+
+    /// Root of a 4 leaf allowlist and the proof of each receiver
+    fn allowlist(receivers: &[Pubkey; 4]) -> ([u8; 32], Vec<Vec<[u8; 32]>>) {
+        let leaves: Vec<[u8; 32]> = receivers
+            .iter()
+            .map(|receiver| hashv(&[receiver.as_ref()]).0)
+            .collect();
+        let left = hash_pair(&leaves[0], &leaves[1]);
+        let right = hash_pair(&leaves[2], &leaves[3]);
+        let proofs = vec![
+            vec![leaves[1], right],
+            vec![leaves[0], right],
+            vec![leaves[3], left],
+            vec![leaves[2], left],
+        ];
+        (hash_pair(&left, &right), proofs)
+    }
+
+    #[test]
+    fn allowlist_proof_of_every_receiver_is_valid() {
+        let receivers = [(); 4].map(|_| Pubkey::new_unique());
+        let (root, proofs) = allowlist(&receivers);
+        for (receiver, proof) in receivers.iter().zip(proofs.iter()) {
+            assert!(verify_allowlist_proof(proof, &root, receiver));
+        }
+    }
+
+    #[test]
+    fn tampered_allowlist_proof_is_rejected() {
+        let receivers = [(); 4].map(|_| Pubkey::new_unique());
+        let (root, proofs) = allowlist(&receivers);
+        let mut proof = proofs[0].clone();
+        proof[1][0] ^= 1;
+        assert!(!verify_allowlist_proof(&proof, &root, &receivers[0]));
+        // a valid proof of another receiver, or a truncated one, doesn't prove the receiver
+        assert!(!verify_allowlist_proof(&proofs[2], &root, &receivers[0]));
+        assert!(!verify_allowlist_proof(
+            &proofs[0][..1],
+            &root,
+            &receivers[0]
+        ));
+        assert!(!verify_allowlist_proof(
+            &proofs[0],
+            &root,
+            &Pubkey::new_unique()
+        ));
+    }
+
+    #[test]
+    fn empty_allowlist_proof_only_proves_a_single_receiver_root() {
+        let receiver = Pubkey::new_unique();
+        let root = hashv(&[receiver.as_ref()]).0;
+        assert!(verify_allowlist_proof(&[], &root, &receiver));
+        assert!(!verify_allowlist_proof(&[], &root, &Pubkey::new_unique()));
+
+        let receivers = [(); 4].map(|_| Pubkey::new_unique());
+        let (root, _) = allowlist(&receivers);
+        assert!(!verify_allowlist_proof(&[], &root, &receivers[0]));
+    }
+
+    #[test]
+    fn allowlist_pairs_are_hashed_sorted() {
+        let receiver = Pubkey::new_unique();
+        let leaf = hashv(&[receiver.as_ref()]).0;
+        // siblings on either side of the leaf hash to the same parent whatever their order
+        for sibling in [[0u8; 32], [u8::MAX; 32]] {
+            let sorted_root = hash_pair(&leaf, &sibling);
+            assert_eq!(sorted_root, hash_pair(&sibling, &leaf));
+            assert!(verify_allowlist_proof(&[sibling], &sorted_root, &receiver));
+
+            let unsorted_root = if leaf <= sibling {
+                hashv(&[&sibling, &leaf]).0
+            } else {
+                hashv(&[&leaf, &sibling]).0
+            };
+            assert!(!verify_allowlist_proof(
+                &[sibling],
+                &unsorted_root,
+                &receiver
+            ));
+        }
+    }
+
+    #[test]
+    fn user_seed_fits_in_a_single_seed() {
+        let authority = Pubkey::new_unique();
+        assert!(validate_user_seed("").is_ok());
+        assert!(validate_user_seed(&"a".repeat(MAX_SEED_LEN)).is_ok());
+        assert!(get_mint_pda(authority, &"a".repeat(MAX_SEED_LEN)).is_ok());
+        assert!(validate_user_seed(&"a".repeat(MAX_SEED_LEN + 1)).is_err());
+        assert!(get_mint_pda(authority, &"a".repeat(MAX_SEED_LEN + 1)).is_err());
+    }
+
+    #[test]
+    fn group_meta_list_len_matches_the_meta_list() {
+        let group = Pubkey::new_unique();
+        for royalties in [true, false] {
+            assert_eq!(
+                get_meta_list(royalties, Some(group)).unwrap().len(),
+                GROUP_META_LIST_LEN
+            );
+        }
+    }
+
+    #[test]
+    fn secondary_hook_validation_account_follows_the_program() {
+        let secondary_hook = SecondaryHook {
+            group: Pubkey::new_unique(),
+            program_id: Pubkey::new_unique(),
+            accounts: vec![SecondaryHookAccount {
+                address: Pubkey::new_unique(),
+                is_writable: true,
+            }],
+        };
+        let mut metas = get_meta_list(true, Some(secondary_hook.group)).unwrap();
+        metas.extend(get_secondary_hook_metas(&secondary_hook).unwrap());
+
+        // external PDA metas hold the index of their program after the high bit
+        let program_index = EXECUTE_ACCOUNTS_LEN + GROUP_META_LIST_LEN + 1;
+        assert_eq!(
+            metas[program_index - EXECUTE_ACCOUNTS_LEN].address_config,
+            secondary_hook.program_id.to_bytes()
+        );
+        assert_eq!(
+            metas[program_index - EXECUTE_ACCOUNTS_LEN + 1].discriminator,
+            (1 << 7) + program_index as u8
+        );
+        assert_eq!(metas.len(), GROUP_META_LIST_LEN + 3 + 1);
+    }
+
+    #[test]
+    fn transfer_hook_metas_are_never_signers() {
+        let secondary_hook = SecondaryHook {
+            group: Pubkey::new_unique(),
+            program_id: Pubkey::new_unique(),
+            accounts: vec![SecondaryHookAccount {
+                address: Pubkey::new_unique(),
+                is_writable: true,
+            }],
+        };
+        for royalties in [true, false] {
+            for group in [None, Some(secondary_hook.group)] {
+                for provenance in [true, false] {
+                    let data = get_meta_list_data(
+                        royalties,
+                        group,
+                        get_secondary_hook_metas(&secondary_hook).unwrap(),
+                        provenance,
+                    )
+                    .unwrap();
+                    let state = TlvStateBorrowed::unpack(&data).unwrap();
+                    let metas =
+                        ExtraAccountMetaList::unpack_with_tlv_state::<ExecuteInstruction>(&state)
+                            .unwrap();
+                    assert!(metas.data().iter().all(|meta| !bool::from(meta.is_signer)));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn wallet_accounts_are_system_owned_and_on_curve() {
+        // the compressed ed25519 base point, on the curve
+        let mut wallet_bytes = [0x66; 32];
+        wallet_bytes[0] = 0x58;
+        let wallet = Pubkey::new_from_array(wallet_bytes);
+        let (pda, _) = Pubkey::find_program_address(&[b"receiver"], &crate::id());
+        let is_wallet = |key: &Pubkey, owner: &Pubkey| {
+            let mut lamports = 0;
+            let mut data = vec![];
+            is_wallet_account(&AccountInfo::new(
+                key,
+                false,
+                false,
+                &mut lamports,
+                &mut data,
+                owner,
+                false,
+                0,
+            ))
+        };
+        assert!(is_wallet(&wallet, &system_program::ID));
+        assert!(!is_wallet(&wallet, &crate::TOKEN22));
+        assert!(!is_wallet(&pda, &system_program::ID));
+    }
 }
@@ -0,0 +1,604 @@
+#![allow(dead_code)]
+
+use anchor_lang::{
+    prelude::{AccountInfo, Pubkey},
+    solana_program::{entrypoint::ProgramResult, program::invoke, program_error::ProgramError},
+    AccountDeserialize, AccountSerialize, InstructionData, ToAccountMetas,
+};
+use anchor_spl::{
+    associated_token::{self, get_associated_token_address_with_program_id},
+    token_interface::spl_token_2022::{
+        self, extension::StateWithExtensions,
+        offchain::create_transfer_checked_instruction_with_extra_metas,
+        state::Account as TokenAccount,
+    },
+};
+use solana_program_test::{processor, BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    account::Account,
+    instruction::{AccountMeta, Instruction, InstructionError},
+    rent::Rent,
+    signature::{Keypair, Signer},
+    transaction::{Transaction, TransactionError},
+};
+use wen_new_standard::{
+    accounts, get_member_index_pda, instruction as ix, AddRoyaltiesArgs, CreateMintAccountArgs,
+    CreatorWithShare, GroupMemberIndex, Manager, MetadataErrors, TokenGroup, APPROVE_ACCOUNT_SEED,
+    COLLECTION_DISTRIBUTION_SEED, GROUP_DELEGATES_SEED, GROUP_FINALIZATION_SEED,
+    GROUP_MEMBER_COUNTER_SEED, GROUP_ROYALTY_DEFAULTS_SEED, GROUP_SOULBOUND_SEED, MANAGER_SEED,
+    MEMBER_ACCOUNT_SEED, META_LIST_ACCOUNT_SEED, POLICY_SEED, VERIFIED_CREATORS_SEED,
+};
+use wen_royalty_distribution::{DistributionErrors, PaymentDistribution, DISTRIBUTION_SEED};
+
+/// Runs the program natively, the anchor entrypoint ties the accounts to their own lifetime
+/// which the test runtime keeps alive for the whole instruction
+fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    let accounts = Box::leak(Box::new(accounts.to_vec()));
+    wen_new_standard::entry(program_id, accounts, data)
+}
+
+fn process_distribution_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    let accounts = Box::leak(Box::new(accounts.to_vec()));
+    wen_royalty_distribution::entry(program_id, accounts, data)
+}
+
+pub fn program_test() -> ProgramTest {
+    let mut program_test = ProgramTest::new(
+        "wen_new_standard",
+        wen_new_standard::ID,
+        processor!(process_instruction),
+    );
+    program_test.prefer_bpf(false);
+    add_token_2022(&mut program_test);
+    program_test.add_program(
+        "wen_royalty_distribution",
+        wen_royalty_distribution::ID,
+        processor!(process_distribution_instruction),
+    );
+    program_test
+}
+
+/// Runs the program built for the SBF target by `cargo test-sbf`, which meters its compute units
+pub fn sbf_program_test() -> ProgramTest {
+    let mut program_test = ProgramTest::new("wen_new_standard", wen_new_standard::ID, None);
+    program_test.prefer_bpf(true);
+    add_token_2022(&mut program_test);
+    program_test
+}
+
+/// The token program of the crate runs natively so it supports the extensions the program
+/// initializes, whatever token program the test runtime bundles
+fn add_token_2022(program_test: &mut ProgramTest) {
+    program_test.add_program(
+        "spl_token_2022",
+        spl_token_2022::id(),
+        processor!(spl_token_2022::processor::Processor::process),
+    );
+}
+
+/// Marketplace like program invoking the program of its last account with the other accounts,
+/// the transfer hook then runs in a CPI
+pub const MARKETPLACE_ID: Pubkey = Pubkey::new_from_array([9; 32]);
+
+fn process_marketplace(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    let (program, instruction_accounts) = accounts
+        .split_last()
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let instruction = Instruction {
+        program_id: *program.key,
+        accounts: instruction_accounts
+            .iter()
+            .map(|account| AccountMeta {
+                pubkey: *account.key,
+                is_signer: account.is_signer,
+                is_writable: account.is_writable,
+            })
+            .collect(),
+        data: data.to_vec(),
+    };
+    invoke(&instruction, accounts)
+}
+
+pub fn add_marketplace(program_test: &mut ProgramTest) {
+    program_test.add_program(
+        "marketplace",
+        MARKETPLACE_ID,
+        processor!(process_marketplace),
+    );
+}
+
+/// Sends `instruction` through the marketplace program
+pub fn marketplace_ix(mut instruction: Instruction) -> Instruction {
+    instruction
+        .accounts
+        .push(AccountMeta::new_readonly(instruction.program_id, false));
+    instruction.program_id = MARKETPLACE_ID;
+    instruction
+}
+
+/// Adds the global `Manager` holding the authorities of mints created without a manager authority
+pub fn add_manager(program_test: &mut ProgramTest) -> Pubkey {
+    let manager = Pubkey::find_program_address(&[MANAGER_SEED], &wen_new_standard::ID).0;
+    add_program_account(program_test, manager, &Manager::new(), Manager::LEN);
+    manager
+}
+
+/// Adds an anchor account owned by the program, rent exempt for its serialized size
+pub fn add_program_account<T: AccountSerialize>(
+    program_test: &mut ProgramTest,
+    address: Pubkey,
+    account: &T,
+    len: usize,
+) {
+    let mut data = Vec::with_capacity(len);
+    account.try_serialize(&mut data).unwrap();
+    data.resize(len, 0);
+    program_test.add_account(
+        address,
+        Account {
+            lamports: Rent::default().minimum_balance(len),
+            data,
+            owner: wen_new_standard::ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+}
+
+/// Adds a group of `authority` without going through its mint, for instructions that never
+/// read the group mint
+pub fn add_group(program_test: &mut ProgramTest, authority: Pubkey, max_size: u32) -> Pubkey {
+    let group = Pubkey::new_unique();
+    add_program_account(
+        program_test,
+        group,
+        &TokenGroup::new(&Pubkey::new_unique(), authority, max_size),
+        TokenGroup::LEN,
+    );
+    group
+}
+
+pub fn event_authority() -> Pubkey {
+    Pubkey::find_program_address(&[b"__event_authority"], &wen_new_standard::ID).0
+}
+
+pub fn group_permission_pda(group: Pubkey, delegate: Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            wen_new_standard::GROUP_PERMISSION_SEED,
+            group.as_ref(),
+            delegate.as_ref(),
+        ],
+        &wen_new_standard::ID,
+    )
+    .0
+}
+
+pub fn extra_metas_pda(mint: Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[META_LIST_ACCOUNT_SEED, mint.as_ref()],
+        &wen_new_standard::ID,
+    )
+    .0
+}
+
+/// Args of a transferable mint of a single token, with `royalty_basis_points` royalties paid to
+/// a single creator when set
+pub fn mint_args(royalty_basis_points: Option<u16>) -> CreateMintAccountArgs {
+    CreateMintAccountArgs {
+        name: "WNS".to_string(),
+        symbol: "WNS".to_string(),
+        uri: "https://wns.example/mint.json".to_string(),
+        soulbound: false,
+        permanent_delegate: None,
+        frozen: false,
+        freeze_authority: None,
+        decimals: None,
+        initial_supply: None,
+        additional_metadata: vec![],
+        royalties: royalty_basis_points.map(|royalty_basis_points| AddRoyaltiesArgs {
+            royalty_basis_points,
+            creators: vec![CreatorWithShare {
+                address: Pubkey::new_unique().to_string(),
+                share: 100,
+            }],
+        }),
+        update_authority: None,
+        immutable: false,
+        allow_pda_receiver: false,
+        external_metadata: false,
+        content_hash: None,
+        mint_record: false,
+        manager_authority: None,
+        provenance: false,
+    }
+}
+
+/// Creates `mint` with the global manager, its initial supply minted to `receiver`
+pub fn create_mint_account_ix(
+    payer: Pubkey,
+    authority: Pubkey,
+    receiver: Pubkey,
+    mint: Pubkey,
+    args: CreateMintAccountArgs,
+) -> Instruction {
+    instruction(
+        accounts::CreateMintAccount {
+            payer,
+            authority,
+            receiver,
+            mint,
+            mint_token_account: get_associated_token_address_with_program_id(
+                &receiver,
+                &mint,
+                &spl_token_2022::id(),
+            ),
+            extra_metas_account: extra_metas_pda(mint),
+            metadata_account: None,
+            mint_record: None,
+            manager: Pubkey::find_program_address(&[MANAGER_SEED], &wen_new_standard::ID).0,
+            system_program: anchor_lang::system_program::ID,
+            associated_token_program: associated_token::ID,
+            token_program: spl_token_2022::id(),
+            event_authority: event_authority(),
+            program: wen_new_standard::ID,
+        },
+        ix::CreateMintAccount { args },
+    )
+}
+
+pub fn member_pda(mint: Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[MEMBER_ACCOUNT_SEED, mint.as_ref()], &wen_new_standard::ID).0
+}
+
+/// Adds `mint` to `group` as its `member_number`th member, signed by `authority`
+pub fn add_group_to_mint_ix(
+    payer: Pubkey,
+    authority: Pubkey,
+    group: Pubkey,
+    mint: Pubkey,
+    member_number: u32,
+) -> Instruction {
+    let group_pda = |seed: &[u8]| {
+        Pubkey::find_program_address(&[seed, group.as_ref()], &wen_new_standard::ID).0
+    };
+    instruction(
+        accounts::AddGroup {
+            payer,
+            authority,
+            group,
+            group_delegates: group_pda(GROUP_DELEGATES_SEED),
+            group_permission: group_permission_pda(group, authority),
+            member: member_pda(mint),
+            member_counter: group_pda(GROUP_MEMBER_COUNTER_SEED),
+            member_index: get_member_index_pda(group, GroupMemberIndex::position(member_number).0)
+                .0,
+            mint,
+            finalization: group_pda(GROUP_FINALIZATION_SEED),
+            soulbound: group_pda(GROUP_SOULBOUND_SEED),
+            policy: group_pda(POLICY_SEED),
+            metadata_account: None,
+            extra_metas_account: extra_metas_pda(mint),
+            system_program: anchor_lang::system_program::ID,
+            token_program: spl_token_2022::id(),
+            event_authority: event_authority(),
+            program: wen_new_standard::ID,
+        },
+        ix::AddGroupToMint {},
+    )
+}
+
+pub fn distribution_event_authority() -> Pubkey {
+    Pubkey::find_program_address(&[b"__event_authority"], &wen_royalty_distribution::ID).0
+}
+
+/// Distribution of the royalties of `collection` paid in `payment_mint`, `Pubkey::default()`
+/// for SOL
+pub fn distribution_pda(collection: Pubkey, payment_mint: Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            DISTRIBUTION_SEED,
+            collection.as_ref(),
+            payment_mint.as_ref(),
+        ],
+        &wen_royalty_distribution::ID,
+    )
+    .0
+}
+
+pub fn initialize_distribution_ix(
+    payer: Pubkey,
+    collection: Pubkey,
+    payment_mint: Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: wen_royalty_distribution::ID,
+        accounts: wen_royalty_distribution::accounts::InitializeDistribution {
+            payer,
+            authority: payer,
+            mint: collection,
+            distribution: distribution_pda(collection, payment_mint),
+            system_program: anchor_lang::system_program::ID,
+        }
+        .to_account_metas(None),
+        data: wen_royalty_distribution::instruction::InitializeDistribution { payment_mint }.data(),
+    }
+}
+
+/// Claims of a distribution account by creator
+pub async fn get_claims(
+    context: &mut ProgramTestContext,
+    distribution: Pubkey,
+) -> Vec<(Pubkey, u64)> {
+    let account = get_account(context, distribution).await;
+    PaymentDistribution::try_deserialize(&mut &account.data[..])
+        .unwrap()
+        .data
+        .iter()
+        .map(|claim| (claim.address, claim.amount))
+        .collect()
+}
+
+/// Replaces the royalties of `mint` with `creators` at 500 basis points
+pub fn modify_royalties_ix(
+    authority: Pubkey,
+    mint: Pubkey,
+    creators: &[(Pubkey, u8)],
+) -> Instruction {
+    instruction(
+        accounts::ModifyRoyalties {
+            payer: authority,
+            authority: authority,
+            mint: mint,
+            metadata_account: None,
+            extra_metas_account: extra_metas_pda(mint),
+            verified_creators: Pubkey::find_program_address(
+                &[VERIFIED_CREATORS_SEED, mint.as_ref()],
+                &wen_new_standard::ID,
+            )
+            .0,
+            member: None,
+            group: None,
+            royalty_defaults: None,
+            policy: None,
+            royalty_timelock: None,
+            pending_royalty_update: None,
+            system_program: anchor_lang::system_program::ID,
+            token_program: spl_token_2022::id(),
+            event_authority: event_authority(),
+            program: wen_new_standard::ID,
+        },
+        ix::ModifyRoyalties {
+            args: AddRoyaltiesArgs {
+                royalty_basis_points: 500,
+                creators: creators
+                    .iter()
+                    .map(|(address, share)| CreatorWithShare {
+                        address: address.to_string(),
+                        share: *share,
+                    })
+                    .collect(),
+            },
+        },
+    )
+}
+
+pub fn approve_pda(mint: Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[APPROVE_ACCOUNT_SEED, mint.as_ref()],
+        &wen_new_standard::ID,
+    )
+    .0
+}
+
+/// Accounts approving a sale of `mint`, paid by `authority` to the distribution of the mint
+fn approve_accounts(
+    authority: Pubkey,
+    mint: Pubkey,
+    payment_mint: Pubkey,
+    source_token_account: Option<Pubkey>,
+) -> accounts::ApproveTransfer {
+    let distribution = distribution_pda(mint, payment_mint);
+    let (distribution_address, payer_address) = if payment_mint == Pubkey::default() {
+        (distribution, authority)
+    } else {
+        (
+            get_associated_token_address_with_program_id(
+                &distribution,
+                &payment_mint,
+                &spl_token_2022::id(),
+            ),
+            get_associated_token_address_with_program_id(
+                &authority,
+                &payment_mint,
+                &spl_token_2022::id(),
+            ),
+        )
+    };
+    accounts::ApproveTransfer {
+        payer: authority,
+        authority,
+        mint,
+        approve_account: approve_pda(mint),
+        payment_mint,
+        distribution_address,
+        payer_address,
+        distribution,
+        distribution_event_authority: distribution_event_authority(),
+        system_program: anchor_lang::system_program::ID,
+        distribution_program: wen_royalty_distribution::ID,
+        token_program: spl_token_2022::id(),
+        associated_token_program: associated_token::ID,
+        metadata_account: None,
+        member: None,
+        group: None,
+        collection_distribution: None,
+        royalty_defaults: None,
+        source_token_account,
+        event_authority: event_authority(),
+        program: wen_new_standard::ID,
+    }
+}
+
+/// Approves a transfer of `transfer_amount` tokens of `mint` to `destination` sold for `amount`
+/// of `payment_mint`, `Pubkey::default()` for SOL. `authority` pays the royalties to the
+/// distribution of the mint, from its associated token account for tokens.
+pub fn approve_ix(
+    authority: Pubkey,
+    mint: Pubkey,
+    payment_mint: Pubkey,
+    amount: u64,
+    transfer_amount: u64,
+    destination: Pubkey,
+    source_token_account: Option<Pubkey>,
+) -> Instruction {
+    instruction(
+        approve_accounts(authority, mint, payment_mint, source_token_account),
+        ix::Approve {
+            amount,
+            expiry_slot: None,
+            transfer_amount,
+            destination,
+            seller: authority,
+        },
+    )
+}
+
+/// Approves a transfer of the single token of `mint`, a member of `group`, to `destination`
+/// sold for `amount` SOL. The group doesn't pay collection-wide, so the royalties go to the
+/// distribution of the mint.
+pub fn approve_member_ix(
+    authority: Pubkey,
+    mint: Pubkey,
+    group: Pubkey,
+    amount: u64,
+    destination: Pubkey,
+) -> Instruction {
+    let group_pda = |seed: &[u8]| {
+        Pubkey::find_program_address(&[seed, group.as_ref()], &wen_new_standard::ID).0
+    };
+    let mut accounts = approve_accounts(authority, mint, Pubkey::default(), None);
+    accounts.member = Some(member_pda(mint));
+    accounts.group = Some(group);
+    accounts.collection_distribution = Some(group_pda(COLLECTION_DISTRIBUTION_SEED));
+    accounts.royalty_defaults = Some(group_pda(GROUP_ROYALTY_DEFAULTS_SEED));
+    instruction(
+        accounts,
+        ix::Approve {
+            amount,
+            expiry_slot: None,
+            transfer_amount: 1,
+            destination,
+            seller: authority,
+        },
+    )
+}
+
+pub fn instruction(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: wen_new_standard::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+/// Sends `instructions` paid by the context payer and signed by `signers`, on a fresh
+/// blockhash so identical transactions aren't deduplicated
+pub async fn process(
+    context: &mut ProgramTestContext,
+    instructions: &[Instruction],
+    signers: &[&Keypair],
+) -> Result<(), BanksClientError> {
+    let blockhash = context.get_new_latest_blockhash().await.unwrap();
+    let mut all_signers = vec![&context.payer];
+    all_signers.extend_from_slice(signers);
+    let transaction = Transaction::new_signed_with_payer(
+        instructions,
+        Some(&context.payer.pubkey()),
+        &all_signers,
+        blockhash,
+    );
+    context.banks_client.process_transaction(transaction).await
+}
+
+/// Account at `address`, which must exist
+pub async fn get_account(context: &mut ProgramTestContext, address: Pubkey) -> Account {
+    context
+        .banks_client
+        .get_account(address)
+        .await
+        .unwrap()
+        .unwrap()
+}
+
+/// Transfer of `amount` tokens of `mint` with the extra metas resolved by the SPL helper
+pub async fn resolved_transfer_ix(
+    context: &mut ProgramTestContext,
+    source: Pubkey,
+    mint: Pubkey,
+    destination: Pubkey,
+    authority: Pubkey,
+    amount: u64,
+    decimals: u8,
+) -> Instruction {
+    let banks_client = context.banks_client.clone();
+    create_transfer_checked_instruction_with_extra_metas(
+        &spl_token_2022::id(),
+        &source,
+        &mint,
+        &destination,
+        &authority,
+        &[],
+        amount,
+        decimals,
+        |address| {
+            let mut banks_client = banks_client.clone();
+            async move {
+                banks_client
+                    .get_account(address)
+                    .await
+                    .map(|account| account.map(|account| account.data))
+                    .map_err(Into::into)
+            }
+        },
+    )
+    .await
+    .unwrap()
+}
+
+pub async fn token_balance(context: &mut ProgramTestContext, token_account: Pubkey) -> u64 {
+    let account = get_account(context, token_account).await;
+    StateWithExtensions::<TokenAccount>::unpack(&account.data)
+        .unwrap()
+        .base
+        .amount
+}
+
+/// Asserts that the first instruction of a transaction failed with `error`
+pub fn assert_metadata_error(result: Result<(), BanksClientError>, error: MetadataErrors) {
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(u32::from(error)))
+    );
+}
+
+/// Asserts that the first instruction of a transaction failed with `error` of the distribution
+/// program
+pub fn assert_distribution_error(result: Result<(), BanksClientError>, error: DistributionErrors) {
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(u32::from(error)))
+    );
+}
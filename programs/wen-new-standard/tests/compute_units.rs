@@ -0,0 +1,51 @@
+#![cfg(feature = "test-sbf")]
+
+mod common;
+
+use common::{add_manager, create_mint_account_ix, mint_args, sbf_program_test};
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use wen_new_standard::AddMetadataArgs;
+
+/// Compute units used by `create_mint_account` with metadata, royalties and a content hash,
+/// plus a 10% margin so that any growth of the handler fails the test. The token program runs
+/// natively so only WNS, the associated token program and the CPIs are metered. Update it to
+/// the units reported by `cargo test-sbf` when the handler changes.
+const CREATE_MINT_ACCOUNT_COMPUTE_UNITS: u64 = 90_000;
+
+#[tokio::test]
+async fn create_mint_account_stays_under_its_compute_budget() {
+    let mut program_test = sbf_program_test();
+    add_manager(&mut program_test);
+    let mut context = program_test.start_with_context().await;
+    let payer = context.payer.pubkey();
+    let mint = Keypair::new();
+
+    let mut args = mint_args(Some(500));
+    args.additional_metadata = vec![AddMetadataArgs {
+        field: "trait".to_string(),
+        value: "value".to_string(),
+    }];
+    args.content_hash = Some([7; 32]);
+    let create = create_mint_account_ix(payer, payer, payer, mint.pubkey(), args);
+    let transaction = Transaction::new_signed_with_payer(
+        &[create],
+        Some(&payer),
+        &[&context.payer, &mint],
+        context.last_blockhash,
+    );
+    let result = context
+        .banks_client
+        .process_transaction_with_metadata(transaction)
+        .await
+        .unwrap();
+    result.result.unwrap();
+    let compute_units = result.metadata.unwrap().compute_units_consumed;
+    assert!(
+        compute_units <= CREATE_MINT_ACCOUNT_COMPUTE_UNITS,
+        "create_mint_account used {compute_units} compute units, over \
+         {CREATE_MINT_ACCOUNT_COMPUTE_UNITS}"
+    );
+}
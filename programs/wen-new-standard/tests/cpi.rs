@@ -0,0 +1,181 @@
+mod common;
+
+use anchor_lang::{
+    prelude::{AccountInfo, Pubkey},
+    solana_program::{
+        entrypoint::ProgramResult,
+        instruction::{AccountMeta, Instruction},
+        program::{get_return_data, invoke_signed, set_return_data},
+        program_error::ProgramError,
+        program_option::COption,
+    },
+    AnchorDeserialize,
+};
+use anchor_spl::token_interface::{
+    spl_token_2022::{
+        self,
+        extension::{BaseStateWithExtensions, StateWithExtensions},
+        state::Mint,
+    },
+    spl_token_metadata_interface::state::TokenMetadata,
+};
+use common::{
+    add_group, add_group_to_mint_ix, add_manager, create_mint_account_ix, extra_metas_pda,
+    get_account, mint_args, process, program_test,
+};
+use solana_program_test::{processor, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use wen_new_standard::CreatedMintAccount;
+
+/// Launchpad like program invoking WNS with its PDA as the authority
+const WRAPPER_ID: Pubkey = Pubkey::new_from_array([7; 32]);
+const WRAPPER_AUTHORITY_SEED: &[u8] = b"authority";
+
+fn wrapper_authority() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[WRAPPER_AUTHORITY_SEED], &WRAPPER_ID)
+}
+
+/// Forwards its accounts and data to WNS signed by its authority PDA, and returns the data
+/// returned by WNS
+fn process_wrapper(_program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let (authority, bump) = wrapper_authority();
+    let instruction = Instruction {
+        program_id: wen_new_standard::ID,
+        accounts: accounts
+            .iter()
+            .map(|account| AccountMeta {
+                pubkey: *account.key,
+                is_signer: account.is_signer || *account.key == authority,
+                is_writable: account.is_writable,
+            })
+            .collect(),
+        data: data.to_vec(),
+    };
+    invoke_signed(
+        &instruction,
+        accounts,
+        &[&[WRAPPER_AUTHORITY_SEED, &[bump]]],
+    )?;
+
+    match get_return_data() {
+        Some((program_id, return_data)) if program_id == wen_new_standard::ID => {
+            set_return_data(&return_data);
+            Ok(())
+        }
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
+
+fn program_test_with_wrapper() -> ProgramTest {
+    let mut program_test = program_test();
+    program_test.add_program("wrapper", WRAPPER_ID, processor!(process_wrapper));
+    program_test
+}
+
+/// Sends `instruction` to WNS through the wrapper and returns the data WNS returned, the
+/// wrapper signs for its PDA so the transaction doesn't
+async fn process_wrapped(
+    context: &mut ProgramTestContext,
+    mut instruction: Instruction,
+    signers: &[&Keypair],
+) -> Vec<u8> {
+    let (authority, _) = wrapper_authority();
+    instruction.program_id = WRAPPER_ID;
+    for account in instruction.accounts.iter_mut() {
+        if account.pubkey == authority {
+            account.is_signer = false;
+        }
+    }
+    let mut all_signers = vec![&context.payer];
+    all_signers.extend_from_slice(signers);
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &all_signers,
+        context.get_new_latest_blockhash().await.unwrap(),
+    );
+    let result = context
+        .banks_client
+        .process_transaction_with_metadata(transaction)
+        .await
+        .unwrap();
+    result.result.unwrap();
+
+    let return_data = result.metadata.unwrap().return_data.unwrap();
+    assert_eq!(return_data.program_id, WRAPPER_ID);
+    return_data.data
+}
+
+#[tokio::test]
+async fn program_creates_a_mint_with_its_pda_as_authority() {
+    let mut program_test = program_test_with_wrapper();
+    let manager = add_manager(&mut program_test);
+    let mut context = program_test.start_with_context().await;
+    let payer = context.payer.pubkey();
+    let (authority, _) = wrapper_authority();
+    let update_authority = Pubkey::new_unique();
+    let mint = Keypair::new();
+
+    let mut args = mint_args(Some(500));
+    args.update_authority = Some(update_authority);
+    let create = create_mint_account_ix(payer, authority, payer, mint.pubkey(), args);
+    let return_data = process_wrapped(&mut context, create, &[&mint]).await;
+    let created_mint = CreatedMintAccount::try_from_slice(&return_data).unwrap();
+    assert_eq!(created_mint.mint, mint.pubkey());
+    assert_eq!(
+        created_mint.extra_metas_account,
+        extra_metas_pda(mint.pubkey())
+    );
+    assert_eq!(created_mint.manager, manager);
+    assert_eq!(created_mint.metadata_address, mint.pubkey());
+
+    // the PDA handed the mint authority to the manager and the metadata to its update authority
+    let mint_account = get_account(&mut context, mint.pubkey()).await;
+    assert_eq!(mint_account.owner, spl_token_2022::id());
+    let mint_state = StateWithExtensions::<Mint>::unpack(&mint_account.data).unwrap();
+    assert_eq!(mint_state.base.mint_authority, COption::Some(manager));
+    let metadata = mint_state
+        .get_variable_len_extension::<TokenMetadata>()
+        .unwrap();
+    assert_eq!(
+        Option::<Pubkey>::from(metadata.update_authority),
+        Some(update_authority)
+    );
+}
+
+#[tokio::test]
+async fn program_reads_the_member_number_of_added_mints() {
+    let mut program_test = program_test_with_wrapper();
+    add_manager(&mut program_test);
+    let authority = Keypair::new();
+    let group = add_group(&mut program_test, authority.pubkey(), 0);
+    let mut context = program_test.start_with_context().await;
+    let payer = context.payer.pubkey();
+
+    for member_number in 1..=2 {
+        let mint = Keypair::new();
+        let create = create_mint_account_ix(
+            payer,
+            authority.pubkey(),
+            payer,
+            mint.pubkey(),
+            mint_args(None),
+        );
+        process(&mut context, &[create], &[&authority, &mint])
+            .await
+            .unwrap();
+
+        let add = add_group_to_mint_ix(
+            payer,
+            authority.pubkey(),
+            group,
+            mint.pubkey(),
+            member_number,
+        );
+        let return_data = process_wrapped(&mut context, add, &[&authority]).await;
+        assert_eq!(u32::try_from_slice(&return_data).unwrap(), member_number);
+    }
+}
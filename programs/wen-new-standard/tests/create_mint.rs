@@ -0,0 +1,200 @@
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_spl::{
+    associated_token::get_associated_token_address_with_program_id,
+    token_interface::spl_token_2022::{self, extension::StateWithExtensions, state::Mint},
+};
+use common::{
+    add_manager, assert_metadata_error, create_mint_account_ix, extra_metas_pda, get_account,
+    mint_args, process, program_test, token_balance,
+};
+use solana_program_test::{BanksClientError, ProgramTestContext};
+use solana_sdk::{
+    account::Account,
+    rent::Rent,
+    signature::{Keypair, Signer},
+};
+use wen_new_standard::{
+    get_meta_list_data, get_meta_list_size, AddMetadataArgs, CreateMintAccountArgs, MetadataErrors,
+};
+
+async fn setup() -> ProgramTestContext {
+    let mut program_test = program_test();
+    add_manager(&mut program_test);
+    program_test.start_with_context().await
+}
+
+/// Creates `mint` with `args`, the context payer being its authority and receiver
+async fn create_keypair_mint(
+    context: &mut ProgramTestContext,
+    mint: &Keypair,
+    args: CreateMintAccountArgs,
+) -> Result<(), BanksClientError> {
+    let payer = context.payer.pubkey();
+    let create = create_mint_account_ix(payer, payer, payer, mint.pubkey(), args);
+    process(context, &[create], &[mint]).await
+}
+
+/// Creates a new mint with `args`, the context payer being its authority and receiver
+async fn create_mint(
+    context: &mut ProgramTestContext,
+    args: CreateMintAccountArgs,
+) -> (Pubkey, Result<(), BanksClientError>) {
+    let mint = Keypair::new();
+    let result = create_keypair_mint(context, &mint, args).await;
+    (mint.pubkey(), result)
+}
+
+fn receiver_token_account(context: &ProgramTestContext, mint: Pubkey) -> Pubkey {
+    get_associated_token_address_with_program_id(
+        &context.payer.pubkey(),
+        &mint,
+        &spl_token_2022::id(),
+    )
+}
+
+#[tokio::test]
+async fn mint_defaults_to_a_single_token_without_decimals() {
+    let mut context = setup().await;
+    let (mint, result) = create_mint(&mut context, mint_args(None)).await;
+    result.unwrap();
+
+    let mint_account = get_account(&mut context, mint).await;
+    let mint_state = StateWithExtensions::<Mint>::unpack(&mint_account.data).unwrap();
+    assert_eq!(mint_state.base.decimals, 0);
+    assert_eq!(mint_state.base.supply, 1);
+    let token_account = receiver_token_account(&context, mint);
+    assert_eq!(token_balance(&mut context, token_account).await, 1);
+}
+
+#[tokio::test]
+async fn mint_has_the_requested_decimals_and_initial_supply() {
+    let mut context = setup().await;
+    let mut args = mint_args(None);
+    args.decimals = Some(6);
+    args.initial_supply = Some(1_000_000_000);
+    let (mint, result) = create_mint(&mut context, args).await;
+    result.unwrap();
+
+    let mint_account = get_account(&mut context, mint).await;
+    let mint_state = StateWithExtensions::<Mint>::unpack(&mint_account.data).unwrap();
+    assert_eq!(mint_state.base.decimals, 6);
+    assert_eq!(mint_state.base.supply, 1_000_000_000);
+    let token_account = receiver_token_account(&context, mint);
+    assert_eq!(
+        token_balance(&mut context, token_account).await,
+        1_000_000_000
+    );
+}
+
+#[tokio::test]
+async fn zero_initial_supply_is_rejected() {
+    let mut context = setup().await;
+    let mut args = mint_args(None);
+    args.initial_supply = Some(0);
+    let (_, result) = create_mint(&mut context, args).await;
+    assert_metadata_error(result, MetadataErrors::InvalidInitialSupply);
+}
+
+#[tokio::test]
+async fn prefunded_extra_metas_account_is_initialized() {
+    let mint = Keypair::new();
+    let extra_metas_account = extra_metas_pda(mint.pubkey());
+    let mut program_test = program_test();
+    add_manager(&mut program_test);
+    program_test.add_account(
+        extra_metas_account,
+        Account {
+            lamports: 1_000,
+            ..Account::default()
+        },
+    );
+    let mut context = program_test.start_with_context().await;
+
+    create_keypair_mint(&mut context, &mint, mint_args(Some(500)))
+        .await
+        .unwrap();
+    let extra_metas = get_account(&mut context, extra_metas_account).await;
+    assert_eq!(extra_metas.owner, wen_new_standard::ID);
+    assert_eq!(
+        extra_metas.data.len(),
+        get_meta_list_size(true, None).unwrap()
+    );
+}
+
+#[tokio::test]
+async fn initialized_extra_metas_account_is_rejected() {
+    let mint = Keypair::new();
+    let data = get_meta_list_data(true, None, vec![], false).unwrap();
+    let mut program_test = program_test();
+    add_manager(&mut program_test);
+    program_test.add_account(
+        extra_metas_pda(mint.pubkey()),
+        Account {
+            lamports: Rent::default().minimum_balance(data.len()),
+            data,
+            owner: wen_new_standard::ID,
+            ..Account::default()
+        },
+    );
+    let mut context = program_test.start_with_context().await;
+
+    assert_metadata_error(
+        create_keypair_mint(&mut context, &mint, mint_args(Some(500))).await,
+        MetadataErrors::ExtraMetasAlreadyInitialized,
+    );
+}
+
+#[tokio::test]
+async fn mint_is_funded_for_its_final_size() {
+    let mut context = setup().await;
+    let mut args = mint_args(Some(500));
+    args.additional_metadata = vec![AddMetadataArgs {
+        field: "trait".to_string(),
+        value: "value".to_string(),
+    }];
+    args.content_hash = Some([7; 32]);
+    let (mint, result) = create_mint(&mut context, args).await;
+    result.unwrap();
+
+    // the metadata appended by the token program is already paid for, without any top-up
+    let mint_account = get_account(&mut context, mint).await;
+    let rent = context.banks_client.get_rent().await.unwrap();
+    assert_eq!(
+        mint_account.lamports,
+        rent.minimum_balance(mint_account.data.len())
+    );
+}
+
+#[tokio::test]
+async fn receiver_that_is_not_a_wallet_is_rejected() {
+    let mut context = setup().await;
+    let payer = context.payer.pubkey();
+    let (receiver, _) = Pubkey::find_program_address(&[b"escrow"], &wen_new_standard::ID);
+    let mint = Keypair::new();
+    let create = create_mint_account_ix(payer, payer, receiver, mint.pubkey(), mint_args(None));
+    assert_metadata_error(
+        process(&mut context, &[create], &[&mint]).await,
+        MetadataErrors::ReceiverNotAllowed,
+    );
+}
+
+#[tokio::test]
+async fn receiver_that_is_not_a_wallet_is_allowed_on_request() {
+    let mut context = setup().await;
+    let payer = context.payer.pubkey();
+    let (receiver, _) = Pubkey::find_program_address(&[b"escrow"], &wen_new_standard::ID);
+    let mint = Keypair::new();
+    let mut args = mint_args(None);
+    args.allow_pda_receiver = true;
+    let create = create_mint_account_ix(payer, payer, receiver, mint.pubkey(), args);
+    process(&mut context, &[create], &[&mint]).await.unwrap();
+
+    let token_account = get_associated_token_address_with_program_id(
+        &receiver,
+        &mint.pubkey(),
+        &spl_token_2022::id(),
+    );
+    assert_eq!(token_balance(&mut context, token_account).await, 1);
+}
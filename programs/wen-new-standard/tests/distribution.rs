@@ -0,0 +1,437 @@
+mod common;
+
+use anchor_lang::{prelude::Pubkey, InstructionData, ToAccountMetas};
+use anchor_spl::{
+    associated_token::{
+        self, get_associated_token_address_with_program_id,
+        spl_associated_token_account::instruction::create_associated_token_account,
+    },
+    token_interface::spl_token_2022::{
+        self,
+        instruction::{initialize_mint2, mint_to},
+        state::Mint,
+    },
+};
+use common::{
+    add_manager, approve_ix, assert_distribution_error, create_mint_account_ix,
+    distribution_event_authority, distribution_pda, get_account, get_claims,
+    initialize_distribution_ix, mint_args, modify_royalties_ix, process, program_test,
+    token_balance,
+};
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    native_token::LAMPORTS_PER_SOL,
+    program_pack::Pack,
+    signature::{Keypair, Signer},
+    system_instruction::{create_account, transfer},
+};
+use wen_new_standard::{AddMetadataArgs, AddRoyaltiesArgs, CreatorWithShare, MAX_CREATORS};
+use wen_royalty_distribution::{DistributionErrors, PaymentDistribution, UpdateDistributionArgs};
+
+/// Creates a royalty mint owned by the context payer and its SOL distribution
+async fn setup() -> (ProgramTestContext, Pubkey) {
+    let mut program_test = program_test();
+    add_manager(&mut program_test);
+    let mut context = program_test.start_with_context().await;
+    let owner = context.payer.pubkey();
+    let mint = Keypair::new();
+
+    let create = create_mint_account_ix(owner, owner, owner, mint.pubkey(), mint_args(Some(500)));
+    let initialize = initialize_distribution_ix(owner, mint.pubkey(), Pubkey::default());
+    process(&mut context, &[create, initialize], &[&mint])
+        .await
+        .unwrap();
+    (context, mint.pubkey())
+}
+
+/// Pays the royalties of a 100 SOL sale of the mint
+async fn pay_royalties(context: &mut ProgramTestContext, mint: Pubkey) {
+    let owner = context.payer.pubkey();
+    let approve = approve_ix(
+        owner,
+        mint,
+        Pubkey::default(),
+        100 * LAMPORTS_PER_SOL,
+        1,
+        Pubkey::new_unique(),
+        None,
+    );
+    process(context, &[approve], &[]).await.unwrap();
+}
+
+async fn set_creators(context: &mut ProgramTestContext, mint: Pubkey, creators: &[Pubkey]) {
+    let share = 100 / creators.len() as u8;
+    let creators: Vec<(Pubkey, u8)> = creators.iter().map(|creator| (*creator, share)).collect();
+    let modify = modify_royalties_ix(context.payer.pubkey(), mint, &creators);
+    process(context, &[modify], &[]).await.unwrap();
+}
+
+/// Creates a payment mint of `decimals` and its distribution for `mint`, 1000 tokens are minted
+/// to the context payer
+async fn create_payment_mint(
+    context: &mut ProgramTestContext,
+    mint: Pubkey,
+    decimals: u8,
+) -> Pubkey {
+    let owner = context.payer.pubkey();
+    let payment_mint = Keypair::new();
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let payer_token_account = get_associated_token_address_with_program_id(
+        &owner,
+        &payment_mint.pubkey(),
+        &spl_token_2022::id(),
+    );
+    let instructions = [
+        create_account(
+            &owner,
+            &payment_mint.pubkey(),
+            rent.minimum_balance(Mint::LEN),
+            Mint::LEN as u64,
+            &spl_token_2022::id(),
+        ),
+        initialize_mint2(
+            &spl_token_2022::id(),
+            &payment_mint.pubkey(),
+            &owner,
+            None,
+            decimals,
+        )
+        .unwrap(),
+        create_associated_token_account(
+            &owner,
+            &owner,
+            &payment_mint.pubkey(),
+            &spl_token_2022::id(),
+        ),
+        mint_to(
+            &spl_token_2022::id(),
+            &payment_mint.pubkey(),
+            &payer_token_account,
+            &owner,
+            &[],
+            1000 * 10u64.pow(u32::from(decimals)),
+        )
+        .unwrap(),
+        initialize_distribution_ix(owner, mint, payment_mint.pubkey()),
+        create_associated_token_account(
+            &owner,
+            &distribution_pda(mint, payment_mint.pubkey()),
+            &payment_mint.pubkey(),
+            &spl_token_2022::id(),
+        ),
+    ];
+    process(context, &instructions, &[&payment_mint])
+        .await
+        .unwrap();
+    payment_mint.pubkey()
+}
+
+fn claim_ix(payer: Pubkey, creator: Pubkey, distribution: Pubkey) -> Instruction {
+    Instruction {
+        program_id: wen_royalty_distribution::ID,
+        accounts: wen_royalty_distribution::accounts::ClaimDistribution {
+            payer,
+            creator,
+            distribution,
+            claim_destination: None,
+            destination: None,
+            payment_mint_account: None,
+            distribution_token_account: None,
+            creator_token_account: None,
+            token_program: spl_token_2022::id(),
+        }
+        .to_account_metas(None),
+        data: wen_royalty_distribution::instruction::ClaimDistribution {
+            payment_mint: Pubkey::default(),
+            amount: None,
+        }
+        .data(),
+    }
+}
+
+/// Asserts the distribution is sized for `claims` and holds their SOL on top of its rent
+async fn assert_distribution_size(
+    context: &mut ProgramTestContext,
+    distribution: Pubkey,
+    claims: usize,
+) {
+    let claimed: u64 = get_claims(context, distribution)
+        .await
+        .iter()
+        .map(|(_, amount)| amount)
+        .sum();
+    let account = get_account(context, distribution).await;
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let size = PaymentDistribution::size_of(claims).max(PaymentDistribution::LEN);
+    assert_eq!(account.data.len(), size);
+    assert_eq!(account.lamports, rent.minimum_balance(size) + claimed);
+}
+
+#[tokio::test]
+async fn distribution_grows_with_creators_and_shrinks_back_once_claimed() {
+    let (mut context, mint) = setup().await;
+    let distribution = distribution_pda(mint, Pubkey::default());
+
+    let first_creators = [Pubkey::new_unique(), Pubkey::new_unique()];
+    set_creators(&mut context, mint, &first_creators).await;
+    pay_royalties(&mut context, mint).await;
+    assert_eq!(get_claims(&mut context, distribution).await.len(), 2);
+    assert_distribution_size(&mut context, distribution, 2).await;
+
+    // the claims of the previous creators are kept along the new ones, past the initial size
+    let creators: Vec<Keypair> = (0..MAX_CREATORS).map(|_| Keypair::new()).collect();
+    let creator_keys: Vec<Pubkey> = creators.iter().map(Keypair::pubkey).collect();
+    set_creators(&mut context, mint, &creator_keys).await;
+    pay_royalties(&mut context, mint).await;
+    assert_eq!(
+        get_claims(&mut context, distribution).await.len(),
+        MAX_CREATORS + 2
+    );
+    assert_distribution_size(&mut context, distribution, MAX_CREATORS + 2).await;
+
+    let payer = context.payer.pubkey();
+    for creator in creators.iter() {
+        let claim = claim_ix(payer, creator.pubkey(), distribution);
+        process(&mut context, &[claim], &[creator]).await.unwrap();
+    }
+
+    // emptied claims are dropped by the next payment and their rent refunded to the payer
+    set_creators(&mut context, mint, &first_creators).await;
+    pay_royalties(&mut context, mint).await;
+    let claims = get_claims(&mut context, distribution).await;
+    assert_eq!(
+        claims
+            .iter()
+            .map(|(address, _)| *address)
+            .collect::<Vec<_>>(),
+        first_creators
+    );
+    assert_distribution_size(&mut context, distribution, 2).await;
+}
+
+#[tokio::test]
+async fn royalties_are_paid_in_payment_mints_of_any_decimals() {
+    let (mut context, mint) = setup().await;
+    let owner = context.payer.pubkey();
+
+    for decimals in [6, 9] {
+        let payment_mint = create_payment_mint(&mut context, mint, decimals).await;
+        let unit = 10u64.pow(u32::from(decimals));
+        let approve = approve_ix(
+            owner,
+            mint,
+            payment_mint,
+            100 * unit,
+            1,
+            Pubkey::new_unique(),
+            None,
+        );
+        process(&mut context, &[approve], &[]).await.unwrap();
+
+        let distribution = distribution_pda(mint, payment_mint);
+        let distribution_token_account = get_associated_token_address_with_program_id(
+            &distribution,
+            &payment_mint,
+            &spl_token_2022::id(),
+        );
+        assert_eq!(
+            token_balance(&mut context, distribution_token_account).await,
+            5 * unit
+        );
+        let claims = get_claims(&mut context, distribution).await;
+        assert_eq!(claims.len(), 1);
+        assert_eq!(claims[0].1, 5 * unit);
+    }
+}
+
+#[tokio::test]
+async fn token_royalties_must_go_to_the_distribution_token_account() {
+    let (mut context, mint) = setup().await;
+    let owner = context.payer.pubkey();
+    let payment_mint = create_payment_mint(&mut context, mint, 6).await;
+
+    // the payer token account is a valid token account of the payment mint, but not the
+    // associated token account of the distribution
+    let mut approve = approve_ix(
+        owner,
+        mint,
+        payment_mint,
+        100_000_000,
+        1,
+        Pubkey::new_unique(),
+        None,
+    );
+    let payer_token_account =
+        get_associated_token_address_with_program_id(&owner, &payment_mint, &spl_token_2022::id());
+    approve.accounts[5] = AccountMeta::new(payer_token_account, false);
+    assert_distribution_error(
+        process(&mut context, &[approve], &[]).await,
+        DistributionErrors::InvalidDistributionTokenAccount,
+    );
+}
+
+#[tokio::test]
+async fn token_royalties_need_the_payment_mint_account() {
+    let (mut context, mint) = setup().await;
+    let owner = context.payer.pubkey();
+    let payment_mint = create_payment_mint(&mut context, mint, 9).await;
+
+    let distribution = distribution_pda(mint, payment_mint);
+    let update = Instruction {
+        program_id: wen_royalty_distribution::ID,
+        accounts: wen_royalty_distribution::accounts::UpdateDistribution {
+            payer: owner,
+            authority: owner,
+            mint,
+            distribution,
+            distribution_address: get_associated_token_address_with_program_id(
+                &distribution,
+                &payment_mint,
+                &spl_token_2022::id(),
+            ),
+            payer_address: get_associated_token_address_with_program_id(
+                &owner,
+                &payment_mint,
+                &spl_token_2022::id(),
+            ),
+            system_program: anchor_lang::system_program::ID,
+            associated_token_program: associated_token::ID,
+            token_program: spl_token_2022::id(),
+            metadata_account: None,
+            payment_mint: None,
+            member: None,
+            event_authority: distribution_event_authority(),
+            program: wen_royalty_distribution::ID,
+        }
+        .to_account_metas(None),
+        data: wen_royalty_distribution::instruction::UpdateDistribution {
+            args: UpdateDistributionArgs {
+                amount: 1_000_000,
+                payment_mint,
+            },
+        }
+        .data(),
+    };
+    assert_distribution_error(
+        process(&mut context, &[update], &[]).await,
+        DistributionErrors::InvalidPaymentMint,
+    );
+}
+
+#[tokio::test]
+async fn royalties_skip_the_other_metadata_of_the_mint() {
+    let mut program_test = program_test();
+    add_manager(&mut program_test);
+    let mut context = program_test.start_with_context().await;
+    let owner = context.payer.pubkey();
+    let mint = Keypair::new();
+    let creator = Pubkey::new_unique();
+
+    let mut args = mint_args(None);
+    args.royalties = Some(AddRoyaltiesArgs {
+        royalty_basis_points: 500,
+        creators: vec![CreatorWithShare {
+            address: creator.to_string(),
+            share: 100,
+        }],
+    });
+    args.additional_metadata = vec![AddMetadataArgs {
+        field: "background".to_string(),
+        value: "blue".to_string(),
+    }];
+    args.content_hash = Some([7; 32]);
+    let create = create_mint_account_ix(owner, owner, owner, mint.pubkey(), args);
+    let initialize = initialize_distribution_ix(owner, mint.pubkey(), Pubkey::default());
+    process(&mut context, &[create, initialize], &[&mint])
+        .await
+        .unwrap();
+
+    // 5% of the sale goes to the only creator, the other metadata keys aren't creators
+    pay_royalties(&mut context, mint.pubkey()).await;
+    let distribution = distribution_pda(mint.pubkey(), Pubkey::default());
+    assert_eq!(
+        get_claims(&mut context, distribution).await,
+        vec![(creator, 5 * LAMPORTS_PER_SOL)]
+    );
+}
+
+#[tokio::test]
+async fn closing_a_claimed_distribution_sweeps_donated_lamports() {
+    let (mut context, mint) = setup().await;
+    let owner = context.payer.pubkey();
+    let distribution = distribution_pda(mint, Pubkey::default());
+    let rent_destination = Pubkey::new_unique();
+
+    // a lamport sent to the distribution can't keep it open
+    let donate = transfer(&owner, &distribution, 1);
+    process(&mut context, &[donate], &[]).await.unwrap();
+    let lamports = get_account(&mut context, distribution).await.lamports;
+
+    let close = Instruction {
+        program_id: wen_royalty_distribution::ID,
+        accounts: wen_royalty_distribution::accounts::CloseDistribution {
+            authority: owner,
+            distribution,
+            rent_destination,
+        }
+        .to_account_metas(None),
+        data: wen_royalty_distribution::instruction::CloseDistribution {}.data(),
+    };
+    process(&mut context, &[close], &[]).await.unwrap();
+    assert!(context
+        .banks_client
+        .get_account(distribution)
+        .await
+        .unwrap()
+        .is_none());
+    assert_eq!(
+        get_account(&mut context, rent_destination).await.lamports,
+        lamports
+    );
+}
+
+#[tokio::test]
+async fn distribution_only_shrinks_when_its_payer_pays() {
+    let (mut context, mint) = setup().await;
+    let owner = context.payer.pubkey();
+    let distribution = distribution_pda(mint, Pubkey::default());
+
+    let first_creators = [Pubkey::new_unique(), Pubkey::new_unique()];
+    set_creators(&mut context, mint, &first_creators).await;
+    pay_royalties(&mut context, mint).await;
+    let creators: Vec<Keypair> = (0..MAX_CREATORS).map(|_| Keypair::new()).collect();
+    let creator_keys: Vec<Pubkey> = creators.iter().map(Keypair::pubkey).collect();
+    set_creators(&mut context, mint, &creator_keys).await;
+    pay_royalties(&mut context, mint).await;
+    for creator in creators.iter() {
+        let claim = claim_ix(owner, creator.pubkey(), distribution);
+        process(&mut context, &[claim], &[creator]).await.unwrap();
+    }
+    set_creators(&mut context, mint, &first_creators).await;
+
+    // the freed rent belongs to the payer recorded at initialization, not to another payer
+    let other_payer = Keypair::new();
+    let fund = transfer(&owner, &other_payer.pubkey(), 200 * LAMPORTS_PER_SOL);
+    let approve = approve_ix(
+        other_payer.pubkey(),
+        mint,
+        Pubkey::default(),
+        100 * LAMPORTS_PER_SOL,
+        1,
+        Pubkey::new_unique(),
+        None,
+    );
+    process(&mut context, &[fund], &[]).await.unwrap();
+    process(&mut context, &[approve], &[&other_payer])
+        .await
+        .unwrap();
+    assert_eq!(get_claims(&mut context, distribution).await.len(), 2);
+    assert_eq!(
+        get_account(&mut context, distribution).await.data.len(),
+        PaymentDistribution::size_of(MAX_CREATORS + 2)
+    );
+
+    pay_royalties(&mut context, mint).await;
+    assert_distribution_size(&mut context, distribution, 2).await;
+}
@@ -0,0 +1,136 @@
+mod common;
+
+use anchor_lang::{prelude::Pubkey, solana_program::system_program};
+use anchor_spl::{
+    associated_token::{
+        self, get_associated_token_address_with_program_id,
+        spl_associated_token_account::instruction::create_associated_token_account,
+    },
+    token_interface::spl_token_2022,
+};
+use common::{
+    add_group, add_group_to_mint_ix, add_manager, approve_member_ix, create_mint_account_ix,
+    distribution_pda, event_authority, extra_metas_pda, get_claims, initialize_distribution_ix,
+    instruction, member_pda, mint_args, process, program_test, resolved_transfer_ix, token_balance,
+};
+use solana_sdk::signature::{Keypair, Signer};
+use wen_new_standard::{
+    accounts, get_member_index_pda, instruction as ix, EnableEditionsArgs, GROUP_FINALIZATION_SEED,
+    GROUP_MEMBER_COUNTER_SEED, MANAGER_SEED, MASTER_EDITION_SEED, POLICY_SEED,
+};
+
+#[tokio::test]
+async fn transfer_of_a_printed_edition_pays_the_master_royalties() {
+    let group_authority = Keypair::new();
+    let mut program_test = program_test();
+    add_manager(&mut program_test);
+    let group = add_group(&mut program_test, group_authority.pubkey(), 0);
+    let mut context = program_test.start_with_context().await;
+    let owner = context.payer.pubkey();
+    let master = Keypair::new();
+    let edition = Keypair::new();
+
+    let pda = |seeds: &[&[u8]]| Pubkey::find_program_address(seeds, &wen_new_standard::ID).0;
+    let master_edition = pda(&[MASTER_EDITION_SEED, master.pubkey().as_ref()]);
+    let create = create_mint_account_ix(owner, owner, owner, master.pubkey(), mint_args(Some(500)));
+    let add = add_group_to_mint_ix(owner, group_authority.pubkey(), group, master.pubkey(), 1);
+    let enable = instruction(
+        accounts::EnableEditions {
+            payer: owner,
+            authority: owner,
+            mint: master.pubkey(),
+            metadata_account: None,
+            master_edition,
+            system_program: system_program::ID,
+            token_program: spl_token_2022::id(),
+        },
+        ix::EnableEditions {
+            args: EnableEditionsArgs { max_supply: 10 },
+        },
+    );
+    process(&mut context, &[create], &[&master]).await.unwrap();
+    process(&mut context, &[add, enable], &[&group_authority])
+        .await
+        .unwrap();
+
+    // the edition is the second member of the group, and carries its edition number
+    let print = instruction(
+        accounts::PrintEdition {
+            payer: owner,
+            authority: owner,
+            receiver: owner,
+            master_mint: master.pubkey(),
+            master_metadata_account: None,
+            master_edition,
+            group,
+            master_member: member_pda(master.pubkey()),
+            member: member_pda(edition.pubkey()),
+            member_counter: pda(&[GROUP_MEMBER_COUNTER_SEED, group.as_ref()]),
+            member_index: get_member_index_pda(group, 0).0,
+            finalization: pda(&[GROUP_FINALIZATION_SEED, group.as_ref()]),
+            policy: pda(&[POLICY_SEED, group.as_ref()]),
+            mint: edition.pubkey(),
+            mint_token_account: get_associated_token_address_with_program_id(
+                &owner,
+                &edition.pubkey(),
+                &spl_token_2022::id(),
+            ),
+            extra_metas_account: extra_metas_pda(edition.pubkey()),
+            manager_authority: None,
+            manager: pda(&[MANAGER_SEED]),
+            system_program: system_program::ID,
+            associated_token_program: associated_token::ID,
+            token_program: spl_token_2022::id(),
+            event_authority: event_authority(),
+            program: wen_new_standard::ID,
+        },
+        ix::PrintEdition {},
+    );
+    let initialize = initialize_distribution_ix(owner, edition.pubkey(), Pubkey::default());
+    let receiver = Pubkey::new_unique();
+    let create_destination = create_associated_token_account(
+        &owner,
+        &receiver,
+        &edition.pubkey(),
+        &spl_token_2022::id(),
+    );
+    process(
+        &mut context,
+        &[print, initialize, create_destination],
+        &[&edition],
+    )
+    .await
+    .unwrap();
+
+    // the edition number isn't taken for a creator of the copied royalties
+    let source = get_associated_token_address_with_program_id(
+        &owner,
+        &edition.pubkey(),
+        &spl_token_2022::id(),
+    );
+    let destination = get_associated_token_address_with_program_id(
+        &receiver,
+        &edition.pubkey(),
+        &spl_token_2022::id(),
+    );
+    let approve = approve_member_ix(owner, edition.pubkey(), group, 10_000, receiver);
+    let transfer = resolved_transfer_ix(
+        &mut context,
+        source,
+        edition.pubkey(),
+        destination,
+        owner,
+        1,
+        0,
+    )
+    .await;
+    process(&mut context, &[approve, transfer], &[])
+        .await
+        .unwrap();
+    assert_eq!(token_balance(&mut context, destination).await, 1);
+
+    let distribution = distribution_pda(edition.pubkey(), Pubkey::default());
+    let claims = get_claims(&mut context, distribution).await;
+    assert_eq!(claims.len(), 1);
+    assert_eq!(claims[0].1, 500);
+}
@@ -0,0 +1,158 @@
+mod common;
+
+use anchor_lang::{prelude::Pubkey, solana_program::system_program};
+use anchor_spl::{
+    associated_token::{
+        get_associated_token_address_with_program_id,
+        spl_associated_token_account::instruction::create_associated_token_account,
+    },
+    token_interface::spl_token_2022,
+};
+use common::{
+    add_group, add_group_to_mint_ix, add_manager, add_marketplace, assert_metadata_error,
+    create_mint_account_ix, event_authority, instruction, marketplace_ix, mint_args, process,
+    program_test, resolved_transfer_ix, token_balance,
+};
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    instruction::Instruction,
+    signature::{Keypair, Signer},
+};
+use wen_new_standard::{
+    accounts, get_royalty_enforcement_pda, instruction as ix, EnforcementMode, MetadataErrors,
+    GROUP_SOULBOUND_SEED,
+};
+
+struct Setup {
+    context: ProgramTestContext,
+    destination: Pubkey,
+    transfer: Instruction,
+}
+
+fn set_enforcement_mode_ix(
+    payer: Pubkey,
+    authority: Pubkey,
+    group: Pubkey,
+    mode: EnforcementMode,
+) -> Instruction {
+    instruction(
+        accounts::SetEnforcementMode {
+            payer,
+            authority,
+            group,
+            soulbound: Pubkey::find_program_address(
+                &[GROUP_SOULBOUND_SEED, group.as_ref()],
+                &wen_new_standard::ID,
+            )
+            .0,
+            royalty_enforcement: get_royalty_enforcement_pda(group),
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: wen_new_standard::ID,
+        },
+        ix::SetEnforcementMode { mode },
+    )
+}
+
+/// Adds a royalty mint owned by the context payer to a group enforced with `mode`, and
+/// resolves the transfer of its token to another owner
+async fn setup(mode: EnforcementMode) -> Setup {
+    let authority = Keypair::new();
+    let mut program_test = program_test();
+    add_manager(&mut program_test);
+    add_marketplace(&mut program_test);
+    let group = add_group(&mut program_test, authority.pubkey(), 0);
+    let mut context = program_test.start_with_context().await;
+    let owner = context.payer.pubkey();
+    let mint = Keypair::new();
+
+    let create = create_mint_account_ix(owner, owner, owner, mint.pubkey(), mint_args(Some(500)));
+    let add = add_group_to_mint_ix(owner, authority.pubkey(), group, mint.pubkey(), 1);
+    let set_mode = set_enforcement_mode_ix(owner, authority.pubkey(), group, mode);
+    let receiver = Pubkey::new_unique();
+    let create_destination =
+        create_associated_token_account(&owner, &receiver, &mint.pubkey(), &spl_token_2022::id());
+    process(&mut context, &[create], &[&mint]).await.unwrap();
+    process(&mut context, &[add, set_mode], &[&authority])
+        .await
+        .unwrap();
+    process(&mut context, &[create_destination], &[])
+        .await
+        .unwrap();
+
+    let source =
+        get_associated_token_address_with_program_id(&owner, &mint.pubkey(), &spl_token_2022::id());
+    let destination = get_associated_token_address_with_program_id(
+        &receiver,
+        &mint.pubkey(),
+        &spl_token_2022::id(),
+    );
+    let transfer = resolved_transfer_ix(
+        &mut context,
+        source,
+        mint.pubkey(),
+        destination,
+        owner,
+        1,
+        0,
+    )
+    .await;
+    Setup {
+        context,
+        destination,
+        transfer,
+    }
+}
+
+#[tokio::test]
+async fn strict_mode_requires_an_approval_for_wallet_transfers() {
+    let mut setup = setup(EnforcementMode::Strict).await;
+    let transfer = setup.transfer.clone();
+    assert_metadata_error(
+        process(&mut setup.context, &[transfer], &[]).await,
+        MetadataErrors::ExpiredApproveAccount,
+    );
+}
+
+#[tokio::test]
+async fn standard_mode_requires_an_approval_for_program_transfers_only() {
+    let mut setup = setup(EnforcementMode::Standard).await;
+    let transfer = marketplace_ix(setup.transfer.clone());
+    assert_metadata_error(
+        process(&mut setup.context, &[transfer], &[]).await,
+        MetadataErrors::ExpiredApproveAccount,
+    );
+
+    let transfer = setup.transfer.clone();
+    process(&mut setup.context, &[transfer], &[]).await.unwrap();
+    assert_eq!(
+        token_balance(&mut setup.context, setup.destination).await,
+        1
+    );
+}
+
+#[tokio::test]
+async fn permissive_mode_lets_programs_transfer_without_approval() {
+    let mut setup = setup(EnforcementMode::Permissive).await;
+    let transfer = marketplace_ix(setup.transfer.clone());
+    process(&mut setup.context, &[transfer], &[]).await.unwrap();
+    assert_eq!(
+        token_balance(&mut setup.context, setup.destination).await,
+        1
+    );
+}
+
+#[tokio::test]
+async fn frozen_mode_rejects_every_transfer() {
+    let mut setup = setup(EnforcementMode::Frozen).await;
+    let transfer = setup.transfer.clone();
+    assert_metadata_error(
+        process(&mut setup.context, &[transfer], &[]).await,
+        MetadataErrors::TransfersFrozen,
+    );
+    let transfer = marketplace_ix(setup.transfer.clone());
+    assert_metadata_error(
+        process(&mut setup.context, &[transfer], &[]).await,
+        MetadataErrors::TransfersFrozen,
+    );
+}
@@ -0,0 +1,205 @@
+mod common;
+
+use anchor_lang::{prelude::Pubkey, solana_program::system_program, AccountDeserialize};
+use common::{
+    add_group, add_group_to_mint_ix, add_manager, assert_metadata_error, create_mint_account_ix,
+    event_authority, get_account, instruction, member_pda, mint_args, process, program_test,
+};
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    instruction::Instruction,
+    signature::{Keypair, Signer},
+};
+use wen_new_standard::{
+    accounts, instruction as ix, GroupFinalization, MetadataErrors, TokenGroup,
+    GROUP_DELEGATES_SEED, GROUP_FINALIZATION_SEED,
+};
+
+struct Setup {
+    context: ProgramTestContext,
+    authority: Keypair,
+    group: Pubkey,
+}
+
+async fn setup(max_size: u32) -> Setup {
+    let authority = Keypair::new();
+    let mut program_test = program_test();
+    add_manager(&mut program_test);
+    let group = add_group(&mut program_test, authority.pubkey(), max_size);
+    Setup {
+        context: program_test.start_with_context().await,
+        authority,
+        group,
+    }
+}
+
+/// Creates a mint without royalties owned by the context payer
+async fn create_mint(setup: &mut Setup) -> Pubkey {
+    let payer = setup.context.payer.pubkey();
+    let mint = Keypair::new();
+    let create = create_mint_account_ix(payer, payer, payer, mint.pubkey(), mint_args(None));
+    process(&mut setup.context, &[create], &[&mint])
+        .await
+        .unwrap();
+    mint.pubkey()
+}
+
+fn group_delegates_pda(group: Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[GROUP_DELEGATES_SEED, group.as_ref()],
+        &wen_new_standard::ID,
+    )
+    .0
+}
+
+fn approve_group_delegate_ix(setup: &Setup, delegate: Pubkey) -> Instruction {
+    instruction(
+        accounts::ApproveGroupDelegate {
+            payer: setup.context.payer.pubkey(),
+            authority: setup.authority.pubkey(),
+            group: setup.group,
+            group_delegates: group_delegates_pda(setup.group),
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: wen_new_standard::ID,
+        },
+        ix::ApproveGroupDelegate { delegate },
+    )
+}
+
+fn revoke_group_delegate_ix(setup: &Setup, delegate: Pubkey) -> Instruction {
+    instruction(
+        accounts::RevokeGroupDelegate {
+            authority: setup.authority.pubkey(),
+            group: setup.group,
+            group_delegates: group_delegates_pda(setup.group),
+            event_authority: event_authority(),
+            program: wen_new_standard::ID,
+        },
+        ix::RevokeGroupDelegate { delegate },
+    )
+}
+
+fn finalization_pda(group: Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[GROUP_FINALIZATION_SEED, group.as_ref()],
+        &wen_new_standard::ID,
+    )
+    .0
+}
+
+fn finalize_group_ix(setup: &Setup) -> Instruction {
+    instruction(
+        accounts::FinalizeGroup {
+            payer: setup.context.payer.pubkey(),
+            authority: setup.authority.pubkey(),
+            group: setup.group,
+            finalization: finalization_pda(setup.group),
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: wen_new_standard::ID,
+        },
+        ix::FinalizeGroup {},
+    )
+}
+
+async fn get_group(setup: &mut Setup) -> TokenGroup {
+    let account = get_account(&mut setup.context, setup.group).await;
+    TokenGroup::try_deserialize(&mut &account.data[..]).unwrap()
+}
+
+#[tokio::test]
+async fn full_group_rejects_members_with_group_full() {
+    let mut setup = setup(2).await;
+    let payer = setup.context.payer.pubkey();
+    let authority = setup.authority.pubkey();
+
+    for member_number in 1..=2 {
+        let mint = create_mint(&mut setup).await;
+        let add = add_group_to_mint_ix(payer, authority, setup.group, mint, member_number);
+        process(&mut setup.context, &[add], &[&setup.authority])
+            .await
+            .unwrap();
+    }
+    assert!(get_group(&mut setup).await.is_full());
+
+    let mint = create_mint(&mut setup).await;
+    let add = add_group_to_mint_ix(payer, authority, setup.group, mint, 3);
+    assert_metadata_error(
+        process(&mut setup.context, &[add], &[&setup.authority]).await,
+        MetadataErrors::GroupFull,
+    );
+
+    // nothing of the rejected member was written
+    assert_eq!(get_group(&mut setup).await.size, 2);
+    assert!(setup
+        .context
+        .banks_client
+        .get_account(member_pda(mint))
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn revoked_group_delegate_cannot_add_members() {
+    let mut setup = setup(0).await;
+    let payer = setup.context.payer.pubkey();
+    let delegate = Keypair::new();
+    let approve = approve_group_delegate_ix(&setup, delegate.pubkey());
+    process(&mut setup.context, &[approve], &[&setup.authority])
+        .await
+        .unwrap();
+
+    // the delegate signs in place of the group authority
+    let mint = create_mint(&mut setup).await;
+    let add = add_group_to_mint_ix(payer, delegate.pubkey(), setup.group, mint, 1);
+    process(&mut setup.context, &[add], &[&delegate])
+        .await
+        .unwrap();
+    assert_eq!(get_group(&mut setup).await.size, 1);
+
+    let revoke = revoke_group_delegate_ix(&setup, delegate.pubkey());
+    process(&mut setup.context, &[revoke], &[&setup.authority])
+        .await
+        .unwrap();
+    let mint = create_mint(&mut setup).await;
+    let add = add_group_to_mint_ix(payer, delegate.pubkey(), setup.group, mint, 2);
+    assert_metadata_error(
+        process(&mut setup.context, &[add], &[&delegate]).await,
+        MetadataErrors::GroupAuthoritySignatureRequired,
+    );
+    assert_eq!(get_group(&mut setup).await.size, 1);
+}
+
+#[tokio::test]
+async fn finalized_group_rejects_members_and_a_second_finalization() {
+    let mut setup = setup(0).await;
+    let payer = setup.context.payer.pubkey();
+    let authority = setup.authority.pubkey();
+    let mint = create_mint(&mut setup).await;
+    let add = add_group_to_mint_ix(payer, authority, setup.group, mint, 1);
+    let finalize = finalize_group_ix(&setup);
+    process(&mut setup.context, &[add, finalize], &[&setup.authority])
+        .await
+        .unwrap();
+
+    let account = get_account(&mut setup.context, finalization_pda(setup.group)).await;
+    let finalization = GroupFinalization::try_deserialize(&mut &account.data[..]).unwrap();
+    assert_eq!(finalization.group, setup.group);
+    assert_eq!(finalization.size, 1);
+
+    let finalize = finalize_group_ix(&setup);
+    assert_metadata_error(
+        process(&mut setup.context, &[finalize], &[&setup.authority]).await,
+        MetadataErrors::GroupFinalized,
+    );
+
+    let mint = create_mint(&mut setup).await;
+    let add = add_group_to_mint_ix(payer, authority, setup.group, mint, 2);
+    assert_metadata_error(
+        process(&mut setup.context, &[add], &[&setup.authority]).await,
+        MetadataErrors::GroupFinalized,
+    );
+    assert_eq!(get_group(&mut setup).await.size, 1);
+}
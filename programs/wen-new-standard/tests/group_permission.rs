@@ -0,0 +1,226 @@
+mod common;
+
+use anchor_lang::{prelude::Pubkey, solana_program::system_program, AccountDeserialize};
+use common::{
+    add_group, assert_metadata_error, event_authority, group_permission_pda, instruction, process,
+    program_test,
+};
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    clock::Clock,
+    instruction::Instruction,
+    signature::{Keypair, Signer},
+};
+use wen_new_standard::{
+    accounts, instruction as ix, GroupExtensionPolicyArgs, GroupPermission, GroupPermissionArgs,
+    MetadataErrors, POLICY_SEED,
+};
+
+struct Setup {
+    context: ProgramTestContext,
+    authority: Keypair,
+    delegate: Keypair,
+    group: Pubkey,
+}
+
+async fn setup() -> Setup {
+    let authority = Keypair::new();
+    let delegate = Keypair::new();
+    let mut program_test = program_test();
+    let group = add_group(&mut program_test, authority.pubkey(), 0);
+    Setup {
+        context: program_test.start_with_context().await,
+        authority,
+        delegate,
+        group,
+    }
+}
+
+fn grant_ix(setup: &Setup, permissions: u8, expires_at: Option<i64>) -> Instruction {
+    instruction(
+        accounts::GrantGroupPermission {
+            payer: setup.context.payer.pubkey(),
+            authority: setup.authority.pubkey(),
+            group: setup.group,
+            group_permission: group_permission_pda(setup.group, setup.delegate.pubkey()),
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: wen_new_standard::ID,
+        },
+        ix::GrantGroupPermission {
+            delegate: setup.delegate.pubkey(),
+            args: GroupPermissionArgs {
+                permissions,
+                expires_at,
+            },
+        },
+    )
+}
+
+fn revoke_ix(setup: &Setup) -> Instruction {
+    instruction(
+        accounts::RevokeGroupPermission {
+            authority: setup.authority.pubkey(),
+            group: setup.group,
+            group_permission: group_permission_pda(setup.group, setup.delegate.pubkey()),
+            event_authority: event_authority(),
+            program: wen_new_standard::ID,
+        },
+        ix::RevokeGroupPermission {
+            delegate: setup.delegate.pubkey(),
+        },
+    )
+}
+
+/// Sets the group extension policy signed by the delegate, `max_royalty_basis_points` keeps
+/// successive transactions distinct
+fn set_policy_ix(setup: &Setup, max_royalty_basis_points: u16) -> Instruction {
+    instruction(
+        accounts::SetGroupExtensionPolicy {
+            payer: setup.context.payer.pubkey(),
+            authority: setup.delegate.pubkey(),
+            group: setup.group,
+            group_permission: group_permission_pda(setup.group, setup.delegate.pubkey()),
+            policy: Pubkey::find_program_address(
+                &[POLICY_SEED, setup.group.as_ref()],
+                &wen_new_standard::ID,
+            )
+            .0,
+            system_program: system_program::ID,
+        },
+        ix::SetGroupExtensionPolicy {
+            args: GroupExtensionPolicyArgs {
+                allowed_extensions: vec![],
+                required_extensions: vec![],
+                min_royalty_basis_points: 0,
+                max_royalty_basis_points,
+                max_additional_metadata_bytes: 0,
+            },
+        },
+    )
+}
+
+async fn get_grant(setup: &mut Setup) -> Option<GroupPermission> {
+    let address = group_permission_pda(setup.group, setup.delegate.pubkey());
+    let account = setup
+        .context
+        .banks_client
+        .get_account(address)
+        .await
+        .unwrap()?;
+    Some(GroupPermission::try_deserialize(&mut &account.data[..]).unwrap())
+}
+
+async fn now(context: &mut ProgramTestContext) -> i64 {
+    context
+        .banks_client
+        .get_sysvar::<Clock>()
+        .await
+        .unwrap()
+        .unix_timestamp
+}
+
+async fn warp_to(context: &mut ProgramTestContext, unix_timestamp: i64) {
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    context.set_sysvar(&Clock {
+        unix_timestamp,
+        ..clock
+    });
+}
+
+#[tokio::test]
+async fn granted_delegate_can_use_its_permission() {
+    let mut setup = setup().await;
+    let grant = grant_ix(&setup, GroupPermission::SET_POLICY, None);
+    let authority = setup.authority.insecure_clone();
+    process(&mut setup.context, &[grant], &[&authority])
+        .await
+        .unwrap();
+
+    let grant = get_grant(&mut setup).await.unwrap();
+    assert_eq!(grant.group, setup.group);
+    assert_eq!(grant.delegate, setup.delegate.pubkey());
+    assert_eq!(grant.permissions, GroupPermission::SET_POLICY);
+    assert_eq!(grant.expires_at, None);
+
+    let set_policy = set_policy_ix(&setup, 1_000);
+    let delegate = setup.delegate.insecure_clone();
+    process(&mut setup.context, &[set_policy], &[&delegate])
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn delegate_without_the_permission_is_denied() {
+    let mut setup = setup().await;
+    let grant = grant_ix(&setup, GroupPermission::ADD_MEMBER, None);
+    let authority = setup.authority.insecure_clone();
+    process(&mut setup.context, &[grant], &[&authority])
+        .await
+        .unwrap();
+
+    let set_policy = set_policy_ix(&setup, 1_000);
+    let delegate = setup.delegate.insecure_clone();
+    assert_metadata_error(
+        process(&mut setup.context, &[set_policy], &[&delegate]).await,
+        MetadataErrors::GroupPermissionDenied,
+    );
+}
+
+#[tokio::test]
+async fn revoked_delegate_is_denied() {
+    let mut setup = setup().await;
+    let grant = grant_ix(&setup, GroupPermission::SET_POLICY, None);
+    let revoke = revoke_ix(&setup);
+    let authority = setup.authority.insecure_clone();
+    process(&mut setup.context, &[grant], &[&authority])
+        .await
+        .unwrap();
+    process(&mut setup.context, &[revoke], &[&authority])
+        .await
+        .unwrap();
+    assert!(get_grant(&mut setup).await.is_none());
+
+    let set_policy = set_policy_ix(&setup, 1_000);
+    let delegate = setup.delegate.insecure_clone();
+    assert_metadata_error(
+        process(&mut setup.context, &[set_policy], &[&delegate]).await,
+        MetadataErrors::GroupAuthoritySignatureRequired,
+    );
+}
+
+#[tokio::test]
+async fn expired_grant_is_rejected() {
+    let mut setup = setup().await;
+    let expires_at = now(&mut setup.context).await + 100;
+    let grant = grant_ix(&setup, GroupPermission::SET_POLICY, Some(expires_at));
+    let authority = setup.authority.insecure_clone();
+    process(&mut setup.context, &[grant], &[&authority])
+        .await
+        .unwrap();
+
+    let delegate = setup.delegate.insecure_clone();
+    let set_policy = set_policy_ix(&setup, 1_000);
+    process(&mut setup.context, &[set_policy], &[&delegate])
+        .await
+        .unwrap();
+
+    warp_to(&mut setup.context, expires_at).await;
+    let set_policy = set_policy_ix(&setup, 2_000);
+    assert_metadata_error(
+        process(&mut setup.context, &[set_policy], &[&delegate]).await,
+        MetadataErrors::GroupPermissionExpired,
+    );
+}
+
+#[tokio::test]
+async fn grant_expiring_in_the_past_is_rejected() {
+    let mut setup = setup().await;
+    let expires_at = now(&mut setup.context).await;
+    let grant = grant_ix(&setup, GroupPermission::SET_POLICY, Some(expires_at));
+    let authority = setup.authority.insecure_clone();
+    assert_metadata_error(
+        process(&mut setup.context, &[grant], &[&authority]).await,
+        MetadataErrors::GroupPermissionExpired,
+    );
+}
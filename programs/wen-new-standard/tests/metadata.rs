@@ -0,0 +1,62 @@
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_spl::token_interface::spl_token_2022;
+use common::{
+    add_manager, create_mint_account_ix, event_authority, get_account, instruction, mint_args,
+    process, program_test,
+};
+use solana_sdk::signature::{Keypair, Signer};
+use wen_new_standard::{accounts, instruction as ix, AddMetadataArgs, RemoveMetadataArgs};
+
+#[tokio::test]
+async fn removing_a_large_field_refunds_its_rent() {
+    let mut program_test = program_test();
+    let manager = add_manager(&mut program_test);
+    let mut context = program_test.start_with_context().await;
+    let payer = context.payer.pubkey();
+    let mint = Keypair::new();
+
+    let mut args = mint_args(None);
+    args.additional_metadata = vec![AddMetadataArgs {
+        field: "description".to_string(),
+        value: "x".repeat(400),
+    }];
+    let create = create_mint_account_ix(payer, payer, payer, mint.pubkey(), args);
+    process(&mut context, &[create], &[&mint]).await.unwrap();
+    let created_mint = get_account(&mut context, mint.pubkey()).await;
+
+    let rent_destination = Pubkey::new_unique();
+    let remove = instruction(
+        accounts::RemoveMetadata {
+            authority: payer,
+            mint: mint.pubkey(),
+            metadata_account: None,
+            rent_destination: Some(rent_destination),
+            manager_authority: None,
+            manager,
+            token_program: spl_token_2022::id(),
+            event_authority: event_authority(),
+            program: wen_new_standard::ID,
+        },
+        ix::RemoveMetadata {
+            args: RemoveMetadataArgs {
+                field: "description".to_string(),
+            },
+        },
+    );
+    process(&mut context, &[remove], &[]).await.unwrap();
+
+    // the mint keeps the minimum balance of its shrunk size, the rest goes to the destination
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let updated_mint = get_account(&mut context, mint.pubkey()).await;
+    assert!(updated_mint.data.len() < created_mint.data.len() - 400);
+    assert_eq!(
+        updated_mint.lamports,
+        rent.minimum_balance(updated_mint.data.len())
+    );
+    assert_eq!(
+        get_account(&mut context, rent_destination).await.lamports,
+        created_mint.lamports - updated_mint.lamports
+    );
+}
@@ -0,0 +1,352 @@
+mod common;
+
+use anchor_lang::{prelude::Pubkey, AccountDeserialize};
+use anchor_spl::{
+    associated_token::{
+        get_associated_token_address_with_program_id,
+        spl_associated_token_account::instruction::create_associated_token_account,
+    },
+    token_interface::spl_token_2022::{
+        self,
+        instruction::{approve_checked, transfer_checked},
+    },
+};
+use common::{
+    add_manager, add_marketplace, approve_ix, approve_pda, assert_metadata_error,
+    create_mint_account_ix, distribution_pda, extra_metas_pda, get_account, get_claims,
+    initialize_distribution_ix, marketplace_ix, mint_args, modify_royalties_ix, process,
+    program_test, resolved_transfer_ix, token_balance,
+};
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    native_token::LAMPORTS_PER_SOL,
+    signature::{Keypair, Signer},
+    system_instruction,
+};
+use spl_transfer_hook_interface::instruction::execute;
+use wen_new_standard::{ApproveAccount, CreateMintAccountArgs, MetadataErrors};
+
+struct Setup {
+    context: ProgramTestContext,
+    owner: Pubkey,
+    mint: Pubkey,
+    source: Pubkey,
+    receiver: Pubkey,
+    destination: Pubkey,
+}
+
+/// Creates a mint of `args` owned by the context payer, and the token account of another owner
+async fn setup(args: CreateMintAccountArgs) -> Setup {
+    let mut program_test = program_test();
+    add_marketplace(&mut program_test);
+    add_manager(&mut program_test);
+    let mut context = program_test.start_with_context().await;
+    let owner = context.payer.pubkey();
+    let mint = Keypair::new();
+
+    let create = create_mint_account_ix(owner, owner, owner, mint.pubkey(), args);
+    process(&mut context, &[create], &[&mint]).await.unwrap();
+
+    let receiver = Pubkey::new_unique();
+    let create_destination =
+        create_associated_token_account(&owner, &receiver, &mint.pubkey(), &spl_token_2022::id());
+    process(&mut context, &[create_destination], &[])
+        .await
+        .unwrap();
+
+    Setup {
+        context,
+        owner,
+        mint: mint.pubkey(),
+        source: get_associated_token_address_with_program_id(
+            &owner,
+            &mint.pubkey(),
+            &spl_token_2022::id(),
+        ),
+        receiver,
+        destination: get_associated_token_address_with_program_id(
+            &receiver,
+            &mint.pubkey(),
+            &spl_token_2022::id(),
+        ),
+    }
+}
+
+/// Transfer of `amount` tokens of a royalty mint by `authority`, with the accounts the token
+/// program resolves the extra metas written at creation among
+fn transfer_ix(setup: &Setup, authority: Pubkey, amount: u64, decimals: u8) -> Instruction {
+    let mut transfer = transfer_checked(
+        &spl_token_2022::id(),
+        &setup.source,
+        &setup.mint,
+        &setup.destination,
+        &authority,
+        &[],
+        amount,
+        decimals,
+    )
+    .unwrap();
+    transfer.accounts.extend([
+        AccountMeta::new_readonly(extra_metas_pda(setup.mint), false),
+        AccountMeta::new(approve_pda(setup.mint), false),
+        AccountMeta::new_readonly(wen_new_standard::ID, false),
+    ]);
+    transfer
+}
+
+#[tokio::test]
+async fn transfer_of_royalty_mint_is_signed_by_the_owner_only() {
+    let mut setup = setup(mint_args(Some(500))).await;
+    let transfer = transfer_ix(&setup, setup.owner, 1, 0);
+    let signers: Vec<Pubkey> = transfer
+        .accounts
+        .iter()
+        .filter(|account| account.is_signer)
+        .map(|account| account.pubkey)
+        .collect();
+    assert_eq!(signers, vec![setup.owner]);
+
+    // the owner pays for the transaction, it's its only signature
+    process(&mut setup.context, &[transfer], &[]).await.unwrap();
+    assert_eq!(token_balance(&mut setup.context, setup.source).await, 0);
+    assert_eq!(
+        token_balance(&mut setup.context, setup.destination).await,
+        1
+    );
+}
+
+#[tokio::test]
+async fn partial_amount_of_semi_fungible_mint_is_transferred() {
+    let mut args = mint_args(Some(500));
+    args.decimals = Some(6);
+    args.initial_supply = Some(1_000_000);
+    let mut setup = setup(args).await;
+
+    let transfer = transfer_ix(&setup, setup.owner, 250_000, 6);
+    process(&mut setup.context, &[transfer], &[]).await.unwrap();
+    assert_eq!(
+        token_balance(&mut setup.context, setup.source).await,
+        750_000
+    );
+    assert_eq!(
+        token_balance(&mut setup.context, setup.destination).await,
+        250_000
+    );
+}
+
+#[tokio::test]
+async fn modified_creators_are_paid_the_royalties_of_a_transfer() {
+    let mut setup = setup(mint_args(Some(500))).await;
+    let (first_creator, second_creator) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let modify = modify_royalties_ix(
+        setup.owner,
+        setup.mint,
+        &[(first_creator, 60), (second_creator, 40)],
+    );
+    let initialize = initialize_distribution_ix(setup.owner, setup.mint, Pubkey::default());
+    process(&mut setup.context, &[modify, initialize], &[])
+        .await
+        .unwrap();
+
+    // the extra metas still resolve the approve account once the creators changed
+    let approve = approve_ix(
+        setup.owner,
+        setup.mint,
+        Pubkey::default(),
+        10_000,
+        1,
+        setup.receiver,
+        None,
+    );
+    let transfer = transfer_ix(&setup, setup.owner, 1, 0);
+    process(&mut setup.context, &[approve, transfer], &[])
+        .await
+        .unwrap();
+    assert_eq!(
+        token_balance(&mut setup.context, setup.destination).await,
+        1
+    );
+
+    // 5% of the sale is split between the new creators only
+    let distribution = distribution_pda(setup.mint, Pubkey::default());
+    assert_eq!(
+        get_claims(&mut setup.context, distribution).await,
+        vec![(first_creator, 300), (second_creator, 200)]
+    );
+}
+
+#[tokio::test]
+async fn hook_invoked_outside_of_a_transfer_is_rejected() {
+    let mut setup = setup(mint_args(Some(500))).await;
+    let initialize = initialize_distribution_ix(setup.owner, setup.mint, Pubkey::default());
+    let approve = approve_ix(
+        setup.owner,
+        setup.mint,
+        Pubkey::default(),
+        10_000,
+        1,
+        setup.receiver,
+        None,
+    );
+    process(&mut setup.context, &[initialize, approve], &[])
+        .await
+        .unwrap();
+
+    // the accounts of a transfer, without token-2022 flagging the source as transferring
+    let mut execute = execute(
+        &wen_new_standard::ID,
+        &setup.source,
+        &setup.mint,
+        &setup.destination,
+        &setup.owner,
+        &extra_metas_pda(setup.mint),
+        1,
+    );
+    execute
+        .accounts
+        .push(AccountMeta::new(approve_pda(setup.mint), false));
+    assert_metadata_error(
+        process(&mut setup.context, &[execute], &[]).await,
+        MetadataErrors::NotInTransferContext,
+    );
+
+    // the approval is left for the transfer it was paid for
+    let approve_account = get_account(&mut setup.context, approve_pda(setup.mint)).await;
+    let approve = ApproveAccount::try_deserialize(&mut &approve_account.data[..]).unwrap();
+    assert_ne!(approve.slot, 0);
+}
+
+#[tokio::test]
+async fn delegate_approves_and_transfers_on_behalf_of_the_owner() {
+    let mut setup = setup(mint_args(Some(500))).await;
+    let delegate = Keypair::new();
+    let delegate_approval = approve_checked(
+        &spl_token_2022::id(),
+        &setup.source,
+        &setup.mint,
+        &delegate.pubkey(),
+        &setup.owner,
+        &[],
+        1,
+        0,
+    )
+    .unwrap();
+    let fund_delegate =
+        system_instruction::transfer(&setup.owner, &delegate.pubkey(), LAMPORTS_PER_SOL);
+    let initialize = initialize_distribution_ix(setup.owner, setup.mint, Pubkey::default());
+    process(
+        &mut setup.context,
+        &[delegate_approval, fund_delegate, initialize],
+        &[],
+    )
+    .await
+    .unwrap();
+
+    // the delegate pays the royalties, the owner of the source is recorded as seller
+    let approve = approve_ix(
+        delegate.pubkey(),
+        setup.mint,
+        Pubkey::default(),
+        10_000,
+        1,
+        setup.receiver,
+        Some(setup.source),
+    );
+    let transfer = transfer_ix(&setup, delegate.pubkey(), 1, 0);
+    process(&mut setup.context, &[approve, transfer], &[&delegate])
+        .await
+        .unwrap();
+    assert_eq!(
+        token_balance(&mut setup.context, setup.destination).await,
+        1
+    );
+
+    let approve_account = get_account(&mut setup.context, approve_pda(setup.mint)).await;
+    let approve = ApproveAccount::try_deserialize(&mut &approve_account.data[..]).unwrap();
+    assert_eq!(approve.seller, setup.owner);
+    assert_eq!(approve.buyer, delegate.pubkey());
+    let distribution = distribution_pda(setup.mint, Pubkey::default());
+    let claims = get_claims(&mut setup.context, distribution).await;
+    assert_eq!(claims.len(), 1);
+    assert_eq!(claims[0].1, 500);
+}
+
+#[tokio::test]
+async fn transfer_built_by_the_spl_helper_resolves_the_extra_metas() {
+    let mut setup = setup(mint_args(Some(500))).await;
+    let transfer = resolved_transfer_ix(
+        &mut setup.context,
+        setup.source,
+        setup.mint,
+        setup.destination,
+        setup.owner,
+        1,
+        0,
+    )
+    .await;
+
+    // the approve account is derived from the mint, as the manually built transfer passes it
+    let manual_transfer = transfer_ix(&setup, setup.owner, 1, 0);
+    assert!(transfer
+        .accounts
+        .contains(&AccountMeta::new(approve_pda(setup.mint), false)));
+    assert_eq!(transfer.accounts.len(), manual_transfer.accounts.len());
+
+    process(&mut setup.context, &[transfer], &[]).await.unwrap();
+    assert_eq!(
+        token_balance(&mut setup.context, setup.destination).await,
+        1
+    );
+}
+
+#[tokio::test]
+async fn zero_amount_transfer_needs_no_approval() {
+    let mut setup = setup(mint_args(Some(500))).await;
+
+    // transfers invoked by a program are enforced, this one hasn't been approved
+    let transfer = marketplace_ix(transfer_ix(&setup, setup.owner, 1, 0));
+    assert_metadata_error(
+        process(&mut setup.context, &[transfer], &[]).await,
+        MetadataErrors::ExpiredApproveAccount,
+    );
+
+    let transfer = marketplace_ix(transfer_ix(&setup, setup.owner, 0, 0));
+    process(&mut setup.context, &[transfer], &[]).await.unwrap();
+    assert_eq!(token_balance(&mut setup.context, setup.source).await, 1);
+    assert_eq!(
+        token_balance(&mut setup.context, setup.destination).await,
+        0
+    );
+}
+
+#[tokio::test]
+async fn zero_royalty_transfer_needs_only_the_mandatory_hook_accounts() {
+    let mut setup = setup(mint_args(Some(0))).await;
+
+    // the validation account and the hook program, the meta list of the mint is empty
+    let mut transfer = transfer_checked(
+        &spl_token_2022::id(),
+        &setup.source,
+        &setup.mint,
+        &setup.destination,
+        &setup.owner,
+        &[],
+        1,
+        0,
+    )
+    .unwrap();
+    transfer.accounts.extend([
+        AccountMeta::new_readonly(extra_metas_pda(setup.mint), false),
+        AccountMeta::new_readonly(wen_new_standard::ID, false),
+    ]);
+
+    // invoked by a program, where royalty mints would need an approval
+    process(&mut setup.context, &[marketplace_ix(transfer)], &[])
+        .await
+        .unwrap();
+    assert_eq!(
+        token_balance(&mut setup.context, setup.destination).await,
+        1
+    );
+}
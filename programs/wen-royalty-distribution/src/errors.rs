@@ -6,4 +6,30 @@ pub enum DistributionErrors {
     InvalidGroupAuthority,
     #[msg("Invalid creator pct amount. Must add up to 100")]
     InvalidCreatorPctAmount,
+    #[msg("Metadata account does not match the mint metadata pointer")]
+    InvalidMetadataAccount,
+    #[msg("Payment mint and token accounts are required to claim token royalties")]
+    InvalidPaymentTokenAccounts,
+    #[msg("Claim amount exceeds the claimable balance")]
+    InsufficientClaimableBalance,
+    #[msg("Invalid number of remaining accounts for the batch")]
+    InvalidBatchAccounts,
+    #[msg("Too many distribution accounts in the batch")]
+    TooManyDistributions,
+    #[msg("Distribution account still holds claims or funds")]
+    DistributionNotEmpty,
+    #[msg("Only the distribution payer or authority can close it")]
+    InvalidCloseAuthority,
+    #[msg("Payment mint account does not match the payment mint")]
+    InvalidPaymentMint,
+    #[msg("Distribution token account is not the associated token account of the distribution")]
+    InvalidDistributionTokenAccount,
+    #[msg("Destination does not match the claim destination registered by the creator")]
+    InvalidClaimDestination,
+    #[msg("Mint is neither the distribution collection nor a member of its group")]
+    InvalidDistributionMint,
+    #[msg("Distribution address must be the distribution account for SOL royalties")]
+    InvalidDistributionAddress,
+    #[msg("Arithmetic overflow")]
+    ArithmeticOverflow,
 }
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone)]
+pub struct CreatorPayment {
+    pub address: Pubkey,
+    pub amount: u64,
+}
+
+/// Emitted when a royalty payment is credited to the creators of a mint
+#[event]
+pub struct RoyaltyPaid {
+    pub mint: Pubkey,
+    /// `Pubkey::default()` for SOL
+    pub payment_mint: Pubkey,
+    pub amount: u64,
+    /// amount credited to each creator
+    pub creators: Vec<CreatorPayment>,
+}
+
+/// Emitted once per `claim_distribution_batch` and `push_distribution` with the total claimed
+#[event]
+pub struct DistributionClaimed {
+    pub creator: Pubkey,
+    pub payment_mint: Pubkey,
+    pub amount: u64,
+    /// number of distribution accounts with a non zero claim
+    pub distributions: u32,
+}
@@ -1,67 +1,153 @@
-use anchor_lang::{prelude::*, solana_program::entrypoint::ProgramResult};
-use anchor_spl::{
-    token::{self, Transfer},
-    token_interface::Token2022,
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
 };
 
-use crate::{get_and_clear_creator_royalty_value, DistributionAccount};
+use crate::{
+    claim_creator_royalty_value, ClaimDestination, DistributionErrors, PaymentDistribution,
+    CLAIM_DESTINATION_SEED, DISTRIBUTION_SEED,
+};
 
 #[derive(Accounts)]
-#[instruction()]
+#[instruction(payment_mint: Pubkey, amount: Option<u64>)]
 pub struct ClaimDistribution<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
+    /// CHECK: creator listed in the distribution, signs unless claiming to its registered destination
     #[account(mut)]
-    pub creator: Signer<'info>,
+    pub creator: UncheckedAccount<'info>,
     #[account(
         mut,
-        seeds = [distribution.collection.as_ref()],
+        seeds = [DISTRIBUTION_SEED, distribution.collection.as_ref(), payment_mint.as_ref()],
         bump,
     )]
-    pub distribution: Account<'info, DistributionAccount>,
-    /// CHECK: can be token account or distribution account
-    #[account(mut)]
-    pub distribution_address: UncheckedAccount<'info>,
-    /// CHECK: can be token account or distribution account
+    pub distribution: Account<'info, PaymentDistribution>,
+    /// destination registered by the creator, claims to it are permissionless
+    #[account(
+        seeds = [CLAIM_DESTINATION_SEED, creator.key().as_ref(), payment_mint.as_ref()],
+        bump,
+    )]
+    pub claim_destination: Option<Account<'info, ClaimDestination>>,
+    /// CHECK: registered destination of SOL royalties, only needed along `claim_destination`
     #[account(mut)]
-    pub payer_address: UncheckedAccount<'info>,
-    pub token_program: Program<'info, Token2022>,
+    pub destination: Option<UncheckedAccount<'info>>,
+    /// only needed to claim token royalties
+    #[account(
+        address = payment_mint,
+        mint::token_program = token_program,
+    )]
+    pub payment_mint_account: Option<Box<InterfaceAccount<'info, Mint>>>,
+    /// token account of the distribution, only needed to claim token royalties
+    #[account(
+        mut,
+        associated_token::mint = payment_mint_account,
+        associated_token::authority = distribution,
+        associated_token::token_program = token_program,
+    )]
+    pub distribution_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    /// token account of the creator or its registered destination, only needed to claim token royalties
+    #[account(
+        mut,
+        token::mint = payment_mint_account,
+        token::token_program = token_program,
+    )]
+    pub creator_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
 impl ClaimDistribution<'_> {
-    pub fn transfer_tokens(&self, amount: u64, signer_seeds: &[&[&[u8]]]) -> ProgramResult {
-        let cpi_accounts = Transfer {
-            from: self.payer_address.to_account_info(),
-            to: self.distribution_address.to_account_info(),
+    /// Registered destination of the creator, the creator must sign without one
+    pub fn get_destination(&self) -> Result<Option<Pubkey>> {
+        match self.claim_destination.as_ref() {
+            Some(claim_destination) => Ok(Some(claim_destination.destination)),
+            None if self.creator.is_signer => Ok(None),
+            None => Err(ErrorCode::AccountNotSigner.into()),
+        }
+    }
+
+    pub fn transfer_sol(&self, amount: u64, destination: Option<Pubkey>) -> Result<()> {
+        let to = match destination {
+            Some(destination) => self
+                .destination
+                .as_ref()
+                .filter(|account| account.key() == destination)
+                .ok_or(DistributionErrors::InvalidClaimDestination)?
+                .to_account_info(),
+            None => self.creator.to_account_info(),
+        };
+        self.distribution.sub_lamports(amount)?;
+        to.add_lamports(amount)?;
+        Ok(())
+    }
+
+    pub fn transfer_tokens(
+        &self,
+        amount: u64,
+        destination: Option<Pubkey>,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        let (Some(mint), Some(from), Some(to)) = (
+            self.payment_mint_account.as_ref(),
+            self.distribution_token_account.as_ref(),
+            self.creator_token_account.as_ref(),
+        ) else {
+            return Err(DistributionErrors::InvalidPaymentTokenAccounts.into());
+        };
+        match destination {
+            Some(destination) if to.key() != destination => {
+                return Err(DistributionErrors::InvalidClaimDestination.into());
+            }
+            None if to.owner != self.creator.key() => {
+                return Err(DistributionErrors::InvalidPaymentTokenAccounts.into());
+            }
+            _ => {}
+        }
+        let cpi_accounts = TransferChecked {
+            from: from.to_account_info(),
+            mint: mint.to_account_info(),
+            to: to.to_account_info(),
             authority: self.distribution.to_account_info(),
         };
         let cpi_program = self.token_program.to_account_info();
         let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
-        token::transfer(cpi_ctx, amount)?;
+        transfer_checked(cpi_ctx, amount, mint.decimals)?;
         Ok(())
     }
 }
 
-pub fn handler(ctx: Context<ClaimDistribution>, payment_mint: Pubkey) -> Result<()> {
+/// Claims `amount` of the royalties of the creator in `payment_mint`, `Pubkey::default()` for SOL.
+/// The whole claimable balance is claimed when `amount` is `None`. Royalties go to the
+/// destination registered by the creator when there is one, anyone can claim them there.
+pub fn handler(
+    ctx: Context<ClaimDistribution>,
+    payment_mint: Pubkey,
+    amount: Option<u64>,
+) -> Result<()> {
+    let destination = ctx.accounts.get_destination()?;
     let mut creators = ctx.accounts.distribution.data.clone();
     let royalty_amount =
-        get_and_clear_creator_royalty_value(ctx.accounts.creator.key(), &mut creators);
+        claim_creator_royalty_value(ctx.accounts.creator.key(), amount, &mut creators)?;
 
     if royalty_amount == 0 {
         return Ok(()); // No royalties to claim
     }
 
-    let signer_seeds = &[ctx.accounts.distribution.collection.as_ref()][..];
+    let collection = ctx.accounts.distribution.collection;
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        DISTRIBUTION_SEED,
+        collection.as_ref(),
+        payment_mint.as_ref(),
+        &[ctx.bumps.distribution],
+    ]];
 
-    if payment_mint.key() == Pubkey::default() {
-        ctx.accounts.distribution.sub_lamports(royalty_amount)?;
-        ctx.accounts.creator.add_lamports(royalty_amount)?;
+    if payment_mint == Pubkey::default() {
+        ctx.accounts.transfer_sol(royalty_amount, destination)?;
     } else {
         ctx.accounts
-            .transfer_tokens(royalty_amount, &[signer_seeds])?;
+            .transfer_tokens(royalty_amount, destination, signer_seeds)?;
     }
 
-    // update distribution account
+    // update distribution account, only persisted if the transfer succeeded
     ctx.accounts.distribution.data = creators;
 
     Ok(())
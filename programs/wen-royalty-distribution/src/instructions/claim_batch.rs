@@ -0,0 +1,156 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::get_associated_token_address_with_program_id,
+    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+use crate::{
+    claim_creator_royalty_value, DistributionClaimed, DistributionErrors, PaymentDistribution,
+    DISTRIBUTION_SEED,
+};
+
+/// Maximum number of distribution accounts claimed by a single `claim_distribution_batch`, bounded by compute
+pub const MAX_CLAIM_BATCH_SIZE: usize = 10;
+
+/// Claims all the royalties of the creator in `payment_mint` from several distribution accounts
+/// into a single destination, the creator for SOL or `creator_token_account` for tokens.
+///
+/// `remaining_accounts` holds the distribution accounts (writable) for SOL, and pairs of
+/// distribution account and distribution token account (writable) for tokens. At most
+/// `MAX_CLAIM_BATCH_SIZE` distributions can be claimed at once, distributions with nothing
+/// to claim are skipped.
+#[derive(Accounts)]
+#[instruction(payment_mint: Pubkey)]
+pub struct ClaimDistributionBatch<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    /// only needed to claim token royalties
+    #[account(
+        address = payment_mint,
+        mint::token_program = token_program,
+    )]
+    pub payment_mint_account: Option<Box<InterfaceAccount<'info, Mint>>>,
+    /// token account of the creator, only needed to claim token royalties
+    #[account(
+        mut,
+        token::mint = payment_mint_account,
+        token::authority = creator,
+        token::token_program = token_program,
+    )]
+    pub creator_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> ClaimDistributionBatch<'info> {
+    /// Claims from a single distribution account, returns the claimed amount
+    fn claim(&self, accounts: &[AccountInfo<'info>], payment_mint: Pubkey) -> Result<u64> {
+        let distribution_info = &accounts[0];
+        let mut distribution = Account::<PaymentDistribution>::try_from(distribution_info)?;
+        let (expected_distribution, bump) = Pubkey::find_program_address(
+            &[
+                DISTRIBUTION_SEED,
+                distribution.collection.as_ref(),
+                payment_mint.as_ref(),
+            ],
+            &crate::id(),
+        );
+        if distribution_info.key() != expected_distribution {
+            return Err(anchor_lang::error::ErrorCode::ConstraintSeeds.into());
+        }
+
+        let mut creators = distribution.data.clone();
+        let royalty_amount = claim_creator_royalty_value(self.creator.key(), None, &mut creators)?;
+        if royalty_amount == 0 {
+            return Ok(0);
+        }
+
+        if payment_mint == Pubkey::default() {
+            distribution_info.sub_lamports(royalty_amount)?;
+            self.creator.add_lamports(royalty_amount)?;
+        } else {
+            let (Some(mint), Some(to), Some(from)) = (
+                self.payment_mint_account.as_ref(),
+                self.creator_token_account.as_ref(),
+                accounts.get(1),
+            ) else {
+                return Err(DistributionErrors::InvalidPaymentTokenAccounts.into());
+            };
+            let expected_token_account = get_associated_token_address_with_program_id(
+                &distribution_info.key(),
+                &payment_mint,
+                &self.token_program.key(),
+            );
+            if from.key() != expected_token_account {
+                return Err(DistributionErrors::InvalidPaymentTokenAccounts.into());
+            }
+
+            let collection = distribution.collection;
+            let signer_seeds: &[&[&[u8]]] = &[&[
+                DISTRIBUTION_SEED,
+                collection.as_ref(),
+                payment_mint.as_ref(),
+                &[bump],
+            ]];
+            let cpi_accounts = TransferChecked {
+                from: from.clone(),
+                mint: mint.to_account_info(),
+                to: to.to_account_info(),
+                authority: distribution_info.clone(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                cpi_accounts,
+                signer_seeds,
+            );
+            transfer_checked(cpi_ctx, royalty_amount, mint.decimals)?;
+        }
+
+        // update distribution account
+        distribution.data = creators;
+        distribution.exit(&crate::id())?;
+
+        Ok(royalty_amount)
+    }
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ClaimDistributionBatch<'info>>,
+    payment_mint: Pubkey,
+) -> Result<()> {
+    let chunk_size = if payment_mint == Pubkey::default() {
+        1
+    } else {
+        2
+    };
+    let remaining_accounts = ctx.remaining_accounts;
+    if remaining_accounts.len() % chunk_size != 0 {
+        return Err(DistributionErrors::InvalidBatchAccounts.into());
+    }
+    if remaining_accounts.len() / chunk_size > MAX_CLAIM_BATCH_SIZE {
+        return Err(DistributionErrors::TooManyDistributions.into());
+    }
+
+    let mut total_amount: u64 = 0;
+    let mut distributions: u32 = 0;
+    for (index, accounts) in remaining_accounts.chunks(chunk_size).enumerate() {
+        let amount = ctx.accounts.claim(accounts, payment_mint).map_err(|err| {
+            msg!("Failed to claim distribution at batch index {}", index);
+            err
+        })?;
+        if amount > 0 {
+            total_amount += amount;
+            distributions += 1;
+        }
+    }
+
+    emit!(DistributionClaimed {
+        creator: ctx.accounts.creator.key(),
+        payment_mint,
+        amount: total_amount,
+        distributions,
+    });
+
+    Ok(())
+}
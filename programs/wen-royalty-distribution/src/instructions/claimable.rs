@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+use crate::{compute_claimable, PaymentDistribution};
+
+#[derive(Accounts)]
+#[instruction(creator: Pubkey, payment_mint: Option<Pubkey>)]
+pub struct GetClaimable<'info> {
+    pub distribution: Account<'info, PaymentDistribution>,
+}
+
+/// Returns the claimable royalties of `creator` as return data, 0 when `payment_mint` isn't
+/// the payment mint of the distribution
+pub fn handler(
+    ctx: Context<GetClaimable>,
+    creator: Pubkey,
+    payment_mint: Option<Pubkey>,
+) -> Result<u64> {
+    Ok(compute_claimable(
+        &ctx.accounts.distribution,
+        &creator,
+        payment_mint.as_ref(),
+    ))
+}
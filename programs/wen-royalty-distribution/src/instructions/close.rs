@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::{DistributionErrors, PaymentDistribution, DISTRIBUTION_SEED};
+
+/// Closes a distribution account once every creator claimed, a new distribution can be
+/// initialized for the collection and payment mint afterwards. Its whole balance goes to the
+/// rent destination.
+#[derive(Accounts)]
+#[instruction()]
+pub struct CloseDistribution<'info> {
+    /// payer recorded at initialization or authority of the distribution
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            DISTRIBUTION_SEED,
+            distribution.collection.as_ref(),
+            distribution.payment_mint.as_ref(),
+        ],
+        bump,
+        constraint = authority.key() == distribution.payer
+            || authority.key() == distribution.authority @ DistributionErrors::InvalidCloseAuthority,
+        close = rent_destination,
+    )]
+    pub distribution: Account<'info, PaymentDistribution>,
+    /// CHECK: receives the distribution rent, can be any account
+    #[account(mut)]
+    pub rent_destination: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<CloseDistribution>) -> Result<()> {
+    // SOL royalties held by the distribution account are all claimed once no creator is owed
+    // anything, lamports sent to the account on top of its rent go to the rent destination
+    let distribution = &ctx.accounts.distribution;
+    if distribution.data.iter().any(|creator| creator.amount > 0) {
+        return Err(DistributionErrors::DistributionNotEmpty.into());
+    }
+
+    Ok(())
+}
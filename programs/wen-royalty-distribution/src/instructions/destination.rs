@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::{ClaimDestination, CLAIM_DESTINATION_SEED};
+
+#[derive(Accounts)]
+#[instruction(payment_mint: Pubkey)]
+pub struct SetClaimDestination<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub creator: Signer<'info>,
+    #[account(
+        init_if_needed,
+        seeds = [CLAIM_DESTINATION_SEED, creator.key().as_ref(), payment_mint.as_ref()],
+        bump,
+        payer = payer,
+        space = ClaimDestination::LEN
+    )]
+    pub claim_destination: Account<'info, ClaimDestination>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Registers where the royalties of the creator in `payment_mint` are claimed to, anyone can
+/// claim them to `destination` afterwards. Setting it again changes the destination.
+pub fn handler(
+    ctx: Context<SetClaimDestination>,
+    payment_mint: Pubkey,
+    destination: Pubkey,
+) -> Result<()> {
+    let claim_destination = &mut ctx.accounts.claim_destination;
+    claim_destination.creator = ctx.accounts.creator.key();
+    claim_destination.payment_mint = payment_mint;
+    claim_destination.destination = destination;
+    Ok(())
+}
@@ -1,11 +1,11 @@
 use anchor_lang::prelude::*;
 
-use crate::DistributionAccount;
+use crate::{PaymentDistribution, DISTRIBUTION_SEED};
 
 use anchor_spl::token_interface::Mint;
 
 #[derive(Accounts)]
-#[instruction()]
+#[instruction(payment_mint: Pubkey)]
 pub struct InitializeDistribution<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
@@ -18,18 +18,22 @@ pub struct InitializeDistribution<'info> {
     pub mint: Box<InterfaceAccount<'info, Mint>>,
     #[account(
         init,
-        seeds = [mint.key().as_ref()],
+        seeds = [DISTRIBUTION_SEED, mint.key().as_ref(), payment_mint.as_ref()],
         bump,
         payer = payer,
-        space = DistributionAccount::LEN
+        space = PaymentDistribution::LEN
     )]
-    pub distribution: Box<Account<'info, DistributionAccount>>,
+    pub distribution: Box<Account<'info, PaymentDistribution>>,
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<InitializeDistribution>) -> Result<()> {
+/// Initializes the distribution of the collection royalties paid in `payment_mint`,
+/// `Pubkey::default()` for SOL
+pub fn handler(ctx: Context<InitializeDistribution>, payment_mint: Pubkey) -> Result<()> {
     ctx.accounts.distribution.data = vec![];
     ctx.accounts.distribution.authority = ctx.accounts.authority.key();
+    ctx.accounts.distribution.payer = ctx.accounts.payer.key();
     ctx.accounts.distribution.collection = ctx.accounts.mint.key();
+    ctx.accounts.distribution.payment_mint = payment_mint;
     Ok(())
 }
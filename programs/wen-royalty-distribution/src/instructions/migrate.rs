@@ -0,0 +1,177 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{program::invoke, system_instruction::transfer},
+};
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::{
+    CreatorClaim, DistributionErrors, LegacyDistributionAccount, PaymentDistribution,
+    DISTRIBUTION_SEED,
+};
+
+/// Moves the claims and balance of a distribution of the previous layout to the distribution
+/// of the collection for `payment_mint`, then closes the previous distribution. Claims of the
+/// previous layout don't record their payment mint, they are all migrated as `payment_mint`
+/// royalties, SOL for `Pubkey::default()`.
+#[derive(Accounts)]
+#[instruction(payment_mint: Pubkey)]
+pub struct MigrateDistribution<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [legacy_distribution.collection.as_ref()],
+        bump,
+    )]
+    pub legacy_distribution: Box<Account<'info, LegacyDistributionAccount>>,
+    /// CHECK: authority of the previous distribution, refunded its rent since its payer isn't recorded
+    #[account(
+        mut,
+        address = legacy_distribution.authority,
+    )]
+    pub legacy_authority: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        seeds = [
+            DISTRIBUTION_SEED,
+            legacy_distribution.collection.as_ref(),
+            payment_mint.as_ref(),
+        ],
+        bump,
+        payer = payer,
+        space = PaymentDistribution::LEN
+    )]
+    pub distribution: Box<Account<'info, PaymentDistribution>>,
+    /// only needed to migrate token royalties
+    #[account(
+        address = payment_mint,
+        mint::token_program = token_program,
+    )]
+    pub payment_mint_account: Option<Box<InterfaceAccount<'info, Mint>>>,
+    /// token account of the previous distribution, only needed to migrate token royalties
+    #[account(
+        mut,
+        associated_token::mint = payment_mint_account,
+        associated_token::authority = legacy_distribution,
+        associated_token::token_program = token_program,
+    )]
+    pub legacy_distribution_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    /// token account of the distribution, only needed to migrate token royalties
+    #[account(
+        mut,
+        associated_token::mint = payment_mint_account,
+        associated_token::authority = distribution,
+        associated_token::token_program = token_program,
+    )]
+    pub distribution_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl MigrateDistribution<'_> {
+    pub fn transfer_tokens(&self, amount: u64, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        let (Some(mint), Some(from), Some(to)) = (
+            self.payment_mint_account.as_ref(),
+            self.legacy_distribution_token_account.as_ref(),
+            self.distribution_token_account.as_ref(),
+        ) else {
+            return Err(DistributionErrors::InvalidPaymentTokenAccounts.into());
+        };
+        let cpi_accounts = TransferChecked {
+            from: from.to_account_info(),
+            mint: mint.to_account_info(),
+            to: to.to_account_info(),
+            authority: self.legacy_distribution.to_account_info(),
+        };
+        let cpi_program = self.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+        transfer_checked(cpi_ctx, amount, mint.decimals)?;
+        Ok(())
+    }
+
+    /// Reallocates the distribution account, the payer funds the added rent
+    pub fn realloc_distribution(&self, new_size: usize) -> Result<()> {
+        let distribution = self.distribution.to_account_info();
+        let rent = Rent::get()?;
+        let current_rent = rent.minimum_balance(distribution.data_len());
+        let new_rent = rent.minimum_balance(new_size);
+        distribution.realloc(new_size, false)?;
+        if new_rent > current_rent {
+            invoke(
+                &transfer(self.payer.key, distribution.key, new_rent - current_rent),
+                &[
+                    self.payer.to_account_info(),
+                    distribution,
+                    self.system_program.to_account_info(),
+                ],
+            )?;
+        }
+        Ok(())
+    }
+}
+
+pub fn handler(ctx: Context<MigrateDistribution>, payment_mint: Pubkey) -> Result<()> {
+    let collection = ctx.accounts.legacy_distribution.collection;
+    let migrated_creators = ctx.accounts.legacy_distribution.data.clone();
+    let amount = migrated_creators
+        .iter()
+        .try_fold(0u64, |sum, creator| sum.checked_add(creator.amount))
+        .ok_or(DistributionErrors::ArithmeticOverflow)?;
+
+    // distributions initialized since the upgrade already hold claims, they are merged
+    if ctx.accounts.distribution.collection == Pubkey::default() {
+        let authority = ctx.accounts.legacy_distribution.authority;
+        let payer = ctx.accounts.payer.key();
+        let distribution = &mut ctx.accounts.distribution;
+        distribution.collection = collection;
+        distribution.payment_mint = payment_mint;
+        distribution.authority = authority;
+        distribution.payer = payer;
+    }
+    let mut new_data = ctx.accounts.distribution.data.clone();
+    for creator in migrated_creators
+        .iter()
+        .filter(|creator| creator.amount > 0)
+    {
+        match new_data
+            .iter_mut()
+            .find(|current_creator| current_creator.address == creator.address)
+        {
+            Some(current_creator) => {
+                current_creator.amount = current_creator
+                    .amount
+                    .checked_add(creator.amount)
+                    .ok_or(DistributionErrors::ArithmeticOverflow)?
+            }
+            None => new_data.push(CreatorClaim {
+                address: creator.address,
+                amount: creator.amount,
+            }),
+        }
+    }
+    let new_size = PaymentDistribution::size_of(new_data.len()).max(PaymentDistribution::LEN);
+    if new_size > ctx.accounts.distribution.to_account_info().data_len() {
+        ctx.accounts.realloc_distribution(new_size)?;
+    }
+    ctx.accounts.distribution.data = new_data;
+
+    // claimable balances move along their claims
+    if amount > 0 {
+        if payment_mint == Pubkey::default() {
+            ctx.accounts.legacy_distribution.sub_lamports(amount)?;
+            ctx.accounts.distribution.add_lamports(amount)?;
+        } else {
+            let signer_seeds: &[&[&[u8]]] =
+                &[&[collection.as_ref(), &[ctx.bumps.legacy_distribution]]];
+            ctx.accounts.transfer_tokens(amount, signer_seeds)?;
+        }
+    }
+
+    ctx.accounts
+        .legacy_distribution
+        .close(ctx.accounts.legacy_authority.to_account_info())?;
+
+    Ok(())
+}
@@ -1,9 +1,21 @@
 #![allow(ambiguous_glob_reexports)]
 
 pub mod claim;
+pub mod claim_batch;
+pub mod claimable;
+pub mod close;
+pub mod destination;
 pub mod initialize;
+pub mod migrate;
+pub mod push;
 pub mod update;
 
 pub use claim::*;
+pub use claim_batch::*;
+pub use claimable::*;
+pub use close::*;
+pub use destination::*;
 pub use initialize::*;
+pub use migrate::*;
+pub use push::*;
 pub use update::*;
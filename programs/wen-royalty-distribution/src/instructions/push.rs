@@ -0,0 +1,156 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::get_associated_token_address_with_program_id,
+    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+use crate::{
+    claim_creator_royalty_value, ClaimDestination, DistributionClaimed, DistributionErrors,
+    PaymentDistribution, CLAIM_DESTINATION_SEED, DISTRIBUTION_SEED,
+};
+
+/// Pays all the royalties of a creator in `payment_mint` to the creator without its signature,
+/// so creators that can't sign such as program owned PDAs (e.g. DAO treasuries) get paid.
+/// SOL is credited to the creator account and tokens to the creator associated token account,
+/// or to the destination registered by the creator when there is one. Anyone can push since
+/// the royalties can't be paid anywhere else.
+#[derive(Accounts)]
+#[instruction(payment_mint: Pubkey)]
+pub struct PushDistribution<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: creator listed in the distribution, can be a PDA of any program
+    #[account(mut)]
+    pub creator: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [DISTRIBUTION_SEED, distribution.collection.as_ref(), payment_mint.as_ref()],
+        bump,
+    )]
+    pub distribution: Account<'info, PaymentDistribution>,
+    /// CHECK: destination PDA registered by the creator, uninitialized when the creator never registered one
+    #[account(
+        seeds = [CLAIM_DESTINATION_SEED, creator.key().as_ref(), payment_mint.as_ref()],
+        bump,
+    )]
+    pub claim_destination: UncheckedAccount<'info>,
+    /// CHECK: registered destination of SOL royalties, only needed when the creator registered one
+    #[account(mut)]
+    pub destination: Option<UncheckedAccount<'info>>,
+    /// only needed to push token royalties
+    #[account(
+        address = payment_mint,
+        mint::token_program = token_program,
+    )]
+    pub payment_mint_account: Option<Box<InterfaceAccount<'info, Mint>>>,
+    /// token account of the distribution, only needed to push token royalties
+    #[account(
+        mut,
+        associated_token::mint = payment_mint_account,
+        associated_token::authority = distribution,
+        associated_token::token_program = token_program,
+    )]
+    pub distribution_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    /// associated token account of the creator or its registered destination, only needed to
+    /// push token royalties
+    #[account(
+        mut,
+        token::mint = payment_mint_account,
+        token::token_program = token_program,
+    )]
+    pub creator_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl PushDistribution<'_> {
+    pub fn transfer_sol(&self, amount: u64, destination: Option<Pubkey>) -> Result<()> {
+        let to = match destination {
+            Some(destination) => self
+                .destination
+                .as_ref()
+                .filter(|account| account.key() == destination)
+                .ok_or(DistributionErrors::InvalidClaimDestination)?
+                .to_account_info(),
+            None => self.creator.to_account_info(),
+        };
+        // crediting lamports doesn't require the creator to sign, nor to be system owned
+        self.distribution.sub_lamports(amount)?;
+        to.add_lamports(amount)?;
+        Ok(())
+    }
+
+    pub fn transfer_tokens(
+        &self,
+        amount: u64,
+        destination: Option<Pubkey>,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        let (Some(mint), Some(from), Some(to)) = (
+            self.payment_mint_account.as_ref(),
+            self.distribution_token_account.as_ref(),
+            self.creator_token_account.as_ref(),
+        ) else {
+            return Err(DistributionErrors::InvalidPaymentTokenAccounts.into());
+        };
+        let expected_destination = destination.unwrap_or_else(|| {
+            get_associated_token_address_with_program_id(
+                self.creator.key,
+                &mint.key(),
+                self.token_program.key,
+            )
+        });
+        if to.key() != expected_destination {
+            return Err(DistributionErrors::InvalidClaimDestination.into());
+        }
+        let cpi_accounts = TransferChecked {
+            from: from.to_account_info(),
+            mint: mint.to_account_info(),
+            to: to.to_account_info(),
+            authority: self.distribution.to_account_info(),
+        };
+        let cpi_program = self.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+        transfer_checked(cpi_ctx, amount, mint.decimals)?;
+        Ok(())
+    }
+}
+
+pub fn handler(ctx: Context<PushDistribution>, payment_mint: Pubkey) -> Result<()> {
+    // royalties can only be pushed where the creator would claim them
+    let destination = ClaimDestination::load(&ctx.accounts.claim_destination.to_account_info())?
+        .map(|claim_destination| claim_destination.destination);
+    let mut creators = ctx.accounts.distribution.data.clone();
+    let royalty_amount =
+        claim_creator_royalty_value(ctx.accounts.creator.key(), None, &mut creators)?;
+
+    if royalty_amount == 0 {
+        return Ok(()); // No royalties to push
+    }
+
+    let collection = ctx.accounts.distribution.collection;
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        DISTRIBUTION_SEED,
+        collection.as_ref(),
+        payment_mint.as_ref(),
+        &[ctx.bumps.distribution],
+    ]];
+
+    if payment_mint == Pubkey::default() {
+        ctx.accounts.transfer_sol(royalty_amount, destination)?;
+    } else {
+        ctx.accounts
+            .transfer_tokens(royalty_amount, destination, signer_seeds)?;
+    }
+
+    // update distribution account, only persisted if the transfer succeeded
+    ctx.accounts.distribution.data = creators;
+
+    emit!(DistributionClaimed {
+        creator: ctx.accounts.creator.key(),
+        payment_mint,
+        amount: royalty_amount,
+        distributions: 1,
+    });
+
+    Ok(())
+}
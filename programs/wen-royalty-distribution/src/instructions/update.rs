@@ -2,23 +2,18 @@ use std::str::FromStr;
 
 use anchor_lang::{
     prelude::*,
-    solana_program::{entrypoint::ProgramResult, program::invoke, system_instruction::transfer},
+    solana_program::{program::invoke, system_instruction::transfer},
 };
 
 use anchor_spl::{
-    associated_token::AssociatedToken,
-    token::{self, Transfer},
-    token_interface::{
-        spl_token_2022::{
-            extension::{BaseStateWithExtensions, StateWithExtensions},
-            state::Mint as BaseStateMint,
-        },
-        spl_token_metadata_interface::state::TokenMetadata,
-        Mint, Token2022,
-    },
+    associated_token::{get_associated_token_address_with_program_id, AssociatedToken},
+    token_interface::{transfer_checked, Mint, TokenInterface, TransferChecked},
 };
 
-use crate::{Creator, DistributionAccount, DistributionErrors, ROYALTY_BASIS_POINTS_FIELD};
+use crate::{
+    get_metadata, split_royalty_amount, validate_distribution_mint, CreatorClaim, CreatorPayment,
+    DistributionErrors, PaymentDistribution, RoyaltyPaid, DISTRIBUTION_SEED,
+};
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct CreatorShare {
@@ -32,8 +27,9 @@ pub struct UpdateDistributionArgs {
     pub payment_mint: Pubkey,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
-#[instruction()]
+#[instruction(args: UpdateDistributionArgs)]
 pub struct UpdateDistribution<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
@@ -43,8 +39,12 @@ pub struct UpdateDistribution<'info> {
         mint::token_program = anchor_spl::token_interface::spl_token_2022::id(),
     )]
     pub mint: Box<InterfaceAccount<'info, Mint>>,
-    #[account(mut)]
-    pub distribution: Account<'info, DistributionAccount>,
+    #[account(
+        mut,
+        seeds = [DISTRIBUTION_SEED, distribution.collection.as_ref(), args.payment_mint.as_ref()],
+        bump,
+    )]
+    pub distribution: Account<'info, PaymentDistribution>,
     /// CHECK: can be token account or distribution account
     #[account(mut)]
     pub distribution_address: UncheckedAccount<'info>,
@@ -53,23 +53,78 @@ pub struct UpdateDistribution<'info> {
     pub payer_address: UncheckedAccount<'info>,
     pub system_program: Program<'info, System>,
     pub associated_token_program: Program<'info, AssociatedToken>,
-    pub token_program: Program<'info, Token2022>,
+    pub token_program: Interface<'info, TokenInterface>,
+    /// CHECK: account referenced by the mint metadata pointer, only needed when metadata isn't stored in the mint
+    pub metadata_account: Option<UncheckedAccount<'info>>,
+    /// only needed to pay token royalties
+    #[account(
+        mint::token_program = token_program,
+    )]
+    pub payment_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+    /// CHECK: WNS member account of the mint, only needed when paying the distribution of its group mint
+    pub member: Option<UncheckedAccount<'info>>,
 }
 
 impl UpdateDistribution<'_> {
-    pub fn transfer_royalty_amount(&self, amount: u64) -> ProgramResult {
-        let cpi_accounts = Transfer {
+    /// Transfers token royalties to the associated token account of the distribution for
+    /// the exact payment mint, checked against the payment mint decimals
+    pub fn transfer_royalty_amount(&self, amount: u64, payment_mint: Pubkey) -> Result<()> {
+        let mint = self
+            .payment_mint
+            .as_ref()
+            .filter(|mint| mint.key() == payment_mint)
+            .ok_or(DistributionErrors::InvalidPaymentMint)?;
+        let distribution_token_account = get_associated_token_address_with_program_id(
+            &self.distribution.key(),
+            &payment_mint,
+            &self.token_program.key(),
+        );
+        if self.distribution_address.key() != distribution_token_account {
+            return Err(DistributionErrors::InvalidDistributionTokenAccount.into());
+        }
+
+        let cpi_accounts = TransferChecked {
             from: self.payer_address.to_account_info(),
+            mint: mint.to_account_info(),
             to: self.distribution_address.to_account_info(),
             authority: self.authority.to_account_info(),
         };
         let cpi_program = self.token_program.to_account_info();
         let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-        token::transfer(cpi_ctx, amount)?;
+        transfer_checked(cpi_ctx, amount, mint.decimals)?;
+        Ok(())
+    }
+
+    /// Reallocates the distribution account, the payer funds the added rent and is refunded the
+    /// freed rent, it must be the recorded payer to shrink the account. SOL royalties held by
+    /// the account are left untouched.
+    pub fn realloc_distribution(&self, new_size: usize) -> Result<()> {
+        let distribution = self.distribution.to_account_info();
+        let rent = Rent::get()?;
+        let current_rent = rent.minimum_balance(distribution.data_len());
+        let new_rent = rent.minimum_balance(new_size);
+        distribution.realloc(new_size, false)?;
+        if new_rent > current_rent {
+            invoke(
+                &transfer(self.payer.key, distribution.key, new_rent - current_rent),
+                &[
+                    self.payer.to_account_info(),
+                    distribution,
+                    self.system_program.to_account_info(),
+                ],
+            )?;
+        } else if current_rent > new_rent {
+            distribution.sub_lamports(current_rent - new_rent)?;
+            self.payer.add_lamports(current_rent - new_rent)?;
+        }
         Ok(())
     }
 
-    pub fn transfer_sol(&self, amount: u64) -> ProgramResult {
+    pub fn transfer_sol(&self, amount: u64) -> Result<()> {
+        // SOL royalties are held by the distribution account itself
+        if self.distribution_address.key() != self.distribution.key() {
+            return Err(DistributionErrors::InvalidDistributionAddress.into());
+        }
         invoke(
             &transfer(
                 self.payer_address.key,
@@ -87,77 +142,100 @@ impl UpdateDistribution<'_> {
 }
 
 pub fn handler(ctx: Context<UpdateDistribution>, args: UpdateDistributionArgs) -> Result<()> {
-    let mint_account = ctx.accounts.mint.to_account_info();
-    let mint_account_data = mint_account.try_borrow_data()?;
-    let mint_data = StateWithExtensions::<BaseStateMint>::unpack(&mint_account_data)?;
-    let metadata = mint_data.get_variable_len_extension::<TokenMetadata>()?;
+    // only the collection mint and its group members pay the distribution
+    let member = ctx
+        .accounts
+        .member
+        .as_ref()
+        .map(|member| member.to_account_info());
+    validate_distribution_mint(
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.distribution.collection,
+        member.as_ref(),
+    )?;
+
+    let metadata = get_metadata(
+        &ctx.accounts.mint.to_account_info(),
+        ctx.accounts
+            .metadata_account
+            .as_ref()
+            .map(|metadata_account| metadata_account.as_ref()),
+    )?;
 
-    // get all creators from metadata Vec(String, String), only royalty_basis_points needs to be removed
-    // TODO: If creator adds other extra metadata or an invalid pubkey they will be unable to pay royalties. Should handle this gracefully, although this is not possible currently.
+    // creators are the metadata keys parsing as pubkeys with a share as value, other metadata
+    // like royalty_basis_points, edition numbers or content hashes is skipped
     let creators = metadata
         .additional_metadata
         .iter()
-        .filter(|(key, _)| key != ROYALTY_BASIS_POINTS_FIELD)
-        .map(|(key, value)| CreatorShare {
-            address: Pubkey::from_str(key).unwrap(),
-            pct: u8::from_str(value).unwrap(),
+        .filter_map(|(key, value)| {
+            Some(CreatorShare {
+                address: Pubkey::from_str(key).ok()?,
+                pct: u8::from_str(value).ok()?,
+            })
         })
         .collect::<Vec<CreatorShare>>();
 
-    // update creator amounts in distribution account. add creator if not present, else update amount (amount * pct / 100)
-    let current_data = ctx.accounts.distribution.data.clone();
-    let mut new_data = vec![];
-    let mut pct_sum: u8 = 0;
+    // update creator amounts in the distribution account of the payment mint. add creator if not present, else update amount (amount * pct / 100)
+    let mut new_data = ctx.accounts.distribution.data.clone();
+    let shares = split_royalty_amount(
+        args.amount,
+        &creators
+            .iter()
+            .map(|creator| creator.pct)
+            .collect::<Vec<u8>>(),
+    )?;
+    let mut payments = vec![];
     // Incoming creator updates
-    for creator in creators.iter() {
-        pct_sum += creator.pct;
-        let mut creator_found = false;
-        for current_creator in current_data.iter() {
-            if creator.address == current_creator.address {
-                creator_found = true;
-                new_data.push(Creator {
-                    address: creator.address,
-                    amount: current_creator.amount + (args.amount * creator.pct as u64 / 100),
-                });
-                break;
+    for (creator, amount) in creators.iter().zip(shares) {
+        payments.push(CreatorPayment {
+            address: creator.address,
+            amount,
+        });
+        match new_data
+            .iter_mut()
+            .find(|current_creator| current_creator.address == creator.address)
+        {
+            Some(current_creator) => {
+                current_creator.amount = current_creator
+                    .amount
+                    .checked_add(amount)
+                    .ok_or(DistributionErrors::ArithmeticOverflow)?
             }
-        }
-        if !creator_found {
-            new_data.push(Creator {
+            None => new_data.push(CreatorClaim {
                 address: creator.address,
-                amount: args.amount * creator.pct as u64 / 100,
-            });
+                amount,
+            }),
         }
     }
-    for orig_creator in current_data.iter() {
-        let mut creator_found = false;
-        for added_creator in new_data.iter() {
-            if orig_creator.address == added_creator.address {
-                creator_found = true;
-                break;
-            }
-        }
-        if !creator_found {
-            // Check if creator is still owed anything
-            if orig_creator.amount > 0 {
-                new_data.push(Creator {
-                    address: orig_creator.address,
-                    amount: orig_creator.amount,
-                });
-            }
-            // If not, they don't need to take up the space.
-        }
+    // creators that aren't owed anything don't need to take up the space.
+    new_data.retain(|creator| creator.amount > 0);
+
+    // grow the account when needed and shrink it back down to its initial size as claims are
+    // emptied, the freed rent goes back to the payer recorded at initialization so the account
+    // only shrinks when that payer pays the update
+    let current_size = ctx.accounts.distribution.to_account_info().data_len();
+    let mut new_size = PaymentDistribution::size_of(new_data.len()).max(PaymentDistribution::LEN);
+    if new_size < current_size && ctx.accounts.payer.key() != ctx.accounts.distribution.payer {
+        new_size = current_size;
     }
-    if pct_sum != 100 {
-        return Err(DistributionErrors::InvalidCreatorPctAmount.into());
+    if new_size != current_size {
+        ctx.accounts.realloc_distribution(new_size)?;
     }
     ctx.accounts.distribution.data = new_data;
 
     if args.payment_mint == Pubkey::default() {
         ctx.accounts.transfer_sol(args.amount)?;
     } else {
-        ctx.accounts.transfer_royalty_amount(args.amount)?;
+        ctx.accounts
+            .transfer_royalty_amount(args.amount, args.payment_mint)?;
     }
 
+    emit_cpi!(RoyaltyPaid {
+        mint: ctx.accounts.mint.key(),
+        payment_mint: args.payment_mint,
+        amount: args.amount,
+        creators: payments,
+    });
+
     Ok(())
 }
@@ -1,11 +1,13 @@
 use anchor_lang::prelude::*;
 
 pub mod errors;
+pub mod events;
 pub mod instructions;
 pub mod state;
 pub mod utils;
 
 pub use errors::*;
+pub use events::*;
 pub use instructions::*;
 pub use state::*;
 pub use utils::*;
@@ -17,12 +19,17 @@ pub mod wen_royalty_distribution {
 
     use super::*;
 
-    /// Initializes a new distribution account.
-    pub fn initialize_distribution(ctx: Context<InitializeDistribution>) -> Result<()> {
-        instructions::initialize::handler(ctx)
+    /// Initializes a new distribution account for a payment mint of the collection.
+    pub fn initialize_distribution(
+        ctx: Context<InitializeDistribution>,
+        payment_mint: Pubkey,
+    ) -> Result<()> {
+        instructions::initialize::handler(ctx, payment_mint)
     }
 
     /// Update royalty amount for creators a distribution account.
+    /// Each creator is credited the floor of its share of the amount, the remainder is
+    /// credited one lamport each to creators in the order they're declared in the metadata.
     pub fn update_distribution(
         ctx: Context<UpdateDistribution>,
         args: UpdateDistributionArgs,
@@ -30,8 +37,56 @@ pub mod wen_royalty_distribution {
         instructions::update::handler(ctx, args)
     }
 
-    /// Claim royalties from a distribution account.
-    pub fn claim_distribution(ctx: Context<ClaimDistribution>, payment_mint: Pubkey) -> Result<()> {
-        instructions::claim::handler(ctx, payment_mint)
+    /// Claim royalties from a distribution account, all of them when `amount` is `None`.
+    pub fn claim_distribution(
+        ctx: Context<ClaimDistribution>,
+        payment_mint: Pubkey,
+        amount: Option<u64>,
+    ) -> Result<()> {
+        instructions::claim::handler(ctx, payment_mint, amount)
+    }
+
+    /// Get the royalties a creator can claim from a distribution account as return data.
+    pub fn get_claimable(
+        ctx: Context<GetClaimable>,
+        creator: Pubkey,
+        payment_mint: Option<Pubkey>,
+    ) -> Result<u64> {
+        instructions::claimable::handler(ctx, creator, payment_mint)
+    }
+
+    /// Register where the royalties of the signing creator in a payment mint are claimed to.
+    pub fn set_claim_destination(
+        ctx: Context<SetClaimDestination>,
+        payment_mint: Pubkey,
+        destination: Pubkey,
+    ) -> Result<()> {
+        instructions::destination::handler(ctx, payment_mint, destination)
+    }
+
+    /// Claim all royalties from several distribution accounts at once.
+    pub fn claim_distribution_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimDistributionBatch<'info>>,
+        payment_mint: Pubkey,
+    ) -> Result<()> {
+        instructions::claim_batch::handler(ctx, payment_mint)
+    }
+
+    /// Pay all royalties of a creator that can't sign, such as a PDA, to the creator.
+    pub fn push_distribution(ctx: Context<PushDistribution>, payment_mint: Pubkey) -> Result<()> {
+        instructions::push::handler(ctx, payment_mint)
+    }
+
+    /// Move the claims of a distribution account of the previous layout to a payment mint distribution.
+    pub fn migrate_distribution(
+        ctx: Context<MigrateDistribution>,
+        payment_mint: Pubkey,
+    ) -> Result<()> {
+        instructions::migrate::handler(ctx, payment_mint)
+    }
+
+    /// Close a fully claimed distribution account.
+    pub fn close_distribution(ctx: Context<CloseDistribution>) -> Result<()> {
+        instructions::close::handler(ctx)
     }
 }
@@ -1,20 +1,164 @@
 use anchor_lang::prelude::*;
 
+pub const DISTRIBUTION_SEED: &[u8] = b"distribution";
+pub const CLAIM_DESTINATION_SEED: &[u8] = b"claim_destination";
+/// Number of creator claims a distribution account is initially sized for
+pub const INITIAL_CLAIMS: usize = 10;
+/// WNS program owning the group and member accounts of collections
+pub const WNS_PROGRAM_ID: Pubkey =
+    anchor_lang::solana_program::pubkey!("wns1gDLt8fgLcGhWi5MqAqgXpwEP1JftKE9eZnXS1HM");
+pub const WNS_GROUP_ACCOUNT_SEED: &[u8] = b"group";
+pub const WNS_MEMBER_ACCOUNT_SEED: &[u8] = b"member";
+
 #[derive(Clone, AnchorSerialize, AnchorDeserialize)]
 pub struct Creator {
     pub address: Pubkey,
     pub amount: u64,
 }
 
-#[account()]
-pub struct DistributionAccount {
+/// Distribution of the previous layout mixing every payment mint of a collection, seeded by
+/// the collection alone. Only read by `migrate_distribution`, its layout is frozen to the
+/// bytes of deployed accounts.
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct LegacyDistributionAccount {
     pub collection: Pubkey,
     pub authority: Pubkey,
     pub data: Vec<Creator>,
 }
 
-impl DistributionAccount {
-    pub const LEN: usize = 8 + ((32 + 8) * 10); // 10 pairs of creator tuples
+impl Discriminator for LegacyDistributionAccount {
+    /// discriminator of the deployed `DistributionAccount`, `sha256("account:DistributionAccount")[..8]`
+    const DISCRIMINATOR: [u8; 8] = [98, 90, 112, 65, 49, 161, 198, 154];
+}
+
+impl Owner for LegacyDistributionAccount {
+    fn owner() -> Pubkey {
+        crate::ID
+    }
+}
+
+impl AccountSerialize for LegacyDistributionAccount {
+    fn try_serialize<W: std::io::Write>(&self, writer: &mut W) -> Result<()> {
+        writer
+            .write_all(&Self::DISCRIMINATOR)
+            .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotSerialize)?;
+        AnchorSerialize::serialize(self, writer)
+            .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotSerialize)?;
+        Ok(())
+    }
+}
+
+impl AccountDeserialize for LegacyDistributionAccount {
+    fn try_deserialize(buf: &mut &[u8]) -> Result<Self> {
+        if buf.len() < Self::DISCRIMINATOR.len() {
+            return Err(anchor_lang::error::ErrorCode::AccountDiscriminatorNotFound.into());
+        }
+        if buf[..Self::DISCRIMINATOR.len()] != Self::DISCRIMINATOR {
+            return Err(anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch.into());
+        }
+        Self::try_deserialize_unchecked(buf)
+    }
+
+    fn try_deserialize_unchecked(buf: &mut &[u8]) -> Result<Self> {
+        let mut data: &[u8] = &buf[Self::DISCRIMINATOR.len()..];
+        AnchorDeserialize::deserialize(&mut data)
+            .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotDeserialize.into())
+    }
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct CreatorClaim {
+    pub address: Pubkey,
+    pub amount: u64,
+}
+
+impl CreatorClaim {
+    pub const LEN: usize = 32 + 8;
+}
+
+/// Royalties of a collection in a single payment mint, seeded by the collection and the
+/// payment mint. SOL royalties are held as lamports, token royalties by the associated
+/// token account of the distribution.
+#[account()]
+pub struct PaymentDistribution {
+    pub collection: Pubkey,
+    /// mint of the royalty payments, `Pubkey::default()` for SOL
+    pub payment_mint: Pubkey,
+    pub authority: Pubkey,
+    /// funded the account rent, can close it along the authority
+    pub payer: Pubkey,
+    pub data: Vec<CreatorClaim>,
+}
+
+impl PaymentDistribution {
+    pub const LEN: usize = Self::size_of(INITIAL_CLAIMS);
+
+    pub const fn size_of(claims: usize) -> usize {
+        8 + 32 + 32 + 32 + 32 + 4 + CreatorClaim::LEN * claims
+    }
 }
 
 pub const ROYALTY_BASIS_POINTS_FIELD: &str = "royalty_basis_points";
+
+/// Destination registered by a creator for its royalties in a payment mint, claims to it
+/// don't need the creator signature
+#[account()]
+pub struct ClaimDestination {
+    pub creator: Pubkey,
+    /// mint of the royalty payments, `Pubkey::default()` for SOL
+    pub payment_mint: Pubkey,
+    /// system account for SOL, token account of the payment mint for tokens
+    pub destination: Pubkey,
+}
+
+impl ClaimDestination {
+    pub const LEN: usize = 8 + 32 + 32 + 32;
+
+    /// Loads the destination from its PDA, `None` when the creator never registered one
+    pub fn load(account: &AccountInfo) -> Result<Option<Self>> {
+        if account.data_is_empty() {
+            return Ok(None);
+        }
+        if account.owner != &crate::ID {
+            return Err(ErrorCode::AccountOwnedByWrongProgram.into());
+        }
+        let data = account.try_borrow_data()?;
+        Ok(Some(Self::try_deserialize(&mut &data[..])?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn legacy_distribution_reads_deployed_layout() {
+        let collection = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let creator = Pubkey::new_unique();
+        // discriminator, collection, authority, then the borsh vector of (address, amount)
+        let mut data = anchor_lang::solana_program::hash::hash(b"account:DistributionAccount")
+            .to_bytes()[..8]
+            .to_vec();
+        data.extend_from_slice(collection.as_ref());
+        data.extend_from_slice(authority.as_ref());
+        data.extend_from_slice(&1u32.to_le_bytes());
+        data.extend_from_slice(creator.as_ref());
+        data.extend_from_slice(&42u64.to_le_bytes());
+        // deployed accounts were allocated with trailing zeroes
+        data.resize(8 + (32 + 8) * 10, 0);
+
+        let legacy = LegacyDistributionAccount::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(legacy.collection, collection);
+        assert_eq!(legacy.authority, authority);
+        assert_eq!(legacy.data.len(), 1);
+        assert_eq!(legacy.data[0].address, creator);
+        assert_eq!(legacy.data[0].amount, 42);
+    }
+
+    #[test]
+    fn legacy_distribution_rejects_other_accounts() {
+        let data = PaymentDistribution::DISCRIMINATOR.to_vec();
+        assert!(LegacyDistributionAccount::try_deserialize(&mut &data[..]).is_err());
+    }
+}
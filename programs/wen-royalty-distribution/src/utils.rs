@@ -2,25 +2,134 @@ use anchor_lang::prelude::*;
 
 use anchor_lang::solana_program::{account_info::AccountInfo, pubkey::Pubkey};
 use spl_pod::optional_keys::OptionalNonZeroPubkey;
+use spl_type_length_value::state::{TlvState, TlvStateBorrowed};
 
-use crate::{Creator, DistributionErrors};
+use crate::{
+    CreatorClaim, DistributionErrors, PaymentDistribution, WNS_GROUP_ACCOUNT_SEED,
+    WNS_MEMBER_ACCOUNT_SEED, WNS_PROGRAM_ID,
+};
 
-use anchor_spl::token_interface::spl_token_2022::{
-    extension::{BaseStateWithExtensions, Extension, StateWithExtensions},
-    solana_zk_token_sdk::zk_token_proof_instruction::Pod,
-    state::Mint as BaseStateMint,
+use anchor_spl::token_interface::{
+    spl_token_2022::{
+        extension::{
+            metadata_pointer::MetadataPointer, BaseStateWithExtensions, Extension,
+            StateWithExtensions,
+        },
+        solana_zk_token_sdk::zk_token_proof_instruction::Pod,
+        state::Mint as BaseStateMint,
+    },
+    spl_token_metadata_interface::state::TokenMetadata,
 };
 
-pub fn get_and_clear_creator_royalty_value(address: Pubkey, data: &mut [Creator]) -> u64 {
-    let mut ra = 0;
-    for creator in data.iter_mut() {
-        if creator.address == address {
-            ra = creator.amount;
-            creator.amount = 0;
-            break;
-        }
+/// Royalties `creator` can claim from `distribution`, 0 when `payment_mint` is set and isn't
+/// the payment mint of the distribution. Backs `get_claimable` and can be used off-chain.
+pub fn compute_claimable(
+    distribution: &PaymentDistribution,
+    creator: &Pubkey,
+    payment_mint: Option<&Pubkey>,
+) -> u64 {
+    if payment_mint.is_some_and(|payment_mint| *payment_mint != distribution.payment_mint) {
+        return 0;
+    }
+    distribution
+        .data
+        .iter()
+        .find(|claim| claim.address == *creator)
+        .map_or(0, |claim| claim.amount)
+}
+
+/// Splits `amount` between creators by their percentage `shares`, which must add up to 100.
+/// Each creator is credited the floor of its share, then the remainder lamports, fewer than
+/// the number of creators, are credited one each to creators in declared order. Credited
+/// amounts always add up to `amount` exactly, clients can use it to predict payouts.
+pub fn split_royalty_amount(amount: u64, shares: &[u8]) -> Result<Vec<u64>> {
+    let pct_sum = shares
+        .iter()
+        .try_fold(0u8, |sum, share| sum.checked_add(*share));
+    if pct_sum != Some(100) {
+        return Err(DistributionErrors::InvalidCreatorPctAmount.into());
     }
-    ra
+
+    let mut amounts = shares
+        .iter()
+        .map(|share| -> Result<u64> {
+            let share_amount = u128::from(amount)
+                .checked_mul(u128::from(*share))
+                .ok_or(DistributionErrors::ArithmeticOverflow)?
+                / 100;
+            Ok(u64::try_from(share_amount).map_err(|_| DistributionErrors::ArithmeticOverflow)?)
+        })
+        .collect::<Result<Vec<u64>>>()?;
+    let credited = amounts
+        .iter()
+        .try_fold(0u64, |sum, share_amount| sum.checked_add(*share_amount))
+        .ok_or(DistributionErrors::ArithmeticOverflow)?;
+    let remainder = amount
+        .checked_sub(credited)
+        .ok_or(DistributionErrors::ArithmeticOverflow)?;
+    // the remainder is below the number of creators since each floor loses less than 1
+    for share_amount in amounts.iter_mut().take(remainder as usize) {
+        *share_amount = share_amount
+            .checked_add(1)
+            .ok_or(DistributionErrors::ArithmeticOverflow)?;
+    }
+    Ok(amounts)
+}
+
+/// Royalties of `mint` can only be paid to the distribution of its own `collection`, either the
+/// mint itself or the group mint of the WNS group the mint is a member of, whose `member`
+/// account is then required.
+pub fn validate_distribution_mint(
+    mint: &AccountInfo,
+    collection: &Pubkey,
+    member: Option<&AccountInfo>,
+) -> Result<()> {
+    if mint.key == collection {
+        return Ok(());
+    }
+    let member = member.ok_or(DistributionErrors::InvalidDistributionMint)?;
+    let expected_member = Pubkey::find_program_address(
+        &[WNS_MEMBER_ACCOUNT_SEED, mint.key.as_ref()],
+        &WNS_PROGRAM_ID,
+    )
+    .0;
+    if member.owner != &WNS_PROGRAM_ID || *member.key != expected_member {
+        return Err(DistributionErrors::InvalidDistributionMint.into());
+    }
+    // discriminator, mint and group of the `TokenGroupMember`
+    let member_data = member.try_borrow_data()?;
+    let member_group = member_data
+        .get(40..72)
+        .and_then(|group| Pubkey::try_from(group).ok())
+        .ok_or(DistributionErrors::InvalidDistributionMint)?;
+    let expected_group = Pubkey::find_program_address(
+        &[WNS_GROUP_ACCOUNT_SEED, collection.as_ref()],
+        &WNS_PROGRAM_ID,
+    )
+    .0;
+    if member_group != expected_group {
+        return Err(DistributionErrors::InvalidDistributionMint.into());
+    }
+    Ok(())
+}
+
+/// Deducts `amount` from the creator claim, the whole claim when `None`.
+/// Returns the claimed amount.
+pub fn claim_creator_royalty_value(
+    address: Pubkey,
+    amount: Option<u64>,
+    data: &mut [CreatorClaim],
+) -> Result<u64> {
+    let creator = data.iter_mut().find(|creator| creator.address == address);
+    let claimable = creator.as_ref().map_or(0, |creator| creator.amount);
+    let ra = amount.unwrap_or(claimable);
+    if ra > claimable {
+        return Err(DistributionErrors::InsufficientClaimableBalance.into());
+    }
+    if let Some(creator) = creator {
+        creator.amount -= ra;
+    }
+    Ok(ra)
 }
 
 pub fn get_extension_data<T: Extension + Pod>(account: &mut AccountInfo) -> Result<T> {
@@ -30,6 +139,32 @@ pub fn get_extension_data<T: Extension + Pod>(account: &mut AccountInfo) -> Resu
     Ok(extension_data)
 }
 
+/// Reads the mint metadata from the account referenced by its metadata pointer, either the mint
+/// itself or `metadata_account`
+pub fn get_metadata(
+    mint: &AccountInfo,
+    metadata_account: Option<&AccountInfo>,
+) -> Result<TokenMetadata> {
+    let mint_data = mint.try_borrow_data()?;
+    let mint_with_extension = StateWithExtensions::<BaseStateMint>::unpack(&mint_data)?;
+    let metadata_address = Option::<Pubkey>::from(
+        mint_with_extension
+            .get_extension::<MetadataPointer>()?
+            .metadata_address,
+    )
+    .unwrap_or(*mint.key);
+    if metadata_address == *mint.key {
+        return Ok(mint_with_extension.get_variable_len_extension::<TokenMetadata>()?);
+    }
+
+    let metadata_account = metadata_account
+        .filter(|metadata_account| *metadata_account.key == metadata_address)
+        .ok_or(DistributionErrors::InvalidMetadataAccount)?;
+    let metadata_data = metadata_account.try_borrow_data()?;
+    let state = TlvStateBorrowed::unpack(&metadata_data)?;
+    Ok(state.get_first_variable_len_value::<TokenMetadata>()?)
+}
+
 pub fn get_pubkey_from_optional_nonzero_pubkey(a: OptionalNonZeroPubkey) -> Result<Pubkey> {
     let pubkey = Option::<Pubkey>::from(a).unwrap();
 
@@ -39,3 +174,94 @@ pub fn get_pubkey_from_optional_nonzero_pubkey(a: OptionalNonZeroPubkey) -> Resu
         Ok(pubkey)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SHARES: [&[u8]; 7] = [
+        &[100],
+        &[50, 50],
+        &[33, 33, 34],
+        &[1, 99],
+        &[10, 10, 10, 10, 10, 10, 10, 10, 10, 10],
+        &[97, 1, 1, 1],
+        &[3, 7, 11, 13, 17, 19, 30],
+    ];
+
+    #[test]
+    fn split_royalty_amount_credits_the_whole_amount() {
+        let mut amounts = vec![0, 1, 2, 3, 7, 99, 100, 101, 12_345, 999_999_999];
+        amounts.extend([u64::MAX / 2, u64::MAX - 1, u64::MAX]);
+        // a deterministic spread of amounts on top of the edge cases
+        let mut amount: u64 = 1;
+        for _ in 0..64 {
+            amount = amount
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1);
+            amounts.push(amount);
+        }
+        for shares in SHARES {
+            for amount in amounts.iter() {
+                let split = split_royalty_amount(*amount, shares).unwrap();
+                assert_eq!(split.len(), shares.len());
+                assert_eq!(
+                    split.iter().map(|share| u128::from(*share)).sum::<u128>(),
+                    u128::from(*amount)
+                );
+                // each creator gets its floor share, plus at most one remainder unit
+                for (share_amount, share) in split.iter().zip(shares.iter()) {
+                    let floor = u128::from(*amount) * u128::from(*share) / 100;
+                    assert!(u128::from(*share_amount) - floor <= 1);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn split_royalty_amount_credits_the_remainder_in_declared_order() {
+        // floors of 3 each, the remainder goes to the first creator
+        assert_eq!(
+            split_royalty_amount(10, &[33, 33, 34]).unwrap(),
+            vec![4, 3, 3]
+        );
+        // floors of 0, 0 and 1, the remainder of 2 goes to the first two creators
+        assert_eq!(
+            split_royalty_amount(3, &[33, 33, 34]).unwrap(),
+            vec![1, 1, 1]
+        );
+        assert_eq!(split_royalty_amount(1, &[50, 50]).unwrap(), vec![1, 0]);
+        assert_eq!(split_royalty_amount(1, &[1, 99]).unwrap(), vec![1, 0]);
+    }
+
+    #[test]
+    fn split_royalty_amount_credits_a_single_lamport_to_the_first_creator() {
+        // from a single creator up to the 10 creators a WNS mint can have
+        for creators in 1..=10u8 {
+            let mut shares = vec![100 / creators; usize::from(creators)];
+            shares[0] += 100 % creators;
+            let mut expected = vec![0; usize::from(creators)];
+            expected[0] = 1;
+            assert_eq!(split_royalty_amount(1, &shares).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn split_royalty_amount_does_not_overflow() {
+        assert_eq!(
+            split_royalty_amount(u64::MAX, &[100]).unwrap(),
+            vec![u64::MAX]
+        );
+        let split = split_royalty_amount(u64::MAX, &[1, 99]).unwrap();
+        assert_eq!(split[0].checked_add(split[1]), Some(u64::MAX));
+    }
+
+    #[test]
+    fn split_royalty_amount_rejects_invalid_shares() {
+        assert!(split_royalty_amount(100, &[]).is_err());
+        assert!(split_royalty_amount(100, &[50, 49]).is_err());
+        assert!(split_royalty_amount(100, &[50, 51]).is_err());
+        // the share sum overflows a u8
+        assert!(split_royalty_amount(100, &[200, 100, 212]).is_err());
+    }
+}